@@ -25,7 +25,13 @@ use tracing::{
     instrument,
 };
 
-use crate::submission::ToBlobsError;
+use crate::{
+    envelope::{
+        self,
+        DecodeEnvelopeError,
+    },
+    submission::ToBlobsError,
+};
 
 impl CelestiaClientExt for jsonrpsee::http_client::HttpClient {}
 impl CelestiaClientExt for jsonrpsee::ws_client::WsClient {}
@@ -59,6 +65,7 @@ pub struct BadBlob {
 
 pub enum BadBlobReason {
     Conversion(CelestiaSequencerBlobError),
+    Envelope(DecodeEnvelopeError),
     Deserialization(DecodeError),
     WrongNamespace(Namespace),
 }
@@ -102,9 +109,19 @@ pub trait CelestiaClientExt: BlobClient {
                 });
             }
             'blob: {
+                let data = match envelope::decode(&blob.data) {
+                    Ok(data) => data,
+                    Err(err) => {
+                        bad_blobs.push(BadBlob {
+                            reason: BadBlobReason::Envelope(err),
+                            commitment: blob.commitment,
+                        });
+                        break 'blob;
+                    }
+                };
                 let raw_blob =
                     match astria_core::generated::sequencer::v1::CelestiaSequencerBlob::decode(
-                        &*blob.data,
+                        &*data,
                     ) {
                         Ok(blob) => blob,
                         Err(err) => {
@@ -238,6 +255,7 @@ pub enum BlobAssemblyError {
 ///
 /// Drops a blob under the following conditions:
 /// + the blob's namespace does not match the provided [`Namespace`]
+/// + the blob's envelope cannot be decoded
 /// + cannot be decode/convert to [`CelestiaRollupBlob`]
 /// + block hash does not match that of [`CcelestiaSequencerBlob`]
 /// + the proof, ID, and transactions recorded in the blob cannot be verified against the seuencer
@@ -253,8 +271,19 @@ fn convert_and_filter_rollup_blobs(
             debug!("blob does not belong to expected namespace; skipping");
             continue;
         }
+        let data = match envelope::decode(&blob.data) {
+            Err(e) => {
+                debug!(
+                    error = &e as &dyn std::error::Error,
+                    blob.commitment = %Base64Display::new(&blob.commitment.0, &STANDARD),
+                    "failed decoding blob envelope; skipping"
+                );
+                continue;
+            }
+            Ok(data) => data,
+        };
         let proto_blob =
-            match astria_core::generated::sequencer::v1::CelestiaRollupBlob::decode(&*blob.data) {
+            match astria_core::generated::sequencer::v1::CelestiaRollupBlob::decode(&*data) {
                 Err(e) => {
                     debug!(
                         error = &e as &dyn std::error::Error,
@@ -0,0 +1,143 @@
+//! The versioned envelope wrapping compressed blob data written to Celestia.
+//!
+//! An enveloped blob starts with [`ENVELOPE_MARKER`], followed by a one byte version describing
+//! the encoding of the rest of the blob:
+//!
+//! + version 1: the protobuf encoded blob compressed with zstd.
+//!
+//! Legacy blobs hold the protobuf encoded blob without an envelope. They can never start with
+//! [`ENVELOPE_MARKER`] because a protobuf message cannot start with a field number of zero,
+//! which allows [`decode`] to read both formats.
+
+use std::{
+    borrow::Cow,
+    io::Read as _,
+};
+
+/// The first byte of every enveloped blob.
+pub const ENVELOPE_MARKER: u8 = 0;
+
+/// The envelope version of zstd compressed blobs.
+pub const VERSION_ZSTD: u8 = 1;
+
+/// The zstd compression level used for blobs.
+const ZSTD_LEVEL: i32 = 3;
+
+/// The max number of bytes a blob is decompressed to, protecting against decompression bombs.
+///
+/// Celestia limits the size of a blob to a few megabytes, and protobuf encoded sequencer data
+/// does not compress anywhere near this ratio.
+pub const MAX_DECOMPRESSED_SIZE: u64 = 64 * 1024 * 1024;
+
+#[derive(Debug, thiserror::Error)]
+#[error("failed compressing blob data with zstd")]
+pub struct CompressError(#[source] std::io::Error);
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct DecodeEnvelopeError(DecodeEnvelopeErrorKind);
+
+impl DecodeEnvelopeError {
+    fn missing_version() -> Self {
+        Self(DecodeEnvelopeErrorKind::MissingVersion)
+    }
+
+    fn unknown_version(version: u8) -> Self {
+        Self(DecodeEnvelopeErrorKind::UnknownVersion {
+            version,
+        })
+    }
+
+    fn decompress(source: std::io::Error) -> Self {
+        Self(DecodeEnvelopeErrorKind::Decompress(source))
+    }
+
+    fn too_large() -> Self {
+        Self(DecodeEnvelopeErrorKind::TooLarge)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum DecodeEnvelopeErrorKind {
+    #[error("the blob envelope did not contain a version")]
+    MissingVersion,
+    #[error("the blob envelope has unknown version `{version}`")]
+    UnknownVersion { version: u8 },
+    #[error("failed decompressing blob data with zstd")]
+    Decompress(#[source] std::io::Error),
+    #[error("the blob data decompressed to more than {MAX_DECOMPRESSED_SIZE} bytes")]
+    TooLarge,
+}
+
+/// Compresses the protobuf encoded `payload` and wraps it in an envelope of the latest version.
+///
+/// # Errors
+///
+/// Returns an error if zstd failed compressing `payload`.
+pub fn compress(payload: &[u8]) -> Result<Vec<u8>, CompressError> {
+    let mut enveloped = vec![ENVELOPE_MARKER, VERSION_ZSTD];
+    zstd::stream::copy_encode(payload, &mut enveloped, ZSTD_LEVEL).map_err(CompressError)?;
+    Ok(enveloped)
+}
+
+/// Returns the protobuf encoded payload of blob `data`, which is either enveloped or a legacy
+/// blob.
+///
+/// Legacy blobs are returned as is.
+///
+/// # Errors
+///
+/// Returns an error if the envelope has an unknown version, or if its payload could not be
+/// decompressed.
+pub fn decode(data: &[u8]) -> Result<Cow<'_, [u8]>, DecodeEnvelopeError> {
+    let Some((&ENVELOPE_MARKER, rest)) = data.split_first() else {
+        return Ok(Cow::Borrowed(data));
+    };
+    let Some((&version, payload)) = rest.split_first() else {
+        return Err(DecodeEnvelopeError::missing_version());
+    };
+    match version {
+        VERSION_ZSTD => {
+            let mut decompressed = Vec::new();
+            zstd::stream::read::Decoder::new(payload)
+                .map_err(DecodeEnvelopeError::decompress)?
+                .take(MAX_DECOMPRESSED_SIZE + 1)
+                .read_to_end(&mut decompressed)
+                .map_err(DecodeEnvelopeError::decompress)?;
+            if decompressed.len() as u64 > MAX_DECOMPRESSED_SIZE {
+                return Err(DecodeEnvelopeError::too_large());
+            }
+            Ok(Cow::Owned(decompressed))
+        }
+        other => Err(DecodeEnvelopeError::unknown_version(other)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compressed_payload_is_decoded() {
+        let payload = b"sequencer data ".repeat(100);
+        let enveloped = compress(&payload).unwrap();
+        assert_eq!(&enveloped[..2], &[ENVELOPE_MARKER, VERSION_ZSTD]);
+        assert!(enveloped.len() < payload.len());
+        assert_eq!(decode(&enveloped).unwrap(), payload.as_slice());
+    }
+
+    #[test]
+    fn legacy_blob_is_returned_as_is() {
+        // a protobuf encoded message starting with field 1 of wire type length delimited
+        let legacy = [0x0a, 0x02, 0xff, 0xff];
+        assert!(matches!(decode(&legacy).unwrap(), Cow::Borrowed(data) if data == legacy));
+        assert!(matches!(decode(&[]).unwrap(), Cow::Borrowed(data) if data.is_empty()));
+    }
+
+    #[test]
+    fn malformed_envelopes_are_rejected() {
+        assert!(decode(&[ENVELOPE_MARKER]).is_err());
+        assert!(decode(&[ENVELOPE_MARKER, 2, 1, 2, 3]).is_err());
+        assert!(decode(&[ENVELOPE_MARKER, VERSION_ZSTD, 1, 2, 3]).is_err());
+    }
+}
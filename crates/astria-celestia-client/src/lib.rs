@@ -1,4 +1,5 @@
 pub mod client;
+pub mod envelope;
 pub mod metrics_init;
 pub mod submission;
 
@@ -1,8 +1,9 @@
 /// The Celestia JSON RPC blob API.
 ///
-/// This currently only provides a wrapper for the `blob.Submit` RPC method.
+/// This currently only provides wrappers for the `blob.Submit` and `blob.GetAll` RPC methods.
 use celestia_client::celestia_types::{
     blob::SubmitOptions,
+    nmt::Namespace,
     Blob,
 };
 use jsonrpsee::proc_macros::rpc;
@@ -18,4 +19,11 @@ pub trait Blob {
         blobs: Vec<Blob>,
         opts: SubmitOptions,
     ) -> Result<u64, ErrorObjectOwned>;
+
+    #[method(name = "blob.GetAll")]
+    async fn blob_get_all(
+        &self,
+        height: u64,
+        namespaces: Vec<Namespace>,
+    ) -> Result<Vec<Blob>, ErrorObjectOwned>;
 }
@@ -35,6 +35,8 @@ pub enum Command {
     InitBridgeAccount(InitBridgeAccountArgs),
     /// Command for transferring to a bridge account
     BridgeLock(BridgeLockArgs),
+    /// Command for withdrawing from a bridge account
+    BridgeUnlock(BridgeUnlockArgs),
 }
 
 #[derive(Debug, Subcommand)]
@@ -106,6 +108,10 @@ pub struct InitBridgeAccountArgs {
     /// to initialize the bridge account with.
     #[clap(long)]
     pub(crate) rollup_name: String,
+    /// The address authorized to withdraw from the bridge account.
+    /// Defaults to the bridge account itself.
+    #[clap(long)]
+    pub(crate) withdrawer_address: Option<SequencerAddressArg>,
 }
 
 #[derive(Args, Debug)]
@@ -132,6 +138,34 @@ pub struct BridgeLockArgs {
     pub(crate) sequencer_url: String,
 }
 
+#[derive(Args, Debug)]
+pub struct BridgeUnlockArgs {
+    /// The address of the bridge account to withdraw from
+    #[clap(long)]
+    pub(crate) bridge_address: SequencerAddressArg,
+    /// The address of the Sequencer account to send the withdrawn amount to
+    pub(crate) to_address: SequencerAddressArg,
+    /// The amount being withdrawn
+    #[clap(long)]
+    pub(crate) amount: u128,
+    /// The identifier of the withdrawal event on the rollup
+    #[clap(long)]
+    pub(crate) rollup_withdrawal_event_id: String,
+    // TODO: https://github.com/astriaorg/astria/issues/594
+    // Don't use a plain text private, prefer wrapper like from
+    // the secrecy crate with specialized `Debug` and `Drop` implementations
+    // that overwrite the key on drop and don't reveal it when printing.
+    #[clap(long, env = "SEQUENCER_PRIVATE_KEY")]
+    pub(crate) private_key: String,
+    /// The url of the Sequencer node
+    #[clap(
+        long,
+        env = "SEQUENCER_URL", 
+        default_value = crate::cli::DEFAULT_SEQUENCER_RPC
+    )]
+    pub(crate) sequencer_url: String,
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub(crate) struct SequencerAddressArg(pub(crate) Address);
 
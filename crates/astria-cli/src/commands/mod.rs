@@ -83,6 +83,7 @@ pub async fn run(cli: Cli) -> eyre::Result<()> {
                     sequencer::init_bridge_account(&args).await?;
                 }
                 SequencerCommand::BridgeLock(args) => sequencer::bridge_lock(&args).await?,
+                SequencerCommand::BridgeUnlock(args) => sequencer::bridge_unlock(&args).await?,
             },
         }
     } else {
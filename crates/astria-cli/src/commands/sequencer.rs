@@ -2,9 +2,11 @@ use astria_core::sequencer::v1::{
     transaction::action::{
         Action,
         BridgeLockAction,
+        BridgeUnlockAction,
         InitBridgeAccountAction,
         TransferAction,
     },
+    TransactionParams,
     UnsignedTransaction,
 };
 use astria_sequencer_client::{
@@ -28,6 +30,7 @@ use crate::cli::sequencer::{
     BasicAccountArgs,
     BlockHeightGetArgs,
     BridgeLockArgs,
+    BridgeUnlockArgs,
     InitBridgeAccountArgs,
     TransferArgs,
 };
@@ -196,6 +199,7 @@ pub(crate) async fn init_bridge_account(args: &InitBridgeAccountArgs) -> eyre::R
             rollup_id,
             asset_ids: vec![default_native_asset_id()],
             fee_asset_id: default_native_asset_id(),
+            withdrawer_address: args.withdrawer_address.as_ref().map(|address| address.0),
         }),
     )
     .await
@@ -232,6 +236,30 @@ pub(crate) async fn bridge_lock(args: &BridgeLockArgs) -> eyre::Result<()> {
     Ok(())
 }
 
+pub(crate) async fn bridge_unlock(args: &BridgeUnlockArgs) -> eyre::Result<()> {
+    use astria_core::sequencer::v1::asset::default_native_asset_id;
+
+    let res = submit_transaction(
+        args.sequencer_url.as_str(),
+        args.private_key.as_str(),
+        Action::BridgeUnlock(BridgeUnlockAction {
+            bridge_address: args.bridge_address.0,
+            to: args.to_address.0,
+            amount: args.amount,
+            asset_id: default_native_asset_id(),
+            fee_asset_id: default_native_asset_id(),
+            rollup_withdrawal_event_id: args.rollup_withdrawal_event_id.clone(),
+        }),
+    )
+    .await
+    .wrap_err("failed to submit BridgeUnlock transaction")?;
+
+    ensure!(res.tx_result.code.is_ok(), "error with BridgeUnlock");
+    println!("BridgeUnlock completed!");
+    println!("Included in block: {}", res.height);
+    Ok(())
+}
+
 async fn submit_transaction(
     sequencer_url: &str,
     private_key: &str,
@@ -248,14 +276,21 @@ async fn submit_transaction(
 
     let from_address = Address::from_verification_key(sequencer_key.verification_key());
 
+    let chain_id = sequencer_client
+        .get_chain_id()
+        .await
+        .wrap_err("failed to get chain id")?;
     let nonce_res = sequencer_client
         .get_latest_nonce(from_address)
         .await
         .wrap_err("failed to get nonce")?;
 
     let tx = UnsignedTransaction {
-        nonce: nonce_res.nonce,
         actions: vec![action],
+        params: TransactionParams {
+            nonce: nonce_res.nonce,
+            chain_id,
+        },
     }
     .into_signed(&sequencer_key);
     sequencer_client
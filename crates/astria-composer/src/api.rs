@@ -3,6 +3,7 @@ use std::net::SocketAddr;
 use axum::{
     extract::{
         FromRef,
+        Path,
         State,
     },
     response::{
@@ -11,6 +12,7 @@ use axum::{
     },
     routing::{
         get,
+        post,
         IntoMakeService,
     },
     Router,
@@ -20,7 +22,14 @@ use serde::Serialize;
 use tokio::sync::watch;
 use tracing::debug;
 
-use crate::composer;
+use crate::{
+    composer,
+    executor::{
+        DeadLetter,
+        DeadLetterQueueHandle,
+    },
+    tracker,
+};
 
 pub(super) type ApiServer = axum::Server<AddrIncoming, IntoMakeService<Router>>;
 
@@ -30,6 +39,8 @@ type ComposerStatus = watch::Receiver<composer::Status>;
 #[derive(Clone)]
 struct AppState {
     composer_status: ComposerStatus,
+    tx_tracker: tracker::Handle,
+    dead_letter_queue: DeadLetterQueueHandle,
 }
 
 impl FromRef<AppState> for ComposerStatus {
@@ -38,11 +49,33 @@ impl FromRef<AppState> for ComposerStatus {
     }
 }
 
-pub(super) fn start(listen_addr: SocketAddr, composer_status: ComposerStatus) -> ApiServer {
+impl FromRef<AppState> for tracker::Handle {
+    fn from_ref(app_state: &AppState) -> Self {
+        app_state.tx_tracker.clone()
+    }
+}
+
+impl FromRef<AppState> for DeadLetterQueueHandle {
+    fn from_ref(app_state: &AppState) -> Self {
+        app_state.dead_letter_queue.clone()
+    }
+}
+
+pub(super) fn start(
+    listen_addr: SocketAddr,
+    composer_status: ComposerStatus,
+    tx_tracker: tracker::Handle,
+    dead_letter_queue: DeadLetterQueueHandle,
+) -> ApiServer {
     let app = Router::new()
         .route("/readyz", get(readyz))
+        .route("/transactions/:hash", get(transaction_status))
+        .route("/dead-letters", get(dead_letters))
+        .route("/dead-letters/replay", post(replay_dead_letters))
         .with_state(AppState {
             composer_status,
+            tx_tracker,
+            dead_letter_queue,
         });
     axum::Server::bind(&listen_addr).serve(app.into_make_service())
 }
@@ -82,3 +115,97 @@ async fn readyz(State(composer_status): State<ComposerStatus>) -> Readyz {
         Readyz::NotReady
     }
 }
+
+enum TransactionStatus {
+    Tracked(tracker::TxStatus),
+    NotFound,
+    InvalidHash,
+}
+
+impl IntoResponse for TransactionStatus {
+    fn into_response(self) -> Response {
+        #[derive(Debug, Serialize)]
+        struct TrackedBody {
+            status: &'static str,
+            sequencer_tx_hash: String,
+            sequencer_height: Option<u64>,
+        }
+        #[derive(Debug, Serialize)]
+        struct ErrorBody {
+            status: &'static str,
+        }
+        let (status, mut response) = match self {
+            Self::Tracked(tx_status) => {
+                let body = TrackedBody {
+                    status: if tx_status.sequencer_height.is_some() {
+                        "sequenced"
+                    } else {
+                        "submitted"
+                    },
+                    sequencer_tx_hash: hex::encode(tx_status.sequencer_tx_hash),
+                    sequencer_height: tx_status.sequencer_height,
+                };
+                (axum::http::StatusCode::OK, axum::Json(body).into_response())
+            }
+            Self::NotFound => (
+                axum::http::StatusCode::NOT_FOUND,
+                axum::Json(ErrorBody {
+                    status: "not found",
+                })
+                .into_response(),
+            ),
+            Self::InvalidHash => (
+                axum::http::StatusCode::BAD_REQUEST,
+                axum::Json(ErrorBody {
+                    status: "invalid transaction hash",
+                })
+                .into_response(),
+            ),
+        };
+        *response.status_mut() = status;
+        response
+    }
+}
+
+/// Returns the status of the rollup transaction with the hex encoded keccak256 `hash`, with or
+/// without a `0x` prefix.
+#[allow(clippy::unused_async)]
+async fn transaction_status(
+    State(tx_tracker): State<tracker::Handle>,
+    Path(hash): Path<String>,
+) -> TransactionStatus {
+    debug!(hash, "received transaction status request");
+    let mut rollup_tx_hash = [0u8; 32];
+    let hex_hash = hash.strip_prefix("0x").unwrap_or(&hash);
+    if hex::decode_to_slice(hex_hash, &mut rollup_tx_hash).is_err() {
+        return TransactionStatus::InvalidHash;
+    }
+    tx_tracker
+        .status(&rollup_tx_hash)
+        .map_or(TransactionStatus::NotFound, TransactionStatus::Tracked)
+}
+
+/// Returns the bundles in the dead letter queue together with the reasons they were dropped.
+#[allow(clippy::unused_async)]
+async fn dead_letters(
+    State(dead_letter_queue): State<DeadLetterQueueHandle>,
+) -> axum::Json<Vec<DeadLetter>> {
+    debug!("received dead letters request");
+    axum::Json(dead_letter_queue.letters())
+}
+
+/// Moves all bundles out of the dead letter queue for the executor to submit them again.
+#[allow(clippy::unused_async)]
+async fn replay_dead_letters(
+    State(dead_letter_queue): State<DeadLetterQueueHandle>,
+) -> axum::Json<ReplayBody> {
+    debug!("received dead letters replay request");
+    axum::Json(ReplayBody {
+        replayed: dead_letter_queue.replay(),
+    })
+}
+
+#[derive(Debug, Serialize)]
+struct ReplayBody {
+    replayed: usize,
+}
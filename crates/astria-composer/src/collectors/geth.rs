@@ -1,8 +1,8 @@
-//! `GethCollector` fetches pending transactions from a Geth Rollup.
+//! `Geth` is the source of pending transactions of a Geth Rollup.
 //!
-//! //! [`Geth`] subscribes to pending transactions from a [go-ethereum](https://geth.ethereum.org) rollup node,
-//! and forwards them for more processing. and then passing them downstream for the executor to
-//! process.
+//! [`Geth`] subscribes to pending transactions from a [go-ethereum](https://geth.ethereum.org)
+//! rollup node, and RLP-encodes them so that a [`Collector`](super::Collector) can pass them
+//! downstream for the executor to process.
 //!
 //! ## Note
 //! This collector is likely specific to go-ethereum and only checked to work wit. It makes use of
@@ -13,179 +13,55 @@
 //! { "id": 1, "jsonrpc": "2.0", "method": "eth_subscribe", "params": ["newPendingTransactions"] }
 //! ```
 
-use astria_core::sequencer::v1::{
-    asset::default_native_asset_id,
-    transaction::action::SequenceAction,
-    RollupId,
-};
 use astria_eyre::eyre::{
     self,
     WrapErr as _,
 };
-use ethers::providers::{
-    Provider,
-    ProviderError,
-    Ws,
-};
-use tokio::sync::{
-    mpsc::error::SendTimeoutError,
-    watch,
-};
-use tracing::{
-    debug,
-    info,
-    instrument,
-    warn,
+use async_trait::async_trait;
+use ethers::types::Transaction;
+use futures::stream::StreamExt as _;
+use jsonrpsee::{
+    core::client::{
+        Subscription,
+        SubscriptionClientT as _,
+    },
+    rpc_params,
+    ws_client::WsClientBuilder,
 };
+use tracing::debug;
 
-use crate::executor;
-type StdError = dyn std::error::Error;
-
-/// `GethCollector` Collects transactions submitted to a Geth rollup node and passes
-/// them downstream for further processing.
-///
-/// It is responsible for fetching pending transactions submitted to the rollup Geth nodes and then
-/// passing them downstream for the executor to process. Thus, a composer can have multiple
-/// collectors running at the same time funneling data from multiple rollup nodes.
-pub(crate) struct Geth {
-    // Chain ID to identify in the astria sequencer block which rollup a serialized sequencer
-    // action belongs to. Created from `chain_name`.
-    rollup_id: RollupId,
-    // Name of the chain the transactions are read from.
-    chain_name: String,
-    // The channel on which the collector sends new txs to the executor.
-    executor_handle: executor::Handle,
-    // The status of this collector instance.
-    status: watch::Sender<Status>,
-    /// Rollup URL
-    url: String,
-}
-
-#[derive(Debug)]
-pub(crate) struct Status {
-    pub(crate) is_connected: bool,
-}
-
-impl Status {
-    fn new() -> Self {
-        Self {
-            is_connected: false,
-        }
-    }
-
-    pub(crate) fn is_connected(&self) -> bool {
-        self.is_connected
-    }
-}
-
-impl Geth {
-    /// Initializes a new collector instance
-    pub(crate) fn new(chain_name: String, url: String, executor_handle: executor::Handle) -> Self {
-        let (status, _) = watch::channel(Status::new());
-        let rollup_id = RollupId::from_unhashed_bytes(&chain_name);
-        info!(
-            rollup_name = %chain_name,
-            rollup_id = %rollup_id,
-            "created new geth collector for rollup",
-        );
-        Self {
-            rollup_id,
-            chain_name,
-            executor_handle,
-            status,
-            url,
-        }
-    }
-
-    /// Subscribe to the collector's status.
-    pub(crate) fn subscribe(&self) -> watch::Receiver<Status> {
-        self.status.subscribe()
-    }
-
-    /// Starts the collector instance and runs until failure or until
-    /// explicitly closed
-    #[instrument(skip_all, fields(chain_name = self.chain_name, rollup_id = %self.rollup_id))]
-    pub(crate) async fn run_until_stopped(self) -> eyre::Result<()> {
-        use std::time::Duration;
-
-        use ethers::providers::Middleware as _;
-        use futures::stream::StreamExt as _;
-
-        let Self {
-            rollup_id,
-            executor_handle,
-            status,
-            url,
-            ..
-        } = self;
-
-        let retry_config = tryhard::RetryFutureConfig::new(1024)
-            .exponential_backoff(Duration::from_millis(500))
-            .max_delay(Duration::from_secs(60))
-            .on_retry(
-                |attempt, next_delay: Option<Duration>, error: &ProviderError| {
-                    let wait_duration = next_delay
-                        .map(humantime::format_duration)
-                        .map(tracing::field::display);
-                    warn!(
-                        attempt,
-                        wait_duration,
-                        error = error as &StdError,
-                        "attempt to connect to geth node failed; retrying after backoff",
-                    );
-                    futures::future::ready(())
-                },
-            );
+use super::{
+    PendingTxSource,
+    TxStream,
+};
 
-        let client = tryhard::retry_fn(|| {
-            let url = url.clone();
-            async move {
-                let websocket_client = Ws::connect_with_reconnects(url, 0).await?;
-                Ok(Provider::new(websocket_client))
-            }
-        })
-        .with_config(retry_config)
-        .await
-        .wrap_err("failed connecting to geth after several retries; giving up")?;
+/// Subscribes to the full pending transactions of a Geth rollup node.
+pub(crate) struct Geth;
 
-        let mut tx_stream = client
-            .subscribe_full_pending_txs()
+#[async_trait]
+impl PendingTxSource for Geth {
+    async fn subscribe(&self, url: &str) -> eyre::Result<TxStream> {
+        let client = WsClientBuilder::default()
+            .build(url)
+            .await
+            .wrap_err("failed connecting to geth")?;
+        let subscription: Subscription<Transaction> = client
+            .subscribe(
+                "eth_subscribe",
+                rpc_params!["newPendingTransactions", true],
+                "eth_unsubscribe",
+            )
             .await
             .wrap_err("failed to subscribe eth client to full pending transactions")?;
 
-        status.send_modify(|status| status.is_connected = true);
-
-        while let Some(tx) = tx_stream.next().await {
-            let tx_hash = tx.hash;
-            debug!(transaction.hash = %tx_hash, "collected transaction from rollup");
-            let data = tx.rlp().to_vec();
-            let seq_action = SequenceAction {
-                rollup_id,
-                data,
-                fee_asset_id: default_native_asset_id(),
-            };
-
-            match executor_handle
-                .send_timeout(seq_action, Duration::from_millis(500))
-                .await
-            {
-                Ok(()) => {}
-                Err(SendTimeoutError::Timeout(_seq_action)) => {
-                    warn!(
-                        transaction.hash = %tx_hash,
-                        "timed out sending new transaction to executor after 500ms; dropping tx"
-                    );
-                }
-                Err(SendTimeoutError::Closed(_seq_action)) => {
-                    warn!(
-                        transaction.hash = %tx_hash,
-                        "executor channel closed while sending transaction; dropping transaction \
-                         and exiting event loop"
-                    );
-                    break;
-                }
-            }
-        }
-        Ok(())
+        Ok(subscription
+            .map(move |tx| {
+                // the subscription is closed once the client is dropped
+                let _client = &client;
+                let tx = tx.wrap_err("failed decoding pending transaction sent by geth")?;
+                debug!(transaction.hash = %tx.hash, "collected transaction from rollup");
+                Ok(tx.rlp().to_vec())
+            })
+            .boxed())
     }
 }
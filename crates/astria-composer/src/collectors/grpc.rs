@@ -1,6 +1,7 @@
 //! `GrpcCollector` implements the `GrpcCollectorService` rpc service.
 
 use std::{
+    collections::HashMap,
     sync::Arc,
     time::Duration,
 };
@@ -8,39 +9,239 @@ use std::{
 use astria_core::{
     generated::composer::v1alpha1::{
         grpc_collector_service_server::GrpcCollectorService,
+        BatchSubmitRollupTransactionsRequest,
+        GetRollupTransactionStatusRequest,
+        GetRollupTransactionStatusResponse,
+        SubmissionReceipt,
         SubmitRollupTransactionRequest,
         SubmitRollupTransactionResponse,
+        SubmitRollupTransactionsResponse,
     },
     sequencer::v1::{
-        asset::default_native_asset_id,
+        asset::{
+            self,
+            default_native_asset_id,
+        },
         transaction::action::SequenceAction,
         RollupId,
     },
 };
-use tokio::sync::mpsc::error::SendTimeoutError;
+use futures::future::join_all;
+use tokio::sync::{
+    mpsc::error::SendTimeoutError,
+    watch,
+};
 use tonic::{
     Request,
     Response,
     Status,
+    Streaming,
+};
+
+use super::{
+    record_dropped,
+    record_received,
+};
+use crate::{
+    executor,
+    tracker,
 };
 
-use crate::executor;
+/// The name of the gRPC collector, used to label its metrics.
+const COLLECTOR_NAME: &str = "grpc";
+
+/// The time to wait for space in the channel to the executor.
+const SEND_TIMEOUT: Duration = Duration::from_millis(500);
 
 /// Implements the `GrpcCollectorService` which listens for incoming gRPC requests and
 /// sends the Rollup transactions to the Executor. The Executor then sends the transactions
 /// to the Astria Shared Sequencer.
 pub(crate) struct Grpc {
     executor: executor::Handle,
+    // The status of the executor, holding the fee assets accepted by the sequencer.
+    executor_status: watch::Receiver<executor::Status>,
+    // The fee assets configured for rollups, used if a request does not name a fee asset.
+    rollup_fee_asset_ids: HashMap<RollupId, asset::Id>,
+    // The index of submitted rollup transactions, queried for their status.
+    tx_tracker: tracker::Handle,
 }
 
 impl Grpc {
-    pub(crate) fn new(executor: executor::Handle) -> Self {
+    pub(crate) fn new(
+        executor: executor::Handle,
+        executor_status: watch::Receiver<executor::Status>,
+        rollup_fee_asset_ids: HashMap<RollupId, asset::Id>,
+        tx_tracker: tracker::Handle,
+    ) -> Self {
         Self {
             executor,
+            executor_status,
+            rollup_fee_asset_ids,
+            tx_tracker,
+        }
+    }
+
+    /// Returns the fee asset named in a request, falling back to the one configured for the
+    /// rollup or the sequencer's native asset.
+    fn fee_asset_id(&self, rollup_id: RollupId, fee_asset: &str) -> asset::Id {
+        if fee_asset.is_empty() {
+            self.rollup_fee_asset_ids
+                .get(&rollup_id)
+                .copied()
+                .unwrap_or_else(default_native_asset_id)
+        } else {
+            asset::Id::from_denom(fee_asset)
+        }
+    }
+
+    /// Checks the rollup id and fee asset of `request`, turning it into a sequence action.
+    fn sequence_action(
+        &self,
+        request: SubmitRollupTransactionRequest,
+    ) -> Result<SequenceAction, Status> {
+        let Ok(rollup_id) = RollupId::try_from_slice(&request.rollup_id) else {
+            return Err(tonic::Status::invalid_argument("invalid rollup id"));
+        };
+
+        let fee_asset_id = self.fee_asset_id(rollup_id, &request.fee_asset);
+        if !self
+            .executor_status
+            .borrow()
+            .is_allowed_fee_asset(fee_asset_id)
+        {
+            return Err(tonic::Status::invalid_argument(
+                "fee asset is not accepted by the sequencer",
+            ));
+        }
+
+        Ok(SequenceAction {
+            rollup_id,
+            data: request.data,
+            fee_asset_id,
+        })
+    }
+
+    /// Sends `sequence_action` to the executor, returning the pending receipt of its submission
+    /// if `wait_for_receipt` is set.
+    async fn send(
+        &self,
+        sequence_action: SequenceAction,
+        wait_for_receipt: bool,
+    ) -> Result<Option<executor::PendingReceipt>, Status> {
+        let rollup_id = sequence_action.rollup_id;
+        record_received(COLLECTOR_NAME, rollup_id);
+        let result = if wait_for_receipt {
+            self.executor
+                .send_with_receipt_timeout(sequence_action, SEND_TIMEOUT)
+                .await
+                .map(Some)
+        } else {
+            self.executor
+                .send_timeout(sequence_action, SEND_TIMEOUT)
+                .await
+                .map(|()| None)
+        };
+        result.map_err(|error| {
+            record_dropped(COLLECTOR_NAME, rollup_id);
+            send_error_to_status(&error)
+        })
+    }
+
+    /// Sends all `sequence_actions` to the executor, or none of them if there is no space for
+    /// all of them, returning the pending receipts of those whose `wait_for_receipt` flag is set.
+    async fn send_all(
+        &self,
+        sequence_actions: Vec<(SequenceAction, bool)>,
+    ) -> Result<Vec<Option<executor::PendingReceipt>>, Status> {
+        let max_batch_len = self.executor.max_batch_len();
+        if sequence_actions.len() > max_batch_len {
+            return Err(tonic::Status::invalid_argument(format!(
+                "at most {max_batch_len} rollup transactions can be submitted in a batch"
+            )));
+        }
+        let rollup_ids = sequence_actions
+            .iter()
+            .map(|(sequence_action, _)| sequence_action.rollup_id)
+            .collect::<Vec<_>>();
+        for rollup_id in &rollup_ids {
+            record_received(COLLECTOR_NAME, *rollup_id);
+        }
+        self.executor
+            .send_all_timeout(sequence_actions, SEND_TIMEOUT)
+            .await
+            .map_err(|error| {
+                for rollup_id in rollup_ids {
+                    record_dropped(COLLECTOR_NAME, rollup_id);
+                }
+                send_error_to_status(&error)
+            })
+    }
+}
+
+fn send_error_to_status<T>(error: &SendTimeoutError<T>) -> Status {
+    match error {
+        SendTimeoutError::Timeout(_) => {
+            tonic::Status::unavailable("timeout while sending txs to composer")
+        }
+        SendTimeoutError::Closed(_) => {
+            tonic::Status::failed_precondition("composer is not available")
         }
     }
 }
 
+/// Waits for the receipt of a submitted transaction, if one was requested.
+async fn into_response(
+    pending_receipt: Option<executor::PendingReceipt>,
+) -> Result<SubmitRollupTransactionResponse, Status> {
+    let Some(pending_receipt) = pending_receipt else {
+        return Ok(SubmitRollupTransactionResponse {
+            receipt: None,
+            error: String::new(),
+        });
+    };
+    let receipt = pending_receipt.wait().await.map_err(|error| {
+        tonic::Status::aborted(format!(
+            "transaction was not submitted to the sequencer: {error}"
+        ))
+    })?;
+    Ok(SubmitRollupTransactionResponse {
+        receipt: Some(SubmissionReceipt {
+            sequencer_transaction_hash: receipt.sequencer_tx_hash.to_vec(),
+            nonce: receipt.nonce,
+        }),
+        error: String::new(),
+    })
+}
+
+/// Waits for the receipts of multiple submitted transactions, reporting the error of a
+/// transaction in its own response instead of failing the whole request.
+async fn into_responses(
+    pending_receipts: Vec<Result<Option<executor::PendingReceipt>, Status>>,
+) -> SubmitRollupTransactionsResponse {
+    let responses = join_all(
+        pending_receipts
+            .into_iter()
+            .map(|pending_receipt| async move {
+                match pending_receipt {
+                    Ok(pending_receipt) => into_response(pending_receipt).await,
+                    Err(status) => Err(status),
+                }
+            }),
+    )
+    .await
+    .into_iter()
+    .map(|response| {
+        response.unwrap_or_else(|status| SubmitRollupTransactionResponse {
+            receipt: None,
+            error: status.message().to_string(),
+        })
+    })
+    .collect();
+    SubmitRollupTransactionsResponse {
+        responses,
+    }
+}
+
 #[async_trait::async_trait]
 impl GrpcCollectorService for Grpc {
     async fn submit_rollup_transaction(
@@ -48,35 +249,76 @@ impl GrpcCollectorService for Grpc {
         request: Request<SubmitRollupTransactionRequest>,
     ) -> Result<Response<SubmitRollupTransactionResponse>, Status> {
         let submit_rollup_tx_request = request.into_inner();
+        let wait_for_receipt = submit_rollup_tx_request.wait_for_receipt;
+        let sequence_action = self.sequence_action(submit_rollup_tx_request)?;
+        let pending_receipt = self.send(sequence_action, wait_for_receipt).await?;
+        Ok(Response::new(into_response(pending_receipt).await?))
+    }
 
-        let Ok(rollup_id) = RollupId::try_from_slice(&submit_rollup_tx_request.rollup_id) else {
-            return Err(tonic::Status::invalid_argument("invalid rollup id"));
-        };
-
-        let sequence_action = SequenceAction {
-            rollup_id,
-            data: submit_rollup_tx_request.data,
-            fee_asset_id: default_native_asset_id(),
-        };
-
-        match self
-            .executor
-            .send_timeout(sequence_action, Duration::from_millis(500))
-            .await
-        {
-            Ok(()) => {}
-            Err(SendTimeoutError::Timeout(_seq_action)) => {
-                return Err(tonic::Status::unavailable(
-                    "timeout while sending txs to composer",
-                ));
-            }
-            Err(SendTimeoutError::Closed(_seq_action)) => {
-                return Err(tonic::Status::failed_precondition(
-                    "composer is not available",
-                ));
+    async fn submit_rollup_transactions(
+        self: Arc<Self>,
+        request: Request<Streaming<SubmitRollupTransactionRequest>>,
+    ) -> Result<Response<SubmitRollupTransactionsResponse>, Status> {
+        let mut requests = request.into_inner();
+        // transactions are sent to the executor as they arrive; a transaction that fails
+        // validation or cannot be sent is reported in its response without affecting the others.
+        // the number of transactions per stream is capped like a batch so that the pending
+        // receipts held until the stream is closed are bounded.
+        let max_stream_len = self.executor.max_batch_len();
+        let mut pending_receipts = Vec::new();
+        while let Some(submit_rollup_tx_request) = requests.message().await? {
+            if pending_receipts.len() >= max_stream_len {
+                return Err(Status::invalid_argument(format!(
+                    "at most {max_stream_len} rollup transactions can be submitted in a stream"
+                )));
             }
+            let wait_for_receipt = submit_rollup_tx_request.wait_for_receipt;
+            let pending_receipt = match self.sequence_action(submit_rollup_tx_request) {
+                Ok(sequence_action) => self.send(sequence_action, wait_for_receipt).await,
+                Err(status) => Err(status),
+            };
+            pending_receipts.push(pending_receipt);
         }
+        Ok(Response::new(into_responses(pending_receipts).await))
+    }
+
+    async fn batch_submit_rollup_transactions(
+        self: Arc<Self>,
+        request: Request<BatchSubmitRollupTransactionsRequest>,
+    ) -> Result<Response<SubmitRollupTransactionsResponse>, Status> {
+        // validate all transactions before sending any of them to the executor
+        let transactions = request
+            .into_inner()
+            .transactions
+            .into_iter()
+            .map(|submit_rollup_tx_request| {
+                let wait_for_receipt = submit_rollup_tx_request.wait_for_receipt;
+                self.sequence_action(submit_rollup_tx_request)
+                    .map(|sequence_action| (sequence_action, wait_for_receipt))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let pending_receipts = self.send_all(transactions).await?;
+        Ok(Response::new(
+            into_responses(pending_receipts.into_iter().map(Ok).collect()).await,
+        ))
+    }
 
-        Ok(Response::new(SubmitRollupTransactionResponse {}))
+    async fn get_rollup_transaction_status(
+        self: Arc<Self>,
+        request: Request<GetRollupTransactionStatusRequest>,
+    ) -> Result<Response<GetRollupTransactionStatusResponse>, Status> {
+        let rollup_tx_hash: [u8; 32] = request
+            .into_inner()
+            .rollup_transaction_hash
+            .as_slice()
+            .try_into()
+            .map_err(|_| Status::invalid_argument("rollup transaction hash must be 32 bytes"))?;
+        let Some(status) = self.tx_tracker.status(&rollup_tx_hash) else {
+            return Err(Status::not_found("rollup transaction is not tracked"));
+        };
+        Ok(Response::new(GetRollupTransactionStatusResponse {
+            sequencer_transaction_hash: status.sequencer_tx_hash.to_vec(),
+            sequencer_height: status.sequencer_height.unwrap_or(0),
+        }))
     }
 }
@@ -1,5 +1,267 @@
+//! Collectors subscribe to the pending transactions of rollup nodes and forward them to the
+//! executor.
+//!
+//! A [`Collector`] handles connecting to a rollup node, retrying on failure, and forwarding
+//! collected transactions to the executor. What it subscribes to and how the transactions are
+//! decoded is defined by a [`PendingTxSource`]:
+//!
+//! - [`Geth`] subscribes to the full pending transactions of a go-ethereum node and RLP-encodes
+//!   them;
+//! - [`websocket::Subscription`] subscribes to an arbitrary JSON-RPC subscription over websocket
+//!   and extracts raw bytes from each of its messages.
+
+use std::time::Duration;
+
+use astria_core::sequencer::v1::{
+    asset,
+    transaction::action::SequenceAction,
+    RollupId,
+};
+use astria_eyre::eyre::{
+    self,
+    WrapErr as _,
+};
+use async_trait::async_trait;
+use futures::stream::{
+    BoxStream,
+    StreamExt as _,
+};
+use tokio::sync::{
+    mpsc::error::SendTimeoutError,
+    watch,
+};
+use tracing::{
+    info,
+    instrument,
+    warn,
+};
+
+use crate::executor;
+
 pub(crate) mod geth;
 pub(crate) mod grpc;
+pub(crate) mod websocket;
 
 pub(crate) use geth::Geth;
 pub(crate) use grpc::Grpc;
+
+/// A stream of raw rollup transactions, each of which becomes the data of a sequence action.
+pub(crate) type TxStream = BoxStream<'static, eyre::Result<Vec<u8>>>;
+
+/// A source of pending transactions of a rollup node.
+///
+/// Implementors only subscribe to the node and decode the transactions it sends; connecting
+/// with retries and forwarding the transactions to the executor is left to [`Collector`].
+#[async_trait]
+pub(crate) trait PendingTxSource: Send + Sync {
+    /// Connects to the rollup node at `url` and subscribes to its pending transactions.
+    ///
+    /// The returned stream ends once the subscription is closed. Transactions that could not be
+    /// decoded are returned as errors without ending the stream.
+    async fn subscribe(&self, url: &str) -> eyre::Result<TxStream>;
+}
+
+/// The kind of subscription a rollup's collector uses.
+#[derive(Clone, Debug)]
+pub(crate) enum Kind {
+    /// Subscribe to the full pending transactions of a go-ethereum node.
+    Geth,
+    /// Subscribe to a custom JSON-RPC subscription over websocket.
+    WebSocket(websocket::Config),
+}
+
+impl Kind {
+    /// The name of the collector kind, used to label its metrics.
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Geth => "geth",
+            Self::WebSocket(_) => "websocket",
+        }
+    }
+
+    fn into_source(self) -> Box<dyn PendingTxSource> {
+        match self {
+            Self::Geth => Box::new(Geth),
+            Self::WebSocket(config) => Box::new(websocket::Subscription::new(config)),
+        }
+    }
+}
+
+/// Collects pending transactions submitted to a rollup node and passes them downstream for
+/// further processing.
+///
+/// A composer can have multiple collectors running at the same time funneling data from
+/// multiple rollup nodes.
+pub(crate) struct Collector {
+    // Chain ID to identify in the astria sequencer block which rollup a serialized sequencer
+    // action belongs to. Created from `chain_name`.
+    rollup_id: RollupId,
+    // Name of the chain the transactions are read from.
+    chain_name: String,
+    // The asset used to pay sequencer fees for the collected transactions.
+    fee_asset_id: asset::Id,
+    // The channel on which the collector sends new txs to the executor.
+    executor_handle: executor::Handle,
+    // The status of this collector instance.
+    status: watch::Sender<Status>,
+    // The source of the rollup's pending transactions.
+    source: Box<dyn PendingTxSource>,
+    // The name of the collector kind, used to label metrics.
+    kind_name: &'static str,
+    /// Rollup URL
+    url: String,
+}
+
+#[derive(Debug)]
+pub(crate) struct Status {
+    pub(crate) is_connected: bool,
+}
+
+impl Status {
+    fn new() -> Self {
+        Self {
+            is_connected: false,
+        }
+    }
+
+    pub(crate) fn is_connected(&self) -> bool {
+        self.is_connected
+    }
+}
+
+impl Collector {
+    /// Initializes a new collector instance
+    pub(crate) fn new(
+        chain_name: String,
+        url: String,
+        fee_asset_id: asset::Id,
+        kind: Kind,
+        executor_handle: executor::Handle,
+    ) -> Self {
+        let (status, _) = watch::channel(Status::new());
+        let rollup_id = RollupId::from_unhashed_bytes(&chain_name);
+        info!(
+            rollup_name = %chain_name,
+            rollup_id = %rollup_id,
+            fee_asset_id = %fee_asset_id,
+            ?kind,
+            "created new collector for rollup",
+        );
+        Self {
+            rollup_id,
+            chain_name,
+            fee_asset_id,
+            executor_handle,
+            status,
+            kind_name: kind.name(),
+            source: kind.into_source(),
+            url,
+        }
+    }
+
+    /// Subscribe to the collector's status.
+    pub(crate) fn subscribe(&self) -> watch::Receiver<Status> {
+        self.status.subscribe()
+    }
+
+    /// Starts the collector instance and runs until failure or until
+    /// explicitly closed
+    #[instrument(skip_all, fields(chain_name = self.chain_name, rollup_id = %self.rollup_id))]
+    pub(crate) async fn run_until_stopped(self) -> eyre::Result<()> {
+        let Self {
+            rollup_id,
+            fee_asset_id,
+            executor_handle,
+            status,
+            source,
+            url,
+            kind_name,
+            ..
+        } = self;
+
+        let retry_config = tryhard::RetryFutureConfig::new(1024)
+            .exponential_backoff(Duration::from_millis(500))
+            .max_delay(Duration::from_secs(60))
+            .on_retry(
+                |attempt, next_delay: Option<Duration>, error: &eyre::Report| {
+                    let wait_duration = next_delay
+                        .map(humantime::format_duration)
+                        .map(tracing::field::display);
+                    warn!(
+                        attempt,
+                        wait_duration,
+                        error = %format!("{error:#}"),
+                        "attempt to subscribe to rollup node failed; retrying after backoff",
+                    );
+                    futures::future::ready(())
+                },
+            );
+
+        let mut tx_stream = tryhard::retry_fn(|| source.subscribe(&url))
+            .with_config(retry_config)
+            .await
+            .wrap_err("failed subscribing to rollup node after several retries; giving up")?;
+
+        status.send_modify(|status| status.is_connected = true);
+
+        while let Some(tx) = tx_stream.next().await {
+            let data = match tx {
+                Ok(data) => data,
+                Err(error) => {
+                    warn!(
+                        error = %format!("{error:#}"),
+                        "failed decoding transaction collected from rollup; dropping it",
+                    );
+                    continue;
+                }
+            };
+            let seq_action = SequenceAction {
+                rollup_id,
+                data,
+                fee_asset_id,
+            };
+            record_received(kind_name, rollup_id);
+
+            match executor_handle
+                .send_timeout(seq_action, Duration::from_millis(500))
+                .await
+            {
+                Ok(()) => {}
+                Err(SendTimeoutError::Timeout(_seq_action)) => {
+                    record_dropped(kind_name, rollup_id);
+                    warn!("timed out sending new transaction to executor after 500ms; dropping tx");
+                }
+                Err(SendTimeoutError::Closed(_seq_action)) => {
+                    record_dropped(kind_name, rollup_id);
+                    warn!(
+                        "executor channel closed while sending transaction; dropping transaction \
+                         and exiting event loop"
+                    );
+                    break;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Records that the collector `collector` received a transaction for `rollup_id`.
+fn record_received(collector: &'static str, rollup_id: RollupId) {
+    metrics::counter!(
+        crate::metrics_init::TRANSACTIONS_RECEIVED,
+        crate::metrics_init::COLLECTOR_LABEL => collector,
+        crate::metrics_init::ROLLUP_ID_LABEL => rollup_id.to_string(),
+    )
+    .increment(1);
+}
+
+/// Records that the collector `collector` dropped a transaction for `rollup_id` because it could
+/// not be sent to the executor.
+fn record_dropped(collector: &'static str, rollup_id: RollupId) {
+    metrics::counter!(
+        crate::metrics_init::TRANSACTIONS_DROPPED,
+        crate::metrics_init::COLLECTOR_LABEL => collector,
+        crate::metrics_init::ROLLUP_ID_LABEL => rollup_id.to_string(),
+    )
+    .increment(1);
+}
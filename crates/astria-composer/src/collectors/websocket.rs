@@ -0,0 +1,214 @@
+//! A source of pending transactions from an arbitrary JSON-RPC subscription over websocket.
+//!
+//! [`Subscription`] allows collecting transactions from rollup nodes other than go-ethereum. It
+//! calls the configured subscription method and extracts the transaction bytes from each message
+//! the node sends on the subscription, using a [JSON pointer](https://datatracker.ietf.org/doc/html/rfc6901)
+//! into the message and the configured encoding of the payload found there.
+
+use astria_eyre::eyre::{
+    self,
+    eyre,
+    WrapErr as _,
+};
+use async_trait::async_trait;
+use futures::stream::StreamExt as _;
+use jsonrpsee::{
+    core::{
+        client::SubscriptionClientT as _,
+        params::ArrayParams,
+    },
+    ws_client::WsClientBuilder,
+};
+use serde::Deserialize;
+use serde_json::Value;
+
+use super::{
+    PendingTxSource,
+    TxStream,
+};
+
+/// The configuration of a websocket subscription for one rollup.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct Config {
+    /// The name of the rollup whose node is subscribed to.
+    pub(crate) rollup_name: String,
+    /// The JSON-RPC method to open the subscription.
+    pub(crate) subscribe_method: String,
+    /// The JSON-RPC method to close the subscription.
+    pub(crate) unsubscribe_method: String,
+    /// The parameters passed to the subscribe method.
+    #[serde(default)]
+    pub(crate) params: Vec<Value>,
+    /// The JSON pointer to the transaction payload in each subscription message. The empty
+    /// pointer refers to the entire message.
+    #[serde(default)]
+    pub(crate) payload_path: String,
+    /// How the transaction bytes are encoded in the payload.
+    #[serde(default)]
+    pub(crate) payload_encoding: PayloadEncoding,
+}
+
+/// The encoding of the transaction payload in a subscription message.
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum PayloadEncoding {
+    /// A hex encoded string, with or without a `0x` prefix.
+    #[default]
+    Hex,
+    /// A string whose UTF-8 bytes are the transaction.
+    Utf8,
+    /// Any JSON value, whose serialization is the transaction.
+    Json,
+}
+
+/// Parses a JSON array of [`Config`]s. An empty string is treated as an empty array.
+pub(crate) fn parse_configs(configs: &str) -> eyre::Result<Vec<Config>> {
+    if configs.trim().is_empty() {
+        return Ok(Vec::new());
+    }
+    serde_json::from_str(configs).wrap_err("failed parsing websocket collectors as a JSON array")
+}
+
+/// Subscribes to the configured JSON-RPC subscription of a rollup node over websocket.
+pub(crate) struct Subscription {
+    config: Config,
+}
+
+impl Subscription {
+    pub(crate) fn new(config: Config) -> Self {
+        Self {
+            config,
+        }
+    }
+}
+
+#[async_trait]
+impl PendingTxSource for Subscription {
+    async fn subscribe(&self, url: &str) -> eyre::Result<TxStream> {
+        let client = WsClientBuilder::default()
+            .build(url)
+            .await
+            .wrap_err("failed connecting to rollup node")?;
+        let mut params = ArrayParams::new();
+        for param in &self.config.params {
+            params
+                .insert(param)
+                .wrap_err("failed serializing subscription parameter")?;
+        }
+        let subscription: jsonrpsee::core::client::Subscription<Value> = client
+            .subscribe(
+                &self.config.subscribe_method,
+                params,
+                &self.config.unsubscribe_method,
+            )
+            .await
+            .wrap_err_with(|| {
+                format!(
+                    "failed to subscribe to `{}` of rollup node",
+                    self.config.subscribe_method
+                )
+            })?;
+
+        let payload_path = self.config.payload_path.clone();
+        let payload_encoding = self.config.payload_encoding;
+        Ok(subscription
+            .map(move |message| {
+                // the subscription is closed once the client is dropped
+                let _client = &client;
+                let message = message.wrap_err("failed decoding subscription message as JSON")?;
+                extract_payload(&message, &payload_path, payload_encoding)
+            })
+            .boxed())
+    }
+}
+
+/// Extracts the transaction bytes found at `payload_path` in `message`.
+fn extract_payload(
+    message: &Value,
+    payload_path: &str,
+    payload_encoding: PayloadEncoding,
+) -> eyre::Result<Vec<u8>> {
+    let payload = message
+        .pointer(payload_path)
+        .ok_or_else(|| eyre!("subscription message has no payload at `{payload_path}`"))?;
+    let payload_str = || {
+        payload
+            .as_str()
+            .ok_or_else(|| eyre!("payload at `{payload_path}` is not a string"))
+    };
+    match payload_encoding {
+        PayloadEncoding::Hex => {
+            let payload = payload_str()?;
+            hex::decode(payload.strip_prefix("0x").unwrap_or(payload))
+                .wrap_err("failed decoding payload as hex")
+        }
+        PayloadEncoding::Utf8 => Ok(payload_str()?.as_bytes().to_vec()),
+        PayloadEncoding::Json => {
+            serde_json::to_vec(payload).wrap_err("failed serializing payload as JSON")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn hex_payload_is_decoded_with_and_without_prefix() {
+        let message = json!({ "tx": { "data": "0x0102ff" }, "raw": "0102ff" });
+        assert_eq!(
+            extract_payload(&message, "/tx/data", PayloadEncoding::Hex).unwrap(),
+            vec![1, 2, 255],
+        );
+        assert_eq!(
+            extract_payload(&message, "/raw", PayloadEncoding::Hex).unwrap(),
+            vec![1, 2, 255],
+        );
+    }
+
+    #[test]
+    fn utf8_and_json_payloads_are_extracted() {
+        let message = json!({ "tx": "hello", "nested": { "a": 1 } });
+        assert_eq!(
+            extract_payload(&message, "/tx", PayloadEncoding::Utf8).unwrap(),
+            b"hello".to_vec(),
+        );
+        assert_eq!(
+            extract_payload(&message, "/nested", PayloadEncoding::Json).unwrap(),
+            br#"{"a":1}"#.to_vec(),
+        );
+        assert_eq!(
+            extract_payload(&message, "", PayloadEncoding::Json).unwrap(),
+            serde_json::to_vec(&message).unwrap(),
+        );
+    }
+
+    #[test]
+    fn missing_or_non_string_payload_is_rejected() {
+        let message = json!({ "tx": 1 });
+        assert!(extract_payload(&message, "/data", PayloadEncoding::Hex).is_err());
+        assert!(extract_payload(&message, "/tx", PayloadEncoding::Utf8).is_err());
+    }
+
+    #[test]
+    fn configs_are_parsed_with_defaults() {
+        assert!(parse_configs("").unwrap().is_empty());
+
+        let configs = parse_configs(
+            r#"[{
+                "rollup_name": "chain-1",
+                "subscribe_method": "chain_subscribePendingTxs",
+                "unsubscribe_method": "chain_unsubscribePendingTxs"
+            }]"#,
+        )
+        .unwrap();
+        assert_eq!(configs.len(), 1);
+        assert_eq!(configs[0].rollup_name, "chain-1");
+        assert!(configs[0].params.is_empty());
+        assert_eq!(configs[0].payload_path, "");
+        assert_eq!(configs[0].payload_encoding, PayloadEncoding::Hex);
+    }
+}
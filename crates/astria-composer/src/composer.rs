@@ -1,21 +1,42 @@
 use std::{
     collections::HashMap,
     net::SocketAddr,
+    time::Duration,
 };
 
+use astria_core::sequencer::v1::{
+    asset::{
+        self,
+        default_native_asset_id,
+    },
+    RollupId,
+};
 use astria_eyre::eyre::{
     self,
+    bail,
     WrapErr as _,
 };
 use tokio::{
     io,
+    signal::unix::{
+        signal,
+        SignalKind,
+    },
     sync::watch,
-    task::JoinError,
+    task::{
+        JoinError,
+        JoinHandle,
+    },
+    time::timeout,
+};
+use tokio_util::{
+    sync::CancellationToken,
+    task::JoinMap,
 };
-use tokio_util::task::JoinMap;
 use tracing::{
     error,
     info,
+    warn,
 };
 
 use crate::{
@@ -24,17 +45,20 @@ use crate::{
         ApiServer,
     },
     collectors,
-    collectors::Geth,
     composer,
     executor,
     executor::Executor,
     grpc::GrpcServer,
-    rollup::Rollup,
+    rollup::{
+        Rollup,
+        RollupParts,
+    },
+    tracker,
     Config,
 };
 
-/// `Composer` is a service responsible for spinning up `GethCollectors` which are responsible
-/// for fetching pending transactions submitted to the rollup Geth nodes and then passing them
+/// `Composer` is a service responsible for spinning up collectors which are responsible
+/// for fetching pending transactions submitted to the rollup nodes and then passing them
 /// downstream for the executor to process. Thus, a composer can have multiple collectors running
 /// at the same time funneling data from multiple rollup nodes.
 pub struct Composer {
@@ -49,18 +73,45 @@ pub struct Composer {
     /// responsible for signing and submitting sequencer transactions.
     /// The sequencer transactions are received from various collectors.
     executor: Executor,
-    /// The collection of geth collectors and their rollup names.
-    geth_collectors: HashMap<String, collectors::Geth>,
-    /// The collection of the status of each geth collector.
-    geth_collector_statuses: HashMap<String, watch::Receiver<collectors::geth::Status>>,
-    /// The set of tasks tracking if the geth collectors are still running and to receive
-    /// the final result of each geth collector.
-    geth_collector_tasks: JoinMap<String, eyre::Result<()>>,
-    /// The map of chain ID to the URLs to which geth collectors should connect.
-    rollups: HashMap<String, String>,
+    /// The collection of rollup collectors and their rollup names.
+    rollup_collectors: HashMap<String, collectors::Collector>,
+    /// The collection of the status of each rollup collector.
+    rollup_collector_statuses: HashMap<String, watch::Receiver<collectors::Status>>,
+    /// The set of tasks tracking if the rollup collectors are still running and to receive
+    /// the final result of each rollup collector.
+    rollup_collector_tasks: JoinMap<String, eyre::Result<()>>,
+    /// The map of chain ID to the URLs to which rollup collectors should connect, the
+    /// assets their transactions pay fees in, and the kind of subscription they use.
+    rollups: HashMap<String, RollupParts>,
     /// The gRPC server that listens for incoming requests from the collectors via the
     /// GrpcCollector service. It also exposes a health service.
     grpc_server: GrpcServer,
+    /// Polls the sequencer for the inclusion of the submitted sequencer transactions.
+    tx_tracker: tracker::Tracker,
+    /// The time the executor is given to submit its pending bundles on shutdown.
+    shutdown_drain_timeout: Duration,
+    /// Token to signal the composer to shut down, cancelled through a [`ShutdownHandle`].
+    shutdown_token: CancellationToken,
+    /// Token to signal the gRPC server to stop accepting requests.
+    grpc_shutdown_token: CancellationToken,
+    /// Token to signal the executor to drain its pending bundles and stop.
+    executor_shutdown_token: CancellationToken,
+}
+
+/// A handle to request a graceful shutdown of a running [`Composer`].
+#[derive(Clone, Debug)]
+pub struct ShutdownHandle {
+    token: CancellationToken,
+}
+
+impl ShutdownHandle {
+    /// Requests the composer to shut down.
+    ///
+    /// The composer stops its collectors and then gives the executor until the configured
+    /// drain timeout to submit all pending bundles to the sequencer.
+    pub fn shutdown(&self) {
+        self.token.cancel();
+    }
 }
 
 /// Announces the current status of the Composer for other modules in the crate to use
@@ -93,48 +144,121 @@ impl Composer {
     /// See `[from_config]` for its error scenarios.
     pub async fn from_config(cfg: &Config) -> eyre::Result<Self> {
         let (composer_status_sender, _) = watch::channel(Status::default());
-        let (executor, executor_handle) = Executor::new(
+        let mut rollups = cfg
+            .rollups
+            .split(',')
+            .filter(|s| !s.is_empty())
+            .map(|s| Rollup::parse(s).map(Rollup::into_parts))
+            .collect::<Result<HashMap<_, _>, _>>()
+            .wrap_err(
+                "failed parsing provided <rollup_name>::<url>[::<fee_asset_denom>] entries as \
+                 rollups",
+            )?;
+        let websocket_collectors = collectors::websocket::parse_configs(&cfg.websocket_collectors)?;
+        for websocket_collector in websocket_collectors {
+            let rollup_name = websocket_collector.rollup_name.to_lowercase();
+            let Some(parts) = rollups.get_mut(&rollup_name) else {
+                bail!(
+                    "websocket collector configured for rollup `{rollup_name}`, which is not in \
+                     the list of rollups"
+                );
+            };
+            parts.collector = collectors::Kind::WebSocket(websocket_collector);
+        }
+        let mut rollup_limits = HashMap::new();
+        for config in
+            executor::rollup_limits::parse_configs(&cfg.rollup_limits, cfg.max_bytes_per_bundle)?
+        {
+            let rollup_name = config.rollup_name.to_lowercase();
+            if !rollups.contains_key(&rollup_name) {
+                bail!(
+                    "limits configured for rollup `{rollup_name}`, which is not in the list of \
+                     rollups"
+                );
+            }
+            rollup_limits.insert(RollupId::from_unhashed_bytes(&rollup_name), config.limits());
+        }
+
+        let tx_tracker_handle = tracker::Handle::new(cfg.tx_tracker_capacity);
+        let tx_tracker = tracker::Tracker::new(
             &cfg.sequencer_url,
-            &cfg.private_key,
-            cfg.block_time_ms,
-            cfg.max_bytes_per_bundle,
+            Duration::from_millis(cfg.block_time_ms),
+            tx_tracker_handle.clone(),
         )
+        .wrap_err("tx tracker construction from config failed")?;
+
+        let shutdown_drain_timeout = Duration::from_millis(cfg.shutdown_drain_timeout_ms);
+        let executor_shutdown_token = CancellationToken::new();
+        let (executor, executor_handle) = executor::Builder {
+            sequencer_url: cfg.sequencer_url.clone(),
+            private_keys: cfg.private_key.clone(),
+            block_time_ms: cfg.block_time_ms,
+            max_bytes_per_bundle: cfg.max_bytes_per_bundle,
+            rollup_limits,
+            max_submission_attempts: cfg.max_submission_attempts,
+            dead_letter_queue_capacity: cfg.dead_letter_queue_capacity,
+            max_in_flight_submissions: cfg.max_in_flight_submissions,
+            shutdown_drain_timeout,
+            shutdown_token: executor_shutdown_token.clone(),
+            monitored_fee_asset_ids: monitored_fee_asset_ids(&rollups),
+            min_signer_balance: cfg.min_signer_balance,
+            balance_check_interval: Duration::from_millis(cfg.balance_check_interval_ms),
+            tx_tracker: tx_tracker_handle.clone(),
+        }
+        .build()
         .wrap_err("executor construction from config failed")?;
 
-        let grpc_server = GrpcServer::new(cfg.grpc_addr, executor_handle.clone())
-            .await
-            .wrap_err("failed to create grpc server")?;
+        let rollup_fee_asset_ids = rollups
+            .iter()
+            .map(|(rollup_name, parts)| {
+                (
+                    RollupId::from_unhashed_bytes(rollup_name),
+                    parts.fee_asset_id,
+                )
+            })
+            .collect();
+        let grpc_server = GrpcServer::new(
+            cfg.grpc_addr,
+            executor_handle.clone(),
+            executor.subscribe(),
+            rollup_fee_asset_ids,
+            tx_tracker_handle.clone(),
+        )
+        .await
+        .wrap_err("failed to create grpc server")?;
 
         info!(
             listen_addr = %grpc_server.local_addr().wrap_err("grpc server listener not bound")?,
             "gRPC server listening"
         );
 
-        let api_server = api::start(cfg.api_listen_addr, composer_status_sender.subscribe());
+        let api_server = api::start(
+            cfg.api_listen_addr,
+            composer_status_sender.subscribe(),
+            tx_tracker_handle,
+            executor.dead_letter_queue(),
+        );
 
         info!(
             listen_addr = %api_server.local_addr(),
             "API server listening"
         );
 
-        let rollups = cfg
-            .rollups
-            .split(',')
-            .filter(|s| !s.is_empty())
-            .map(|s| Rollup::parse(s).map(Rollup::into_parts))
-            .collect::<Result<HashMap<_, _>, _>>()
-            .wrap_err("failed parsing provided <rollup_name>::<url> pairs as rollups")?;
-
-        let geth_collectors = rollups
+        let rollup_collectors = rollups
             .iter()
-            .map(|(rollup_name, url)| {
-                let collector =
-                    Geth::new(rollup_name.clone(), url.clone(), executor_handle.clone());
+            .map(|(rollup_name, parts)| {
+                let collector = collectors::Collector::new(
+                    rollup_name.clone(),
+                    parts.url.clone(),
+                    parts.fee_asset_id,
+                    parts.collector.clone(),
+                    executor_handle.clone(),
+                );
                 (rollup_name.clone(), collector)
             })
             .collect::<HashMap<_, _>>();
-        let geth_collector_statuses: HashMap<String, watch::Receiver<collectors::geth::Status>> =
-            geth_collectors
+        let rollup_collector_statuses: HashMap<String, watch::Receiver<collectors::Status>> =
+            rollup_collectors
                 .iter()
                 .map(|(rollup_name, collector)| (rollup_name.clone(), collector.subscribe()))
                 .collect();
@@ -145,13 +269,26 @@ impl Composer {
             executor_handle,
             executor,
             rollups,
-            geth_collectors,
-            geth_collector_statuses,
-            geth_collector_tasks: JoinMap::new(),
+            rollup_collectors,
+            rollup_collector_statuses,
+            rollup_collector_tasks: JoinMap::new(),
             grpc_server,
+            tx_tracker,
+            shutdown_drain_timeout,
+            shutdown_token: CancellationToken::new(),
+            grpc_shutdown_token: CancellationToken::new(),
+            executor_shutdown_token,
         })
     }
 
+    /// Returns a handle to shut down the composer once it is running.
+    #[must_use]
+    pub fn shutdown_handle(&self) -> ShutdownHandle {
+        ShutdownHandle {
+            token: self.shutdown_token.clone(),
+        }
+    }
+
     /// Returns the socket address the api server is served over
     pub fn local_addr(&self) -> SocketAddr {
         self.api_server.local_addr()
@@ -164,54 +301,81 @@ impl Composer {
         self.grpc_server.local_addr()
     }
 
-    /// Runs the composer.
+    /// Runs the composer until it receives SIGTERM, a shutdown is requested through its
+    /// [`ShutdownHandle`], or one of its tasks ends.
+    ///
+    /// On shutdown the collectors are stopped first, after which the executor submits all
+    /// pending bundles within the configured drain timeout.
     ///
     /// # Errors
-    /// It errors out if the API Server, Executor or any of the Geth Collectors fail to start.
+    /// It errors out if the API Server, Executor or any of the rollup collectors fail to start.
+    ///
+    /// # Panics
+    /// Panics if it could not install a signal handler.
     pub async fn run_until_stopped(self) -> eyre::Result<()> {
         let Self {
             api_server,
             composer_status_sender,
             executor,
             executor_handle,
-            mut geth_collector_tasks,
-            mut geth_collectors,
+            mut rollup_collector_tasks,
+            mut rollup_collectors,
             rollups,
-            mut geth_collector_statuses,
+            mut rollup_collector_statuses,
             grpc_server,
+            tx_tracker,
+            shutdown_drain_timeout,
+            shutdown_token,
+            grpc_shutdown_token,
+            executor_shutdown_token,
         } = self;
 
+        let mut sigterm = signal(SignalKind::terminate()).expect(
+            "setting a SIGTERM listener should always work on unix; is this running on unix?",
+        );
+
         // run the api server
         let mut api_task =
             tokio::spawn(async move { api_server.await.wrap_err("api server ended unexpectedly") });
 
         // run the collectors and executor
-        for (chain_id, collector) in geth_collectors.drain() {
-            geth_collector_tasks.spawn(chain_id, collector.run_until_stopped());
+        for (chain_id, collector) in rollup_collectors.drain() {
+            rollup_collector_tasks.spawn(chain_id, collector.run_until_stopped());
         }
         let executor_status = executor.subscribe().clone();
         let mut executor_task = tokio::spawn(executor.run_until_stopped());
+        let mut tx_tracker_task = tokio::spawn(tx_tracker.run_until_stopped());
 
         // wait for collectors and executor to come online
-        wait_for_collectors(&geth_collector_statuses).await?;
+        wait_for_collectors(&rollup_collector_statuses).await?;
         composer_status_sender.send_modify(|status| {
             status.set_all_collectors_connected(true);
         });
-        wait_for_executor(executor_status).await?;
+        wait_for_executor(executor_status.clone()).await?;
+        ensure_fee_assets_are_allowed(&rollups, &executor_status.borrow())?;
         composer_status_sender.send_modify(|status| {
             status.set_executor_connected(true);
         });
 
         // run the grpc server
+        let grpc_server_shutdown_token = grpc_shutdown_token.clone();
         let mut grpc_server_handle = tokio::spawn(async move {
             grpc_server
-                .run_until_stopped()
+                .run_until_stopped(grpc_server_shutdown_token)
                 .await
                 .wrap_err("grpc server failed")
         });
 
         loop {
             tokio::select!(
+            _ = sigterm.recv() => {
+                    info!(reason = "received SIGTERM", "initiating shutdown");
+                    break;
+            },
+            () = shutdown_token.cancelled() => {
+                    info!(reason = "shutdown was requested", "initiating shutdown");
+                    break;
+            },
             o = &mut api_task => {
                     report_exit("api server unexpectedly ended", o);
                     return Ok(());
@@ -224,10 +388,14 @@ impl Composer {
                     report_exit("grpc server unexpectedly ended", o);
                     return Ok(());
             },
-            Some((rollup, collector_exit)) = geth_collector_tasks.join_next() => {
+            o = &mut tx_tracker_task => {
+                    report_exit("tx tracker unexpectedly ended", o.map(Ok));
+                    return Ok(());
+            },
+            Some((rollup, collector_exit)) = rollup_collector_tasks.join_next() => {
                    reconnect_exited_collector(
-                    &mut geth_collector_statuses,
-                    &mut geth_collector_tasks,
+                    &mut rollup_collector_statuses,
+                    &mut rollup_collector_tasks,
                     executor_handle.clone(),
                     &rollups,
                     rollup,
@@ -235,6 +403,37 @@ impl Composer {
                 );
             });
         }
+
+        // stop the collectors first so that no new transactions reach the executor
+        grpc_shutdown_token.cancel();
+        report_exit("grpc server", grpc_server_handle.await);
+        rollup_collector_tasks.shutdown().await;
+        info!("stopped all collectors");
+
+        executor_shutdown_token.cancel();
+        shutdown_executor(executor_task, shutdown_drain_timeout).await;
+
+        tx_tracker_task.abort();
+        api_task.abort();
+        Ok(())
+    }
+}
+
+/// Waits for the executor to submit its pending bundles, aborting it if it did not exit
+/// shortly after the drain timeout.
+async fn shutdown_executor(
+    mut executor_task: JoinHandle<eyre::Result<()>>,
+    shutdown_drain_timeout: Duration,
+) {
+    // the executor enforces the drain timeout itself; the margin gives it time to report
+    // the dropped actions and exit
+    const EXIT_MARGIN: Duration = Duration::from_secs(1);
+    match timeout(shutdown_drain_timeout + EXIT_MARGIN, &mut executor_task).await {
+        Ok(outcome) => report_exit("executor", outcome),
+        Err(_) => {
+            warn!("executor did not exit after the shutdown drain timeout; aborting it");
+            executor_task.abort();
+        }
     }
 }
 
@@ -249,9 +448,36 @@ async fn wait_for_executor(
     Ok(())
 }
 
+/// Returns the fee assets the sequencer accounts pay fees in: the assets configured for the
+/// rollups, and the native asset used by transactions that do not specify a fee asset.
+fn monitored_fee_asset_ids(rollups: &HashMap<String, RollupParts>) -> Vec<asset::Id> {
+    let mut fee_asset_ids: Vec<_> = rollups.values().map(|parts| parts.fee_asset_id).collect();
+    fee_asset_ids.push(default_native_asset_id());
+    fee_asset_ids.sort_unstable();
+    fee_asset_ids.dedup();
+    fee_asset_ids
+}
+
+/// Checks that the sequencer accepts the fee assets configured for all rollups.
+fn ensure_fee_assets_are_allowed(
+    rollups: &HashMap<String, RollupParts>,
+    executor_status: &executor::Status,
+) -> eyre::Result<()> {
+    for (rollup_name, parts) in rollups {
+        if !executor_status.is_allowed_fee_asset(parts.fee_asset_id) {
+            bail!(
+                "fee asset `{}` configured for rollup `{rollup_name}` is not accepted by the \
+                 sequencer",
+                parts.fee_asset_id,
+            );
+        }
+    }
+    Ok(())
+}
+
 /// Waits for all collectors to come online.
 async fn wait_for_collectors(
-    collector_statuses: &HashMap<String, watch::Receiver<collectors::geth::Status>>,
+    collector_statuses: &HashMap<String, watch::Receiver<collectors::Status>>,
 ) -> eyre::Result<()> {
     use futures::{
         future::FutureExt as _,
@@ -265,10 +491,7 @@ async fn wait_for_collectors(
         .map(|(chain_id, status)| {
             let mut status = status.clone();
             async move {
-                match status
-                    .wait_for(collectors::geth::Status::is_connected)
-                    .await
-                {
+                match status.wait_for(collectors::Status::is_connected).await {
                     // `wait_for` returns a reference to status; throw it
                     // away because this future cannot return a reference to
                     // a stack local object.
@@ -295,15 +518,15 @@ async fn wait_for_collectors(
 }
 
 pub(super) fn reconnect_exited_collector(
-    collector_statuses: &mut HashMap<String, watch::Receiver<collectors::geth::Status>>,
+    collector_statuses: &mut HashMap<String, watch::Receiver<collectors::Status>>,
     collector_tasks: &mut JoinMap<String, eyre::Result<()>>,
     executor_handle: executor::Handle,
-    rollups: &HashMap<String, String>,
+    rollups: &HashMap<String, RollupParts>,
     rollup: String,
     exit_result: Result<eyre::Result<()>, JoinError>,
 ) {
     report_exit("collector", exit_result);
-    let Some(url) = rollups.get(&rollup) else {
+    let Some(parts) = rollups.get(&rollup) else {
         error!(
             "rollup should have had an entry in the rollup->url map but doesn't; not reconnecting \
              it"
@@ -311,7 +534,13 @@ pub(super) fn reconnect_exited_collector(
         return;
     };
 
-    let collector = Geth::new(rollup.clone(), url.clone(), executor_handle);
+    let collector = collectors::Collector::new(
+        rollup.clone(),
+        parts.url.clone(),
+        parts.fee_asset_id,
+        parts.collector.clone(),
+        executor_handle,
+    );
     collector_statuses.insert(rollup.clone(), collector.subscribe());
     collector_tasks.spawn(rollup, collector.run_until_stopped());
 }
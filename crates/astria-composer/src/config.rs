@@ -25,10 +25,11 @@ pub struct Config {
     /// Address of the RPC server for the sequencer chain
     pub sequencer_url: String,
 
-    /// A list of <rollup_name>::<url> pairs
+    /// A list of <rollup_name>::<url>[::<fee_asset_denom>] entries
     pub rollups: String,
 
-    /// Private key for the sequencer account used for signing transactions
+    /// Comma-separated private keys of the sequencer accounts used for signing transactions.
+    /// Bundles are distributed round robin across all keys.
     #[serde(serialize_with = "serialize_private_key")]
     pub private_key: SecretString,
 
@@ -57,6 +58,41 @@ pub struct Config {
 
     /// The address at which the gRPC server is listening
     pub grpc_addr: SocketAddr,
+
+    /// The number of attempts to submit a bundle to the sequencer before moving it to the dead
+    /// letter queue
+    pub max_submission_attempts: u32,
+
+    /// The max number of bundles kept in the dead letter queue
+    pub dead_letter_queue_capacity: usize,
+
+    /// The max number of bundles submitted to the sequencer without waiting for the result of
+    /// previous submissions
+    pub max_in_flight_submissions: usize,
+
+    /// The time in milliseconds given to submit all pending bundles to the sequencer after a
+    /// shutdown was requested
+    pub shutdown_drain_timeout_ms: u64,
+
+    /// The minimum balance of each rollup fee asset a sequencer account must hold to be used
+    /// for signing new bundles
+    pub min_signer_balance: u128,
+
+    /// The interval in milliseconds at which the balances of the sequencer accounts are checked;
+    /// must be greater than zero
+    pub balance_check_interval_ms: u64,
+
+    /// A JSON array of websocket subscriptions replacing the Geth subscription of the named
+    /// rollups
+    pub websocket_collectors: String,
+
+    /// A JSON array of per-rollup weights, quotas and rate limits applied when bundling the
+    /// rollups' sequence actions
+    pub rollup_limits: String,
+
+    /// The max number of submitted rollup transactions whose inclusion in a sequencer block is
+    /// tracked
+    pub tx_tracker_capacity: usize,
 }
 
 impl config::Config for Config {
@@ -0,0 +1,168 @@
+//! Benchmarks of bundling and submitting sequence actions to a mock sequencer.
+//!
+//! These are ignored by default and should be run in release mode to get meaningful numbers:
+//! ```text
+//! cargo test --release -p astria-composer benchmarks -- --ignored --nocapture
+//! ```
+
+use std::{
+    collections::HashMap,
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+use astria_core::sequencer::v1::{
+    asset::default_native_asset_id,
+    transaction::action::SequenceAction,
+    RollupId,
+    ROLLUP_ID_LEN,
+};
+use serde_json::json;
+use tendermint_rpc::{
+    endpoint::broadcast::tx_sync,
+    response,
+    Id,
+};
+use tokio_util::sync::CancellationToken;
+use wiremock::{
+    matchers::body_partial_json,
+    Mock,
+    MockGuard,
+    MockServer,
+    ResponseTemplate,
+};
+
+use super::{
+    bundle_factory::BundleFactory,
+    tests::{
+        executor_builder,
+        setup,
+        wait_for_startup,
+    },
+};
+
+/// The simulated round trip time of a `broadcast_tx_sync` request.
+const SEQUENCER_LATENCY: Duration = Duration::from_millis(50);
+
+/// The number of bundles submitted per benchmark run.
+const NUMBER_OF_BUNDLES: u64 = 20;
+
+fn sequence_action(rollup_byte: u8, data_len: usize) -> SequenceAction {
+    SequenceAction {
+        rollup_id: RollupId::new([rollup_byte; ROLLUP_ID_LEN]),
+        data: vec![rollup_byte; data_len],
+        fee_asset_id: default_native_asset_id(),
+    }
+}
+
+/// Mount a mock accepting `n` transactions after [`SEQUENCER_LATENCY`].
+async fn mount_slow_broadcast_tx_sync_mock(server: &MockServer, n: u64) -> MockGuard {
+    let jsonrpc_rsp = response::Wrapper::new_with_id(
+        Id::Num(1),
+        Some(tx_sync::Response {
+            code: 0.into(),
+            data: vec![].into(),
+            log: String::new(),
+            hash: tendermint::Hash::Sha256([0; 32]),
+        }),
+        None,
+    );
+    Mock::given(body_partial_json(json!({
+        "method": "broadcast_tx_sync"
+    })))
+    .respond_with(
+        ResponseTemplate::new(200)
+            .set_body_json(&jsonrpc_rsp)
+            .set_delay(SEQUENCER_LATENCY),
+    )
+    .up_to_n_times(n)
+    .expect(n)
+    .mount_as_scoped(server)
+    .await
+}
+
+#[test]
+#[ignore = "benchmark; run explicitly in release mode"]
+fn bundle_factory_throughput() {
+    const NUMBER_OF_ACTIONS: usize = 100_000;
+    const MAX_BYTES_PER_BUNDLE: usize = 200_000;
+
+    let actions: Vec<_> = (0..NUMBER_OF_ACTIONS)
+        .map(|i| sequence_action(u8::try_from(i % 10).unwrap(), 100))
+        .collect();
+
+    let start = Instant::now();
+    let mut bundle_factory = BundleFactory::new(MAX_BYTES_PER_BUNDLE, HashMap::new());
+    let mut bundles = 0usize;
+    for action in actions {
+        bundle_factory.try_push(action, None).unwrap();
+        if let Some(next_bundle) = bundle_factory.next_finished() {
+            next_bundle.pop();
+            bundles += 1;
+        }
+    }
+    if !bundle_factory.pop_now().is_empty() {
+        bundles += 1;
+    }
+    let elapsed = start.elapsed();
+
+    println!(
+        "bundled {NUMBER_OF_ACTIONS} sequence actions into {bundles} bundles in {}; {:?} per \
+         action",
+        humantime::format_duration(elapsed),
+        elapsed / u32::try_from(NUMBER_OF_ACTIONS).unwrap(),
+    );
+}
+
+/// Submits [`NUMBER_OF_BUNDLES`] full bundles to a mock sequencer with a latency of
+/// [`SEQUENCER_LATENCY`] and returns how long it took until all were received.
+async fn submit_bundles(max_in_flight_submissions: usize) -> Duration {
+    let (sequencer, nonce_guard, mut cfg) = setup().await;
+    cfg.max_in_flight_submissions = max_in_flight_submissions;
+    let (executor, executor_handle) = executor_builder(&cfg, CancellationToken::new())
+        .build()
+        .unwrap();
+    let status = executor.subscribe();
+    let _executor_task = tokio::spawn(executor.run_until_stopped());
+    wait_for_startup(status, nonce_guard).await.unwrap();
+
+    let response_guard = mount_slow_broadcast_tx_sync_mock(&sequencer, NUMBER_OF_BUNDLES).await;
+
+    let start = Instant::now();
+    // every action fills an entire bundle, with the last small action pushing the final full
+    // bundle out of the factory
+    for i in 0..NUMBER_OF_BUNDLES {
+        executor_handle
+            .send_timeout(
+                sequence_action(
+                    u8::try_from(i).unwrap(),
+                    cfg.max_bytes_per_bundle - ROLLUP_ID_LEN,
+                ),
+                Duration::from_millis(1000),
+            )
+            .await
+            .unwrap();
+    }
+    executor_handle
+        .send_timeout(sequence_action(0, 1), Duration::from_millis(1000))
+        .await
+        .unwrap();
+    response_guard.wait_until_satisfied().await;
+    start.elapsed()
+}
+
+#[tokio::test]
+#[ignore = "benchmark; run explicitly in release mode"]
+async fn submission_throughput() {
+    for max_in_flight_submissions in [1, 2, 4, 8] {
+        let elapsed = submit_bundles(max_in_flight_submissions).await;
+        println!(
+            "submitted {NUMBER_OF_BUNDLES} bundles with {max_in_flight_submissions} in-flight \
+             submissions and a sequencer latency of {} in {}",
+            humantime::format_duration(SEQUENCER_LATENCY),
+            humantime::format_duration(elapsed),
+        );
+    }
+}
@@ -0,0 +1,133 @@
+use std::{
+    collections::HashMap,
+    time::Duration,
+};
+
+use astria_core::sequencer::v1::{
+    asset,
+    RollupId,
+};
+use astria_eyre::eyre::{
+    self,
+    ensure,
+    eyre,
+    WrapErr as _,
+};
+use ed25519_consensus::SigningKey;
+use secrecy::{
+    ExposeSecret as _,
+    SecretString,
+    Zeroize as _,
+};
+use tokio::sync::{
+    mpsc,
+    watch,
+};
+use tokio_util::sync::CancellationToken;
+
+use super::{
+    rollup_limits::Limits,
+    DeadLetterQueueHandle,
+    Executor,
+    Handle,
+    Status,
+};
+use crate::tracker;
+
+pub(crate) struct Builder {
+    pub(crate) sequencer_url: String,
+    /// Comma-separated hex encoded private keys of the sequencer accounts.
+    pub(crate) private_keys: SecretString,
+    pub(crate) block_time_ms: u64,
+    pub(crate) max_bytes_per_bundle: usize,
+    /// The limits of rollups that don't use the default limits.
+    pub(crate) rollup_limits: HashMap<RollupId, Limits>,
+    pub(crate) max_submission_attempts: u32,
+    pub(crate) dead_letter_queue_capacity: usize,
+    pub(crate) max_in_flight_submissions: usize,
+    pub(crate) shutdown_drain_timeout: Duration,
+    pub(crate) shutdown_token: CancellationToken,
+    pub(crate) monitored_fee_asset_ids: Vec<asset::Id>,
+    pub(crate) min_signer_balance: u128,
+    pub(crate) balance_check_interval: Duration,
+    /// Records the rollup transactions of submitted bundles.
+    pub(crate) tx_tracker: tracker::Handle,
+}
+
+impl Builder {
+    pub(crate) fn build(self) -> eyre::Result<(Executor, Handle)> {
+        let Self {
+            sequencer_url,
+            private_keys,
+            block_time_ms,
+            max_bytes_per_bundle,
+            rollup_limits,
+            max_submission_attempts,
+            dead_letter_queue_capacity,
+            max_in_flight_submissions,
+            shutdown_drain_timeout,
+            shutdown_token,
+            monitored_fee_asset_ids,
+            min_signer_balance,
+            balance_check_interval,
+            tx_tracker,
+        } = self;
+        let sequencer_client = sequencer_client::HttpClient::new(sequencer_url.as_str())
+            .wrap_err("failed constructing sequencer client")?;
+        let (status, _) = watch::channel(Status::new());
+        let signing_keys = private_keys
+            .expose_secret()
+            .split(',')
+            .map(str::trim)
+            .filter(|private_key| !private_key.is_empty())
+            .enumerate()
+            .map(|(index, private_key)| {
+                signing_key_from_hex(private_key)
+                    .wrap_err_with(|| format!("failed to parse private key at position {index}"))
+            })
+            .collect::<eyre::Result<Vec<_>>>()?;
+        ensure!(
+            !signing_keys.is_empty(),
+            "at least one private key must be provided"
+        );
+        ensure!(
+            !balance_check_interval.is_zero(),
+            "the balance check interval must be greater than zero"
+        );
+
+        let (serialized_rollup_transaction_tx, serialized_rollup_transaction_rx) =
+            mpsc::channel(256);
+
+        Ok((
+            Executor {
+                status,
+                serialized_rollup_transactions: serialized_rollup_transaction_rx,
+                sequencer_client,
+                signing_keys,
+                monitored_fee_asset_ids,
+                min_signer_balance,
+                balance_check_interval,
+                block_time: Duration::from_millis(block_time_ms),
+                max_bytes_per_bundle,
+                rollup_limits,
+                max_submission_attempts,
+                dead_letter_queue: DeadLetterQueueHandle::new(dead_letter_queue_capacity),
+                max_in_flight_submissions,
+                shutdown_drain_timeout,
+                shutdown_token,
+                tx_tracker,
+            },
+            Handle::new(serialized_rollup_transaction_tx),
+        ))
+    }
+}
+
+fn signing_key_from_hex(private_key: &str) -> eyre::Result<SigningKey> {
+    let mut private_key_bytes: [u8; 32] = hex::decode(private_key)
+        .wrap_err("failed to decode private key bytes from hex string")?
+        .try_into()
+        .map_err(|_| eyre!("invalid private key length; must be 32 bytes"))?;
+    let signing_key = SigningKey::from(private_key_bytes);
+    private_key_bytes.zeroize();
+    Ok(signing_key)
+}
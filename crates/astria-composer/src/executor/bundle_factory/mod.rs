@@ -1,11 +1,8 @@
 /// ! This module is responsible for bundling sequence actions into bundles that can be
 /// submitted to the sequencer.
-use std::{
-    collections::{
-        HashMap,
-        VecDeque,
-    },
-    mem,
+use std::collections::{
+    HashMap,
+    VecDeque,
 };
 
 use astria_core::sequencer::v1::{
@@ -22,6 +19,18 @@ use serde::ser::{
 };
 use tracing::trace;
 
+use super::{
+    receipt::{
+        Receipt,
+        ReceiptSender,
+        Reporter,
+    },
+    rollup_limits::{
+        Limits,
+        TokenBucket,
+    },
+};
+
 mod tests;
 
 #[derive(Debug, thiserror::Error)]
@@ -60,6 +69,8 @@ pub(super) struct SizedBundle {
     max_size: usize,
     /// Mapping of rollup id to the number of sequence actions for that rollup id in the bundle.
     rollup_counts: HashMap<RollupId, usize>,
+    /// Reports the outcome of submitting the bundle to those waiting for a receipt.
+    reporter: Reporter,
 }
 
 impl SizedBundle {
@@ -70,6 +81,7 @@ impl SizedBundle {
             curr_size: 0,
             max_size,
             rollup_counts: HashMap::new(),
+            reporter: Reporter::new(),
         }
     }
 
@@ -96,8 +108,9 @@ impl SizedBundle {
     }
 
     /// Replace self with a new empty bundle, returning the old bundle.
+    #[cfg(test)]
     fn flush(&mut self) -> SizedBundle {
-        mem::replace(self, Self::new(self.max_size))
+        std::mem::replace(self, Self::new(self.max_size))
     }
 
     /// Consume self and return the underlying buffer of actions.
@@ -109,66 +122,216 @@ impl SizedBundle {
     pub(super) fn is_empty(&self) -> bool {
         self.buffer.is_empty()
     }
+
+    /// Returns the data of the sequence actions in the bundle.
+    pub(super) fn rollup_txs(&self) -> impl Iterator<Item = &[u8]> {
+        self.buffer
+            .iter()
+            .filter_map(Action::as_sequence)
+            .map(|seq_action| seq_action.data.as_slice())
+    }
+
+    /// Returns the number of actions in the bundle.
+    pub(super) fn len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Reports to those waiting for a receipt that the bundle was submitted.
+    pub(super) fn report_submitted(&self, receipt: Receipt) {
+        self.reporter.submitted(receipt);
+    }
+
+    /// Reports to those waiting for a receipt that the bundle was given up on.
+    pub(super) fn report_failed(&self, reason: String) {
+        self.reporter.failed(reason);
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
 pub(super) enum BundleFactoryError {
     #[error("sequence action is larger than the max bundle size. seq_action size: {size}")]
     SequenceActionTooLarge { size: usize, max_size: usize },
+    #[error(
+        "rollup `{rollup_id}` exceeded its quota of queued bytes. queued: {queued}, seq_action \
+         size: {size}, max queued: {max_queued}"
+    )]
+    QuotaExceeded {
+        rollup_id: RollupId,
+        queued: usize,
+        size: usize,
+        max_queued: usize,
+    },
+    #[error("rollup `{rollup_id}` exceeded its rate limit. seq_action size: {size}")]
+    RateLimited { rollup_id: RollupId, size: usize },
 }
 
-/// Manages the bundling of sequence actions into `SizedBundle`s. A `Vec<Action>` is flushed and
-/// added to the `finished` queue when an incoming `SequenceAction` won't fit in the current bundle.
-/// The `finished` queue operates in FIFO order, where `Vec<Action>`s are added to the back and
-/// taken off from the front.
+/// The scale of the virtual time, so that dividing sizes by weights keeps enough precision.
+const VIRTUAL_TIME_SCALE: u128 = 1 << 20;
+
+/// A sequence action waiting to be bundled.
+struct QueuedAction {
+    seq_action: SequenceAction,
+    receipt_sender: Option<ReceiptSender>,
+    size: usize,
+    /// The virtual time at which the action starts being served.
+    start_tag: u128,
+    /// The order in which the action was pushed into the factory, breaking ties between equal
+    /// start tags.
+    arrival: u64,
+}
+
+/// The sequence actions of one rollup waiting to be bundled, together with its limits.
+struct RollupQueue {
+    actions: VecDeque<QueuedAction>,
+    queued_bytes: usize,
+    /// The virtual time at which the last action pushed into the queue finishes being served.
+    last_finish_tag: u128,
+    limits: Limits,
+    bucket: Option<TokenBucket>,
+}
+
+impl RollupQueue {
+    fn new(limits: Limits) -> Self {
+        Self {
+            actions: VecDeque::new(),
+            queued_bytes: 0,
+            last_finish_tag: 0,
+            limits,
+            bucket: limits.rate_limit.map(TokenBucket::new),
+        }
+    }
+}
+
+/// Manages the bundling of sequence actions into `SizedBundle`s.
+///
+/// Incoming sequence actions are queued per rollup, subject to the rollup's quota of queued bytes
+/// and its rate limit. Once more bytes are queued than fit into one bundle, the next bundle is
+/// assembled from the queues using start-time fair queuing: each action is tagged with the
+/// virtual time at which it starts being served, which advances by its size divided by its
+/// rollup's weight, and actions are taken in the order of their tags. A rollup sending large
+/// amounts of data thus cannot crowd out the others, while actions are bundled in arrival order
+/// if there is no contention.
+///
+/// Assembled bundles are added to the `finished` queue, which operates in FIFO order, where
+/// bundles are added to the back and taken off from the front.
 pub(super) struct BundleFactory {
-    /// The current bundle being built.
-    curr_bundle: SizedBundle,
+    /// The max bundle size in bytes to enforce.
+    max_bytes_per_bundle: usize,
+    /// The sequence actions waiting to be bundled, per rollup. The queues of rollups without
+    /// configured limits are removed once they are empty.
+    queues: HashMap<RollupId, RollupQueue>,
+    /// The limits of rollups that don't use the default limits.
+    rollup_limits: HashMap<RollupId, Limits>,
+    /// The sum of the sizes of all queued sequence actions.
+    queued_bytes: usize,
+    /// The start tag of the last sequence action taken out of the queues.
+    virtual_time: u128,
+    /// The number of sequence actions pushed so far.
+    arrivals: u64,
     /// The queue of bundles that have been built but not yet sent to the sequencer.
     finished: VecDeque<SizedBundle>,
 }
 
 impl BundleFactory {
-    pub(super) fn new(max_bytes_per_bundle: usize) -> Self {
+    pub(super) fn new(
+        max_bytes_per_bundle: usize,
+        rollup_limits: HashMap<RollupId, Limits>,
+    ) -> Self {
         Self {
-            curr_bundle: SizedBundle::new(max_bytes_per_bundle),
+            max_bytes_per_bundle,
+            queues: HashMap::new(),
+            rollup_limits,
+            queued_bytes: 0,
+            virtual_time: 0,
+            arrivals: 0,
             finished: VecDeque::new(),
         }
     }
 
-    /// Buffer `seq_action` into the current bundle. If the bundle won't fit `seq_action`, flush
-    /// `curr_bundle` into the `finished` queue and start a new bundle
+    /// Queue `seq_action` for bundling. If more bytes are queued than fit into a bundle and there
+    /// is no finished bundle, assemble the next bundle into the `finished` queue.
+    ///
+    /// If a `receipt_sender` is given, it is handed the reporter of the bundle the sequence
+    /// action ends up in.
     pub(super) fn try_push(
         &mut self,
         seq_action: SequenceAction,
+        receipt_sender: Option<ReceiptSender>,
     ) -> Result<(), BundleFactoryError> {
-        let seq_action_size = estimate_size_of_sequence_action(&seq_action);
+        let size = estimate_size_of_sequence_action(&seq_action);
+        if size > self.max_bytes_per_bundle {
+            return Err(BundleFactoryError::SequenceActionTooLarge {
+                size,
+                max_size: self.max_bytes_per_bundle,
+            });
+        }
 
-        match self.curr_bundle.push(seq_action) {
-            Err(SizedBundleError::SequenceActionTooLarge(_seq_action)) => {
-                // reject the sequence action if it is larger than the max bundle size
-                Err(BundleFactoryError::SequenceActionTooLarge {
-                    size: seq_action_size,
-                    max_size: self.curr_bundle.max_size,
-                })
-            }
-            Err(SizedBundleError::NotEnoughSpace(seq_action)) => {
-                // if the bundle is full, flush it and start a new one
-                self.finished.push_back(self.curr_bundle.flush());
-                self.curr_bundle
-                    .push(seq_action)
-                    .expect("seq_action should not be larger than max bundle size, this is a bug");
-                Ok(())
+        let rollup_id = seq_action.rollup_id;
+        let queue = self.queues.entry(rollup_id).or_insert_with(|| {
+            RollupQueue::new(
+                self.rollup_limits
+                    .get(&rollup_id)
+                    .copied()
+                    .unwrap_or_default(),
+            )
+        });
+        if let Some(max_queued) = queue.limits.max_queued_bytes {
+            if queue.queued_bytes + size > max_queued {
+                record_rejected_bytes(rollup_id, size);
+                return Err(BundleFactoryError::QuotaExceeded {
+                    rollup_id,
+                    queued: queue.queued_bytes,
+                    size,
+                    max_queued,
+                });
             }
-            Ok(()) => {
-                trace!(
-                    new_bundle_size = self.curr_bundle.curr_size,
-                    seq_action_size = seq_action_size,
-                    "bundled new sequence action"
-                );
-                Ok(())
+        }
+        if let Some(bucket) = &mut queue.bucket {
+            if !bucket.try_take(size) {
+                record_rejected_bytes(rollup_id, size);
+                return Err(BundleFactoryError::RateLimited {
+                    rollup_id,
+                    size,
+                });
             }
         }
+
+        let start_tag = self.virtual_time.max(queue.last_finish_tag);
+        queue.last_finish_tag =
+            start_tag + size as u128 * VIRTUAL_TIME_SCALE / u128::from(queue.limits.weight);
+        queue.actions.push_back(QueuedAction {
+            seq_action,
+            receipt_sender,
+            size,
+            start_tag,
+            arrival: self.arrivals,
+        });
+        queue.queued_bytes += size;
+        record_queued_bytes(rollup_id, queue.queued_bytes);
+        self.arrivals += 1;
+        self.queued_bytes += size;
+        trace!(
+            %rollup_id,
+            seq_action_size = size,
+            queued_bytes = self.queued_bytes,
+            "queued new sequence action"
+        );
+
+        self.finish_full_bundle();
+        Ok(())
+    }
+
+    /// Assembles the next bundle into the `finished` queue if it is empty and more bytes are
+    /// queued than fit into one bundle.
+    ///
+    /// Only one bundle is assembled ahead of time so that the remaining sequence actions stay in
+    /// their queues, where sequence actions of other rollups arriving later can still overtake
+    /// them.
+    fn finish_full_bundle(&mut self) {
+        if self.finished.is_empty() && self.queued_bytes > self.max_bytes_per_bundle {
+            let bundle = self.assemble_bundle();
+            self.finished.push_back(bundle);
+        }
     }
 
     /// Returns a handle to the next finished bundle if it exists.
@@ -176,6 +339,7 @@ impl BundleFactory {
     /// The bundle is only removed from the factory on calling [`NextFinishedBundle::pop`].
     /// This method primarily exists to work around async cancellation.
     pub(super) fn next_finished(&mut self) -> Option<NextFinishedBundle> {
+        self.finish_full_bundle();
         if self.finished.is_empty() {
             None
         } else {
@@ -185,14 +349,99 @@ impl BundleFactory {
         }
     }
 
-    /// Immediately the currently aggregating bundle.
+    /// Assembles all queued sequence actions into bundles in the `finished` queue.
+    pub(super) fn finish_queued(&mut self) {
+        while self.queued_bytes > 0 {
+            let bundle = self.assemble_bundle();
+            self.finished.push_back(bundle);
+        }
+    }
+
+    /// Returns true if the factory holds neither finished bundles nor queued sequence actions.
+    pub(super) fn is_empty(&self) -> bool {
+        self.finished.is_empty() && self.queued_bytes == 0
+    }
+
+    /// Returns the number of sequence actions held by the factory.
+    pub(super) fn pending_actions(&self) -> usize {
+        self.finished.iter().map(SizedBundle::len).sum::<usize>()
+            + self
+                .queues
+                .values()
+                .map(|queue| queue.actions.len())
+                .sum::<usize>()
+    }
+
+    /// Immediately returns the next finished bundle, or assembles one from the queued sequence
+    /// actions if there is none.
     ///
-    /// Returns an empty bundle if there are no bundled transactions.
+    /// Returns an empty bundle if there are no bundled or queued transactions.
     pub(super) fn pop_now(&mut self) -> SizedBundle {
         self.finished
             .pop_front()
-            .or_else(|| Some(self.curr_bundle.flush()))
-            .unwrap_or(SizedBundle::new(self.curr_bundle.max_size))
+            .unwrap_or_else(|| self.assemble_bundle())
+    }
+
+    /// Assembles a bundle by taking queued sequence actions in the order of their start tags
+    /// until the next one does not fit.
+    fn assemble_bundle(&mut self) -> SizedBundle {
+        let mut bundle = SizedBundle::new(self.max_bytes_per_bundle);
+        while let Some(rollup_id) = self.next_rollup() {
+            let queue = self
+                .queues
+                .get_mut(&rollup_id)
+                .expect("the next rollup has a queue; this is a bug");
+            let action = queue
+                .actions
+                .pop_front()
+                .expect("the queue of the next rollup is not empty; this is a bug");
+            match bundle.push(action.seq_action) {
+                Ok(()) => {
+                    queue.queued_bytes -= action.size;
+                    record_queued_bytes(rollup_id, queue.queued_bytes);
+                    self.queued_bytes -= action.size;
+                    self.virtual_time = action.start_tag;
+                    if let Some(receipt_sender) = action.receipt_sender {
+                        receipt_sender.send(&bundle.reporter);
+                    }
+                    // rollups without limits get a new queue on their next sequence action, so
+                    // the queues of rollups that stopped sending are not kept around
+                    if queue.actions.is_empty() && !self.rollup_limits.contains_key(&rollup_id) {
+                        self.queues.remove(&rollup_id);
+                    }
+                }
+                // sequence actions larger than a bundle are rejected when pushed into the
+                // factory, so the action is only returned if the bundle is full
+                Err(
+                    SizedBundleError::NotEnoughSpace(seq_action)
+                    | SizedBundleError::SequenceActionTooLarge(seq_action),
+                ) => {
+                    queue.actions.push_front(QueuedAction {
+                        seq_action,
+                        ..action
+                    });
+                    break;
+                }
+            }
+        }
+        if !bundle.is_empty() {
+            record_bundle(&bundle);
+        }
+        bundle
+    }
+
+    /// Returns the rollup whose next queued sequence action has the smallest start tag.
+    fn next_rollup(&self) -> Option<RollupId> {
+        self.queues
+            .iter()
+            .filter_map(|(rollup_id, queue)| {
+                queue
+                    .actions
+                    .front()
+                    .map(|action| ((action.start_tag, action.arrival), *rollup_id))
+            })
+            .min_by_key(|(tags, _)| *tags)
+            .map(|(_, rollup_id)| rollup_id)
     }
 }
 
@@ -209,6 +458,32 @@ impl<'a> NextFinishedBundle<'a> {
     }
 }
 
+fn record_queued_bytes(rollup_id: RollupId, queued_bytes: usize) {
+    // allow: the precision loss is irrelevant for a metric of queued bytes
+    #[allow(clippy::cast_precision_loss)]
+    metrics::gauge!(
+        crate::metrics_init::BYTES_QUEUED_PER_ROLLUP,
+        crate::metrics_init::ROLLUP_ID_LABEL => rollup_id.to_string(),
+    )
+    .set(queued_bytes as f64);
+}
+
+fn record_bundle(bundle: &SizedBundle) {
+    // allow: the precision loss is irrelevant for metrics of bundle sizes
+    #[allow(clippy::cast_precision_loss)]
+    let (size, max_size) = (bundle.curr_size as f64, bundle.max_size as f64);
+    metrics::histogram!(crate::metrics_init::BUNDLE_SIZE).record(size);
+    metrics::histogram!(crate::metrics_init::BUNDLE_FILL_RATIO).record(size / max_size);
+}
+
+fn record_rejected_bytes(rollup_id: RollupId, size: usize) {
+    metrics::counter!(
+        crate::metrics_init::BYTES_REJECTED_PER_ROLLUP,
+        crate::metrics_init::ROLLUP_ID_LABEL => rollup_id.to_string(),
+    )
+    .increment(size as u64);
+}
+
 /// The size of the `seq_action` in bytes, including the rollup id.
 fn estimate_size_of_sequence_action(seq_action: &SequenceAction) -> usize {
     seq_action.data.len() + ROLLUP_ID_LEN
@@ -146,6 +146,11 @@ mod sized_bundle_tests {
 
 #[cfg(test)]
 mod bundle_factory_tests {
+    use std::{
+        collections::HashMap,
+        time::Duration,
+    };
+
     use astria_core::sequencer::v1::{
         asset::default_native_asset_id,
         transaction::action::SequenceAction,
@@ -153,16 +158,31 @@ mod bundle_factory_tests {
         ROLLUP_ID_LEN,
     };
 
-    use crate::executor::bundle_factory::{
-        estimate_size_of_sequence_action,
-        BundleFactory,
-        BundleFactoryError,
+    use crate::executor::{
+        bundle_factory::{
+            estimate_size_of_sequence_action,
+            BundleFactory,
+            BundleFactoryError,
+        },
+        rollup_limits::{
+            Limits,
+            RateLimit,
+        },
     };
 
+    /// A sequence action of `size` bytes in total for the rollup `[id; ROLLUP_ID_LEN]`.
+    fn seq_action_of_size(id: u8, size: usize) -> SequenceAction {
+        SequenceAction {
+            rollup_id: RollupId::new([id; ROLLUP_ID_LEN]),
+            data: vec![id; size - ROLLUP_ID_LEN],
+            fee_asset_id: default_native_asset_id(),
+        }
+    }
+
     #[test]
     fn try_push_works_no_flush() {
         // create a bundle factory with max bundle size as 100 bytes
-        let mut bundle_factory = BundleFactory::new(100);
+        let mut bundle_factory = BundleFactory::new(100, HashMap::new());
 
         // push a sequence action that is 100 bytes total
         let seq_action = SequenceAction {
@@ -170,7 +190,7 @@ mod bundle_factory_tests {
             data: vec![0; 100 - ROLLUP_ID_LEN],
             fee_asset_id: default_native_asset_id(),
         };
-        bundle_factory.try_push(seq_action).unwrap();
+        bundle_factory.try_push(seq_action, None).unwrap();
 
         // assert that the bundle factory has no bundles in the finished queue
         assert!(bundle_factory.finished.is_empty());
@@ -179,7 +199,7 @@ mod bundle_factory_tests {
     #[test]
     fn try_push_seq_action_too_large() {
         // create a bundle factory with max bundle size as 100 bytes
-        let mut bundle_factory = BundleFactory::new(100);
+        let mut bundle_factory = BundleFactory::new(100, HashMap::new());
 
         // push a sequence action that is >100 bytes total
         let seq_action = SequenceAction {
@@ -190,7 +210,7 @@ mod bundle_factory_tests {
         let actual_size = estimate_size_of_sequence_action(&seq_action);
 
         assert!(matches!(
-            bundle_factory.try_push(seq_action),
+            bundle_factory.try_push(seq_action, None),
             Err(BundleFactoryError::SequenceActionTooLarge {
                 size,
                 max_size
@@ -201,7 +221,7 @@ mod bundle_factory_tests {
     #[test]
     fn try_push_flushes_and_pop_finished_works() {
         // create a bundle factory with max bundle size as 100 bytes
-        let mut bundle_factory = BundleFactory::new(100);
+        let mut bundle_factory = BundleFactory::new(100, HashMap::new());
 
         // push a sequence action that is 100 bytes total
         let seq_action0 = SequenceAction {
@@ -209,7 +229,7 @@ mod bundle_factory_tests {
             data: vec![0; 100 - ROLLUP_ID_LEN],
             fee_asset_id: default_native_asset_id(),
         };
-        bundle_factory.try_push(seq_action0.clone()).unwrap();
+        bundle_factory.try_push(seq_action0.clone(), None).unwrap();
 
         // push another sequence action that is <100 bytes total to force the current bundle to
         // flush
@@ -218,7 +238,7 @@ mod bundle_factory_tests {
             data: vec![1; 100 - ROLLUP_ID_LEN],
             fee_asset_id: default_native_asset_id(),
         };
-        bundle_factory.try_push(seq_action1).unwrap();
+        bundle_factory.try_push(seq_action1, None).unwrap();
 
         // assert that the bundle factory has one bundle in the finished queue
         assert_eq!(bundle_factory.finished.len(), 1);
@@ -233,7 +253,7 @@ mod bundle_factory_tests {
     #[test]
     fn pop_finished_empty() {
         // create a bundle factory with max bundle size as 100 bytes
-        let mut bundle_factory = BundleFactory::new(100);
+        let mut bundle_factory = BundleFactory::new(100, HashMap::new());
 
         // push a sequence action that is 100 bytes total so it doesn't flush
         let seq_action = SequenceAction {
@@ -241,7 +261,7 @@ mod bundle_factory_tests {
             data: vec![0; 100 - ROLLUP_ID_LEN],
             fee_asset_id: default_native_asset_id(),
         };
-        bundle_factory.try_push(seq_action.clone()).unwrap();
+        bundle_factory.try_push(seq_action.clone(), None).unwrap();
 
         // assert that the finished queue is empty
         assert_eq!(bundle_factory.finished.len(), 0);
@@ -253,7 +273,7 @@ mod bundle_factory_tests {
     #[test]
     fn pop_now_finished_empty() {
         // create a bundle factory with max bundle size as 100 bytes
-        let mut bundle_factory = BundleFactory::new(100);
+        let mut bundle_factory = BundleFactory::new(100, HashMap::new());
 
         // push a sequence action that is 100 bytes total so it doesn't flush
         let seq_action = SequenceAction {
@@ -261,7 +281,7 @@ mod bundle_factory_tests {
             data: vec![0; 100 - ROLLUP_ID_LEN],
             fee_asset_id: default_native_asset_id(),
         };
-        bundle_factory.try_push(seq_action.clone()).unwrap();
+        bundle_factory.try_push(seq_action.clone(), None).unwrap();
 
         // assert that the finished queue is empty (curr wasnt flushed)
         assert_eq!(bundle_factory.finished.len(), 0);
@@ -275,7 +295,7 @@ mod bundle_factory_tests {
     #[test]
     fn pop_now_finished_not_empty() {
         // create a bundle factory with max bundle size as 100 bytes
-        let mut bundle_factory = BundleFactory::new(100);
+        let mut bundle_factory = BundleFactory::new(100, HashMap::new());
 
         // push a sequence action that is 100 bytes total
         let seq_action0 = SequenceAction {
@@ -283,7 +303,7 @@ mod bundle_factory_tests {
             data: vec![0; 100 - ROLLUP_ID_LEN],
             fee_asset_id: default_native_asset_id(),
         };
-        bundle_factory.try_push(seq_action0.clone()).unwrap();
+        bundle_factory.try_push(seq_action0.clone(), None).unwrap();
 
         // push another sequence action that is <100 bytes total to force the current bundle to
         // flush
@@ -292,7 +312,7 @@ mod bundle_factory_tests {
             data: vec![1; 100 - ROLLUP_ID_LEN],
             fee_asset_id: default_native_asset_id(),
         };
-        bundle_factory.try_push(seq_action1).unwrap();
+        bundle_factory.try_push(seq_action1, None).unwrap();
 
         // assert that the bundle factory has one bundle in the finished queue
         assert_eq!(bundle_factory.finished.len(), 1);
@@ -306,7 +326,7 @@ mod bundle_factory_tests {
     #[test]
     fn pop_now_all_empty() {
         // create a bundle factory with max bundle size as 100 bytes
-        let mut bundle_factory = BundleFactory::new(100);
+        let mut bundle_factory = BundleFactory::new(100, HashMap::new());
 
         // assert that the finished queue is empty
         assert_eq!(bundle_factory.finished.len(), 0);
@@ -318,7 +338,7 @@ mod bundle_factory_tests {
     #[test]
     fn pop_now_finished_then_curr_then_empty() {
         // create a bundle factory with max bundle size as 100 bytes
-        let mut bundle_factory = BundleFactory::new(100);
+        let mut bundle_factory = BundleFactory::new(100, HashMap::new());
 
         // push a sequence action that is 100 bytes total
         let seq_action0 = SequenceAction {
@@ -326,7 +346,7 @@ mod bundle_factory_tests {
             data: vec![0; 100 - ROLLUP_ID_LEN],
             fee_asset_id: default_native_asset_id(),
         };
-        bundle_factory.try_push(seq_action0.clone()).unwrap();
+        bundle_factory.try_push(seq_action0.clone(), None).unwrap();
 
         // push another sequence action that is <100 bytes total to force the current bundle to
         // flush
@@ -335,7 +355,7 @@ mod bundle_factory_tests {
             data: vec![1; 100 - ROLLUP_ID_LEN],
             fee_asset_id: default_native_asset_id(),
         };
-        bundle_factory.try_push(seq_action1.clone()).unwrap();
+        bundle_factory.try_push(seq_action1.clone(), None).unwrap();
 
         // assert that the bundle factory has one bundle in the finished queue
         assert_eq!(bundle_factory.finished.len(), 1);
@@ -361,4 +381,174 @@ mod bundle_factory_tests {
         let actions_empty = bundle_factory.pop_now();
         assert!(actions_empty.is_empty());
     }
+
+    #[test]
+    fn later_rollup_overtakes_backlog_of_noisy_rollup() {
+        let mut bundle_factory = BundleFactory::new(100, HashMap::new());
+
+        // rollup 0 queues more than two bundles worth of sequence actions
+        for _ in 0..6 {
+            bundle_factory
+                .try_push(seq_action_of_size(0, 50), None)
+                .unwrap();
+        }
+        // rollup 1 sends a single sequence action after that
+        bundle_factory
+            .try_push(seq_action_of_size(1, 50), None)
+            .unwrap();
+
+        let first = bundle_factory.next_finished().unwrap().pop();
+        assert_eq!(first.rollup_counts[&RollupId::new([0; ROLLUP_ID_LEN])], 2);
+        assert!(
+            !first
+                .rollup_counts
+                .contains_key(&RollupId::new([1; ROLLUP_ID_LEN]))
+        );
+
+        // assert that the sequence action of rollup 1 is bundled ahead of rollup 0's backlog
+        let second = bundle_factory.next_finished().unwrap().pop();
+        assert_eq!(second.rollup_counts[&RollupId::new([0; ROLLUP_ID_LEN])], 1);
+        assert_eq!(second.rollup_counts[&RollupId::new([1; ROLLUP_ID_LEN])], 1);
+        assert_eq!(
+            second.into_actions()[0].as_sequence().unwrap().rollup_id,
+            RollupId::new([1; ROLLUP_ID_LEN]),
+        );
+    }
+
+    #[test]
+    fn bundle_space_is_shared_by_weight() {
+        let rollup_limits = HashMap::from([(
+            RollupId::new([0; ROLLUP_ID_LEN]),
+            Limits {
+                weight: 2,
+                ..Limits::default()
+            },
+        )]);
+        let mut bundle_factory = BundleFactory::new(300, rollup_limits);
+
+        // both rollups send sequence actions alternately until more than a bundle is queued
+        for _ in 0..3 {
+            bundle_factory
+                .try_push(seq_action_of_size(0, 50), None)
+                .unwrap();
+            bundle_factory
+                .try_push(seq_action_of_size(1, 50), None)
+                .unwrap();
+        }
+        bundle_factory
+            .try_push(seq_action_of_size(0, 50), None)
+            .unwrap();
+
+        // assert that rollup 0 gets twice the bundle space of rollup 1
+        let bundle = bundle_factory.next_finished().unwrap().pop();
+        assert_eq!(bundle.rollup_counts[&RollupId::new([0; ROLLUP_ID_LEN])], 4);
+        assert_eq!(bundle.rollup_counts[&RollupId::new([1; ROLLUP_ID_LEN])], 2);
+
+        let rest = bundle_factory.pop_now();
+        assert_eq!(rest.len(), 1);
+        assert_eq!(rest.rollup_counts[&RollupId::new([1; ROLLUP_ID_LEN])], 1);
+    }
+
+    #[test]
+    fn empty_queues_are_only_kept_for_rollups_with_limits() {
+        let limited_rollup_id = RollupId::new([0; ROLLUP_ID_LEN]);
+        let rollup_limits = HashMap::from([(
+            limited_rollup_id,
+            Limits {
+                max_queued_bytes: Some(100),
+                ..Limits::default()
+            },
+        )]);
+        let mut bundle_factory = BundleFactory::new(1000, rollup_limits);
+
+        bundle_factory
+            .try_push(seq_action_of_size(0, 50), None)
+            .unwrap();
+        bundle_factory
+            .try_push(seq_action_of_size(1, 50), None)
+            .unwrap();
+        assert_eq!(bundle_factory.queues.len(), 2);
+
+        assert_eq!(bundle_factory.pop_now().len(), 2);
+        assert_eq!(
+            bundle_factory.queues.keys().collect::<Vec<_>>(),
+            [&limited_rollup_id]
+        );
+    }
+
+    #[test]
+    fn try_push_rejects_seq_action_exceeding_quota() {
+        let rollup_id = RollupId::new([0; ROLLUP_ID_LEN]);
+        let rollup_limits = HashMap::from([(
+            rollup_id,
+            Limits {
+                max_queued_bytes: Some(100),
+                ..Limits::default()
+            },
+        )]);
+        let mut bundle_factory = BundleFactory::new(1000, rollup_limits);
+
+        bundle_factory
+            .try_push(seq_action_of_size(0, 50), None)
+            .unwrap();
+        bundle_factory
+            .try_push(seq_action_of_size(0, 50), None)
+            .unwrap();
+        assert!(matches!(
+            bundle_factory.try_push(seq_action_of_size(0, 50), None),
+            Err(BundleFactoryError::QuotaExceeded {
+                rollup_id: actual_rollup_id,
+                queued: 100,
+                size: 50,
+                max_queued: 100,
+            }) if actual_rollup_id == rollup_id
+        ));
+
+        // assert that other rollups are not affected by the quota
+        bundle_factory
+            .try_push(seq_action_of_size(1, 50), None)
+            .unwrap();
+
+        // assert that the quota is freed once the sequence actions are bundled
+        assert_eq!(bundle_factory.pop_now().len(), 3);
+        bundle_factory
+            .try_push(seq_action_of_size(0, 50), None)
+            .unwrap();
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn try_push_rejects_seq_action_exceeding_rate_limit() {
+        let rollup_id = RollupId::new([0; ROLLUP_ID_LEN]);
+        let rollup_limits = HashMap::from([(
+            rollup_id,
+            Limits {
+                rate_limit: Some(RateLimit {
+                    bytes_per_sec: 100,
+                    burst_bytes: 100,
+                }),
+                ..Limits::default()
+            },
+        )]);
+        let mut bundle_factory = BundleFactory::new(1000, rollup_limits);
+
+        bundle_factory
+            .try_push(seq_action_of_size(0, 60), None)
+            .unwrap();
+        assert!(matches!(
+            bundle_factory.try_push(seq_action_of_size(0, 60), None),
+            Err(BundleFactoryError::RateLimited {
+                rollup_id: actual_rollup_id,
+                size: 60,
+            }) if actual_rollup_id == rollup_id
+        ));
+
+        // assert that the rejected sequence action was not queued
+        assert_eq!(bundle_factory.pending_actions(), 1);
+
+        tokio::time::advance(Duration::from_millis(600)).await;
+        bundle_factory
+            .try_push(seq_action_of_size(0, 60), None)
+            .unwrap();
+        assert_eq!(bundle_factory.pending_actions(), 2);
+    }
 }
@@ -0,0 +1,257 @@
+//! A bounded queue holding bundles that the executor gave up submitting to the sequencer.
+//!
+//! The queue is shared through a [`Handle`], over which the API lists the dead letters and
+//! requests them to be replayed. Replayed bundles are taken back by the executor and submitted
+//! again before new bundles.
+use std::{
+    collections::VecDeque,
+    sync::{
+        Arc,
+        Mutex,
+    },
+};
+
+use serde::{
+    ser::SerializeStruct as _,
+    Serialize,
+};
+use tracing::warn;
+
+use super::bundle_factory::{
+    SizedBundle,
+    SizedBundleReport,
+};
+
+/// A bundle that could not be submitted to the sequencer, together with the reason why.
+#[derive(Clone)]
+pub(crate) struct DeadLetter {
+    pub(super) bundle: SizedBundle,
+    pub(super) reason: String,
+}
+
+impl Serialize for DeadLetter {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut letter = serializer.serialize_struct("DeadLetter", 2)?;
+        letter.serialize_field("reason", &self.reason)?;
+        letter.serialize_field("bundle", &SizedBundleReport(&self.bundle))?;
+        letter.end()
+    }
+}
+
+/// A FIFO queue of [`DeadLetter`]s with a fixed capacity.
+///
+/// Once the queue is full, pushing a new dead letter evicts the oldest one.
+pub(super) struct DeadLetterQueue {
+    capacity: usize,
+    letters: VecDeque<DeadLetter>,
+}
+
+impl DeadLetterQueue {
+    pub(super) fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            letters: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    /// Pushes `letter` to the back of the queue, evicting the oldest dead letter if the queue
+    /// is at capacity.
+    pub(super) fn push(&mut self, letter: DeadLetter) {
+        if self.capacity == 0 {
+            warn!(
+                bundle = %telemetry::display::json(&SizedBundleReport(&letter.bundle)),
+                reason = letter.reason,
+                "dead letter queue has zero capacity; dropping bundle",
+            );
+            return;
+        }
+        if self.letters.len() >= self.capacity {
+            if let Some(evicted) = self.letters.pop_front() {
+                warn!(
+                    bundle = %telemetry::display::json(&SizedBundleReport(&evicted.bundle)),
+                    reason = evicted.reason,
+                    "dead letter queue is full; evicting oldest bundle",
+                );
+            }
+        }
+        self.letters.push_back(letter);
+    }
+
+    /// Returns the number of dead letters in the queue.
+    pub(super) fn len(&self) -> usize {
+        self.letters.len()
+    }
+
+    /// Returns the number of sequence actions in the bundles of all dead letters.
+    fn pending_actions(&self) -> usize {
+        self.letters.iter().map(|letter| letter.bundle.len()).sum()
+    }
+
+    pub(super) fn iter(&self) -> impl Iterator<Item = &DeadLetter> {
+        self.letters.iter()
+    }
+
+    /// Removes all dead letters from the queue, from the oldest to the most recent.
+    pub(super) fn drain(&mut self) -> impl Iterator<Item = DeadLetter> + '_ {
+        self.letters.drain(..)
+    }
+}
+
+struct Inner {
+    queue: DeadLetterQueue,
+    /// Bundles taken out of the queue to be submitted again.
+    replays: VecDeque<SizedBundle>,
+}
+
+/// A shared handle to the dead letter queue of the executor.
+#[derive(Clone)]
+pub(crate) struct Handle {
+    inner: Arc<Mutex<Inner>>,
+}
+
+impl Handle {
+    pub(super) fn new(capacity: usize) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(Inner {
+                queue: DeadLetterQueue::new(capacity),
+                replays: VecDeque::new(),
+            })),
+        }
+    }
+
+    pub(super) fn push(&self, letter: DeadLetter) {
+        self.lock().queue.push(letter);
+    }
+
+    /// Returns the number of dead letters in the queue.
+    pub(super) fn len(&self) -> usize {
+        self.lock().queue.len()
+    }
+
+    /// Returns the number of sequence actions in the dead letters and the replayed bundles that
+    /// were not yet submitted again.
+    pub(super) fn pending_actions(&self) -> usize {
+        let inner = self.lock();
+        inner.queue.pending_actions() + inner.replays.iter().map(SizedBundle::len).sum::<usize>()
+    }
+
+    /// Returns the dead letters in the queue, from the oldest to the most recent.
+    pub(crate) fn letters(&self) -> Vec<DeadLetter> {
+        self.lock().queue.iter().cloned().collect()
+    }
+
+    /// Moves all dead letters out of the queue to be submitted again by the executor.
+    ///
+    /// Returns the number of replayed bundles.
+    pub(crate) fn replay(&self) -> usize {
+        let mut inner = self.lock();
+        let Inner {
+            queue,
+            replays,
+        } = &mut *inner;
+        let len_before = replays.len();
+        replays.extend(queue.drain().map(|letter| letter.bundle));
+        replays.len() - len_before
+    }
+
+    /// Returns if there are replayed bundles waiting to be submitted.
+    pub(super) fn has_replays(&self) -> bool {
+        !self.lock().replays.is_empty()
+    }
+
+    /// Takes the oldest replayed bundle waiting to be submitted.
+    pub(super) fn pop_replay(&self) -> Option<SizedBundle> {
+        self.lock().replays.pop_front()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Inner> {
+        self.inner
+            .lock()
+            .expect("the dead letter queue lock is never held across a panic")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use astria_core::sequencer::v1::{
+        asset::default_native_asset_id,
+        transaction::action::SequenceAction,
+        RollupId,
+    };
+
+    use super::*;
+    use crate::executor::bundle_factory::BundleFactory;
+
+    fn letter(reason: &str) -> DeadLetter {
+        let mut bundle_factory = BundleFactory::new(1000, HashMap::new());
+        bundle_factory
+            .try_push(
+                SequenceAction {
+                    rollup_id: RollupId::from_unhashed_bytes(reason),
+                    data: vec![0; 10],
+                    fee_asset_id: default_native_asset_id(),
+                },
+                None,
+            )
+            .unwrap();
+        DeadLetter {
+            bundle: bundle_factory.pop_now(),
+            reason: reason.to_string(),
+        }
+    }
+
+    #[test]
+    fn full_queue_evicts_oldest_letter() {
+        let mut queue = DeadLetterQueue::new(2);
+        queue.push(letter("first"));
+        queue.push(letter("second"));
+        queue.push(letter("third"));
+
+        assert_eq!(queue.len(), 2);
+        let reasons: Vec<_> = queue.iter().map(|letter| letter.reason.as_str()).collect();
+        assert_eq!(reasons, ["second", "third"]);
+    }
+
+    #[test]
+    fn replayed_letters_are_moved_out_of_the_queue() {
+        let handle = Handle::new(2);
+        handle.push(letter("first"));
+        handle.push(letter("second"));
+        assert_eq!(handle.letters().len(), 2);
+
+        assert_eq!(handle.replay(), 2);
+        assert_eq!(handle.len(), 0);
+        assert!(handle.has_replays());
+        assert!(handle.pop_replay().is_some());
+        assert!(handle.pop_replay().is_some());
+        assert!(handle.pop_replay().is_none());
+        assert!(!handle.has_replays());
+    }
+
+    #[test]
+    fn pending_actions_include_dead_letters_and_unsent_replays() {
+        let handle = Handle::new(2);
+        handle.push(letter("first"));
+        handle.push(letter("second"));
+        assert_eq!(handle.pending_actions(), 2);
+
+        handle.replay();
+        handle.push(letter("third"));
+        assert_eq!(handle.pending_actions(), 3);
+
+        handle.pop_replay();
+        assert_eq!(handle.pending_actions(), 2);
+    }
+
+    #[test]
+    fn zero_capacity_queue_drops_letters() {
+        let mut queue = DeadLetterQueue::new(0);
+        queue.push(letter("first"));
+        assert_eq!(queue.len(), 0);
+    }
+}
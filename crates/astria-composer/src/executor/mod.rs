@@ -4,26 +4,31 @@
 /// - Managing the connection to the sequencer
 /// - Submitting transactions to the sequencer
 use std::{
+    collections::HashMap,
     pin::Pin,
     task::Poll,
     time::Duration,
 };
 
 use astria_core::sequencer::v1::{
+    asset,
     transaction::action::SequenceAction,
     AbciErrorCode,
+    BalanceResponse,
+    RollupId,
     SignedTransaction,
+    TransactionParams,
     UnsignedTransaction,
 };
 use astria_eyre::eyre::{
     self,
-    eyre,
     WrapErr as _,
 };
 use ed25519_consensus::SigningKey;
 use futures::{
     future::{
         self,
+        BoxFuture,
         Fuse,
         FusedFuture as _,
         FutureExt as _,
@@ -33,11 +38,7 @@ use futures::{
 };
 use pin_project_lite::pin_project;
 use prost::Message as _;
-use secrecy::{
-    ExposeSecret as _,
-    SecretString,
-    Zeroize as _,
-};
+use secrecy::Zeroize as _;
 use sequencer_client::{
     tendermint_rpc::endpoint::broadcast::tx_sync,
     Address,
@@ -54,64 +55,124 @@ use tokio::{
     time::{
         self,
         Instant,
+        MissedTickBehavior,
     },
 };
+use tokio_util::sync::CancellationToken;
 use tracing::{
     debug,
     error,
     info,
     info_span,
     instrument,
-    instrument::Instrumented,
     warn,
     Instrument,
     Span,
 };
 
-use self::bundle_factory::SizedBundle;
-use crate::executor::bundle_factory::{
-    BundleFactory,
-    SizedBundleReport,
+use self::{
+    bundle_factory::SizedBundle,
+    pipeline::ResubmittedBundle,
+    receipt::{
+        Receipt,
+        ReceiptSender,
+    },
+    signer::{
+        Signer,
+        SignerEvent,
+        Signers,
+    },
+};
+use crate::{
+    executor::bundle_factory::{
+        BundleFactory,
+        SizedBundleReport,
+    },
+    tracker,
 };
 
+mod builder;
 mod bundle_factory;
+mod dead_letter_queue;
+mod pipeline;
+mod receipt;
+pub(crate) mod rollup_limits;
+mod signer;
+
+pub(crate) use builder::Builder;
+pub(crate) use dead_letter_queue::{
+    DeadLetter,
+    Handle as DeadLetterQueueHandle,
+};
+pub(crate) use receipt::PendingReceipt;
 
+#[cfg(test)]
+mod benchmarks;
 #[cfg(test)]
 mod tests;
 
 type StdError = dyn std::error::Error;
 
+/// The delay before the first resubmission of a bundle. Doubled for each subsequent attempt.
+const RESUBMISSION_INITIAL_DELAY: Duration = Duration::from_millis(200);
+/// The maximum delay between two resubmissions of a bundle.
+const RESUBMISSION_MAX_DELAY: Duration = Duration::from_secs(60);
+
 /// The `Executor` interfaces with the sequencer. It handles account nonces, transaction signing,
 /// and transaction submission.
 /// The `Executor` receives `Vec<Action>` from the bundling logic, packages them with a nonce into
-/// an `Unsigned`, then signs them with the sequencer key and submits to the sequencer.
+/// an `Unsigned`, then signs them with one of the sequencer keys and submits to the sequencer.
 /// Its `status` field indicates that connection to the sequencer node has been established.
 #[derive(Debug)]
 pub(super) struct Executor {
     // The status of this executor
     status: watch::Sender<Status>,
-    // Channel for receiving `SequenceAction`s to be bundled.
-    serialized_rollup_transactions: mpsc::Receiver<SequenceAction>,
+    // Channel for receiving `SequenceAction`s to be bundled, each with an optional sender for the
+    // receipt of its submission.
+    serialized_rollup_transactions: mpsc::Receiver<(SequenceAction, Option<ReceiptSender>)>,
     // The client for submitting wrapped and signed pending eth transactions to the astria
     // sequencer.
     sequencer_client: sequencer_client::HttpClient,
-    // Private key used to sign sequencer transactions
-    sequencer_key: SigningKey,
-    // The sequencer address associated with the private key
-    address: Address,
+    // Private keys used to sign sequencer transactions, one per sequencer account
+    signing_keys: Vec<SigningKey>,
+    // The fee assets whose balances each sequencer account must hold to be given new bundles.
+    monitored_fee_asset_ids: Vec<asset::Id>,
+    // The minimum balance of each monitored fee asset.
+    min_signer_balance: u128,
+    // The interval at which the balances of the sequencer accounts are checked.
+    balance_check_interval: Duration,
     // Milliseconds for bundle timer to make sure bundles are submitted at least once per block.
     block_time: tokio::time::Duration,
     // Max bytes in a sequencer action bundle
     max_bytes_per_bundle: usize,
+    // The limits of rollups that don't use the default limits when bundling their sequence
+    // actions.
+    rollup_limits: HashMap<RollupId, rollup_limits::Limits>,
+    // The number of times a bundle is attempted to be submitted before it is moved to the dead
+    // letter queue.
+    max_submission_attempts: u32,
+    // The bundles the executor gave up submitting, shared with the API.
+    dead_letter_queue: DeadLetterQueueHandle,
+    // The max number of bundles that are submitted to the sequencer without waiting for the
+    // result of the previous submissions.
+    max_in_flight_submissions: usize,
+    // The time given to submit all pending bundles after shutdown was requested.
+    shutdown_drain_timeout: Duration,
+    // Token to signal the executor to drain its pending bundles and stop.
+    shutdown_token: CancellationToken,
+    // Records the rollup transactions of submitted bundles to track their inclusion.
+    tx_tracker: tracker::Handle,
 }
 
 #[derive(Clone)]
 pub(super) struct Handle {
-    serialized_rollup_transactions_tx: mpsc::Sender<SequenceAction>,
+    serialized_rollup_transactions_tx: mpsc::Sender<(SequenceAction, Option<ReceiptSender>)>,
 }
 
 impl Handle {
-    fn new(serialized_rollup_transactions_tx: mpsc::Sender<SequenceAction>) -> Self {
+    fn new(
+        serialized_rollup_transactions_tx: mpsc::Sender<(SequenceAction, Option<ReceiptSender>)>,
+    ) -> Self {
         Self {
             serialized_rollup_transactions_tx,
         }
@@ -123,147 +184,394 @@ impl Handle {
         timeout: Duration,
     ) -> Result<(), SendTimeoutError<SequenceAction>> {
         self.serialized_rollup_transactions_tx
-            .send_timeout(sequence_action, timeout)
+            .send_timeout((sequence_action, None), timeout)
+            .await
+            .map_err(without_receipt_sender)
+    }
+
+    /// Sends `sequence_action` to the executor like [`Handle::send_timeout`], returning the
+    /// receipt of the sequencer transaction it will be submitted in.
+    pub(super) async fn send_with_receipt_timeout(
+        &self,
+        sequence_action: SequenceAction,
+        timeout: Duration,
+    ) -> Result<PendingReceipt, SendTimeoutError<SequenceAction>> {
+        let (receipt_sender, pending_receipt) = receipt::channel();
+        self.serialized_rollup_transactions_tx
+            .send_timeout((sequence_action, Some(receipt_sender)), timeout)
+            .await
+            .map_err(without_receipt_sender)?;
+        Ok(pending_receipt)
+    }
+
+    /// Sends all `sequence_actions` to the executor, or none of them if the channel to the
+    /// executor does not have capacity for all of them within `timeout`.
+    ///
+    /// Returns the pending receipt of each sequence action whose `wait_for_receipt` flag is set.
+    pub(super) async fn send_all_timeout(
+        &self,
+        sequence_actions: Vec<(SequenceAction, bool)>,
+        timeout: Duration,
+    ) -> Result<Vec<Option<PendingReceipt>>, SendTimeoutError<()>> {
+        let reserve_all = async {
+            let mut permits = Vec::with_capacity(sequence_actions.len());
+            for _ in 0..sequence_actions.len() {
+                let permit = self
+                    .serialized_rollup_transactions_tx
+                    .reserve()
+                    .await
+                    .map_err(|_| SendTimeoutError::Closed(()))?;
+                permits.push(permit);
+            }
+            Ok(permits)
+        };
+        let permits = time::timeout(timeout, reserve_all)
             .await
+            .map_err(|_| SendTimeoutError::Timeout(()))??;
+        Ok(permits
+            .into_iter()
+            .zip(sequence_actions)
+            .map(|(permit, (sequence_action, wait_for_receipt))| {
+                if wait_for_receipt {
+                    let (receipt_sender, pending_receipt) = receipt::channel();
+                    permit.send((sequence_action, Some(receipt_sender)));
+                    Some(pending_receipt)
+                } else {
+                    permit.send((sequence_action, None));
+                    None
+                }
+            })
+            .collect())
+    }
+
+    /// Returns the max number of sequence actions that can be sent at once with
+    /// [`Handle::send_all_timeout`].
+    pub(super) fn max_batch_len(&self) -> usize {
+        self.serialized_rollup_transactions_tx.max_capacity()
+    }
+}
+
+fn without_receipt_sender(
+    error: SendTimeoutError<(SequenceAction, Option<ReceiptSender>)>,
+) -> SendTimeoutError<SequenceAction> {
+    match error {
+        SendTimeoutError::Timeout((sequence_action, _)) => {
+            SendTimeoutError::Timeout(sequence_action)
+        }
+        SendTimeoutError::Closed((sequence_action, _)) => SendTimeoutError::Closed(sequence_action),
     }
 }
 
 impl Drop for Executor {
     fn drop(&mut self) {
-        self.sequencer_key.zeroize();
+        for signing_key in &mut self.signing_keys {
+            signing_key.zeroize();
+        }
     }
 }
 
 #[derive(Debug)]
 pub(super) struct Status {
     is_connected: bool,
+    allowed_fee_asset_ids: Vec<asset::Id>,
 }
 
 impl Status {
     pub(super) fn new() -> Self {
         Self {
             is_connected: false,
+            allowed_fee_asset_ids: Vec::new(),
         }
     }
 
     pub(super) fn is_connected(&self) -> bool {
         self.is_connected
     }
-}
 
-impl Executor {
-    pub(super) fn new(
-        sequencer_url: &str,
-        private_key: &SecretString,
-        block_time: u64,
-        max_bytes_per_bundle: usize,
-    ) -> eyre::Result<(Self, Handle)> {
-        let sequencer_client = sequencer_client::HttpClient::new(sequencer_url)
-            .wrap_err("failed constructing sequencer client")?;
-        let (status, _) = watch::channel(Status::new());
-        let mut private_key_bytes: [u8; 32] = hex::decode(private_key.expose_secret())
-            .wrap_err("failed to decode private key bytes from hex string")?
-            .try_into()
-            .map_err(|_| eyre!("invalid private key length; must be 32 bytes"))?;
-        let sequencer_key = SigningKey::from(private_key_bytes);
-        private_key_bytes.zeroize();
-
-        let sequencer_address = Address::from_verification_key(sequencer_key.verification_key());
-
-        let (serialized_rollup_transaction_tx, serialized_rollup_transaction_rx) =
-            tokio::sync::mpsc::channel::<SequenceAction>(256);
-
-        Ok((
-            Self {
-                status,
-                serialized_rollup_transactions: serialized_rollup_transaction_rx,
-                sequencer_client,
-                sequencer_key,
-                address: sequencer_address,
-                block_time: Duration::from_millis(block_time),
-                max_bytes_per_bundle,
-            },
-            Handle::new(serialized_rollup_transaction_tx),
-        ))
+    /// Returns if the sequencer accepted `fee_asset_id` as fee payment when the executor
+    /// connected to it.
+    pub(super) fn is_allowed_fee_asset(&self, fee_asset_id: asset::Id) -> bool {
+        self.allowed_fee_asset_ids.contains(&fee_asset_id)
     }
+}
 
+impl Executor {
     /// Return a reader to the status reporting channel
     pub(super) fn subscribe(&self) -> watch::Receiver<Status> {
         self.status.subscribe()
     }
 
-    /// Create a future to submit a bundle to the sequencer.
-    #[instrument(skip_all, fields(nonce.initial = %nonce))]
-    fn submit_bundle(&self, nonce: u32, bundle: SizedBundle) -> Fuse<Instrumented<SubmitFut>> {
-        SubmitFut {
+    /// Returns a handle to inspect and replay the bundles in the dead letter queue.
+    pub(super) fn dead_letter_queue(&self) -> DeadLetterQueueHandle {
+        self.dead_letter_queue.clone()
+    }
+
+    /// Submits `bundle` to the sequencer under the next nonce of `signer`.
+    ///
+    /// `attempts` is the number of times the bundle was already attempted to be submitted.
+    #[instrument(skip_all, fields(address = %signer.address, nonce.initial = %signer.nonce))]
+    fn submit_bundle(
+        &self,
+        chain_id: String,
+        signer: &mut Signer,
+        bundle: SizedBundle,
+        attempts: u32,
+    ) {
+        let fut = SubmitFut {
             client: self.sequencer_client.clone(),
-            address: self.address,
-            nonce,
-            signing_key: self.sequencer_key.clone(),
+            chain_id,
+            nonce: signer.nonce,
+            signing_key: signer.key.clone(),
             state: SubmitState::NotStarted,
-            bundle,
+            bundle: bundle.clone(),
+            attempts,
+            max_attempts: self.max_submission_attempts,
+        }
+        .in_current_span();
+        signer.pipeline.push(signer.nonce, bundle, fut);
+        signer.nonce += 1;
+    }
+
+    /// Handles the `outcome` of the submission `signer` made under `submitted_nonce`.
+    fn handle_submit_outcome(
+        &self,
+        signer: &mut Signer,
+        submitted_nonce: u32,
+        outcome: SubmitOutcome,
+    ) {
+        match outcome {
+            SubmitOutcome::Submitted {
+                bundle,
+                receipt,
+            } => {
+                self.tx_tracker
+                    .submitted(receipt.sequencer_tx_hash, bundle.rollup_txs());
+                bundle.report_submitted(receipt);
+            }
+            SubmitOutcome::InvalidNonce {
+                bundle,
+                attempts,
+            } => {
+                info!(
+                    address = %signer.address,
+                    nonce.rejected = submitted_nonce,
+                    "waiting for in-flight bundles to resolve before resubmitting bundle after \
+                     invalid nonce",
+                );
+                signer.pipeline.requeue(ResubmittedBundle {
+                    bundle,
+                    attempts,
+                });
+            }
+            SubmitOutcome::Failed(dead_letter) => {
+                // the nonce was not consumed, so the bundles submitted after this one are only
+                // accepted once it is used again
+                signer.pipeline.halt();
+                error!(
+                    address = %signer.address,
+                    reason = dead_letter.reason,
+                    bundle = %telemetry::display::json(&SizedBundleReport(&dead_letter.bundle)),
+                    "failed submitting bundle to sequencer; moving it to the dead letter queue",
+                );
+                dead_letter.bundle.report_failed(dead_letter.reason.clone());
+                self.dead_letter_queue.push(dead_letter);
+                debug!(
+                    dead_letter_queue.len = self.dead_letter_queue.len(),
+                    "bundle moved to dead letter queue",
+                );
+            }
+        }
+        if signer.pipeline.resume_if_settled() {
+            metrics::counter!(crate::metrics_init::NONCE_REFETCH_COUNT).increment(1);
+            info!(
+                address = %signer.address,
+                "all in-flight bundles resolved after a failed submission; fetching new nonce to \
+                 resubmit the bundles that were not accepted",
+            );
+            signer.nonce_fut = get_latest_nonce(self.sequencer_client.clone(), signer.address)
+                .boxed()
+                .fuse();
         }
-        .in_current_span()
-        .fuse()
     }
 
     /// Run the Executor loop, calling `process_bundle` on each bundle received from the channel.
     ///
+    /// New bundles are distributed round robin across all signing keys, each of which keeps
+    /// its own nonce. Keys whose account balance of any fee asset fell below the minimum
+    /// balance are not given new bundles until they are topped up.
+    ///
+    /// Bundles that could not be submitted are moved to the dead letter queue instead of
+    /// stopping the executor.
+    ///
+    /// Once the shutdown token is cancelled, the executor stops accepting new sequence actions,
+    /// bundles the ones still in its channel, and submits all pending bundles in nonce order.
+    /// It exits once all bundles are submitted or after the shutdown drain timeout, reporting
+    /// how many sequence actions were dropped, including those of bundles left in the dead letter
+    /// queue.
+    ///
     /// # Errors
     /// An error is returned if connecting to the sequencer fails.
-    #[instrument(skip_all, fields(address = %self.address))]
+    #[instrument(skip_all, fields(signers = self.signing_keys.len()))]
     pub(super) async fn run_until_stopped(mut self) -> eyre::Result<()> {
-        let mut submission_fut: Fuse<Instrumented<SubmitFut>> = Fuse::terminated();
-        let mut nonce = get_latest_nonce(self.sequencer_client.clone(), self.address)
+        let chain_id = get_chain_id(self.sequencer_client.clone())
             .await
-            .wrap_err("failed getting initial nonce from sequencer")?;
+            .wrap_err("failed getting chain id from sequencer")?;
+        let mut signers = Vec::with_capacity(self.signing_keys.len());
+        for signing_key in &self.signing_keys {
+            let address = Address::from_verification_key(signing_key.verification_key());
+            let nonce = get_latest_nonce(self.sequencer_client.clone(), address)
+                .await
+                .wrap_err("failed getting initial nonce from sequencer")?;
+            signers.push(Signer::new(
+                signing_key.clone(),
+                nonce,
+                self.max_in_flight_submissions,
+            ));
+        }
+        let mut signers = Signers::new(signers);
+        let allowed_fee_asset_ids = get_allowed_fee_asset_ids(self.sequencer_client.clone())
+            .await
+            .wrap_err("failed getting allowed fee assets from sequencer")?;
 
-        self.status.send_modify(|status| status.is_connected = true);
+        self.status.send_modify(|status| {
+            status.allowed_fee_asset_ids = allowed_fee_asset_ids;
+            status.is_connected = true;
+        });
 
         let block_timer = time::sleep(self.block_time);
         tokio::pin!(block_timer);
-        let mut bundle_factory = BundleFactory::new(self.max_bytes_per_bundle);
+        let mut bundle_factory =
+            BundleFactory::new(self.max_bytes_per_bundle, self.rollup_limits.clone());
+
+        let mut balance_check_interval = time::interval(self.balance_check_interval);
+        balance_check_interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        let mut balances_fut: Fuse<BoxFuture<'static, Vec<_>>> = Fuse::terminated();
+
+        let shutdown_deadline = time::sleep(Duration::ZERO);
+        tokio::pin!(shutdown_deadline);
+        let mut shutting_down = false;
 
         let reset_time = || Instant::now() + self.block_time;
 
         loop {
+            if shutting_down
+                && signers.is_empty()
+                && bundle_factory.is_empty()
+                && !self.dead_letter_queue.has_replays()
+            {
+                let actions_dropped = self.dead_letter_queue.pending_actions();
+                if actions_dropped == 0 {
+                    info!("submitted all pending bundles; shutting down");
+                } else {
+                    warn!(
+                        actions_dropped,
+                        dead_letter_queue.len = self.dead_letter_queue.len(),
+                        "submitted all pending bundles except those in the dead letter queue; \
+                         shutting down",
+                    );
+                }
+                break Ok(());
+            }
+
+            // new bundles are only submitted by a signer with space in its pipeline, a known
+            // nonce, and sufficient balance
+            let next_signer = signers.next_for_new_bundle();
+            let resubmitting_signer = signers.next_with_resubmissions();
+
             select! {
                 biased;
 
-                // process submission result and update nonce
-                rsp = &mut submission_fut, if !submission_fut.is_terminated() => {
-                    match rsp {
-                        Ok(new_nonce) => nonce = new_nonce,
-                        Err(error) => {
-                            error!(%error, "failed submitting bundle to sequencer; aborting executor");
-                            break Err(error).wrap_err("failed submitting bundle to sequencer");
+                () = self.shutdown_token.cancelled(), if !shutting_down => {
+                    shutting_down = true;
+                    shutdown_deadline.as_mut().reset(Instant::now() + self.shutdown_drain_timeout);
+                    // stop accepting new sequence actions and bundle the ones already sent
+                    self.serialized_rollup_transactions.close();
+                    while let Some((seq_action, receipt_sender)) =
+                        self.serialized_rollup_transactions.recv().await
+                    {
+                        bundle_seq_action(&mut bundle_factory, seq_action, receipt_sender);
+                    }
+                    bundle_factory.finish_queued();
+                    info!(
+                        deadline = %humantime::format_duration(self.shutdown_drain_timeout),
+                        "received shutdown signal; submitting pending bundles before shutting down",
+                    );
+                }
+
+                () = &mut shutdown_deadline, if shutting_down => {
+                    let actions_dropped = signers.pending_actions()
+                        + bundle_factory.pending_actions()
+                        + self.dead_letter_queue.pending_actions();
+                    warn!(
+                        actions_dropped,
+                        dead_letter_queue.len = self.dead_letter_queue.len(),
+                        "shutdown drain timeout elapsed before all pending bundles were submitted; \
+                         shutting down",
+                    );
+                    break Ok(());
+                }
+
+                // process submission results in the order each signer submitted its bundles
+                event = signers.next_event(), if signers.has_pending_events() => {
+                    match event {
+                        SignerEvent::Outcome { index, nonce, outcome } => {
+                            self.handle_submit_outcome(
+                                signers.get_mut(index),
+                                nonce,
+                                outcome,
+                            );
+                            block_timer.as_mut().reset(reset_time());
+                        }
+                        SignerEvent::Nonce { index, nonce } => {
+                            signers.get_mut(index).nonce =
+                                nonce.wrap_err("failed getting new nonce from sequencer")?;
                         }
                     }
+                }
+
+                balances = &mut balances_fut, if !balances_fut.is_terminated() => {
+                    signers.update_balances(balances, &self.monitored_fee_asset_ids, self.min_signer_balance);
+                }
+
+                // bundles taken out of a signer's pipeline are resubmitted before it takes new bundles
+                () = future::ready(()), if resubmitting_signer.is_some() => {
+                    let signer = signers.get_mut(
+                        resubmitting_signer.expect("a signer has bundles to resubmit; this is a bug"),
+                    );
+                    let ResubmittedBundle { bundle, attempts } = signer
+                        .pipeline
+                        .pop_resubmission()
+                        .expect("the pipeline has bundles to resubmit; this is a bug");
+                    self.submit_bundle(chain_id.clone(), signer, bundle, attempts);
+                }
 
-                    block_timer.as_mut().reset(reset_time());
+                // bundles replayed from the dead letter queue are submitted before new bundles
+                () = future::ready(()), if next_signer.is_some() && self.dead_letter_queue.has_replays() => {
+                    if let Some(bundle) = self.dead_letter_queue.pop_replay() {
+                        let signer = signers.take_turn(
+                            next_signer.expect("a signer can take a new bundle; this is a bug"),
+                        );
+                        self.submit_bundle(chain_id.clone(), signer, bundle, 0);
+                    }
                 }
 
-                Some(next_bundle) = future::ready(bundle_factory.next_finished()), if submission_fut.is_terminated() => {
+                Some(next_bundle) = future::ready(bundle_factory.next_finished()), if next_signer.is_some() => {
                     let bundle = next_bundle.pop();
                     if !bundle.is_empty() {
-                        submission_fut = self.submit_bundle(nonce, bundle);
+                        let signer = signers.take_turn(
+                            next_signer.expect("a signer can take a new bundle; this is a bug"),
+                        );
+                        self.submit_bundle(chain_id.clone(), signer, bundle, 0);
                     }
                 }
 
                 // receive new seq_action and bundle it
-                Some(seq_action) = self.serialized_rollup_transactions.recv() => {
-                    let rollup_id = seq_action.rollup_id;
-                    if let Err(e) = bundle_factory.try_push(seq_action) {
-                            warn!(
-                                rollup_id = %rollup_id,
-                                error = &e as &StdError,
-                                "failed to bundle sequence action: too large. sequence action is dropped."
-                            );
-                    }
+                Some((seq_action, receipt_sender)) = self.serialized_rollup_transactions.recv(), if !shutting_down => {
+                    bundle_seq_action(&mut bundle_factory, seq_action, receipt_sender);
                 }
 
                 // try to preempt current bundle if the timer has ticked without submitting the next bundle
-                () = &mut block_timer, if submission_fut.is_terminated() => {
+                () = &mut block_timer, if next_signer.is_some() && !shutting_down => {
                     let bundle = bundle_factory.pop_now();
                     if bundle.is_empty() {
                         debug!("block timer ticked, but no bundle to submit to sequencer");
@@ -272,14 +580,53 @@ impl Executor {
                         debug!(
                             "forcing bundle submission to sequencer due to block timer"
                         );
-                        submission_fut = self.submit_bundle(nonce, bundle);
+                        let signer = signers.take_turn(
+                            next_signer.expect("a signer can take a new bundle; this is a bug"),
+                        );
+                        self.submit_bundle(chain_id.clone(), signer, bundle, 0);
                     }
                 }
+
+                _ = balance_check_interval.tick(), if balances_fut.is_terminated() => {
+                    balances_fut = get_latest_balances(self.sequencer_client.clone(), signers.addresses())
+                        .boxed()
+                        .fuse();
+                }
             }
         }
     }
 }
 
+/// Pushes `seq_action` into the bundle factory, dropping it if it is too large or its rollup
+/// exceeded its limits.
+///
+/// If a `receipt_sender` is given, it is handed the reporter of the bundle the sequence action
+/// ends up in.
+fn bundle_seq_action(
+    bundle_factory: &mut BundleFactory,
+    seq_action: SequenceAction,
+    receipt_sender: Option<ReceiptSender>,
+) {
+    let rollup_id = seq_action.rollup_id;
+    if let Err(e) = bundle_factory.try_push(seq_action, receipt_sender) {
+        warn!(
+            rollup_id = %rollup_id,
+            error = &e as &StdError,
+            "failed to bundle sequence action. sequence action is dropped."
+        );
+    }
+}
+
+/// Queries the sequencer for the ID of its chain with an exponential backoff
+#[instrument(name = "get chain id", skip_all)]
+async fn get_chain_id(client: sequencer_client::HttpClient) -> eyre::Result<String> {
+    debug!("fetching chain id from sequencer");
+    query_with_retry(client, "chain id", |client| async move {
+        client.get_chain_id().await
+    })
+    .await
+}
+
 /// Queries the sequencer for the latest nonce with an exponential backoff
 #[instrument(name = "get latest nonce", skip_all, fields(%address))]
 async fn get_latest_nonce(
@@ -287,6 +634,54 @@ async fn get_latest_nonce(
     address: Address,
 ) -> eyre::Result<u32> {
     debug!("fetching latest nonce from sequencer");
+    query_with_retry(client, "latest nonce", |client| async move {
+        client.get_latest_nonce(address).await.map(|rsp| rsp.nonce)
+    })
+    .await
+}
+
+/// Queries the sequencer for the latest balances of all `addresses`, in the same order.
+///
+/// The queries are not retried; failed queries are repeated at the next balance check.
+async fn get_latest_balances(
+    client: sequencer_client::HttpClient,
+    addresses: Vec<Address>,
+) -> Vec<Result<BalanceResponse, sequencer_client::extension_trait::Error>> {
+    future::join_all(addresses.into_iter().map(|address| {
+        let client = client.clone();
+        async move { client.get_latest_balance(address).await }
+    }))
+    .await
+}
+
+/// Queries the sequencer for the assets it accepts as fee payment with an exponential backoff
+#[instrument(name = "get allowed fee asset ids", skip_all)]
+async fn get_allowed_fee_asset_ids(
+    client: sequencer_client::HttpClient,
+) -> eyre::Result<Vec<asset::Id>> {
+    debug!("fetching allowed fee asset ids from sequencer");
+    query_with_retry(client, "allowed fee asset ids", |client| async move {
+        client
+            .get_latest_allowed_fee_asset_ids()
+            .await
+            .map(|rsp| rsp.fee_asset_ids)
+    })
+    .await
+}
+
+/// Runs `query` against the sequencer until it succeeds, waiting with an exponential backoff
+/// between attempts.
+///
+/// `description` names what is queried in the emitted logs and the returned error.
+async fn query_with_retry<T, F, Fut>(
+    client: sequencer_client::HttpClient,
+    description: &'static str,
+    query: F,
+) -> eyre::Result<T>
+where
+    F: Fn(sequencer_client::HttpClient) -> Fut,
+    Fut: Future<Output = Result<T, sequencer_client::extension_trait::Error>>,
+{
     let span = Span::current();
     let retry_config = tryhard::RetryFutureConfig::new(1024)
         .exponential_backoff(Duration::from_millis(200))
@@ -303,27 +698,27 @@ async fn get_latest_nonce(
                     error = err as &dyn std::error::Error,
                     attempt,
                     wait_duration,
-                    "failed getting latest nonce from sequencer; retrying after backoff",
+                    query = description,
+                    "failed querying sequencer; retrying after backoff",
                 );
                 async move {}
             },
         );
     tryhard::retry_fn(|| {
-        let client = client.clone();
-        let span = info_span!(parent: span.clone(), "attempt get nonce");
-        async move { client.get_latest_nonce(address).await.map(|rsp| rsp.nonce) }.instrument(span)
+        let span = info_span!(parent: span.clone(), "attempt query", query = description);
+        query(client.clone()).instrument(span)
     })
     .with_config(retry_config)
     .await
-    .wrap_err("failed getting latest nonce from sequencer after 1024 attempts")
+    .wrap_err_with(|| format!("failed getting {description} from sequencer after 1024 attempts"))
 }
 
-/// Queries the sequencer for the latest nonce with an exponential backoff
+/// Submits a signed transaction to the sequencer
 #[instrument(
     name = "submit signed transaction",
     skip_all,
     fields(
-        nonce = tx.unsigned_transaction().nonce,
+        nonce = tx.nonce(),
         transaction.hash = hex::encode(sha256(&tx.to_raw().encode_to_vec())),
     )
 )]
@@ -334,56 +729,52 @@ async fn submit_tx(
     // TODO: change to info and log tx hash (to match info log in `SubmitFut`'s response handling
     // logic)
     debug!("submitting signed transaction to sequencer");
-    let span = Span::current();
-    let retry_config = tryhard::RetryFutureConfig::new(1024)
-        .exponential_backoff(Duration::from_millis(200))
-        .max_delay(Duration::from_secs(60))
-        .on_retry(
-            |attempt,
-             next_delay: Option<Duration>,
-             err: &sequencer_client::extension_trait::Error| {
-                let wait_duration = next_delay
-                    .map(humantime::format_duration)
-                    .map(tracing::field::display);
-                warn!(
-                    parent: span.clone(),
-                    attempt,
-                    wait_duration,
-                    error = err as &dyn std::error::Error,
-                    "failed sending transaction to sequencer; retrying after backoff",
-                );
-                async move {}
-            },
-        );
-    tryhard::retry_fn(|| {
-        let client = client.clone();
-        let tx = tx.clone();
-        let span = info_span!(parent: span.clone(), "attempt send");
-        async move { client.submit_transaction_sync(tx).await }.instrument(span)
-    })
-    .with_config(retry_config)
-    .await
-    .wrap_err("failed sending transaction after 1024 attempts")
+    client
+        .submit_transaction_sync(tx)
+        .await
+        .wrap_err("failed sending transaction to sequencer")
+}
+
+/// Returns the delay before the `attempt`th resubmission of a bundle.
+fn resubmission_delay(attempt: u32) -> Duration {
+    RESUBMISSION_INITIAL_DELAY
+        .saturating_mul(2u32.saturating_pow(attempt.saturating_sub(1)))
+        .min(RESUBMISSION_MAX_DELAY)
+}
+
+/// The result of submitting a bundle to the sequencer.
+enum SubmitOutcome {
+    /// The sequencer accepted the transaction.
+    Submitted {
+        bundle: SizedBundle,
+        receipt: Receipt,
+    },
+    /// The sequencer rejected the transaction because of its nonce. The bundle should be
+    /// resubmitted under a new nonce.
+    InvalidNonce { bundle: SizedBundle, attempts: u32 },
+    /// The bundle was given up on and should be moved to the dead letter queue.
+    Failed(DeadLetter),
 }
 
 pin_project! {
-    /// A future to submit a bundle to the sequencer, returning the next nonce that should be used for the next submission.
+    /// A future to submit a bundle to the sequencer under a fixed nonce.
     ///
-    /// The future will fetch a new nonce from the sequencer if a submission returned an `INVALID_NONCE` error code.
+    /// If sending the transaction fails, the future resends it with an exponential backoff. It returns
+    /// [`SubmitOutcome::InvalidNonce`] if the sequencer rejected the transaction due to its nonce, leaving it to the
+    /// caller to fetch a new nonce and resubmit the bundle.
     ///
-    /// The future will only return an error if it ultimately failed submitting a transaction due to the underlying
-    /// transport failing. This can be taken as a break condition to exit the executor loop.
-    ///
-    /// If the sequencer returned a non-zero abci code (albeit not `INVALID_NONCE`), this future will return with
-    /// that nonce it used to submit the non-zero abci code request.
+    /// The future returns [`SubmitOutcome::Failed`] if the sequencer rejected the transaction with a non-zero abci code
+    /// other than `INVALID_NONCE`, or if it did not succeed after `max_attempts` attempts.
     struct SubmitFut {
         client: sequencer_client::HttpClient,
-        address: Address,
+        chain_id: String,
         nonce: u32,
         signing_key: SigningKey,
         #[pin]
         state: SubmitState,
         bundle: SizedBundle,
+        attempts: u32,
+        max_attempts: u32,
     }
 
     impl PinnedDrop for SubmitFut {
@@ -400,16 +791,42 @@ pin_project! {
         WaitingForSend {
             #[pin]
             fut: Pin<Box<dyn Future<Output = eyre::Result<tx_sync::Response>> + Send>>,
+            tx_hash: [u8; 32],
+            started: Instant,
         },
-        WaitingForNonce {
+        WaitingForRetry {
             #[pin]
-            fut: Pin<Box<dyn Future<Output = eyre::Result<u32>> + Send>>,
+            sleep: time::Sleep,
+        },
+    }
+}
+
+impl SubmitFut {
+    /// Moves the future into the backoff state, or gives up if it ran out of attempts.
+    fn retry_or_give_up(self: Pin<&mut Self>, reason: &str) -> Result<SubmitState, SubmitOutcome> {
+        let this = self.project();
+        *this.attempts += 1;
+        if *this.attempts >= *this.max_attempts {
+            return Err(SubmitOutcome::Failed(DeadLetter {
+                bundle: this.bundle.clone(),
+                reason: format!("giving up after {} attempts: {reason}", *this.attempts),
+            }));
         }
+        let delay = resubmission_delay(*this.attempts);
+        warn!(
+            attempt = *this.attempts,
+            wait_duration = %humantime::format_duration(delay),
+            reason,
+            "failed submitting bundle to sequencer; retrying after backoff",
+        );
+        Ok(SubmitState::WaitingForRetry {
+            sleep: time::sleep(delay),
+        })
     }
 }
 
 impl Future for SubmitFut {
-    type Output = eyre::Result<u32>;
+    type Output = SubmitOutcome;
 
     fn poll(mut self: Pin<&mut Self>, cx: &mut std::task::Context<'_>) -> Poll<Self::Output> {
         loop {
@@ -418,85 +835,104 @@ impl Future for SubmitFut {
             let new_state = match this.state.project() {
                 SubmitStateProj::NotStarted => {
                     let tx = UnsignedTransaction {
-                        nonce: *this.nonce,
                         actions: this.bundle.clone().into_actions(),
+                        params: TransactionParams {
+                            nonce: *this.nonce,
+                            chain_id: this.chain_id.clone(),
+                        },
                     }
                     .into_signed(this.signing_key);
+                    let tx_hash = tx.sha256_of_proto_encoding();
                     info!(
                         nonce.actual = *this.nonce,
                         bundle = %telemetry::display::json(&SizedBundleReport(this.bundle)),
-                        transaction.hash = %telemetry::display::base64(&tx.sha256_of_proto_encoding()),
+                        transaction.hash = %telemetry::display::base64(&tx_hash),
                         "submitting transaction to sequencer",
                     );
                     SubmitState::WaitingForSend {
+                        tx_hash,
                         fut: submit_tx(this.client.clone(), tx).boxed(),
+                        started: Instant::now(),
                     }
                 }
 
                 SubmitStateProj::WaitingForSend {
                     fut,
+                    tx_hash,
+                    started,
                 } => match ready!(fut.poll(cx)) {
                     Ok(rsp) => {
+                        metrics::histogram!(crate::metrics_init::SEQUENCER_SUBMISSION_LATENCY)
+                            .record(started.elapsed());
                         let tendermint::abci::Code::Err(code) = rsp.code else {
                             info!("sequencer responded with ok; submission successful");
-                            return Poll::Ready(Ok(*this.nonce + 1));
+                            return Poll::Ready(SubmitOutcome::Submitted {
+                                bundle: this.bundle.clone(),
+                                receipt: Receipt {
+                                    sequencer_tx_hash: *tx_hash,
+                                    nonce: *this.nonce,
+                                },
+                            });
                         };
+                        metrics::counter!(
+                            crate::metrics_init::SEQUENCER_SUBMISSION_FAILURE_COUNT,
+                            crate::metrics_init::ABCI_CODE_LABEL => code.to_string(),
+                        )
+                        .increment(1);
                         match AbciErrorCode::from(code) {
                             AbciErrorCode::INVALID_NONCE => {
+                                *this.attempts += 1;
+                                if *this.attempts >= *this.max_attempts {
+                                    return Poll::Ready(SubmitOutcome::Failed(DeadLetter {
+                                        bundle: this.bundle.clone(),
+                                        reason: format!(
+                                            "giving up after {} attempts: sequencer rejected \
+                                             transaction due to invalid nonce",
+                                            *this.attempts,
+                                        ),
+                                    }));
+                                }
                                 info!(
-                                    "sequencer rejected transaction due to invalid nonce; \
-                                     fetching new nonce"
+                                    "sequencer rejected transaction due to invalid nonce; bundle \
+                                     needs to be resubmitted under a new nonce"
                                 );
-                                SubmitState::WaitingForNonce {
-                                    fut: get_latest_nonce(this.client.clone(), *this.address)
-                                        .boxed(),
-                                }
+                                return Poll::Ready(SubmitOutcome::InvalidNonce {
+                                    bundle: this.bundle.clone(),
+                                    attempts: *this.attempts,
+                                });
                             }
                             _other => {
                                 warn!(
                                     abci.code = rsp.code.value(),
                                     abci.log = rsp.log,
-                                    "sequencer rejected the transaction; the bundle is likely lost",
+                                    "sequencer rejected the transaction",
                                 );
-                                return Poll::Ready(Ok(*this.nonce));
+                                return Poll::Ready(SubmitOutcome::Failed(DeadLetter {
+                                    bundle: this.bundle.clone(),
+                                    reason: format!(
+                                        "sequencer rejected transaction with abci code {}: {}",
+                                        rsp.code.value(),
+                                        rsp.log,
+                                    ),
+                                }));
                             }
                         }
                     }
                     Err(error) => {
-                        error!(%error, "failed sending transaction to sequencer");
-                        return Poll::Ready(
-                            Err(error).wrap_err("failed sending transaction to sequencer"),
-                        );
-                    }
-                },
-
-                SubmitStateProj::WaitingForNonce {
-                    fut,
-                } => match ready!(fut.poll(cx)) {
-                    Ok(nonce) => {
-                        *this.nonce = nonce;
-                        let tx = UnsignedTransaction {
-                            nonce: *this.nonce,
-                            actions: this.bundle.clone().into_actions(),
-                        }
-                        .into_signed(this.signing_key);
-                        info!(
-                            nonce.resubmission = *this.nonce,
-                            bundle = %telemetry::display::json(&SizedBundleReport(this.bundle)),
-                            transaction.hash = %telemetry::display::base64(&tx.sha256_of_proto_encoding()),
-                            "resubmitting transaction to sequencer with new nonce",
-                        );
-                        SubmitState::WaitingForSend {
-                            fut: submit_tx(this.client.clone(), tx).boxed(),
+                        let reason = format!("{error:#}");
+                        match self.as_mut().retry_or_give_up(&reason) {
+                            Ok(state) => state,
+                            Err(outcome) => return Poll::Ready(outcome),
                         }
                     }
-                    Err(error) => {
-                        error!(%error, "critically failed getting a new nonce from the sequencer");
-                        return Poll::Ready(
-                            Err(error).wrap_err("failed getting nonce from sequencer"),
-                        );
-                    }
                 },
+
+                SubmitStateProj::WaitingForRetry {
+                    sleep,
+                } => {
+                    ready!(sleep.poll(cx));
+                    SubmitState::NotStarted
+                }
             };
             self.as_mut().project().state.set(new_state);
         }
@@ -0,0 +1,157 @@
+/// ! The pipeline of bundle submissions that are in flight to the sequencer at the same time.
+use std::{
+    collections::VecDeque,
+    task::{
+        Context,
+        Poll,
+    },
+};
+
+use futures::{
+    ready,
+    stream::{
+        FuturesOrdered,
+        StreamExt as _,
+    },
+};
+use tracing::instrument::Instrumented;
+
+use super::{
+    bundle_factory::SizedBundle,
+    SubmitFut,
+    SubmitOutcome,
+};
+
+/// A bundle that was taken out of the pipeline and has to be submitted again.
+pub(super) struct ResubmittedBundle {
+    pub(super) bundle: SizedBundle,
+    /// The number of times the bundle was already attempted to be submitted.
+    pub(super) attempts: u32,
+}
+
+/// Tracks up to `max_in_flight` submissions to the sequencer, which are signed with consecutive
+/// nonces.
+///
+/// Outcomes are returned in the order the bundles were submitted, so that the executor sees the
+/// result of a submission only after all submissions with lower nonces have resolved. If a
+/// submission fails, the pipeline is halted: it takes no new submissions until all submissions
+/// following the failed one have resolved as well. Only the bundles that were not accepted by the
+/// sequencer are then queued for resubmission under new nonces, so that no bundle ends up in
+/// two transactions.
+pub(super) struct Pipeline {
+    max_in_flight: usize,
+    in_flight: FuturesOrdered<Instrumented<SubmitFut>>,
+    /// The nonces and bundles of the in-flight submissions, in the order they were submitted.
+    in_flight_bundles: VecDeque<(u32, SizedBundle)>,
+    /// Set from a failed submission until all in-flight submissions have resolved.
+    halted: bool,
+    /// Bundles that were not accepted while the pipeline was halted, in the order they were
+    /// submitted.
+    requeued: VecDeque<ResubmittedBundle>,
+    /// Bundles waiting to be resubmitted, which take precedence over new bundles.
+    resubmissions: VecDeque<ResubmittedBundle>,
+}
+
+impl Pipeline {
+    pub(super) fn new(max_in_flight: usize) -> Self {
+        Self {
+            // a pipeline of size 0 would never submit anything
+            max_in_flight: max_in_flight.max(1),
+            in_flight: FuturesOrdered::new(),
+            in_flight_bundles: VecDeque::new(),
+            halted: false,
+            requeued: VecDeque::new(),
+            resubmissions: VecDeque::new(),
+        }
+    }
+
+    pub(super) fn is_empty(&self) -> bool {
+        self.in_flight.is_empty()
+    }
+
+    /// Returns true if the pipeline takes no further submissions, either because the max number
+    /// of submissions are in flight or because it is halted.
+    pub(super) fn is_full(&self) -> bool {
+        self.halted || self.in_flight.len() >= self.max_in_flight
+    }
+
+    pub(super) fn has_resubmissions(&self) -> bool {
+        !self.resubmissions.is_empty()
+    }
+
+    /// Adds the submission of `bundle` under `nonce` to the pipeline.
+    pub(super) fn push(&mut self, nonce: u32, bundle: SizedBundle, fut: Instrumented<SubmitFut>) {
+        self.in_flight.push_back(fut);
+        self.in_flight_bundles.push_back((nonce, bundle));
+    }
+
+    /// Polls for the nonce and outcome of the oldest in-flight submission.
+    ///
+    /// Returns `Poll::Ready(None)` if no submissions are in flight.
+    pub(super) fn poll_next_outcome(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<(u32, SubmitOutcome)>> {
+        let Some(outcome) = ready!(self.in_flight.poll_next_unpin(cx)) else {
+            return Poll::Ready(None);
+        };
+        let (nonce, _) = self
+            .in_flight_bundles
+            .pop_front()
+            .expect("every in-flight submission has a bundle; this is a bug");
+        Poll::Ready(Some((nonce, outcome)))
+    }
+
+    /// Halts the pipeline after a failed submission.
+    ///
+    /// The submissions still in flight are not cancelled, because the sequencer might already
+    /// have accepted them.
+    pub(super) fn halt(&mut self) {
+        self.halted = true;
+    }
+
+    /// Halts the pipeline and queues `bundle`, which the sequencer did not accept, for
+    /// resubmission once all in-flight submissions have resolved.
+    pub(super) fn requeue(&mut self, bundle: ResubmittedBundle) {
+        self.halted = true;
+        self.requeued.push_back(bundle);
+    }
+
+    /// Resumes a halted pipeline once no submissions are in flight, moving the requeued bundles
+    /// ahead of any bundles already waiting for resubmission.
+    ///
+    /// Returns true if the pipeline was resumed, after which the nonce of the signer has to be
+    /// fetched again.
+    pub(super) fn resume_if_settled(&mut self) -> bool {
+        if !self.halted || !self.in_flight.is_empty() {
+            return false;
+        }
+        self.halted = false;
+        for bundle in self.requeued.drain(..).rev() {
+            self.resubmissions.push_front(bundle);
+        }
+        true
+    }
+
+    /// Returns the number of sequence actions in the pipeline, in flight or waiting for
+    /// resubmission.
+    pub(super) fn pending_actions(&self) -> usize {
+        let in_flight = self
+            .in_flight_bundles
+            .iter()
+            .map(|(_, bundle)| bundle.len())
+            .sum::<usize>();
+        let resubmissions = self
+            .requeued
+            .iter()
+            .chain(&self.resubmissions)
+            .map(|resubmission| resubmission.bundle.len())
+            .sum::<usize>();
+        in_flight + resubmissions
+    }
+
+    /// Takes the next bundle to resubmit out of the queue.
+    pub(super) fn pop_resubmission(&mut self) -> Option<ResubmittedBundle> {
+        self.resubmissions.pop_front()
+    }
+}
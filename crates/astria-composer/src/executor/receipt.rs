@@ -0,0 +1,109 @@
+/// ! Receipts of the sequencer transactions that sequence actions were submitted in.
+use std::sync::Arc;
+
+use tokio::sync::{
+    oneshot,
+    watch,
+};
+
+/// Identifies the sequencer transaction a sequence action was submitted in.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Receipt {
+    /// The sha256 hash of the protobuf encoded signed sequencer transaction.
+    pub(crate) sequencer_tx_hash: [u8; 32],
+    /// The nonce the sequencer transaction was signed with.
+    pub(crate) nonce: u32,
+}
+
+/// The outcome of submitting a bundle.
+#[derive(Clone, Debug)]
+enum Report {
+    Submitted(Receipt),
+    Failed(String),
+}
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum ReceiptError {
+    #[error("the sequence action was dropped before it was bundled")]
+    NotBundled,
+    #[error("the bundle was dropped before it was submitted")]
+    Dropped,
+    #[error("submitting the bundle failed: {0}")]
+    Failed(String),
+}
+
+/// Reports the outcome of submitting a bundle to everyone waiting for a receipt of one of its
+/// sequence actions.
+///
+/// All clones of a bundle share the same reporter.
+#[derive(Clone)]
+pub(super) struct Reporter {
+    report: Arc<watch::Sender<Option<Report>>>,
+}
+
+impl Reporter {
+    pub(super) fn new() -> Self {
+        let (report, _) = watch::channel(None);
+        Self {
+            report: Arc::new(report),
+        }
+    }
+
+    pub(super) fn submitted(&self, receipt: Receipt) {
+        self.report.send_replace(Some(Report::Submitted(receipt)));
+    }
+
+    pub(super) fn failed(&self, reason: String) {
+        self.report.send_replace(Some(Report::Failed(reason)));
+    }
+}
+
+/// Hands the reporter of the bundle a sequence action ended up in to its [`PendingReceipt`].
+pub(super) struct ReceiptSender {
+    bundled: oneshot::Sender<watch::Receiver<Option<Report>>>,
+}
+
+impl ReceiptSender {
+    pub(super) fn send(self, reporter: &Reporter) {
+        // the receiver is gone if the caller is no longer interested in the receipt
+        let _ = self.bundled.send(reporter.report.subscribe());
+    }
+}
+
+/// The receipt of a sequence action, which is pending until the bundle it ended up in was
+/// submitted.
+pub(crate) struct PendingReceipt {
+    bundled: oneshot::Receiver<watch::Receiver<Option<Report>>>,
+}
+
+impl PendingReceipt {
+    /// Waits until the bundle the sequence action ended up in was submitted.
+    ///
+    /// # Errors
+    /// Returns an error if the sequence action was not bundled, if the executor gave up on
+    /// submitting its bundle, or if the bundle was dropped before it was submitted.
+    pub(crate) async fn wait(self) -> Result<Receipt, ReceiptError> {
+        let mut report = self.bundled.await.map_err(|_| ReceiptError::NotBundled)?;
+        let report = report
+            .wait_for(Option::is_some)
+            .await
+            .map_err(|_| ReceiptError::Dropped)?
+            .clone();
+        match report.expect("waited for the report to be set") {
+            Report::Submitted(receipt) => Ok(receipt),
+            Report::Failed(reason) => Err(ReceiptError::Failed(reason)),
+        }
+    }
+}
+
+pub(super) fn channel() -> (ReceiptSender, PendingReceipt) {
+    let (tx, rx) = oneshot::channel();
+    (
+        ReceiptSender {
+            bundled: tx,
+        },
+        PendingReceipt {
+            bundled: rx,
+        },
+    )
+}
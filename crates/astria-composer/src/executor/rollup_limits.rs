@@ -0,0 +1,255 @@
+//! Per-rollup limits on the sequence actions accepted for bundling.
+//!
+//! Each rollup can be given a weight for its share of bundle space when several rollups compete
+//! for it, a quota of bytes it may have queued for bundling, and a token bucket rate limit on the
+//! bytes it submits. Rollups without a configured entry use [`Limits::default`], which has a
+//! weight of 1 and no quota or rate limit.
+
+use astria_eyre::eyre::{
+    self,
+    ensure,
+    WrapErr as _,
+};
+use serde::Deserialize;
+use tokio::time::Instant;
+
+const NANOS_PER_SEC: u128 = 1_000_000_000;
+
+/// The configuration of the limits of one rollup.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct Config {
+    /// The name of the rollup the limits apply to.
+    pub(crate) rollup_name: String,
+    /// The relative share of bundle space given to the rollup when other rollups have sequence
+    /// actions queued as well.
+    #[serde(default = "default_weight")]
+    pub(crate) weight: u32,
+    /// The max number of bytes the rollup may have queued for bundling.
+    #[serde(default)]
+    pub(crate) max_queued_bytes: Option<usize>,
+    /// The number of bytes per second the rollup's token bucket is refilled with.
+    #[serde(default)]
+    pub(crate) rate_bytes_per_sec: Option<u64>,
+    /// The capacity of the rollup's token bucket. Defaults to one second worth of
+    /// `rate_bytes_per_sec`.
+    #[serde(default)]
+    pub(crate) burst_bytes: Option<u64>,
+}
+
+fn default_weight() -> u32 {
+    1
+}
+
+impl Config {
+    pub(crate) fn limits(&self) -> Limits {
+        Limits {
+            weight: self.weight,
+            max_queued_bytes: self.max_queued_bytes,
+            rate_limit: self.rate_bytes_per_sec.map(|bytes_per_sec| RateLimit {
+                bytes_per_sec,
+                burst_bytes: self.burst_bytes.unwrap_or(bytes_per_sec),
+            }),
+        }
+    }
+}
+
+/// The limits applied to the sequence actions of one rollup.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct Limits {
+    pub(crate) weight: u32,
+    pub(crate) max_queued_bytes: Option<usize>,
+    pub(crate) rate_limit: Option<RateLimit>,
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Self {
+            weight: default_weight(),
+            max_queued_bytes: None,
+            rate_limit: None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct RateLimit {
+    pub(crate) bytes_per_sec: u64,
+    pub(crate) burst_bytes: u64,
+}
+
+/// Parses a JSON array of [`Config`]s. An empty string is treated as an empty array.
+///
+/// The burst of a rate limited rollup must hold at least `max_bytes_per_bundle`, or its largest
+/// sequence actions could never be bundled.
+pub(crate) fn parse_configs(
+    configs: &str,
+    max_bytes_per_bundle: usize,
+) -> eyre::Result<Vec<Config>> {
+    if configs.trim().is_empty() {
+        return Ok(Vec::new());
+    }
+    let configs: Vec<Config> =
+        serde_json::from_str(configs).wrap_err("failed parsing rollup limits as a JSON array")?;
+    for config in &configs {
+        ensure!(
+            config.weight > 0,
+            "weight of rollup `{}` must be at least 1",
+            config.rollup_name,
+        );
+        ensure!(
+            config.rate_bytes_per_sec.is_some() || config.burst_bytes.is_none(),
+            "burst bytes of rollup `{}` are set without a rate",
+            config.rollup_name,
+        );
+        ensure!(
+            config.rate_bytes_per_sec != Some(0),
+            "rate bytes per second of rollup `{}` must be at least 1",
+            config.rollup_name,
+        );
+        if let Some(rate_limit) = config.limits().rate_limit {
+            ensure!(
+                usize::try_from(rate_limit.burst_bytes)
+                    .map_or(true, |burst_bytes| burst_bytes >= max_bytes_per_bundle),
+                "burst bytes of rollup `{}` must be at least the max bytes per bundle of \
+                 {max_bytes_per_bundle}, but are {} (burst bytes default to the rate bytes per \
+                 second)",
+                config.rollup_name,
+                rate_limit.burst_bytes,
+            );
+        }
+    }
+    Ok(configs)
+}
+
+/// A token bucket holding the number of bytes a rollup may currently submit.
+///
+/// Tokens are tracked in byte-nanoseconds so that refilling at sub-byte granularity does not
+/// lose precision.
+#[derive(Debug)]
+pub(super) struct TokenBucket {
+    rate: RateLimit,
+    tokens: u128,
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    /// Creates a full token bucket.
+    pub(super) fn new(rate: RateLimit) -> Self {
+        Self {
+            rate,
+            tokens: u128::from(rate.burst_bytes) * NANOS_PER_SEC,
+            last_refill: Instant::now(),
+        }
+    }
+
+    /// Takes `bytes` tokens out of the bucket if it holds enough of them.
+    ///
+    /// Returns `false` without taking any tokens otherwise.
+    pub(super) fn try_take(&mut self, bytes: usize) -> bool {
+        self.refill(Instant::now());
+        let needed = bytes as u128 * NANOS_PER_SEC;
+        if needed > self.tokens {
+            return false;
+        }
+        self.tokens -= needed;
+        true
+    }
+
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.last_refill).as_nanos();
+        let capacity = u128::from(self.rate.burst_bytes) * NANOS_PER_SEC;
+        self.tokens = self
+            .tokens
+            .saturating_add(elapsed.saturating_mul(u128::from(self.rate.bytes_per_sec)))
+            .min(capacity);
+        self.last_refill = now;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn configs_are_parsed_with_defaults() {
+        assert!(parse_configs("", 100).unwrap().is_empty());
+
+        let configs = parse_configs(
+            r#"[
+                {"rollup_name": "chain-1"},
+                {"rollup_name": "chain-2", "weight": 3, "max_queued_bytes": 1000, "rate_bytes_per_sec": 100}
+            ]"#,
+            100,
+        )
+        .unwrap();
+        assert_eq!(configs[0].limits(), Limits::default());
+        assert_eq!(
+            configs[1].limits(),
+            Limits {
+                weight: 3,
+                max_queued_bytes: Some(1000),
+                rate_limit: Some(RateLimit {
+                    bytes_per_sec: 100,
+                    burst_bytes: 100,
+                }),
+            }
+        );
+    }
+
+    #[test]
+    fn invalid_configs_are_rejected() {
+        assert!(parse_configs(r#"[{"rollup_name": "chain-1", "weight": 0}]"#, 100).is_err());
+        assert!(parse_configs(r#"[{"rollup_name": "chain-1", "burst_bytes": 10}]"#, 100).is_err());
+        assert!(parse_configs(r#"[{"rollup_name": "chain-1", "rate": 10}]"#, 100).is_err());
+        assert!(
+            parse_configs(
+                r#"[{"rollup_name": "chain-1", "rate_bytes_per_sec": 0, "burst_bytes": 100}]"#,
+                100,
+            )
+            .is_err()
+        );
+        assert!(
+            parse_configs(
+                r#"[{"rollup_name": "chain-1", "rate_bytes_per_sec": 10, "burst_bytes": 99}]"#,
+                100,
+            )
+            .is_err()
+        );
+        assert!(
+            parse_configs(
+                r#"[{"rollup_name": "chain-1", "rate_bytes_per_sec": 99}]"#,
+                100
+            )
+            .is_err()
+        );
+        assert!(
+            parse_configs(
+                r#"[{"rollup_name": "chain-1", "rate_bytes_per_sec": 10, "burst_bytes": 100}]"#,
+                100,
+            )
+            .is_ok()
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn token_bucket_is_refilled_up_to_burst() {
+        let mut bucket = TokenBucket::new(RateLimit {
+            bytes_per_sec: 100,
+            burst_bytes: 200,
+        });
+        assert!(bucket.try_take(150));
+        assert!(!bucket.try_take(100));
+
+        tokio::time::advance(Duration::from_millis(500)).await;
+        assert!(bucket.try_take(100));
+        assert!(!bucket.try_take(1));
+
+        // the bucket holds no more than the burst however long it is refilled
+        tokio::time::advance(Duration::from_secs(10)).await;
+        assert!(!bucket.try_take(201));
+        assert!(bucket.try_take(200));
+    }
+}
@@ -0,0 +1,296 @@
+/// ! The sequencer accounts whose keys the executor signs bundles with.
+use std::{
+    future::poll_fn,
+    task::Poll,
+};
+
+use astria_core::sequencer::v1::{
+    account::AssetBalance,
+    asset,
+    BalanceResponse,
+};
+use astria_eyre::eyre;
+use ed25519_consensus::SigningKey;
+use futures::future::{
+    BoxFuture,
+    Fuse,
+    FusedFuture as _,
+    FutureExt as _,
+};
+use secrecy::Zeroize as _;
+use sequencer_client::Address;
+use tracing::{
+    info,
+    warn,
+};
+
+use super::{
+    pipeline::Pipeline,
+    StdError,
+    SubmitOutcome,
+};
+
+/// A sequencer account used to sign and submit bundles, together with its own nonce and
+/// pipeline of in-flight submissions.
+pub(super) struct Signer {
+    pub(super) key: SigningKey,
+    pub(super) address: Address,
+    /// The nonce the next bundle is submitted under.
+    pub(super) nonce: u32,
+    /// Fetches a new nonce after the sequencer rejected a submission due to its nonce.
+    pub(super) nonce_fut: Fuse<BoxFuture<'static, eyre::Result<u32>>>,
+    pub(super) pipeline: Pipeline,
+    /// Set if the account holds at least the minimum balance of all monitored fee assets.
+    has_sufficient_balance: bool,
+}
+
+impl Signer {
+    pub(super) fn new(key: SigningKey, nonce: u32, max_in_flight: usize) -> Self {
+        let address = Address::from_verification_key(key.verification_key());
+        Self {
+            key,
+            address,
+            nonce,
+            nonce_fut: Fuse::terminated(),
+            pipeline: Pipeline::new(max_in_flight),
+            // the balance is only known after the first balance check
+            has_sufficient_balance: true,
+        }
+    }
+
+    /// Returns if a bundle can be submitted with this signer: its pipeline has space and its
+    /// nonce is known.
+    fn can_submit(&self) -> bool {
+        !self.pipeline.is_full() && self.nonce_fut.is_terminated()
+    }
+}
+
+impl Drop for Signer {
+    fn drop(&mut self) {
+        self.key.zeroize();
+    }
+}
+
+/// An event of one of the [`Signers`].
+pub(super) enum SignerEvent {
+    /// The oldest in-flight submission of the signer at `index` resolved.
+    Outcome {
+        index: usize,
+        nonce: u32,
+        outcome: SubmitOutcome,
+    },
+    /// The signer at `index` fetched a new nonce.
+    Nonce {
+        index: usize,
+        nonce: eyre::Result<u32>,
+    },
+}
+
+/// The set of signers that new bundles are distributed to in round-robin order.
+///
+/// Signers whose balance fell below the minimum balance do not take new bundles, but still
+/// resolve the bundles already assigned to them.
+pub(super) struct Signers {
+    signers: Vec<Signer>,
+    /// The index of the signer that is asked first to take the next new bundle.
+    next: usize,
+}
+
+impl Signers {
+    pub(super) fn new(signers: Vec<Signer>) -> Self {
+        Self {
+            signers,
+            next: 0,
+        }
+    }
+
+    pub(super) fn get_mut(&mut self, index: usize) -> &mut Signer {
+        &mut self.signers[index]
+    }
+
+    pub(super) fn addresses(&self) -> Vec<Address> {
+        self.signers.iter().map(|signer| signer.address).collect()
+    }
+
+    /// Returns true if no signer has bundles in flight or waiting for resubmission.
+    pub(super) fn is_empty(&self) -> bool {
+        self.signers
+            .iter()
+            .all(|signer| signer.pipeline.is_empty() && !signer.pipeline.has_resubmissions())
+    }
+
+    /// Returns true if any signer has a submission in flight or is fetching its nonce.
+    pub(super) fn has_pending_events(&self) -> bool {
+        self.signers
+            .iter()
+            .any(|signer| !signer.pipeline.is_empty() || !signer.nonce_fut.is_terminated())
+    }
+
+    /// Returns the number of sequence actions held by all signers' pipelines.
+    pub(super) fn pending_actions(&self) -> usize {
+        self.signers
+            .iter()
+            .map(|signer| signer.pipeline.pending_actions())
+            .sum()
+    }
+
+    /// Returns the index of the next signer in round-robin order that can take a new bundle.
+    ///
+    /// Signers with insufficient balance or with bundles waiting for resubmission are skipped.
+    pub(super) fn next_for_new_bundle(&self) -> Option<usize> {
+        let len = self.signers.len();
+        (0..len)
+            .map(|offset| (self.next + offset) % len)
+            .find(|&index| {
+                let signer = &self.signers[index];
+                signer.has_sufficient_balance
+                    && signer.can_submit()
+                    && !signer.pipeline.has_resubmissions()
+            })
+    }
+
+    /// Returns the index of a signer that can resubmit one of its bundles.
+    pub(super) fn next_with_resubmissions(&self) -> Option<usize> {
+        self.signers
+            .iter()
+            .position(|signer| signer.can_submit() && signer.pipeline.has_resubmissions())
+    }
+
+    /// Returns the signer at `index` to take a new bundle, passing the turn to the signer after
+    /// it.
+    pub(super) fn take_turn(&mut self, index: usize) -> &mut Signer {
+        self.next = (index + 1) % self.signers.len();
+        &mut self.signers[index]
+    }
+
+    /// Returns the next resolved submission or nonce query of any signer.
+    ///
+    /// This method is cancel safe.
+    pub(super) async fn next_event(&mut self) -> SignerEvent {
+        poll_fn(|cx| {
+            for (index, signer) in self.signers.iter_mut().enumerate() {
+                if !signer.nonce_fut.is_terminated() {
+                    if let Poll::Ready(nonce) = signer.nonce_fut.poll_unpin(cx) {
+                        return Poll::Ready(SignerEvent::Nonce {
+                            index,
+                            nonce,
+                        });
+                    }
+                }
+                if let Poll::Ready(Some((nonce, outcome))) = signer.pipeline.poll_next_outcome(cx) {
+                    return Poll::Ready(SignerEvent::Outcome {
+                        index,
+                        nonce,
+                        outcome,
+                    });
+                }
+            }
+            Poll::Pending
+        })
+        .await
+    }
+
+    /// Updates which signers take new bundles from the `balances` of their accounts, given in
+    /// the same order as [`Signers::addresses`].
+    ///
+    /// Signers whose balance could not be queried keep their previous state.
+    pub(super) fn update_balances(
+        &mut self,
+        balances: Vec<Result<BalanceResponse, sequencer_client::extension_trait::Error>>,
+        fee_asset_ids: &[asset::Id],
+        min_balance: u128,
+    ) {
+        for (signer, balance) in self.signers.iter_mut().zip(balances) {
+            let balance = match balance {
+                Ok(balance) => balance,
+                Err(error) => {
+                    warn!(
+                        address = %signer.address,
+                        error = &error as &StdError,
+                        "failed getting latest balance of signer from sequencer; keeping its \
+                         previous state",
+                    );
+                    continue;
+                }
+            };
+            let has_sufficient_balance =
+                has_sufficient_balance(&balance.balances, fee_asset_ids, min_balance);
+            match (signer.has_sufficient_balance, has_sufficient_balance) {
+                (true, false) => warn!(
+                    address = %signer.address,
+                    min_balance = %min_balance,
+                    "fee asset balance of signer fell below the minimum balance; no longer \
+                     submitting new bundles with its key",
+                ),
+                (false, true) => info!(
+                    address = %signer.address,
+                    "fee asset balance of signer is above the minimum balance again; submitting \
+                     new bundles with its key",
+                ),
+                _ => {}
+            }
+            signer.has_sufficient_balance = has_sufficient_balance;
+        }
+        if !self
+            .signers
+            .iter()
+            .any(|signer| signer.has_sufficient_balance)
+        {
+            warn!(
+                min_balance = %min_balance,
+                "no signer holds the minimum balance of all fee assets; new bundles are held back \
+                 until an account is topped up",
+            );
+        }
+    }
+}
+
+/// Returns if `balances` hold at least `min_balance` of each asset in `fee_asset_ids`.
+fn has_sufficient_balance(
+    balances: &[AssetBalance],
+    fee_asset_ids: &[asset::Id],
+    min_balance: u128,
+) -> bool {
+    fee_asset_ids.iter().all(|fee_asset_id| {
+        balances
+            .iter()
+            .find(|balance| balance.denom.id() == *fee_asset_id)
+            .map_or(0, |balance| balance.balance)
+            >= min_balance
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::sequencer::v1::asset::{
+        default_native_asset_id,
+        Denom,
+    };
+
+    use super::*;
+
+    fn asset_balance(denom: &str, balance: u128) -> AssetBalance {
+        AssetBalance {
+            denom: Denom::from_base_denom(denom),
+            balance,
+        }
+    }
+
+    #[test]
+    fn balance_must_reach_minimum_for_all_fee_assets() {
+        let fee_asset_ids = [default_native_asset_id(), asset::Id::from_denom("other")];
+        let balances = [asset_balance("nria", 100), asset_balance("other", 99)];
+
+        assert!(has_sufficient_balance(&balances, &fee_asset_ids, 99));
+        assert!(!has_sufficient_balance(&balances, &fee_asset_ids, 100));
+    }
+
+    #[test]
+    fn missing_fee_asset_counts_as_zero_balance() {
+        let fee_asset_ids = [asset::Id::from_denom("other")];
+        let balances = [asset_balance("nria", 100)];
+
+        assert!(has_sufficient_balance(&balances, &fee_asset_ids, 0));
+        assert!(!has_sufficient_balance(&balances, &fee_asset_ids, 1));
+    }
+}
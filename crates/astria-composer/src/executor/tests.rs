@@ -1,15 +1,27 @@
-use std::time::Duration;
+use std::{
+    collections::HashMap,
+    time::Duration,
+};
 
 use astria_core::sequencer::v1::{
     asset::default_native_asset_id,
     transaction::action::SequenceAction,
+    AbciErrorCode,
     RollupId,
     ROLLUP_ID_LEN,
 };
 use astria_eyre::eyre;
+use ed25519_consensus::{
+    SigningKey,
+    VerificationKey,
+};
 use once_cell::sync::Lazy;
 use prost::Message;
-use sequencer_client::SignedTransaction;
+use secrecy::ExposeSecret as _;
+use sequencer_client::{
+    Address,
+    SignedTransaction,
+};
 use serde_json::json;
 use tendermint_rpc::{
     endpoint::broadcast::tx_sync,
@@ -17,10 +29,12 @@ use tendermint_rpc::{
     response,
     Id,
 };
+use test_utils::mock::sequencer::mount_status_mock;
 use tokio::{
     sync::watch,
     time,
 };
+use tokio_util::sync::CancellationToken;
 use tracing::debug;
 use wiremock::{
     matchers::{
@@ -36,7 +50,7 @@ use wiremock::{
 
 use crate::{
     executor,
-    executor::Executor,
+    tracker,
     Config,
 };
 
@@ -58,11 +72,22 @@ static TELEMETRY: Lazy<()> = Lazy::new(|| {
     }
 });
 
-/// Start a mock sequencer server and mount a mock for the `accounts/nonce` query.
-async fn setup() -> (MockServer, MockGuard, Config) {
+/// A private key for a second sequencer account, for tests that sign with multiple keys.
+const SECOND_PRIVATE_KEY: &str = "f8d3b1c0e5a2946d7b3e1f0a8c6d4b2e9f7a5c3d1b0e8f6a4c2d0b9e7f5a3c1d";
+
+fn verification_key_from_hex(private_key: &str) -> VerificationKey {
+    let private_key_bytes: [u8; 32] = hex::decode(private_key).unwrap().try_into().unwrap();
+    SigningKey::from(private_key_bytes).verification_key()
+}
+
+/// Start a mock sequencer server and mount mocks for the `status` endpoint and the
+/// `accounts/nonce` and `asset/allowed_fee_asset_ids` queries.
+pub(super) async fn setup() -> (MockServer, MockGuard, Config) {
     use astria_core::generated::sequencer::v1::NonceResponse;
     Lazy::force(&TELEMETRY);
     let server = MockServer::start().await;
+    mount_status_mock(&server, "test").await;
+    mount_allowed_fee_asset_ids_mock(&server).await;
     let startup_guard = mount_nonce_query_mock(
         &server,
         "accounts/nonce",
@@ -89,10 +114,71 @@ async fn setup() -> (MockServer, MockGuard, Config) {
         metrics_http_listener_addr: String::new(),
         pretty_print: true,
         grpc_addr: "127.0.0.1:0".parse().unwrap(),
+        max_submission_attempts: 3,
+        dead_letter_queue_capacity: 10,
+        max_in_flight_submissions: 1,
+        shutdown_drain_timeout_ms: 2000,
+        min_signer_balance: 0,
+        balance_check_interval_ms: 1000,
+        websocket_collectors: String::new(),
+        rollup_limits: String::new(),
+        tx_tracker_capacity: 100,
     };
     (server, startup_guard, cfg)
 }
 
+/// Returns a builder for an executor configured from `cfg` that drains its pending bundles once
+/// `shutdown_token` is cancelled.
+pub(super) fn executor_builder(
+    cfg: &Config,
+    shutdown_token: CancellationToken,
+) -> executor::Builder {
+    executor::Builder {
+        sequencer_url: cfg.sequencer_url.clone(),
+        private_keys: cfg.private_key.clone(),
+        block_time_ms: cfg.block_time_ms,
+        max_bytes_per_bundle: cfg.max_bytes_per_bundle,
+        rollup_limits: HashMap::new(),
+        max_submission_attempts: cfg.max_submission_attempts,
+        dead_letter_queue_capacity: cfg.dead_letter_queue_capacity,
+        max_in_flight_submissions: cfg.max_in_flight_submissions,
+        shutdown_drain_timeout: Duration::from_millis(cfg.shutdown_drain_timeout_ms),
+        shutdown_token,
+        monitored_fee_asset_ids: vec![default_native_asset_id()],
+        min_signer_balance: cfg.min_signer_balance,
+        balance_check_interval: Duration::from_millis(cfg.balance_check_interval_ms),
+        tx_tracker: tracker::Handle::new(cfg.tx_tracker_capacity),
+    }
+}
+
+/// Mount a mock for the `asset/allowed_fee_asset_ids` query, reporting the native asset as the
+/// only allowed fee asset.
+async fn mount_allowed_fee_asset_ids_mock(server: &MockServer) {
+    use astria_core::generated::sequencer::v1::AllowedFeeAssetIdsResponse;
+    let response = tendermint_rpc::endpoint::abci_query::Response {
+        response: tendermint_rpc::endpoint::abci_query::AbciQuery {
+            value: AllowedFeeAssetIdsResponse {
+                height: 0,
+                fee_asset_ids: vec![default_native_asset_id().get().to_vec()],
+            }
+            .encode_to_vec(),
+            ..Default::default()
+        },
+    };
+    let wrapper = response::Wrapper::new_with_id(Id::Num(1), Some(response), None);
+    Mock::given(body_partial_json(json!({
+        "method": "abci_query"
+    })))
+    .and(body_string_contains("asset/allowed_fee_asset_ids"))
+    .respond_with(
+        ResponseTemplate::new(200)
+            .set_body_json(&wrapper)
+            .append_header("Content-Type", "application/json"),
+    )
+    .mount(server)
+    .await;
+}
+
 /// Mount a mock for the `abci_query` endpoint.
 async fn mount_nonce_query_mock(
     server: &MockServer,
@@ -122,6 +208,49 @@ async fn mount_nonce_query_mock(
         .await
 }
 
+/// Mount a mock for the `accounts/balance` query of the account of `verification_key`,
+/// reporting `balance` of the native asset.
+async fn mount_balance_query_mock(
+    server: &MockServer,
+    verification_key: VerificationKey,
+    balance: u128,
+) -> MockGuard {
+    use astria_core::generated::sequencer::v1::{
+        AssetBalance,
+        BalanceResponse,
+    };
+    let address = Address::from_verification_key(verification_key);
+    let response = tendermint_rpc::endpoint::abci_query::Response {
+        response: tendermint_rpc::endpoint::abci_query::AbciQuery {
+            value: BalanceResponse {
+                height: 0,
+                balances: vec![AssetBalance {
+                    denom: "nria".to_string(),
+                    balance: Some(balance.into()),
+                }],
+            }
+            .encode_to_vec(),
+            ..Default::default()
+        },
+    };
+    let wrapper = response::Wrapper::new_with_id(Id::Num(1), Some(response), None);
+    Mock::given(body_partial_json(json!({
+        "method": "abci_query"
+    })))
+    .and(body_string_contains(format!(
+        "accounts/balance/{}",
+        hex::encode(address.get())
+    )))
+    .respond_with(
+        ResponseTemplate::new(200)
+            .set_body_json(&wrapper)
+            .append_header("Content-Type", "application/json"),
+    )
+    .expect(1..)
+    .mount_as_scoped(server)
+    .await
+}
+
 /// Convert a `Request` object to a `SignedTransaction`
 fn signed_tx_from_request(request: &Request) -> SignedTransaction {
     use astria_core::generated::sequencer::v1::SignedTransaction as RawSignedTransaction;
@@ -147,8 +276,10 @@ async fn mount_broadcast_tx_sync_seq_actions_mock(server: &MockServer) -> MockGu
         let signed_tx = signed_tx_from_request(request);
         let actions = signed_tx.actions();
 
-        // verify all received actions are sequence actions
-        actions.iter().all(|action| action.as_sequence().is_some())
+        // verify the transaction is bound to the chain reported by the sequencer and that all
+        // received actions are sequence actions
+        signed_tx.chain_id() == "test"
+            && actions.iter().all(|action| action.as_sequence().is_some())
     };
     let jsonrpc_rsp = response::Wrapper::new_with_id(
         Id::Num(1),
@@ -169,8 +300,69 @@ async fn mount_broadcast_tx_sync_seq_actions_mock(server: &MockServer) -> MockGu
         .await
 }
 
+/// Mount a mock for `broadcast_tx_sync` matching transactions signed with `nonce` whose first
+/// sequence action is for `rollup_id`. The mock responds with `code` after `delay`.
+async fn mount_broadcast_tx_sync_nonce_mock(
+    server: &MockServer,
+    nonce: u32,
+    rollup_id: RollupId,
+    code: tendermint::abci::Code,
+    delay: Duration,
+) -> MockGuard {
+    let matcher = move |request: &Request| {
+        let signed_tx = signed_tx_from_request(request);
+        let Some(sequence_action) = signed_tx.actions().first().and_then(|a| a.as_sequence())
+        else {
+            return false;
+        };
+        signed_tx.nonce() == nonce && sequence_action.rollup_id == rollup_id
+    };
+    let jsonrpc_rsp = response::Wrapper::new_with_id(
+        Id::Num(1),
+        Some(tx_sync::Response {
+            code,
+            data: vec![].into(),
+            log: String::new(),
+            hash: tendermint::Hash::Sha256([0; 32]),
+        }),
+        None,
+    );
+
+    Mock::given(matcher)
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(&jsonrpc_rsp)
+                .set_delay(delay),
+        )
+        .up_to_n_times(1)
+        .expect(1)
+        .mount_as_scoped(server)
+        .await
+}
+
+/// Sends two sequence actions to the executor that each fill an entire bundle, followed by a
+/// small one that pushes the second bundle out of the bundle factory.
+async fn send_two_full_bundles(executor_handle: &executor::Handle, cfg: &Config) {
+    for rollup_byte in 0..3u8 {
+        let data_len = if rollup_byte < 2 {
+            cfg.max_bytes_per_bundle - ROLLUP_ID_LEN
+        } else {
+            1
+        };
+        let seq_action = SequenceAction {
+            rollup_id: RollupId::new([rollup_byte; ROLLUP_ID_LEN]),
+            data: vec![rollup_byte; data_len],
+            fee_asset_id: default_native_asset_id(),
+        };
+        executor_handle
+            .send_timeout(seq_action, Duration::from_millis(1000))
+            .await
+            .unwrap();
+    }
+}
+
 /// Helper to wait for the executor to connect to the mock sequencer
-async fn wait_for_startup(
+pub(super) async fn wait_for_startup(
     mut status: watch::Receiver<executor::Status>,
     nonce_guard: MockGuard,
 ) -> eyre::Result<()> {
@@ -195,13 +387,9 @@ async fn wait_for_startup(
 async fn full_bundle() {
     // set up the executor, channel for writing seq actions, and the sequencer mock
     let (sequencer, nonce_guard, cfg) = setup().await;
-    let (executor, executor_handle) = Executor::new(
-        &cfg.sequencer_url,
-        &cfg.private_key,
-        cfg.block_time_ms,
-        cfg.max_bytes_per_bundle,
-    )
-    .unwrap();
+    let (executor, executor_handle) = executor_builder(&cfg, CancellationToken::new())
+        .build()
+        .unwrap();
 
     let status = executor.subscribe();
 
@@ -281,13 +469,9 @@ async fn full_bundle() {
 async fn bundle_triggered_by_block_timer() {
     // set up the executor, channel for writing seq actions, and the sequencer mock
     let (sequencer, nonce_guard, cfg) = setup().await;
-    let (executor, executor_handle) = Executor::new(
-        &cfg.sequencer_url,
-        &cfg.private_key,
-        cfg.block_time_ms,
-        cfg.max_bytes_per_bundle,
-    )
-    .unwrap();
+    let (executor, executor_handle) = executor_builder(&cfg, CancellationToken::new())
+        .build()
+        .unwrap();
 
     let status = executor.subscribe();
 
@@ -360,13 +544,9 @@ async fn bundle_triggered_by_block_timer() {
 async fn two_seq_actions_single_bundle() {
     // set up the executor, channel for writing seq actions, and the sequencer mock
     let (sequencer, nonce_guard, cfg) = setup().await;
-    let (executor, executor_handle) = Executor::new(
-        &cfg.sequencer_url,
-        &cfg.private_key,
-        cfg.block_time_ms,
-        cfg.max_bytes_per_bundle,
-    )
-    .unwrap();
+    let (executor, executor_handle) = executor_builder(&cfg, CancellationToken::new())
+        .build()
+        .unwrap();
 
     let status = executor.subscribe();
 
@@ -442,3 +622,523 @@ async fn two_seq_actions_single_bundle() {
         );
     }
 }
+
+#[test]
+fn resubmission_delay_doubles_up_to_max_delay() {
+    assert_eq!(executor::resubmission_delay(1), Duration::from_millis(200));
+    assert_eq!(executor::resubmission_delay(2), Duration::from_millis(400));
+    assert_eq!(executor::resubmission_delay(3), Duration::from_millis(800));
+    assert_eq!(executor::resubmission_delay(100), Duration::from_secs(60));
+}
+
+/// Test to check that the executor submits a bundle without waiting for the result of the
+/// previous submission if it is allowed more than one in-flight submission.
+#[tokio::test]
+async fn in_flight_submissions_do_not_wait_for_previous_results() {
+    let (sequencer, nonce_guard, mut cfg) = setup().await;
+    cfg.max_in_flight_submissions = 2;
+    let (executor, executor_handle) = executor_builder(&cfg, CancellationToken::new())
+        .build()
+        .unwrap();
+
+    let status = executor.subscribe();
+    let _executor_task = tokio::spawn(executor.run_until_stopped());
+    wait_for_startup(status, nonce_guard).await.unwrap();
+
+    // the sequencer takes a second to respond, so both transactions can only be received in
+    // time if the second one is sent before the first one is answered
+    let first_guard = mount_broadcast_tx_sync_nonce_mock(
+        &sequencer,
+        0,
+        RollupId::new([0; ROLLUP_ID_LEN]),
+        tendermint::abci::Code::Ok,
+        Duration::from_millis(1000),
+    )
+    .await;
+    let second_guard = mount_broadcast_tx_sync_nonce_mock(
+        &sequencer,
+        1,
+        RollupId::new([1; ROLLUP_ID_LEN]),
+        tendermint::abci::Code::Ok,
+        Duration::from_millis(1000),
+    )
+    .await;
+
+    send_two_full_bundles(&executor_handle, &cfg).await;
+
+    tokio::time::timeout(Duration::from_millis(500), async {
+        first_guard.wait_until_satisfied().await;
+        second_guard.wait_until_satisfied().await;
+    })
+    .await
+    .expect("both bundles should have been in flight at the same time");
+}
+
+/// Test to check that the executor waits for all in-flight bundles to resolve if one of them is
+/// rejected for an invalid nonce, and then resubmits only the rejected bundle under a newly
+/// fetched nonce.
+#[tokio::test]
+async fn invalid_nonce_resubmits_only_rejected_in_flight_submissions() {
+    use astria_core::generated::sequencer::v1::NonceResponse;
+
+    let (sequencer, nonce_guard, mut cfg) = setup().await;
+    cfg.max_in_flight_submissions = 2;
+    let (executor, executor_handle) = executor_builder(&cfg, CancellationToken::new())
+        .build()
+        .unwrap();
+
+    let status = executor.subscribe();
+    let _executor_task = tokio::spawn(executor.run_until_stopped());
+    wait_for_startup(status, nonce_guard).await.unwrap();
+
+    // the rejection is delayed so that the second bundle is in flight when it arrives
+    let rejected_guard = mount_broadcast_tx_sync_nonce_mock(
+        &sequencer,
+        0,
+        RollupId::new([0; ROLLUP_ID_LEN]),
+        AbciErrorCode::INVALID_NONCE.into(),
+        Duration::from_millis(200),
+    )
+    .await;
+    let accepted_guard = mount_broadcast_tx_sync_nonce_mock(
+        &sequencer,
+        1,
+        RollupId::new([1; ROLLUP_ID_LEN]),
+        tendermint::abci::Code::Ok,
+        Duration::ZERO,
+    )
+    .await;
+    let nonce_refetch_guard = mount_nonce_query_mock(
+        &sequencer,
+        "accounts/nonce",
+        NonceResponse {
+            height: 0,
+            nonce: 5,
+        },
+    )
+    .await;
+    // only the rejected bundle is resubmitted under the new nonce
+    let resubmission_guard = mount_broadcast_tx_sync_nonce_mock(
+        &sequencer,
+        5,
+        RollupId::new([0; ROLLUP_ID_LEN]),
+        tendermint::abci::Code::Ok,
+        Duration::ZERO,
+    )
+    .await;
+
+    send_two_full_bundles(&executor_handle, &cfg).await;
+
+    tokio::time::timeout(Duration::from_millis(1000), async {
+        rejected_guard.wait_until_satisfied().await;
+        accepted_guard.wait_until_satisfied().await;
+        nonce_refetch_guard.wait_until_satisfied().await;
+        resubmission_guard.wait_until_satisfied().await;
+    })
+    .await
+    .expect("the rejected bundle should have been resubmitted under the new nonce");
+
+    // give a duplicate of the accepted bundle time to arrive
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    let resubmitted_rollup_ids = sequencer
+        .received_requests()
+        .await
+        .expect("the mock sequencer records requests")
+        .iter()
+        .filter(|request| String::from_utf8_lossy(&request.body).contains("broadcast_tx_sync"))
+        .filter_map(|request| {
+            let signed_tx = signed_tx_from_request(request);
+            let rollup_id = signed_tx
+                .actions()
+                .first()
+                .and_then(|action| action.as_sequence())?
+                .rollup_id;
+            (signed_tx.nonce() >= 5).then_some(rollup_id)
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        resubmitted_rollup_ids,
+        [RollupId::new([0; ROLLUP_ID_LEN])],
+        "the accepted bundle must not be submitted twice",
+    );
+}
+
+/// Test that the executor submits its pending bundle without waiting for the block timer once
+/// it is signalled to shut down, and exits afterwards.
+#[tokio::test]
+async fn shutdown_submits_pending_bundle() {
+    let (sequencer, nonce_guard, cfg) = setup().await;
+    let shutdown_token = CancellationToken::new();
+    let (executor, executor_handle) = executor_builder(&cfg, shutdown_token.clone())
+        .build()
+        .unwrap();
+
+    let status = executor.subscribe();
+    let executor_task = tokio::spawn(executor.run_until_stopped());
+    wait_for_startup(status, nonce_guard).await.unwrap();
+
+    let response_guard = mount_broadcast_tx_sync_nonce_mock(
+        &sequencer,
+        0,
+        RollupId::new([0; ROLLUP_ID_LEN]),
+        tendermint::abci::Code::Ok,
+        Duration::ZERO,
+    )
+    .await;
+
+    let seq0 = SequenceAction {
+        rollup_id: RollupId::new([0; ROLLUP_ID_LEN]),
+        data: vec![0u8; cfg.max_bytes_per_bundle / 4],
+        fee_asset_id: default_native_asset_id(),
+    };
+    executor_handle
+        .send_timeout(seq0, Duration::from_millis(1000))
+        .await
+        .unwrap();
+    shutdown_token.cancel();
+
+    // the block timer would only tick after `cfg.block_time_ms`
+    tokio::time::timeout(
+        Duration::from_millis(cfg.block_time_ms / 4),
+        response_guard.wait_until_satisfied(),
+    )
+    .await
+    .expect("pending bundle should have been submitted on shutdown");
+
+    tokio::time::timeout(Duration::from_millis(1000), executor_task)
+        .await
+        .expect("executor should have exited after submitting all pending bundles")
+        .unwrap()
+        .unwrap();
+}
+
+/// Test that the executor exits after the shutdown drain timeout even if its pending bundles
+/// could not be submitted in time.
+#[tokio::test]
+async fn shutdown_gives_up_on_pending_bundles_after_drain_timeout() {
+    let (sequencer, nonce_guard, mut cfg) = setup().await;
+    cfg.shutdown_drain_timeout_ms = 100;
+    let shutdown_token = CancellationToken::new();
+    let (executor, executor_handle) = executor_builder(&cfg, shutdown_token.clone())
+        .build()
+        .unwrap();
+
+    let status = executor.subscribe();
+    let executor_task = tokio::spawn(executor.run_until_stopped());
+    wait_for_startup(status, nonce_guard).await.unwrap();
+
+    // the sequencer only responds long after the drain timeout
+    let _response_guard = mount_broadcast_tx_sync_nonce_mock(
+        &sequencer,
+        0,
+        RollupId::new([0; ROLLUP_ID_LEN]),
+        tendermint::abci::Code::Ok,
+        Duration::from_millis(5000),
+    )
+    .await;
+
+    send_two_full_bundles(&executor_handle, &cfg).await;
+    shutdown_token.cancel();
+
+    tokio::time::timeout(Duration::from_millis(1000), executor_task)
+        .await
+        .expect("executor should have exited after the shutdown drain timeout")
+        .unwrap()
+        .unwrap();
+}
+
+/// Test that bundles are signed round robin with all configured keys, each using the nonce of
+/// its own account.
+#[tokio::test]
+async fn bundles_are_signed_round_robin_across_keys() {
+    use astria_core::generated::sequencer::v1::NonceResponse;
+
+    let (sequencer, nonce_guard, mut cfg) = setup().await;
+    let first_key = cfg.private_key.expose_secret().clone();
+    cfg.private_key = format!("{first_key},{SECOND_PRIVATE_KEY}").into();
+    // the startup mock only answers the nonce query of the first account
+    let _second_nonce_guard = mount_nonce_query_mock(
+        &sequencer,
+        "accounts/nonce",
+        NonceResponse {
+            height: 0,
+            nonce: 0,
+        },
+    )
+    .await;
+    let (executor, executor_handle) = executor_builder(&cfg, CancellationToken::new())
+        .build()
+        .unwrap();
+
+    let status = executor.subscribe();
+    let _executor_task = tokio::spawn(executor.run_until_stopped());
+    wait_for_startup(status, nonce_guard).await.unwrap();
+
+    let first_guard = mount_broadcast_tx_sync_nonce_mock(
+        &sequencer,
+        0,
+        RollupId::new([0; ROLLUP_ID_LEN]),
+        tendermint::abci::Code::Ok,
+        Duration::ZERO,
+    )
+    .await;
+    let second_guard = mount_broadcast_tx_sync_nonce_mock(
+        &sequencer,
+        0,
+        RollupId::new([1; ROLLUP_ID_LEN]),
+        tendermint::abci::Code::Ok,
+        Duration::ZERO,
+    )
+    .await;
+
+    send_two_full_bundles(&executor_handle, &cfg).await;
+
+    tokio::time::timeout(Duration::from_millis(1000), async {
+        first_guard.wait_until_satisfied().await;
+        second_guard.wait_until_satisfied().await;
+    })
+    .await
+    .expect("both bundles should have been submitted under the nonce of their own account");
+
+    let first_tx = signed_tx_from_request(&first_guard.received_requests().await[0]);
+    let second_tx = signed_tx_from_request(&second_guard.received_requests().await[0]);
+    assert_eq!(
+        first_tx.verification_key(),
+        verification_key_from_hex(&first_key)
+    );
+    assert_eq!(
+        second_tx.verification_key(),
+        verification_key_from_hex(SECOND_PRIVATE_KEY)
+    );
+}
+
+/// Test that a key whose account balance fell below the minimum balance is not used for new
+/// bundles.
+#[tokio::test]
+async fn key_with_insufficient_balance_is_not_used() {
+    use astria_core::generated::sequencer::v1::NonceResponse;
+
+    let (sequencer, nonce_guard, mut cfg) = setup().await;
+    let first_key = cfg.private_key.expose_secret().clone();
+    cfg.private_key = format!("{first_key},{SECOND_PRIVATE_KEY}").into();
+    cfg.min_signer_balance = 100;
+    cfg.max_in_flight_submissions = 2;
+    let _second_nonce_guard = mount_nonce_query_mock(
+        &sequencer,
+        "accounts/nonce",
+        NonceResponse {
+            height: 0,
+            nonce: 0,
+        },
+    )
+    .await;
+    let drained_balance_guard =
+        mount_balance_query_mock(&sequencer, verification_key_from_hex(&first_key), 99).await;
+    let funded_balance_guard = mount_balance_query_mock(
+        &sequencer,
+        verification_key_from_hex(SECOND_PRIVATE_KEY),
+        100,
+    )
+    .await;
+    let (executor, executor_handle) = executor_builder(&cfg, CancellationToken::new())
+        .build()
+        .unwrap();
+
+    let status = executor.subscribe();
+    let _executor_task = tokio::spawn(executor.run_until_stopped());
+    wait_for_startup(status, nonce_guard).await.unwrap();
+
+    // the balances are checked right after startup
+    tokio::time::timeout(Duration::from_millis(1000), async {
+        drained_balance_guard.wait_until_satisfied().await;
+        funded_balance_guard.wait_until_satisfied().await;
+    })
+    .await
+    .expect("the balances of both accounts should have been queried");
+    time::sleep(Duration::from_millis(100)).await;
+
+    let first_guard = mount_broadcast_tx_sync_nonce_mock(
+        &sequencer,
+        0,
+        RollupId::new([0; ROLLUP_ID_LEN]),
+        tendermint::abci::Code::Ok,
+        Duration::ZERO,
+    )
+    .await;
+    let second_guard = mount_broadcast_tx_sync_nonce_mock(
+        &sequencer,
+        1,
+        RollupId::new([1; ROLLUP_ID_LEN]),
+        tendermint::abci::Code::Ok,
+        Duration::ZERO,
+    )
+    .await;
+
+    send_two_full_bundles(&executor_handle, &cfg).await;
+
+    tokio::time::timeout(Duration::from_millis(1000), async {
+        first_guard.wait_until_satisfied().await;
+        second_guard.wait_until_satisfied().await;
+    })
+    .await
+    .expect("both bundles should have been submitted by the funded account");
+
+    for guard in [&first_guard, &second_guard] {
+        let signed_tx = signed_tx_from_request(&guard.received_requests().await[0]);
+        assert_eq!(
+            signed_tx.verification_key(),
+            verification_key_from_hex(SECOND_PRIVATE_KEY)
+        );
+    }
+}
+
+/// Test that the receipt of a sequence action reports the hash and nonce of the sequencer
+/// transaction it was submitted in.
+#[tokio::test]
+async fn receipt_reports_submitted_transaction() {
+    let (sequencer, nonce_guard, cfg) = setup().await;
+    let (executor, executor_handle) = executor_builder(&cfg, CancellationToken::new())
+        .build()
+        .unwrap();
+
+    let status = executor.subscribe();
+    let _executor_task = tokio::spawn(executor.run_until_stopped());
+    wait_for_startup(status, nonce_guard).await.unwrap();
+
+    let response_guard = mount_broadcast_tx_sync_seq_actions_mock(&sequencer).await;
+
+    // the first sequence action fills the bundle, which is submitted once the second arrives
+    let seq0 = SequenceAction {
+        rollup_id: RollupId::new([0; ROLLUP_ID_LEN]),
+        data: vec![0u8; cfg.max_bytes_per_bundle - ROLLUP_ID_LEN],
+        fee_asset_id: default_native_asset_id(),
+    };
+    let seq1 = SequenceAction {
+        rollup_id: RollupId::new([1; ROLLUP_ID_LEN]),
+        data: vec![1u8; 1],
+        fee_asset_id: default_native_asset_id(),
+    };
+    let pending_receipt = executor_handle
+        .send_with_receipt_timeout(seq0, Duration::from_millis(1000))
+        .await
+        .unwrap();
+    executor_handle
+        .send_timeout(seq1, Duration::from_millis(1000))
+        .await
+        .unwrap();
+
+    let receipt = tokio::time::timeout(Duration::from_millis(1000), pending_receipt.wait())
+        .await
+        .expect("the bundle should have been submitted")
+        .unwrap();
+
+    let requests = response_guard.received_requests().await;
+    assert_eq!(requests.len(), 1);
+    let signed_tx = signed_tx_from_request(&requests[0]);
+    assert_eq!(receipt.nonce, signed_tx.nonce());
+    assert_eq!(
+        receipt.sequencer_tx_hash,
+        signed_tx.sha256_of_proto_encoding()
+    );
+}
+
+/// Test that the rollup transactions of a submitted bundle are tracked under the hash of the
+/// sequencer transaction they were submitted in.
+#[tokio::test]
+async fn submitted_rollup_txs_are_tracked() {
+    let (sequencer, nonce_guard, cfg) = setup().await;
+    let tx_tracker = tracker::Handle::new(cfg.tx_tracker_capacity);
+    let (executor, executor_handle) = executor::Builder {
+        tx_tracker: tx_tracker.clone(),
+        ..executor_builder(&cfg, CancellationToken::new())
+    }
+    .build()
+    .unwrap();
+
+    let status = executor.subscribe();
+    let _executor_task = tokio::spawn(executor.run_until_stopped());
+    wait_for_startup(status, nonce_guard).await.unwrap();
+
+    let response_guard = mount_broadcast_tx_sync_seq_actions_mock(&sequencer).await;
+
+    let seq_action = SequenceAction {
+        rollup_id: RollupId::new([0; ROLLUP_ID_LEN]),
+        data: vec![0u8; 10],
+        fee_asset_id: default_native_asset_id(),
+    };
+    let rollup_tx_hash = tracker::rollup_tx_hash(&seq_action.data);
+    let pending_receipt = executor_handle
+        .send_with_receipt_timeout(seq_action, Duration::from_millis(1000))
+        .await
+        .unwrap();
+    assert!(tx_tracker.status(&rollup_tx_hash).is_none());
+
+    // the bundle is submitted once the block timer ticks
+    let receipt = tokio::time::timeout(
+        Duration::from_millis(cfg.block_time_ms + 1000),
+        pending_receipt.wait(),
+    )
+    .await
+    .expect("the bundle should have been submitted")
+    .unwrap();
+    response_guard.wait_until_satisfied().await;
+
+    assert_eq!(
+        tx_tracker.status(&rollup_tx_hash),
+        Some(tracker::TxStatus {
+            sequencer_tx_hash: receipt.sequencer_tx_hash,
+            sequencer_height: None,
+        })
+    );
+}
+
+/// Test that the receipt of a sequence action reports an error if its bundle is rejected by the
+/// sequencer.
+#[tokio::test]
+async fn receipt_reports_rejected_bundle() {
+    let (sequencer, nonce_guard, cfg) = setup().await;
+    let (executor, executor_handle) = executor_builder(&cfg, CancellationToken::new())
+        .build()
+        .unwrap();
+
+    let status = executor.subscribe();
+    let _executor_task = tokio::spawn(executor.run_until_stopped());
+    wait_for_startup(status, nonce_guard).await.unwrap();
+
+    let _response_guard = mount_broadcast_tx_sync_nonce_mock(
+        &sequencer,
+        0,
+        RollupId::new([0; ROLLUP_ID_LEN]),
+        AbciErrorCode::INSUFFICIENT_FUNDS.into(),
+        Duration::ZERO,
+    )
+    .await;
+
+    let seq0 = SequenceAction {
+        rollup_id: RollupId::new([0; ROLLUP_ID_LEN]),
+        data: vec![0u8; cfg.max_bytes_per_bundle - ROLLUP_ID_LEN],
+        fee_asset_id: default_native_asset_id(),
+    };
+    let seq1 = SequenceAction {
+        rollup_id: RollupId::new([1; ROLLUP_ID_LEN]),
+        data: vec![1u8; 1],
+        fee_asset_id: default_native_asset_id(),
+    };
+    let pending_receipt = executor_handle
+        .send_with_receipt_timeout(seq0, Duration::from_millis(1000))
+        .await
+        .unwrap();
+    executor_handle
+        .send_timeout(seq1, Duration::from_millis(1000))
+        .await
+        .unwrap();
+
+    let error = tokio::time::timeout(Duration::from_millis(1000), pending_receipt.wait())
+        .await
+        .expect("the bundle should have been rejected")
+        .unwrap_err();
+    assert!(
+        matches!(error, executor::receipt::ReceiptError::Failed(_)),
+        "unexpected error: {error:?}"
+    );
+}
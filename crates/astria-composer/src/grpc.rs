@@ -6,9 +6,18 @@
 //!
 //! It also implements the tonic health service.
 
-use std::net::SocketAddr;
+use std::{
+    collections::HashMap,
+    net::SocketAddr,
+};
 
-use astria_core::generated::composer::v1alpha1::grpc_collector_service_server::GrpcCollectorServiceServer;
+use astria_core::{
+    generated::composer::v1alpha1::grpc_collector_service_server::GrpcCollectorServiceServer,
+    sequencer::v1::{
+        asset,
+        RollupId,
+    },
+};
 use astria_eyre::{
     eyre,
     eyre::WrapErr as _,
@@ -16,11 +25,14 @@ use astria_eyre::{
 use tokio::{
     io,
     net::TcpListener,
+    sync::watch,
 };
+use tokio_util::sync::CancellationToken;
 
 use crate::{
     collectors,
     executor,
+    tracker,
 };
 
 /// Listens for incoming gRPC requests and sends the Rollup transactions to the
@@ -36,11 +48,19 @@ impl GrpcServer {
     pub(crate) async fn new(
         grpc_addr: SocketAddr,
         executor: executor::Handle,
+        executor_status: watch::Receiver<executor::Status>,
+        rollup_fee_asset_ids: HashMap<RollupId, asset::Id>,
+        tx_tracker: tracker::Handle,
     ) -> eyre::Result<Self> {
         let listener = TcpListener::bind(grpc_addr)
             .await
             .wrap_err("failed to bind grpc listener")?;
-        let grpc_collector = collectors::Grpc::new(executor.clone());
+        let grpc_collector = collectors::Grpc::new(
+            executor.clone(),
+            executor_status,
+            rollup_fee_asset_ids,
+            tx_tracker,
+        );
 
         Ok(Self {
             listener,
@@ -55,7 +75,11 @@ impl GrpcServer {
         self.listener.local_addr()
     }
 
-    pub(crate) async fn run_until_stopped(self) -> eyre::Result<()> {
+    /// Serves gRPC requests until `shutdown_token` is cancelled.
+    pub(crate) async fn run_until_stopped(
+        self,
+        shutdown_token: CancellationToken,
+    ) -> eyre::Result<()> {
         let (mut health_reporter, health_service) = tonic_health::server::health_reporter();
 
         let composer_service = GrpcCollectorServiceServer::new(self.grpc_collector);
@@ -68,9 +92,10 @@ impl GrpcServer {
             .await;
 
         grpc_server
-            .serve_with_incoming(tokio_stream::wrappers::TcpListenerStream::new(
-                self.listener,
-            ))
+            .serve_with_incoming_shutdown(
+                tokio_stream::wrappers::TcpListenerStream::new(self.listener),
+                shutdown_token.cancelled(),
+            )
             .await
             .wrap_err("failed to run grpc server")
     }
@@ -45,9 +45,14 @@ mod composer;
 pub mod config;
 mod executor;
 mod grpc;
+pub mod metrics_init;
 mod rollup;
+mod tracker;
 
 pub use build_info::BUILD_INFO;
-pub use composer::Composer;
+pub use composer::{
+    Composer,
+    ShutdownHandle,
+};
 pub use config::Config;
 pub use telemetry;
@@ -1,6 +1,7 @@
 use std::process::ExitCode;
 
 use astria_composer::{
+    metrics_init,
     Composer,
     Config,
     BUILD_INFO,
@@ -37,8 +38,10 @@ async fn main() -> ExitCode {
     if !cfg.no_metrics {
         telemetry_conf = telemetry_conf
             .metrics_addr(&cfg.metrics_http_listener_addr)
-            .service_name(env!("CARGO_PKG_NAME"));
+            .service_name(env!("CARGO_PKG_NAME"))
+            .gauge_idle_timeout(metrics_init::GAUGE_IDLE_TIMEOUT);
     }
+    metrics_init::register();
 
     if let Err(e) = telemetry_conf
         .try_init()
@@ -0,0 +1,110 @@
+//! Crate-specific metrics functionality.
+//!
+//! Registers metrics & lists constants to be used as metric names throughout crate.
+
+use std::time::Duration;
+
+use metrics::{
+    describe_counter,
+    describe_gauge,
+    describe_histogram,
+    Unit,
+};
+
+/// Registers all metrics used by this crate.
+pub fn register() {
+    describe_gauge!(
+        BYTES_QUEUED_PER_ROLLUP,
+        Unit::Bytes,
+        "The number of bytes of sequence actions queued for bundling, labeled by rollup id"
+    );
+
+    describe_counter!(
+        BYTES_REJECTED_PER_ROLLUP,
+        Unit::Bytes,
+        "The number of bytes of sequence actions rejected because their rollup exceeded its quota \
+         or rate limit, labeled by rollup id"
+    );
+
+    describe_counter!(
+        TRANSACTIONS_RECEIVED,
+        Unit::Count,
+        "The number of rollup transactions received by the collectors, labeled by collector and \
+         rollup id"
+    );
+
+    describe_counter!(
+        TRANSACTIONS_DROPPED,
+        Unit::Count,
+        "The number of rollup transactions dropped by the collectors because they could not be \
+         sent to the executor, labeled by collector and rollup id"
+    );
+
+    describe_histogram!(
+        BUNDLE_SIZE,
+        Unit::Bytes,
+        "The size of the bundles assembled for submission to the sequencer"
+    );
+
+    describe_histogram!(
+        BUNDLE_FILL_RATIO,
+        "The ratio of the size of the bundles assembled for submission to the sequencer to the \
+         max bundle size"
+    );
+
+    describe_histogram!(
+        SEQUENCER_SUBMISSION_LATENCY,
+        Unit::Seconds,
+        "The time it takes the sequencer to respond to a submitted transaction"
+    );
+
+    describe_counter!(
+        NONCE_REFETCH_COUNT,
+        Unit::Count,
+        "The number of times the nonce was fetched again after the sequencer rejected a \
+         transaction due to an invalid nonce"
+    );
+
+    describe_counter!(
+        SEQUENCER_SUBMISSION_FAILURE_COUNT,
+        Unit::Count,
+        "The number of transactions the sequencer rejected in CheckTx, labeled by abci code"
+    );
+}
+
+/// The time after which gauges that were not updated are no longer exported, such as the bytes
+/// queued for a rollup whose queue was removed from the bundle factory.
+pub const GAUGE_IDLE_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// The label holding the rollup id of per-rollup metrics.
+pub const ROLLUP_ID_LABEL: &str = "rollup_id";
+
+/// The label holding the name of the collector of per-collector metrics.
+pub const COLLECTOR_LABEL: &str = "collector";
+
+/// The label holding the abci code of failed sequencer submissions.
+pub const ABCI_CODE_LABEL: &str = "code";
+
+pub const BYTES_QUEUED_PER_ROLLUP: &str =
+    concat!(env!("CARGO_CRATE_NAME"), "_bytes_queued_per_rollup");
+
+pub const BYTES_REJECTED_PER_ROLLUP: &str =
+    concat!(env!("CARGO_CRATE_NAME"), "_bytes_rejected_per_rollup");
+
+pub const TRANSACTIONS_RECEIVED: &str = concat!(env!("CARGO_CRATE_NAME"), "_transactions_received");
+
+pub const TRANSACTIONS_DROPPED: &str = concat!(env!("CARGO_CRATE_NAME"), "_transactions_dropped");
+
+pub const BUNDLE_SIZE: &str = concat!(env!("CARGO_CRATE_NAME"), "_bundle_size");
+
+pub const BUNDLE_FILL_RATIO: &str = concat!(env!("CARGO_CRATE_NAME"), "_bundle_fill_ratio");
+
+pub const SEQUENCER_SUBMISSION_LATENCY: &str =
+    concat!(env!("CARGO_CRATE_NAME"), "_sequencer_submission_latency");
+
+pub const NONCE_REFETCH_COUNT: &str = concat!(env!("CARGO_CRATE_NAME"), "_nonce_refetch_count");
+
+pub const SEQUENCER_SUBMISSION_FAILURE_COUNT: &str = concat!(
+    env!("CARGO_CRATE_NAME"),
+    "_sequencer_submission_failure_count"
+);
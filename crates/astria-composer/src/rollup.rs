@@ -1,14 +1,28 @@
-//! Parsing strings of the form `<rollup_name>::<url>`
+//! Parsing strings of the form `<rollup_name>::<url>[::<fee_asset_denom>]`
 
 use std::fmt;
 
+use astria_core::sequencer::v1::asset;
 use once_cell::sync::Lazy;
 use regex::Regex;
 
+use crate::collectors;
+
 #[derive(Debug)]
 pub(super) struct Rollup {
     rollup_name: String,
     url: String,
+    fee_asset: Option<String>,
+}
+
+/// The connection and fee details of a rollup after parsing.
+#[derive(Clone, Debug)]
+pub(super) struct RollupParts {
+    pub(super) url: String,
+    /// The asset used to pay sequencer fees for this rollup's transactions.
+    pub(super) fee_asset_id: asset::Id,
+    /// The kind of subscription the rollup's collector uses.
+    pub(super) collector: collectors::Kind,
 }
 
 #[derive(Debug)]
@@ -23,8 +37,8 @@ impl ParseError {
 impl fmt::Display for ParseError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.pad(
-            "invalid rollup definition, must be `<chainid>::<url>, with <chainid> being \
-             alphanumeric ascii and -",
+            "invalid rollup definition, must be `<chainid>::<url>[::<fee_asset_denom>]`, with \
+             <chainid> being alphanumeric ascii and -",
         )
     }
 }
@@ -40,10 +54,13 @@ impl Rollup {
                     # lazily match all alphanumeric ascii and dash;
                     # case insignificant, but we will lowercase later
                 ::
-                (?P<url>.+)
+                (?P<url>.+?)
                     # treat all following chars as the url without any verification;
                     # if there are bad chars, the downstream URL parser should
                     # handle that
+                (?:::(?P<fee_asset>[[:alnum:]/._-]+))?
+                    # optionally match the denomination of the fee asset, which
+                    # cannot contain colons so that it is not confused with the url
                 $
             ",
             )
@@ -55,18 +72,33 @@ impl Rollup {
         // match when these capture groups match.
         let rollup_name = caps["rollup_name"].to_string().to_lowercase();
         let url = caps["url"].to_string();
+        let fee_asset = caps.name("fee_asset").map(|m| m.as_str().to_string());
         Ok(Self {
             rollup_name,
             url,
+            fee_asset,
         })
     }
 
-    pub(super) fn into_parts(self) -> (String, String) {
+    /// Splits the rollup into its name and parts, falling back to the sequencer's native asset
+    /// if no fee asset was specified. The rollup is collected from with a Geth subscription.
+    pub(super) fn into_parts(self) -> (String, RollupParts) {
         let Self {
             rollup_name,
             url,
+            fee_asset,
         } = self;
-        (rollup_name, url)
+        let fee_asset_id = fee_asset.map_or_else(asset::default_native_asset_id, |denom| {
+            asset::Id::from_denom(&denom)
+        });
+        (
+            rollup_name,
+            RollupParts {
+                url,
+                fee_asset_id,
+                collector: collectors::Kind::Geth,
+            },
+        )
     }
 }
 
@@ -132,4 +164,32 @@ mod tests {
         assert_eq!(rollups[0].rollup_name, "chain-1");
         assert_eq!(rollups[0].url, ":http://some.url");
     }
+
+    #[test]
+    fn parse_with_fee_asset_is_valid() {
+        let rollups =
+            expect_parse_rollups("chain-1::http://some.url:8545::transfer/channel-0/utia");
+        assert_eq!(rollups[0].rollup_name, "chain-1");
+        assert_eq!(rollups[0].url, "http://some.url:8545");
+        assert_eq!(
+            rollups[0].fee_asset.as_deref(),
+            Some("transfer/channel-0/utia")
+        );
+    }
+
+    #[test]
+    fn parse_without_fee_asset_uses_native_asset() {
+        let (_, parts) = expect_parse_rollups("chain-1::http://some.url")
+            .pop()
+            .unwrap()
+            .into_parts();
+        assert_eq!(parts.fee_asset_id, asset::default_native_asset_id());
+    }
+
+    #[test]
+    fn parse_ipv6_url_is_not_mistaken_for_fee_asset() {
+        let rollups = expect_parse_rollups("chain-1::ws://[::1]:8546");
+        assert_eq!(rollups[0].url, "ws://[::1]:8546");
+        assert_eq!(rollups[0].fee_asset, None);
+    }
 }
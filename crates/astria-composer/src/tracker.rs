@@ -0,0 +1,409 @@
+//! Tracks the rollup transactions submitted to the sequencer until they are sequenced.
+//!
+//! The executor records the hash of each sequencer transaction it submitted together with the
+//! rollup transactions bundled in it. The [`Tracker`] polls the sequencer for new blocks and
+//! marks the rollup transactions of each included sequencer transaction as sequenced at the
+//! block's height.
+//!
+//! Rollup transactions are identified by the keccak256 hash of their raw bytes, which is the
+//! transaction hash of EVM rollups. The index of tracked rollup transactions is bounded, evicting
+//! the oldest entries once it is full. Sequencer transactions that were not included within
+//! [`MAX_PENDING_HEIGHTS`] blocks of their submission are assumed to be dropped and no longer
+//! looked for.
+
+use std::{
+    collections::{
+        hash_map::Entry,
+        HashMap,
+        VecDeque,
+    },
+    sync::{
+        Arc,
+        Mutex,
+    },
+    time::Duration,
+};
+
+use astria_eyre::eyre::{
+    self,
+    WrapErr as _,
+};
+use sequencer_client::{
+    tendermint::block::Height,
+    Client as _,
+};
+use sha2::{
+    Digest as _,
+    Sha256,
+};
+use tokio::time::{
+    self,
+    MissedTickBehavior,
+};
+use tracing::{
+    debug,
+    instrument,
+    warn,
+};
+
+/// The number of blocks after which a submitted sequencer transaction that was not included in
+/// any of them is no longer looked for.
+const MAX_PENDING_HEIGHTS: u64 = 100;
+
+/// Returns the hash identifying a rollup transaction.
+pub(crate) fn rollup_tx_hash(data: &[u8]) -> [u8; 32] {
+    ethers::utils::keccak256(data)
+}
+
+/// The status of a submitted rollup transaction.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct TxStatus {
+    /// The sha256 hash of the sequencer transaction the rollup transaction was submitted in.
+    pub(crate) sequencer_tx_hash: [u8; 32],
+    /// The height of the sequencer block the sequencer transaction was included in, if it was
+    /// included yet.
+    pub(crate) sequencer_height: Option<u64>,
+}
+
+/// A submitted sequencer transaction not yet included in a block.
+#[derive(Debug)]
+struct PendingTx {
+    /// The rollup transactions submitted in the sequencer transaction.
+    rollup_tx_hashes: Vec<[u8; 32]>,
+    /// The latest recorded block height when the sequencer transaction was submitted, or `None`
+    /// if no block was recorded before.
+    submitted_at_height: Option<u64>,
+}
+
+/// The bounded index of tracked rollup transactions.
+#[derive(Debug)]
+struct Index {
+    /// The status of each tracked rollup transaction.
+    statuses: HashMap<[u8; 32], TxStatus>,
+    /// The tracked rollup transactions, from the oldest to the most recently submitted.
+    order: VecDeque<[u8; 32]>,
+    /// The submitted sequencer transactions not yet included in a block.
+    pending: HashMap<[u8; 32], PendingTx>,
+    /// The max number of tracked rollup transactions.
+    capacity: usize,
+    /// The latest recorded block height.
+    latest_height: Option<u64>,
+}
+
+impl Index {
+    fn new(capacity: usize) -> Self {
+        Self {
+            statuses: HashMap::new(),
+            order: VecDeque::new(),
+            pending: HashMap::new(),
+            capacity,
+            latest_height: None,
+        }
+    }
+
+    fn submitted(&mut self, sequencer_tx_hash: [u8; 32], rollup_tx_hashes: Vec<[u8; 32]>) {
+        for rollup_tx_hash in &rollup_tx_hashes {
+            let status = TxStatus {
+                sequencer_tx_hash,
+                sequencer_height: None,
+            };
+            match self.statuses.insert(*rollup_tx_hash, status) {
+                // a rollup transaction sent more than once is tracked under its latest submission
+                Some(previous) => self.forget_pending(*rollup_tx_hash, previous),
+                None => self.order.push_back(*rollup_tx_hash),
+            }
+        }
+        self.pending.insert(
+            sequencer_tx_hash,
+            PendingTx {
+                rollup_tx_hashes,
+                submitted_at_height: self.latest_height,
+            },
+        );
+        while self.order.len() > self.capacity {
+            let Some(evicted) = self.order.pop_front() else {
+                break;
+            };
+            if let Some(status) = self.statuses.remove(&evicted) {
+                self.forget_pending(evicted, status);
+            }
+        }
+    }
+
+    /// Removes `rollup_tx_hash` from the pending sequencer transaction of `status`, if any.
+    fn forget_pending(&mut self, rollup_tx_hash: [u8; 32], status: TxStatus) {
+        if status.sequencer_height.is_some() {
+            return;
+        }
+        if let Entry::Occupied(mut entry) = self.pending.entry(status.sequencer_tx_hash) {
+            let rollup_tx_hashes = &mut entry.get_mut().rollup_tx_hashes;
+            rollup_tx_hashes.retain(|hash| *hash != rollup_tx_hash);
+            if rollup_tx_hashes.is_empty() {
+                entry.remove();
+            }
+        }
+    }
+
+    /// Marks the rollup transactions of `sequencer_tx_hash` as sequenced at `height`.
+    ///
+    /// Returns the number of rollup transactions marked.
+    fn sequenced(&mut self, sequencer_tx_hash: &[u8; 32], height: u64) -> usize {
+        let Some(pending) = self.pending.remove(sequencer_tx_hash) else {
+            return 0;
+        };
+        let mut marked = 0;
+        for rollup_tx_hash in pending.rollup_tx_hashes {
+            if let Some(status) = self.statuses.get_mut(&rollup_tx_hash) {
+                status.sequencer_height = Some(height);
+                marked += 1;
+            }
+        }
+        marked
+    }
+
+    /// Sets the latest recorded block height to `height`, and stops looking for the sequencer
+    /// transactions that were not included within [`MAX_PENDING_HEIGHTS`] blocks.
+    ///
+    /// Returns the number of sequencer transactions no longer looked for.
+    fn expire_pending(&mut self, height: u64) -> usize {
+        self.latest_height = Some(height);
+        let len_before = self.pending.len();
+        self.pending.retain(|_, pending| {
+            // transactions submitted before the first recorded block are counted from it
+            let submitted_at_height = *pending.submitted_at_height.get_or_insert(height);
+            height.saturating_sub(submitted_at_height) < MAX_PENDING_HEIGHTS
+        });
+        len_before - self.pending.len()
+    }
+}
+
+/// A handle to the index of tracked rollup transactions, shared by the executor recording
+/// submissions, the [`Tracker`] recording inclusions, and the APIs serving queries.
+#[derive(Clone, Debug)]
+pub(crate) struct Handle {
+    index: Arc<Mutex<Index>>,
+}
+
+impl Handle {
+    /// Creates a handle to an empty index tracking up to `capacity` rollup transactions.
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            index: Arc::new(Mutex::new(Index::new(capacity))),
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Index> {
+        self.index
+            .lock()
+            .expect("the index is never poisoned because no code panics while holding its lock")
+    }
+
+    /// Records that the rollup transactions `rollup_txs` were submitted in the sequencer
+    /// transaction `sequencer_tx_hash`.
+    pub(crate) fn submitted<'a>(
+        &self,
+        sequencer_tx_hash: [u8; 32],
+        rollup_txs: impl IntoIterator<Item = &'a [u8]>,
+    ) {
+        let rollup_tx_hashes = rollup_txs.into_iter().map(rollup_tx_hash).collect();
+        self.lock().submitted(sequencer_tx_hash, rollup_tx_hashes);
+    }
+
+    /// Returns the status of the rollup transaction `rollup_tx_hash` if it is tracked.
+    pub(crate) fn status(&self, rollup_tx_hash: &[u8; 32]) -> Option<TxStatus> {
+        self.lock().statuses.get(rollup_tx_hash).copied()
+    }
+
+    fn has_pending(&self) -> bool {
+        !self.lock().pending.is_empty()
+    }
+
+    /// Marks the rollup transactions of all sequencer transactions in `txs` as sequenced at
+    /// `height`.
+    fn record_block(&self, height: u64, txs: &[Vec<u8>]) {
+        let mut index = self.lock();
+        for tx in txs {
+            let sequencer_tx_hash: [u8; 32] = Sha256::digest(tx).into();
+            let marked = index.sequenced(&sequencer_tx_hash, height);
+            if marked > 0 {
+                debug!(
+                    height,
+                    sequencer_tx_hash = %telemetry::display::base64(&sequencer_tx_hash),
+                    rollup_txs = marked,
+                    "submitted sequencer transaction was included in block",
+                );
+            }
+        }
+    }
+
+    /// Stops looking for the sequencer transactions that were not included in a block up to
+    /// `latest_height`, the height of the latest block.
+    fn expire_pending(&self, latest_height: u64) {
+        let expired = self.lock().expire_pending(latest_height);
+        if expired > 0 {
+            debug!(
+                latest_height,
+                sequencer_txs = expired,
+                max_pending_heights = MAX_PENDING_HEIGHTS,
+                "submitted sequencer transactions were not included in time; no longer looking \
+                 for them",
+            );
+        }
+    }
+}
+
+/// Polls the sequencer for new blocks, recording the inclusion of submitted sequencer
+/// transactions in the index.
+pub(crate) struct Tracker {
+    sequencer_client: sequencer_client::HttpClient,
+    poll_interval: Duration,
+    handle: Handle,
+}
+
+impl Tracker {
+    pub(crate) fn new(
+        sequencer_url: &str,
+        poll_interval: Duration,
+        handle: Handle,
+    ) -> eyre::Result<Self> {
+        let sequencer_client = sequencer_client::HttpClient::new(sequencer_url)
+            .wrap_err("failed constructing sequencer client")?;
+        Ok(Self {
+            sequencer_client,
+            poll_interval,
+            handle,
+        })
+    }
+
+    /// Polls the sequencer for new blocks once every poll interval. Failing to fetch blocks is
+    /// retried at the next poll.
+    pub(crate) async fn run_until_stopped(self) {
+        let mut interval = time::interval(self.poll_interval);
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        let mut next_height = None;
+        loop {
+            interval.tick().await;
+            if let Err(error) = self.poll(&mut next_height).await {
+                warn!(
+                    error = %format!("{error:#}"),
+                    "failed fetching new sequencer blocks; retrying at the next poll",
+                );
+            }
+        }
+    }
+
+    /// Records the inclusions in all blocks from `next_height` up to the latest block.
+    ///
+    /// Blocks are only fetched while there are submitted sequencer transactions not yet included
+    /// in a block. `next_height` is advanced past each block that was recorded or skipped.
+    /// Sequencer transactions still not included are expired once pending for too long.
+    #[instrument(skip_all)]
+    async fn poll(&self, next_height: &mut Option<u64>) -> eyre::Result<()> {
+        let latest = self
+            .sequencer_client
+            .latest_block()
+            .await
+            .wrap_err("failed fetching latest block")?;
+        let latest_height = latest.block.header.height.value();
+        let start_height = next_height.unwrap_or(latest_height);
+        if start_height > latest_height {
+            return Ok(());
+        }
+        if self.handle.has_pending() {
+            for height in start_height..latest_height {
+                let response = self
+                    .sequencer_client
+                    .block(Height::try_from(height).wrap_err("invalid block height")?)
+                    .await
+                    .wrap_err_with(|| format!("failed fetching block at height {height}"))?;
+                self.handle.record_block(height, &response.block.data);
+                *next_height = Some(height + 1);
+            }
+            self.handle.record_block(latest_height, &latest.block.data);
+        }
+        self.handle.expire_pending(latest_height);
+        *next_height = Some(latest_height + 1);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hash(byte: u8) -> [u8; 32] {
+        [byte; 32]
+    }
+
+    #[test]
+    fn submitted_txs_are_sequenced_once_included() {
+        let mut index = Index::new(10);
+        index.submitted(hash(1), vec![hash(10), hash(11)]);
+        index.submitted(hash(2), vec![hash(20)]);
+
+        assert_eq!(index.sequenced(&hash(1), 5), 2);
+        assert_eq!(
+            index.statuses[&hash(10)],
+            TxStatus {
+                sequencer_tx_hash: hash(1),
+                sequencer_height: Some(5),
+            }
+        );
+        assert_eq!(index.statuses[&hash(11)].sequencer_height, Some(5));
+        assert_eq!(index.statuses[&hash(20)].sequencer_height, None);
+
+        // a sequencer transaction is only recorded once
+        assert_eq!(index.sequenced(&hash(1), 6), 0);
+        assert_eq!(index.statuses[&hash(10)].sequencer_height, Some(5));
+        assert!(!index.pending.contains_key(&hash(1)));
+    }
+
+    #[test]
+    fn oldest_txs_are_evicted_once_full() {
+        let mut index = Index::new(2);
+        index.submitted(hash(1), vec![hash(10), hash(11)]);
+        index.submitted(hash(2), vec![hash(20)]);
+
+        assert!(!index.statuses.contains_key(&hash(10)));
+        assert_eq!(index.statuses.len(), 2);
+        assert_eq!(index.pending[&hash(1)].rollup_tx_hashes, vec![hash(11)]);
+
+        index.submitted(hash(3), vec![hash(30)]);
+        assert!(!index.pending.contains_key(&hash(1)));
+        assert_eq!(index.sequenced(&hash(1), 5), 0);
+    }
+
+    #[test]
+    fn resubmitted_tx_is_tracked_under_latest_submission() {
+        let mut index = Index::new(10);
+        index.submitted(hash(1), vec![hash(10), hash(11)]);
+        index.submitted(hash(2), vec![hash(10)]);
+
+        assert_eq!(index.pending[&hash(1)].rollup_tx_hashes, vec![hash(11)]);
+        assert_eq!(index.order.len(), 2);
+
+        index.sequenced(&hash(2), 7);
+        assert_eq!(
+            index.statuses[&hash(10)],
+            TxStatus {
+                sequencer_tx_hash: hash(2),
+                sequencer_height: Some(7),
+            }
+        );
+    }
+
+    #[test]
+    fn pending_txs_expire_after_max_pending_heights() {
+        let mut index = Index::new(10);
+        // submitted before any block was recorded
+        index.submitted(hash(1), vec![hash(10)]);
+        assert_eq!(index.expire_pending(5), 0);
+        index.submitted(hash(2), vec![hash(20)]);
+
+        assert_eq!(index.expire_pending(5 + MAX_PENDING_HEIGHTS - 1), 0);
+        assert_eq!(index.expire_pending(5 + MAX_PENDING_HEIGHTS), 2);
+        assert!(index.pending.is_empty());
+        // the expired rollup transactions are still tracked, but not as sequenced
+        assert_eq!(index.statuses[&hash(10)].sequencer_height, None);
+    }
+}
@@ -1,9 +1,14 @@
-use std::time::Duration;
+use std::{
+    net::SocketAddr,
+    time::Duration,
+};
 
 use astria_core::{
     generated::{
         composer::v1alpha1::{
             grpc_collector_service_client::GrpcCollectorServiceClient,
+            BatchSubmitRollupTransactionsRequest,
+            GetRollupTransactionStatusRequest,
             SubmitRollupTransactionRequest,
         },
         sequencer::v1::NonceResponse,
@@ -15,6 +20,8 @@ use ethers::prelude::Transaction;
 use crate::helper::{
     mount_broadcast_tx_sync_invalid_nonce_mock,
     mount_broadcast_tx_sync_mock,
+    mount_broadcast_tx_sync_rejection_mock,
+    mount_broadcast_tx_sync_unavailable_mock,
     mount_matcher_verifying_tx_integrity,
     spawn_composer,
     TEST_ETH_TX_JSON,
@@ -47,6 +54,8 @@ async fn tx_from_one_rollup_is_received_by_sequencer() {
         .submit_rollup_transaction(SubmitRollupTransactionRequest {
             rollup_id: rollup_id.as_ref().to_vec(),
             data: tx.rlp().to_vec(),
+            fee_asset: String::new(),
+            wait_for_receipt: false,
         })
         .await
         .expect("rollup transactions should have been submitted successfully to grpc collector");
@@ -109,6 +118,8 @@ async fn invalid_nonce_causes_resubmission_under_different_nonce() {
         .submit_rollup_transaction(SubmitRollupTransactionRequest {
             rollup_id: rollup_id.as_ref().to_vec(),
             data: tx.rlp().to_vec(),
+            fee_asset: String::new(),
+            wait_for_receipt: false,
         })
         .await
         .expect("rollup transactions should have been submitted successfully to grpc collector");
@@ -164,6 +175,8 @@ async fn single_rollup_tx_payload_integrity() {
         .submit_rollup_transaction(SubmitRollupTransactionRequest {
             rollup_id: rollup_id.as_ref().to_vec(),
             data: tx.rlp().to_vec(),
+            fee_asset: String::new(),
+            wait_for_receipt: false,
         })
         .await
         .expect("rollup transactions should have been submitted successfully to grpc collector");
@@ -179,3 +192,453 @@ async fn single_rollup_tx_payload_integrity() {
          from composer",
     );
 }
+
+#[tokio::test]
+async fn tx_with_disallowed_fee_asset_is_rejected() {
+    let test_composer = spawn_composer(&[]).await;
+    tokio::time::timeout(
+        Duration::from_millis(100),
+        test_composer.setup_guard.wait_until_satisfied(),
+    )
+    .await
+    .expect("composer and sequencer should have been setup successfully");
+
+    let rollup_id = RollupId::from_unhashed_bytes("test1");
+    let tx = Transaction::default();
+    let mut composer_client = GrpcCollectorServiceClient::connect(format!(
+        "http://{}",
+        test_composer.grpc_collector_addr
+    ))
+    .await
+    .unwrap();
+    let status = composer_client
+        .submit_rollup_transaction(SubmitRollupTransactionRequest {
+            rollup_id: rollup_id.as_ref().to_vec(),
+            data: tx.rlp().to_vec(),
+            fee_asset: "not-allowed".to_string(),
+            wait_for_receipt: false,
+        })
+        .await
+        .expect_err("rollup transaction paying fees in a disallowed asset should be rejected");
+    assert_eq!(status.code(), tonic::Code::InvalidArgument);
+}
+
+/// Sends an empty rollup transaction for `rollup_id` to the grpc collector at `addr`.
+async fn submit_default_rollup_tx(addr: SocketAddr, rollup_id: RollupId) {
+    let tx = Transaction::default();
+    let mut composer_client = GrpcCollectorServiceClient::connect(format!("http://{addr}"))
+        .await
+        .unwrap();
+    composer_client
+        .submit_rollup_transaction(SubmitRollupTransactionRequest {
+            rollup_id: rollup_id.as_ref().to_vec(),
+            data: tx.rlp().to_vec(),
+            fee_asset: String::new(),
+            wait_for_receipt: false,
+        })
+        .await
+        .expect("rollup transactions should have been submitted successfully to grpc collector");
+}
+
+#[tokio::test]
+async fn unavailable_sequencer_causes_resubmission() {
+    let rollup_id = RollupId::from_unhashed_bytes("test1");
+    let test_composer = spawn_composer(&[]).await;
+    tokio::time::timeout(
+        Duration::from_millis(100),
+        test_composer.setup_guard.wait_until_satisfied(),
+    )
+    .await
+    .expect("composer and sequencer should have been setup successfully");
+
+    // Fail the first submission, then accept the resubmitted tx under the same nonce
+    let unavailable_guard =
+        mount_broadcast_tx_sync_unavailable_mock(&test_composer.sequencer, rollup_id, 1).await;
+    let valid_guard =
+        mount_broadcast_tx_sync_mock(&test_composer.sequencer, vec![rollup_id], vec![0]).await;
+
+    submit_default_rollup_tx(test_composer.grpc_collector_addr, rollup_id).await;
+
+    tokio::time::timeout(
+        Duration::from_millis(test_composer.cfg.block_time_ms),
+        unavailable_guard.wait_until_satisfied(),
+    )
+    .await
+    .expect("mocked sequencer should have received a broadcast message from composer");
+
+    tokio::time::timeout(
+        Duration::from_millis(1000),
+        valid_guard.wait_until_satisfied(),
+    )
+    .await
+    .expect("sequencer tx should have been resubmitted after the sequencer became available");
+}
+
+#[tokio::test]
+async fn rejected_bundle_does_not_stop_composer() {
+    let rejected_rollup_id = RollupId::from_unhashed_bytes("test1");
+    let accepted_rollup_id = RollupId::from_unhashed_bytes("test2");
+    let test_composer = spawn_composer(&[]).await;
+    tokio::time::timeout(
+        Duration::from_millis(100),
+        test_composer.setup_guard.wait_until_satisfied(),
+    )
+    .await
+    .expect("composer and sequencer should have been setup successfully");
+
+    let rejection_guard =
+        mount_broadcast_tx_sync_rejection_mock(&test_composer.sequencer, rejected_rollup_id).await;
+    submit_default_rollup_tx(test_composer.grpc_collector_addr, rejected_rollup_id).await;
+    tokio::time::timeout(
+        Duration::from_millis(test_composer.cfg.block_time_ms),
+        rejection_guard.wait_until_satisfied(),
+    )
+    .await
+    .expect("mocked sequencer should have received a broadcast message from composer");
+
+    // The rejected transaction did not consume the nonce, so the next one is sent under nonce 0
+    let valid_guard =
+        mount_broadcast_tx_sync_mock(&test_composer.sequencer, vec![accepted_rollup_id], vec![0])
+            .await;
+    submit_default_rollup_tx(test_composer.grpc_collector_addr, accepted_rollup_id).await;
+    tokio::time::timeout(
+        Duration::from_millis(test_composer.cfg.block_time_ms),
+        valid_guard.wait_until_satisfied(),
+    )
+    .await
+    .expect("composer should have kept submitting transactions after a rejection");
+    assert!(!test_composer.composer.is_finished());
+}
+
+#[tokio::test]
+async fn bundle_is_given_up_after_max_submission_attempts() {
+    let failing_rollup_id = RollupId::from_unhashed_bytes("test1");
+    let accepted_rollup_id = RollupId::from_unhashed_bytes("test2");
+    let test_composer = spawn_composer(&[]).await;
+    tokio::time::timeout(
+        Duration::from_millis(100),
+        test_composer.setup_guard.wait_until_satisfied(),
+    )
+    .await
+    .expect("composer and sequencer should have been setup successfully");
+
+    let unavailable_guard = mount_broadcast_tx_sync_unavailable_mock(
+        &test_composer.sequencer,
+        failing_rollup_id,
+        test_composer.cfg.max_submission_attempts.into(),
+    )
+    .await;
+    submit_default_rollup_tx(test_composer.grpc_collector_addr, failing_rollup_id).await;
+    tokio::time::timeout(
+        Duration::from_millis(test_composer.cfg.block_time_ms + 1000),
+        unavailable_guard.wait_until_satisfied(),
+    )
+    .await
+    .expect("composer should have attempted to submit the bundle the max number of times");
+
+    let valid_guard =
+        mount_broadcast_tx_sync_mock(&test_composer.sequencer, vec![accepted_rollup_id], vec![0])
+            .await;
+    submit_default_rollup_tx(test_composer.grpc_collector_addr, accepted_rollup_id).await;
+    tokio::time::timeout(
+        Duration::from_millis(test_composer.cfg.block_time_ms),
+        valid_guard.wait_until_satisfied(),
+    )
+    .await
+    .expect("composer should have kept submitting transactions after giving up on a bundle");
+    assert!(!test_composer.composer.is_finished());
+}
+
+#[tokio::test]
+async fn shutdown_submits_pending_bundle() {
+    let rollup_id = RollupId::from_unhashed_bytes("test1");
+    let test_composer = spawn_composer(&[]).await;
+    tokio::time::timeout(
+        Duration::from_millis(100),
+        test_composer.setup_guard.wait_until_satisfied(),
+    )
+    .await
+    .expect("composer and sequencer should have been setup successfully");
+
+    let mock_guard =
+        mount_broadcast_tx_sync_mock(&test_composer.sequencer, vec![rollup_id], vec![0]).await;
+
+    submit_default_rollup_tx(test_composer.grpc_collector_addr, rollup_id).await;
+    test_composer.shutdown_handle.shutdown();
+
+    // the pending bundle should be submitted well before the block timer would have ticked
+    tokio::time::timeout(
+        Duration::from_millis(test_composer.cfg.block_time_ms / 4),
+        mock_guard.wait_until_satisfied(),
+    )
+    .await
+    .expect("pending bundle should have been submitted on shutdown");
+
+    tokio::time::timeout(Duration::from_millis(1000), test_composer.composer)
+        .await
+        .expect("composer should have shut down after draining its pending bundles")
+        .unwrap()
+        .unwrap();
+}
+
+/// Returns a request to submit an empty rollup transaction for `rollup_id`.
+fn default_rollup_tx_request(
+    rollup_id: RollupId,
+    wait_for_receipt: bool,
+) -> SubmitRollupTransactionRequest {
+    SubmitRollupTransactionRequest {
+        rollup_id: rollup_id.as_ref().to_vec(),
+        data: Transaction::default().rlp().to_vec(),
+        fee_asset: String::new(),
+        wait_for_receipt,
+    }
+}
+
+#[tokio::test]
+async fn streamed_txs_are_bundled_and_receipts_are_returned() {
+    let rollup_id = RollupId::from_unhashed_bytes("test1");
+    let test_composer = spawn_composer(&[]).await;
+    tokio::time::timeout(
+        Duration::from_millis(100),
+        test_composer.setup_guard.wait_until_satisfied(),
+    )
+    .await
+    .expect("composer and sequencer should have been setup successfully");
+
+    let mock_guard =
+        mount_broadcast_tx_sync_mock(&test_composer.sequencer, vec![rollup_id], vec![0]).await;
+
+    let mut composer_client = GrpcCollectorServiceClient::connect(format!(
+        "http://{}",
+        test_composer.grpc_collector_addr
+    ))
+    .await
+    .unwrap();
+    let requests = tokio_stream::iter(vec![
+        default_rollup_tx_request(rollup_id, true),
+        default_rollup_tx_request(rollup_id, true),
+    ]);
+    // the response is only sent once the bundle was submitted after one sequencer block time
+    let response = tokio::time::timeout(
+        Duration::from_millis(test_composer.cfg.block_time_ms + 1000),
+        composer_client.submit_rollup_transactions(requests),
+    )
+    .await
+    .expect("composer should have responded once the bundle was submitted")
+    .expect("rollup transactions should have been submitted successfully to grpc collector")
+    .into_inner();
+
+    mock_guard.wait_until_satisfied().await;
+    assert_eq!(response.responses.len(), 2);
+    let receipts = response
+        .responses
+        .into_iter()
+        .map(|response| {
+            response
+                .receipt
+                .expect("a receipt should have been requested")
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(receipts[0].nonce, 0);
+    assert_eq!(receipts[0].sequencer_transaction_hash.len(), 32);
+    assert_eq!(
+        receipts[0], receipts[1],
+        "both transactions should have been submitted in the same sequencer transaction",
+    );
+}
+
+#[tokio::test]
+async fn invalid_streamed_tx_is_reported_in_its_own_response() {
+    let rollup_id = RollupId::from_unhashed_bytes("test1");
+    let test_composer = spawn_composer(&[]).await;
+    tokio::time::timeout(
+        Duration::from_millis(100),
+        test_composer.setup_guard.wait_until_satisfied(),
+    )
+    .await
+    .expect("composer and sequencer should have been setup successfully");
+
+    let mut composer_client = GrpcCollectorServiceClient::connect(format!(
+        "http://{}",
+        test_composer.grpc_collector_addr
+    ))
+    .await
+    .unwrap();
+    let mut invalid_request = default_rollup_tx_request(rollup_id, false);
+    invalid_request.rollup_id = vec![1, 2, 3];
+    let requests = tokio_stream::iter(vec![
+        default_rollup_tx_request(rollup_id, false),
+        invalid_request,
+    ]);
+    let response = composer_client
+        .submit_rollup_transactions(requests)
+        .await
+        .expect("an invalid transaction should not fail the whole stream")
+        .into_inner();
+
+    assert_eq!(response.responses.len(), 2);
+    assert!(response.responses[0].error.is_empty());
+    assert!(!response.responses[1].error.is_empty());
+}
+
+#[tokio::test]
+async fn stream_exceeding_max_batch_len_is_rejected() {
+    let rollup_id = RollupId::from_unhashed_bytes("test1");
+    let test_composer = spawn_composer(&[]).await;
+    tokio::time::timeout(
+        Duration::from_millis(100),
+        test_composer.setup_guard.wait_until_satisfied(),
+    )
+    .await
+    .expect("composer and sequencer should have been setup successfully");
+
+    let mut composer_client = GrpcCollectorServiceClient::connect(format!(
+        "http://{}",
+        test_composer.grpc_collector_addr
+    ))
+    .await
+    .unwrap();
+    // one more than the capacity of the executor's channel, which caps batches and streams
+    let requests =
+        tokio_stream::iter((0..257).map(|_| default_rollup_tx_request(rollup_id, false)));
+    let status = composer_client
+        .submit_rollup_transactions(requests)
+        .await
+        .expect_err("a stream with more transactions than a batch should be rejected");
+    assert_eq!(status.code(), tonic::Code::InvalidArgument);
+}
+
+#[tokio::test]
+async fn batched_txs_are_received_by_sequencer() {
+    let rollup_id = RollupId::from_unhashed_bytes("test1");
+    let test_composer = spawn_composer(&[]).await;
+    tokio::time::timeout(
+        Duration::from_millis(100),
+        test_composer.setup_guard.wait_until_satisfied(),
+    )
+    .await
+    .expect("composer and sequencer should have been setup successfully");
+
+    let mock_guard =
+        mount_broadcast_tx_sync_mock(&test_composer.sequencer, vec![rollup_id], vec![0]).await;
+
+    let mut composer_client = GrpcCollectorServiceClient::connect(format!(
+        "http://{}",
+        test_composer.grpc_collector_addr
+    ))
+    .await
+    .unwrap();
+    let response = composer_client
+        .batch_submit_rollup_transactions(BatchSubmitRollupTransactionsRequest {
+            transactions: vec![
+                default_rollup_tx_request(rollup_id, false),
+                default_rollup_tx_request(rollup_id, false),
+            ],
+        })
+        .await
+        .expect("rollup transactions should have been submitted successfully to grpc collector")
+        .into_inner();
+    assert_eq!(response.responses.len(), 2);
+    assert!(
+        response
+            .responses
+            .iter()
+            .all(|response| response.receipt.is_none())
+    );
+
+    // wait for 1 sequencer block time to make sure the bundle is preempted
+    tokio::time::timeout(
+        Duration::from_millis(test_composer.cfg.block_time_ms),
+        mock_guard.wait_until_satisfied(),
+    )
+    .await
+    .expect("mocked sequencer should have received a broadcast message from composer");
+}
+
+#[tokio::test]
+async fn batch_with_invalid_tx_is_rejected() {
+    let test_composer = spawn_composer(&[]).await;
+    tokio::time::timeout(
+        Duration::from_millis(100),
+        test_composer.setup_guard.wait_until_satisfied(),
+    )
+    .await
+    .expect("composer and sequencer should have been setup successfully");
+
+    let mut composer_client = GrpcCollectorServiceClient::connect(format!(
+        "http://{}",
+        test_composer.grpc_collector_addr
+    ))
+    .await
+    .unwrap();
+    let mut invalid_request =
+        default_rollup_tx_request(RollupId::from_unhashed_bytes("test1"), false);
+    invalid_request.rollup_id = vec![1, 2, 3];
+    let status = composer_client
+        .batch_submit_rollup_transactions(BatchSubmitRollupTransactionsRequest {
+            transactions: vec![
+                default_rollup_tx_request(RollupId::from_unhashed_bytes("test1"), false),
+                invalid_request,
+            ],
+        })
+        .await
+        .expect_err("a batch containing an invalid rollup id should be rejected");
+    assert_eq!(status.code(), tonic::Code::InvalidArgument);
+}
+
+#[tokio::test]
+async fn status_of_submitted_tx_is_tracked() {
+    let rollup_id = RollupId::from_unhashed_bytes("test1");
+    let test_composer = spawn_composer(&[]).await;
+    tokio::time::timeout(
+        Duration::from_millis(100),
+        test_composer.setup_guard.wait_until_satisfied(),
+    )
+    .await
+    .expect("composer and sequencer should have been setup successfully");
+
+    let mock_guard =
+        mount_broadcast_tx_sync_mock(&test_composer.sequencer, vec![rollup_id], vec![0]).await;
+
+    let mut composer_client = GrpcCollectorServiceClient::connect(format!(
+        "http://{}",
+        test_composer.grpc_collector_addr
+    ))
+    .await
+    .unwrap();
+    let request = default_rollup_tx_request(rollup_id, true);
+    let rollup_transaction_hash = ethers::utils::keccak256(&request.data).to_vec();
+    let status = composer_client
+        .get_rollup_transaction_status(GetRollupTransactionStatusRequest {
+            rollup_transaction_hash: rollup_transaction_hash.clone(),
+        })
+        .await
+        .expect_err("a transaction should not be tracked before it was submitted");
+    assert_eq!(status.code(), tonic::Code::NotFound);
+
+    let receipt = tokio::time::timeout(
+        Duration::from_millis(test_composer.cfg.block_time_ms + 1000),
+        composer_client.submit_rollup_transaction(request),
+    )
+    .await
+    .expect("composer should have responded once the bundle was submitted")
+    .expect("rollup transaction should have been submitted successfully to grpc collector")
+    .into_inner()
+    .receipt
+    .expect("a receipt should have been requested");
+    mock_guard.wait_until_satisfied().await;
+
+    let response = composer_client
+        .get_rollup_transaction_status(GetRollupTransactionStatusRequest {
+            rollup_transaction_hash,
+        })
+        .await
+        .expect("a submitted transaction should be tracked")
+        .into_inner();
+    assert_eq!(
+        response.sequencer_transaction_hash,
+        receipt.sequencer_transaction_hash
+    );
+    // the mocked sequencer never produces blocks, so the transaction is not sequenced
+    assert_eq!(response.sequencer_height, 0);
+}
@@ -1,9 +1,11 @@
+use astria_core::sequencer::v1::asset::default_native_asset_id;
 use prost::Message;
 use serde_json::json;
 use tendermint_rpc::{
     response,
     Id,
 };
+use test_utils::mock::sequencer::mount_status_mock;
 use wiremock::{
     matchers::{
         body_partial_json,
@@ -18,6 +20,8 @@ use wiremock::{
 pub async fn start() -> (MockServer, MockGuard) {
     use astria_core::generated::sequencer::v1::NonceResponse;
     let server = MockServer::start().await;
+    mount_status_mock(&server, "test").await;
+    mount_allowed_fee_asset_ids_mock(&server).await;
     let startup_guard = mount_abci_query_mock(
         &server,
         "accounts/nonce",
@@ -30,6 +34,34 @@ pub async fn start() -> (MockServer, MockGuard) {
     (server, startup_guard)
 }
 
+/// Mount a mock for the `asset/allowed_fee_asset_ids` query, reporting the native asset as the
+/// only allowed fee asset.
+pub async fn mount_allowed_fee_asset_ids_mock(server: &MockServer) {
+    use astria_core::generated::sequencer::v1::AllowedFeeAssetIdsResponse;
+    let response = tendermint_rpc::endpoint::abci_query::Response {
+        response: tendermint_rpc::endpoint::abci_query::AbciQuery {
+            value: AllowedFeeAssetIdsResponse {
+                height: 0,
+                fee_asset_ids: vec![default_native_asset_id().get().to_vec()],
+            }
+            .encode_to_vec(),
+            ..Default::default()
+        },
+    };
+    let wrapper = response::Wrapper::new_with_id(Id::Num(1), Some(response), None);
+    Mock::given(body_partial_json(json!({
+        "method": "abci_query"
+    })))
+    .and(body_string_contains("asset/allowed_fee_asset_ids"))
+    .respond_with(
+        ResponseTemplate::new(200)
+            .set_body_json(&wrapper)
+            .append_header("Content-Type", "application/json"),
+    )
+    .mount(server)
+    .await;
+}
+
 pub async fn mount_abci_query_mock(
     server: &MockServer,
     query_path: &str,
@@ -2,3 +2,4 @@ pub mod api;
 pub mod geth_collector;
 mod grpc_collector;
 pub mod helper;
+mod websocket_collector;
@@ -0,0 +1,44 @@
+use std::time::Duration;
+
+use ethers::types::Transaction;
+
+use crate::helper::{
+    mount_matcher_verifying_payload,
+    spawn_composer_with_websocket_collectors,
+    TEST_ETH_TX_JSON,
+};
+
+#[tokio::test]
+async fn payload_extracted_from_subscription_is_received_by_sequencer() {
+    // Collect the `input` field of each pending transaction sent by the mock geth node through a
+    // custom websocket subscription instead of the RLP encoded transaction
+    let websocket_collectors = r#"[{
+        "rollup_name": "test1",
+        "subscribe_method": "eth_subscribe",
+        "unsubscribe_method": "eth_unsubscribe",
+        "params": ["newPendingTransactions", true],
+        "payload_path": "/input",
+        "payload_encoding": "hex"
+    }]"#;
+    let test_composer =
+        spawn_composer_with_websocket_collectors(&["test1"], websocket_collectors).await;
+    tokio::time::timeout(
+        Duration::from_millis(100),
+        test_composer.setup_guard.wait_until_satisfied(),
+    )
+    .await
+    .expect("composer and sequencer should have been setup successfully");
+
+    let tx: Transaction = serde_json::from_str(TEST_ETH_TX_JSON).unwrap();
+    let mock_guard =
+        mount_matcher_verifying_payload(&test_composer.sequencer, tx.input.to_vec()).await;
+    test_composer.rollup_nodes["test1"].push_tx(tx).unwrap();
+
+    // wait for 1 sequencer block time to make sure the bundle is preempted
+    tokio::time::timeout(
+        Duration::from_millis(test_composer.cfg.block_time_ms),
+        mock_guard.wait_until_satisfied(),
+    )
+    .await
+    .expect("mocked sequencer should have received a broadcast message from composer");
+}
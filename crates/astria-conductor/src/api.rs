@@ -0,0 +1,208 @@
+use std::{
+    net::SocketAddr,
+    sync::Arc,
+};
+
+use astria_core::sequencer::v1::RollupId;
+use axum::{
+    extract::{
+        FromRef,
+        State,
+    },
+    response::{
+        IntoResponse,
+        Response,
+    },
+    routing::{
+        get,
+        IntoMakeService,
+    },
+    Json,
+    Router,
+};
+use http::status::StatusCode;
+use hyper::server::conn::AddrIncoming;
+use serde::Serialize;
+use tokio::sync::watch;
+
+use crate::{
+    executor,
+    status::StatusSnapshot,
+};
+
+pub(crate) type ApiServer = axum::Server<AddrIncoming, IntoMakeService<Router>>;
+
+/// The state of each executor, keyed by the execution RPC URL of its rollup.
+type ExecutorStates = Arc<[(String, watch::Receiver<executor::State>)]>;
+type ConductorStatus = watch::Receiver<StatusSnapshot>;
+
+#[derive(Clone)]
+/// `AppState` is used for as an axum extractor in its method handlers.
+struct AppState {
+    executor_states: ExecutorStates,
+    conductor_status: ConductorStatus,
+}
+
+impl FromRef<AppState> for ExecutorStates {
+    fn from_ref(app_state: &AppState) -> Self {
+        app_state.executor_states.clone()
+    }
+}
+
+impl FromRef<AppState> for ConductorStatus {
+    fn from_ref(app_state: &AppState) -> Self {
+        app_state.conductor_status.clone()
+    }
+}
+
+pub(crate) fn start(
+    socket_addr: SocketAddr,
+    executor_states: Vec<(String, watch::Receiver<executor::State>)>,
+    conductor_status: ConductorStatus,
+) -> ApiServer {
+    let app = Router::new()
+        .route("/healthz", get(get_healthz))
+        .route("/readyz", get(get_readyz))
+        .route("/status", get(get_status))
+        .with_state(AppState {
+            executor_states: executor_states.into(),
+            conductor_status,
+        });
+    axum::Server::bind(&socket_addr).serve(app.into_make_service())
+}
+
+#[allow(clippy::unused_async)] // Permit because axum handlers must be async
+async fn get_healthz(State(conductor_status): State<ConductorStatus>) -> Healthz {
+    if conductor_status.borrow().is_healthy() {
+        Healthz::Ok
+    } else {
+        Healthz::Degraded
+    }
+}
+
+/// Handler of a call to `/readyz`.
+///
+/// Returns `Readyz::Ok` if all of the following conditions are met:
+///
+/// + all executors received the genesis info and commitment state of their rollup nodes
+/// + at least one of the sequencer or Celestia readers is connected
+#[allow(clippy::unused_async)] // Permit because axum handlers must be async
+async fn get_readyz(
+    State(executor_states): State<ExecutorStates>,
+    State(conductor_status): State<ConductorStatus>,
+) -> Readyz {
+    let are_executors_init = executor_states
+        .iter()
+        .all(|(_, state)| state.borrow().is_init());
+    let is_any_reader_connected = conductor_status.borrow().is_any_reader_connected();
+    if are_executors_init && is_any_reader_connected {
+        Readyz::Ok
+    } else {
+        Readyz::NotReady
+    }
+}
+
+#[allow(clippy::unused_async)] // Permit because axum handlers must be async
+async fn get_status(
+    State(executor_states): State<ExecutorStates>,
+    State(conductor_status): State<ConductorStatus>,
+) -> Json<StatusBody> {
+    let rollups = executor_states
+        .iter()
+        .map(|(execution_rpc_url, state)| {
+            let state = state.borrow();
+            let is_init = state.is_init();
+            RollupStatus {
+                execution_rpc_url: execution_rpc_url.clone(),
+                rollup_id: is_init.then(|| state.rollup_id()),
+                commitments: is_init.then(|| Commitments {
+                    soft_height: state.soft().number(),
+                    firm_height: state.firm().number(),
+                    next_soft_sequencer_height: state.next_soft_sequencer_height().value(),
+                    next_firm_sequencer_height: state.next_firm_sequencer_height().value(),
+                    spread: state.soft().number().saturating_sub(state.firm().number()),
+                }),
+            }
+        })
+        .collect();
+    Json(StatusBody {
+        rollups,
+        readers: conductor_status.borrow().clone(),
+    })
+}
+
+/// The body returned by `/status`.
+#[derive(Debug, Serialize)]
+struct StatusBody {
+    rollups: Vec<RollupStatus>,
+    #[serde(flatten)]
+    readers: StatusSnapshot,
+}
+
+/// The status of a single rollup driven by Conductor.
+///
+/// `rollup_id` and `commitments` are `None` until the executor received the genesis info and
+/// commitment state of the rollup node.
+#[derive(Debug, Serialize)]
+struct RollupStatus {
+    execution_rpc_url: String,
+    rollup_id: Option<RollupId>,
+    commitments: Option<Commitments>,
+}
+
+#[derive(Debug, Serialize)]
+struct Commitments {
+    soft_height: u32,
+    firm_height: u32,
+    next_soft_sequencer_height: u64,
+    next_firm_sequencer_height: u64,
+    spread: u32,
+}
+
+enum Healthz {
+    Ok,
+    Degraded,
+}
+
+impl IntoResponse for Healthz {
+    fn into_response(self) -> Response {
+        #[derive(Debug, Serialize)]
+        struct HealthzBody {
+            status: &'static str,
+        }
+        let (status, msg) = match self {
+            Self::Ok => (StatusCode::OK, "ok"),
+            Self::Degraded => (StatusCode::INTERNAL_SERVER_ERROR, "degraded"),
+        };
+        let mut response = Json(HealthzBody {
+            status: msg,
+        })
+        .into_response();
+        *response.status_mut() = status;
+        response
+    }
+}
+
+enum Readyz {
+    Ok,
+    NotReady,
+}
+
+impl IntoResponse for Readyz {
+    fn into_response(self) -> Response {
+        #[derive(Debug, Serialize)]
+        struct ReadyzBody {
+            status: &'static str,
+        }
+        let (status, msg) = match self {
+            Self::Ok => (StatusCode::OK, "ok"),
+            Self::NotReady => (StatusCode::SERVICE_UNAVAILABLE, "not ready"),
+        };
+        let mut response = Json(ReadyzBody {
+            status: msg,
+        })
+        .into_response();
+        *response.status_mut() = status;
+        response
+    }
+}
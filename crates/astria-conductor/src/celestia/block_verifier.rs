@@ -391,6 +391,7 @@ mod test {
             rollup_transactions_root,
             rollup_transactions_proof,
             rollup_ids_proof,
+            posted_rollup_blobs: None,
         }
         .try_into_celestia_sequencer_blob()
         .unwrap();
@@ -428,6 +429,7 @@ mod test {
             rollup_transactions_root,
             rollup_transactions_proof,
             rollup_ids_proof,
+            posted_rollup_blobs: None,
         }
         .try_into_celestia_sequencer_blob()
         .unwrap();
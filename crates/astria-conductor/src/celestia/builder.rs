@@ -8,16 +8,18 @@ use super::Reader;
 use crate::{
     celestia::block_verifier::BlockVerifier,
     executor,
+    status::Status,
 };
 
 pub(crate) struct Builder {
     pub(crate) celestia_http_endpoint: String,
     pub(crate) celestia_websocket_endpoint: String,
     pub(crate) celestia_token: String,
-    pub(crate) executor: executor::Handle,
+    pub(crate) executors: executor::Handles,
     pub(crate) sequencer_cometbft_client: HttpClient,
     pub(crate) sequencer_namespace: Namespace,
     pub(crate) shutdown: CancellationToken,
+    pub(crate) status: Status,
 }
 
 impl Builder {
@@ -27,22 +29,24 @@ impl Builder {
             celestia_http_endpoint,
             celestia_websocket_endpoint,
             celestia_token,
-            executor,
+            executors,
             sequencer_cometbft_client,
             sequencer_namespace,
             shutdown,
+            status,
         } = self;
 
         let block_verifier = BlockVerifier::new(sequencer_cometbft_client);
 
         Reader {
-            executor,
+            executors,
             celestia_http_endpoint,
             celestia_ws_endpoint: celestia_websocket_endpoint,
             celestia_auth_token: celestia_token,
             block_verifier,
             sequencer_namespace,
             shutdown,
+            status,
         }
     }
 }
@@ -1,4 +1,5 @@
 use std::{
+    collections::HashMap,
     future::ready,
     pin::Pin,
     task::{
@@ -8,6 +9,7 @@ use std::{
     time::Duration,
 };
 
+use astria_core::sequencer::v1::RollupId;
 use astria_eyre::eyre::{
     self,
     bail,
@@ -56,13 +58,7 @@ use telemetry::display::{
     base64,
     json,
 };
-use tokio::{
-    select,
-    sync::mpsc::error::{
-        SendError,
-        TrySendError,
-    },
-};
+use tokio::select;
 use tokio_util::sync::CancellationToken;
 use tracing::{
     debug,
@@ -84,12 +80,15 @@ use crate::{
         GetSequencerHeight,
     },
     executor,
+    metrics_init,
+    status::Status,
 };
 mod builder;
 pub(crate) use builder::Builder;
 mod reporting;
 use reporting::{
     ReportReconstructedBlocks,
+    ReportRollupNamespaces,
     ReportSequencerHeights,
 };
 
@@ -98,33 +97,65 @@ type StdError = dyn std::error::Error;
 struct ReconstructedBlocks {
     celestia_height: u64,
     sequencer_namespace: Namespace,
-    rollup_namespace: Namespace,
-    blocks: Vec<ReconstructedBlock>,
+    rollup_namespaces: Vec<(RollupId, Namespace)>,
+    blocks: Vec<ReconstructedSequencerBlock>,
 }
 
+/// A sequencer block reconstructed from Celestia, holding the transactions of all rollups
+/// driven by Conductor.
 #[derive(Clone, Debug)]
-pub(crate) struct ReconstructedBlock {
+pub(crate) struct ReconstructedSequencerBlock {
     pub(crate) block_hash: [u8; 32],
     pub(crate) header: tendermint::block::Header,
-    pub(crate) transactions: Vec<Vec<u8>>,
+    pub(crate) rollup_transactions: HashMap<RollupId, Vec<Vec<u8>>>,
     pub(crate) celestia_height: u64,
 }
 
-impl ReconstructedBlock {
+impl ReconstructedSequencerBlock {
     pub(crate) fn sequencer_height(&self) -> SequencerHeight {
         self.header.height
     }
+
+    /// Returns the block containing only the transactions of `rollup_id`.
+    ///
+    /// The transactions are empty if there were none for `rollup_id` in this block.
+    pub(crate) fn to_rollup_block(&self, rollup_id: RollupId) -> ReconstructedBlock {
+        ReconstructedBlock {
+            block_hash: self.block_hash,
+            header: self.header.clone(),
+            transactions: self
+                .rollup_transactions
+                .get(&rollup_id)
+                .cloned()
+                .unwrap_or_default(),
+            celestia_height: self.celestia_height,
+        }
+    }
 }
 
-impl GetSequencerHeight for ReconstructedBlock {
+impl GetSequencerHeight for ReconstructedSequencerBlock {
     fn get_height(&self) -> SequencerHeight {
         self.sequencer_height()
     }
 }
 
+#[derive(Clone, Debug)]
+pub(crate) struct ReconstructedBlock {
+    pub(crate) block_hash: [u8; 32],
+    pub(crate) header: tendermint::block::Header,
+    pub(crate) transactions: Vec<Vec<u8>>,
+    pub(crate) celestia_height: u64,
+}
+
+impl ReconstructedBlock {
+    pub(crate) fn sequencer_height(&self) -> SequencerHeight {
+        self.header.height
+    }
+}
+
 pub(crate) struct Reader {
-    /// The channel used to send messages to the executor task.
-    executor: executor::Handle,
+    /// The channels used to send messages to the executor tasks.
+    executors: executor::Handles,
 
     // The HTTP endpoint to fetch celestia blocks.
     celestia_http_endpoint: String,
@@ -143,25 +174,33 @@ pub(crate) struct Reader {
 
     /// Token to listen for Conductor being shut down.
     shutdown: CancellationToken,
+
+    /// Reports the connection status and progress of the reader through the API.
+    status: Status,
 }
 
 impl Reader {
     #[instrument(skip(self))]
     pub(crate) async fn run_until_stopped(mut self) -> eyre::Result<()> {
-        let mut executor = self
-            .executor
+        self.status.set_celestia_connected(false);
+
+        let mut executors = self
+            .executors
             .wait_for_init()
             .await
-            .wrap_err("handle to executor failed while waiting for it being initialized")?;
+            .wrap_err("handles to executors failed while waiting for them being initialized")?;
 
-        let rollup_id = executor.rollup_id();
-        let initial_expected_sequencer_height = executor.next_expected_firm_height();
-        let initial_celestia_height = executor.celestia_base_block_height();
-        let celestia_variance = executor.celestia_block_variance();
-        let rollup_namespace = celestia_namespace_v0_from_rollup_id(rollup_id);
+        let rollup_namespaces: Vec<_> = executors
+            .rollup_ids()
+            .into_iter()
+            .map(|rollup_id| (rollup_id, celestia_namespace_v0_from_rollup_id(rollup_id)))
+            .collect();
+        let initial_expected_sequencer_height = executors.next_expected_firm_height();
+        let initial_celestia_height = executors.celestia_base_block_height();
+        let celestia_variance = executors.celestia_block_variance();
 
         debug!(
-            %rollup_id,
+            rollup_namespaces = %json(&ReportRollupNamespaces(&rollup_namespaces)),
             %initial_expected_sequencer_height,
             %initial_celestia_height,
             celestia_variance,
@@ -184,11 +223,13 @@ impl Reader {
         };
 
         debug!(height = %latest_celestia_height, "received latest height from celestia");
+        self.status.set_celestia_connected(true);
 
         // XXX: This block cache always starts at height 1, the default value for `Height`.
-        let mut sequential_blocks =
-            BlockCache::<ReconstructedBlock>::with_next_height(initial_expected_sequencer_height)
-                .wrap_err("failed constructing sequential block cache")?;
+        let mut sequential_blocks = BlockCache::<ReconstructedSequencerBlock>::with_next_height(
+            initial_expected_sequencer_height,
+        )
+        .wrap_err("failed constructing sequential block cache")?;
 
         let http_client =
             connect_to_celestia(&self.celestia_http_endpoint, &self.celestia_auth_token)
@@ -209,17 +250,17 @@ impl Reader {
             client: http_client,
             verifier: self.block_verifier.clone(),
             sequencer_namespace: self.sequencer_namespace,
-            rollup_namespace,
+            rollup_namespaces: rollup_namespaces.clone(),
         }
         .instrument(info_span!(
             "celestia_block_stream",
-            namespace.rollup = %base64(&rollup_namespace.as_bytes()),
+            namespace.rollups = %json(&ReportRollupNamespaces(&rollup_namespaces)),
             namespace.sequencer = %base64(&self.sequencer_namespace.as_bytes()),
         ));
 
-        // Enqueued block waiting for executor to free up. Set if the executor exhibits
+        // Enqueued block waiting for executors to free up. Set if any executor exhibits
         // backpressure.
-        let mut enqueued_block: Fuse<BoxFuture<Result<_, SendError<ReconstructedBlock>>>> =
+        let mut enqueued_block: Fuse<BoxFuture<Result<_, executor::channel::SendError>>> =
             future::Fuse::terminated();
 
         // Pending subcription to Celestia network headers. Set if the current subscription fails.
@@ -243,31 +284,32 @@ impl Reader {
                     }
                 }
 
-                // Forward the next block to executor. Enqueue if the executor channel is full.
+                // Forward the next block to the executors. Enqueue if any executor channel is full.
                 Some(block) = sequential_blocks.next_block(), if enqueued_block.is_terminated() => {
                     let celestia_height = block.celestia_height;
-                    match executor.try_send_firm_block(block) {
-                        Ok(()) => {
+                    match executors.try_send_firm_block(&block) {
+                        Ok(None) => {
                             block_stream.inner_mut().update_reference_height_if_greater(celestia_height);
                         }
-                        Err(TrySendError::Full(block)) => {
+                        Ok(Some(full_executors)) => {
                             trace!("executor channel is full; rescheduling block fetch until the channel opens up");
-                            let executor_clone = executor.clone();
                             // must return the celestia height to update the reference height upon completion
                             enqueued_block = async move {
-                                let celestia_height = block.celestia_height;
-                                executor_clone.send_firm_block(block).await?;
+                                full_executors.send_firm_block(block).await?;
                                 Ok(celestia_height)
                             }.boxed().fuse();
                         }
 
-                        Err(TrySendError::Closed(_)) => bail!("exiting because executor channel is closed"),
+                        Err(_) => bail!("exiting because executor channel is closed"),
                     }
                 }
 
                 new_subscription = &mut resubscribing, if !resubscribing.is_terminated() => {
                     match new_subscription {
-                        Ok(new_subscription) => (_wsclient, headers) = new_subscription,
+                        Ok(new_subscription) => {
+                            (_wsclient, headers) = new_subscription;
+                            self.status.set_celestia_connected(true);
+                        }
                         Err(e) => return Err(e).wrap_err("resubscribing to celestia headers ultimately failed"),
                     }
                 }
@@ -311,6 +353,7 @@ impl Reader {
                         }
                     }
                     if resubscribe {
+                        self.status.set_celestia_connected(false);
                         resubscribing = subscribe_to_celestia_headers(
                             &self.celestia_ws_endpoint,
                             &self.celestia_auth_token,
@@ -329,6 +372,8 @@ impl Reader {
                     }
                 }
             );
+            self.status
+                .set_next_celestia_height(block_stream.inner().track_heights.next_height);
         };
 
         // XXX: explicitly setting the message (usually implicitly set by tracing)
@@ -408,7 +453,7 @@ pin_project! {
         client: HttpClient,
         verifier: BlockVerifier,
         sequencer_namespace: Namespace,
-        rollup_namespace: Namespace,
+        rollup_namespaces: Vec<(RollupId, Namespace)>,
     }
 }
 
@@ -445,7 +490,7 @@ impl Stream for ReconstructedBlocksStream {
                     this.verifier.clone(),
                     height,
                     *this.sequencer_namespace,
-                    *this.rollup_namespace,
+                    this.rollup_namespaces.clone(),
                 ),
             ) {
                 Err(PushError::BeyondCapacity(_)) => {
@@ -485,7 +530,7 @@ impl Stream for ReconstructedBlocksStream {
                             this.verifier.clone(),
                             height,
                             *this.sequencer_namespace,
-                            *this.rollup_namespace,
+                            this.rollup_namespaces.clone(),
                         ),
                     )
                 };
@@ -510,19 +555,20 @@ impl Stream for ReconstructedBlocksStream {
     }
 }
 
-/// Fetches all blob data for the desired rollup at celestia `height`.
+/// Fetches all blob data for the desired rollups at celestia `height`.
 ///
 /// Performs the following operations:
 /// 1. retrieves sequencer blobs at `height` matching `sequencer_namespace`;
 /// 2. verifies the sequencer blobs against sequencer, dropping all blobs that failed verification;
-/// 3. retrieves all rollup blobs at `height` matching `rollup_namespace` and the block hash stored
-///    in the sequencer blob.
+/// 3. retrieves all rollup blobs at `height` matching each of `rollup_namespaces` and the block
+///    hash stored in the sequencer blob. If the sequencer blob records the namespaces and heights
+///    rollup blobs were posted under, these are used instead of `rollup_namespaces` and `height`.
 #[instrument(
     skip_all,
     fields(
         %celestia_height,
         namespace.sequencer = %base64(&sequencer_namespace.as_bytes()),
-        namespace.rollup = %base64(&rollup_namespace.as_bytes()),
+        namespace.rollups = %json(&ReportRollupNamespaces(&rollup_namespaces)),
     ),
     err
 )]
@@ -531,7 +577,7 @@ async fn fetch_blocks_at_celestia_height(
     verifier: BlockVerifier,
     celestia_height: u64,
     sequencer_namespace: Namespace,
-    rollup_namespace: Namespace,
+    rollup_namespaces: Vec<(RollupId, Namespace)>,
 ) -> eyre::Result<ReconstructedBlocks> {
     use futures::TryStreamExt as _;
     use tracing_futures::Instrument as _;
@@ -547,7 +593,7 @@ async fn fetch_blocks_at_celestia_height(
             return Ok(ReconstructedBlocks {
                 celestia_height,
                 sequencer_namespace,
-                rollup_namespace,
+                rollup_namespaces,
                 blocks: vec![],
             });
         }
@@ -565,10 +611,10 @@ async fn fetch_blocks_at_celestia_height(
     // We ignore this here and handle that in downstream processing (the sequential cash will reject
     // the second blob), but we should probably do more reporting on this.
     let blocks = futures::stream::iter(sequencer_blobs)
-        .then(move |blob| {
+        .then(|blob| {
             let client = client.clone();
             let verifier = verifier.clone();
-            process_sequencer_blob(client, verifier, celestia_height, rollup_namespace, blob)
+            process_sequencer_blob(client, verifier, celestia_height, &rollup_namespaces, blob)
         })
         .inspect_err(|error| {
             warn!(%error, "failed to reconstruct block from celestia blob");
@@ -580,7 +626,7 @@ async fn fetch_blocks_at_celestia_height(
     let reconstructed = ReconstructedBlocks {
         celestia_height,
         sequencer_namespace,
-        rollup_namespace,
+        rollup_namespaces,
         blocks,
     };
     info!(
@@ -598,7 +644,6 @@ async fn fetch_blocks_at_celestia_height(
     fields(
         blob.sequencer_height = sequencer_blob.height().value(),
         blob.block_hash = %base64(&sequencer_blob.block_hash()),
-        celestia_rollup_namespace = %base64(rollup_namespace.as_bytes()),
     ),
     err,
 )]
@@ -606,42 +651,135 @@ async fn process_sequencer_blob(
     client: HttpClient,
     verifier: BlockVerifier,
     celestia_height: u64,
-    rollup_namespace: Namespace,
+    rollup_namespaces: &[(RollupId, Namespace)],
     sequencer_blob: CelestiaSequencerBlob,
-) -> eyre::Result<ReconstructedBlock> {
-    verifier
-        .verify_blob(&sequencer_blob)
-        .await
-        .wrap_err("failed validating sequencer blob retrieved from celestia")?;
-    let mut rollup_blobs = client
-        .get_rollup_blobs_matching_sequencer_blob(
-            celestia_height,
-            rollup_namespace,
-            &sequencer_blob,
-        )
-        .await
-        .wrap_err("failed fetching rollup blobs from celestia")?;
-    debug!(
-        %celestia_height,
-        number_of_blobs = rollup_blobs.len(),
-        "received rollup blobs from Celestia"
-    );
-    ensure!(
-        rollup_blobs.len() <= 1,
-        "received more than one celestia rollup blob for the given namespace and height"
-    );
-    let transactions = rollup_blobs
-        .pop()
-        .map(|blob| blob.into_unchecked().transactions)
-        .unwrap_or_default();
-    Ok(ReconstructedBlock {
+) -> eyre::Result<ReconstructedSequencerBlock> {
+    if let Err(error) = verifier.verify_blob(&sequencer_blob).await {
+        metrics::counter!(metrics_init::SEQUENCER_BLOBS_REJECTED_COUNT).increment(1);
+        return Err(error).wrap_err("failed validating sequencer blob retrieved from celestia");
+    }
+    metrics::counter!(metrics_init::SEQUENCER_BLOBS_VERIFIED_COUNT).increment(1);
+    let rollup_transactions = future::try_join_all(rollup_namespaces.iter().map(
+        |&(rollup_id, default_namespace)| {
+            let client = &client;
+            let sequencer_blob = &sequencer_blob;
+            async move {
+                // the rollup IDs are verified against the block hash, unlike the recorded
+                // posted rollup blobs
+                if !sequencer_blob.rollup_ids().contains(&rollup_id) {
+                    return Ok((rollup_id, vec![]));
+                }
+                let locations = rollup_blob_locations(
+                    sequencer_blob,
+                    celestia_height,
+                    rollup_id,
+                    default_namespace,
+                );
+                fetch_rollup_transactions(client, &locations, rollup_id, sequencer_blob).await
+            }
+        },
+    ))
+    .await?
+    .into_iter()
+    .collect();
+    Ok(ReconstructedSequencerBlock {
         celestia_height,
         block_hash: sequencer_blob.block_hash(),
         header: sequencer_blob.header().clone(),
-        transactions,
+        rollup_transactions,
     })
 }
 
+/// Returns the celestia heights and namespaces to look up the blob of `rollup_id` at, in the
+/// order they should be tried. `sequencer_blob` was read at `celestia_height`.
+///
+/// The posted rollup blobs recorded in `sequencer_blob` are not covered by its block hash and
+/// only serve as a hint: the location recorded for `rollup_id`, if any, is tried before the
+/// default location of `celestia_height` and `default_namespace`.
+fn rollup_blob_locations(
+    sequencer_blob: &CelestiaSequencerBlob,
+    celestia_height: u64,
+    rollup_id: RollupId,
+    default_namespace: Namespace,
+) -> Vec<(u64, Namespace)> {
+    let default_location = (celestia_height, default_namespace);
+    let posted = sequencer_blob
+        .posted_rollup_blobs()
+        .unwrap_or_default()
+        .iter()
+        .find(|posted| posted.rollup_id() == rollup_id);
+    let Some(posted) = posted else {
+        return vec![default_location];
+    };
+    let namespace = match Namespace::from_raw(&posted.celestia_namespace()) {
+        Ok(namespace) => namespace,
+        Err(error) => {
+            warn!(
+                %rollup_id,
+                error = &error as &StdError,
+                "sequencer blob recorded an invalid celestia namespace for the rollup; ignoring it",
+            );
+            return vec![default_location];
+        }
+    };
+    // rollup blobs of sequencer blocks split across several celestia transactions are posted
+    // before the sequencer blob
+    let hinted_location = (
+        posted.celestia_height().unwrap_or(celestia_height),
+        namespace,
+    );
+    if hinted_location == default_location {
+        vec![default_location]
+    } else {
+        vec![hinted_location, default_location]
+    }
+}
+
+/// Fetches the transactions of the rollup blob matching `rollup_id` and `sequencer_blob`, trying
+/// the celestia heights and namespaces in `locations` in order.
+///
+/// # Errors
+/// Returns an error if no matching rollup blob was found at any of `locations`. Callers must
+/// only fetch the transactions of rollups recorded in `sequencer_blob`, so a missing blob never
+/// stands for a rollup without transactions.
+#[instrument(skip_all, fields(%rollup_id), err)]
+async fn fetch_rollup_transactions(
+    client: &HttpClient,
+    locations: &[(u64, Namespace)],
+    rollup_id: RollupId,
+    sequencer_blob: &CelestiaSequencerBlob,
+) -> eyre::Result<(RollupId, Vec<Vec<u8>>)> {
+    for &(celestia_height, rollup_namespace) in locations {
+        let mut rollup_blobs = client
+            .get_rollup_blobs_matching_sequencer_blob(
+                celestia_height,
+                rollup_namespace,
+                sequencer_blob,
+            )
+            .await
+            .wrap_err("failed fetching rollup blobs from celestia")?;
+        // a custom namespace can be shared by the blobs of several rollups
+        rollup_blobs.retain(|blob| blob.rollup_id() == rollup_id);
+        debug!(
+            %celestia_height,
+            celestia_rollup_namespace = %base64(rollup_namespace.as_bytes()),
+            number_of_blobs = rollup_blobs.len(),
+            "received rollup blobs from Celestia"
+        );
+        ensure!(
+            rollup_blobs.len() <= 1,
+            "received more than one celestia rollup blob for the given namespace and height"
+        );
+        if let Some(blob) = rollup_blobs.pop() {
+            return Ok((rollup_id, blob.into_unchecked().transactions));
+        }
+    }
+    bail!(
+        "the rollup has transactions in the sequencer block, but no matching rollup blob was \
+         found on celestia"
+    )
+}
+
 #[instrument(err)]
 async fn subscribe_to_celestia_headers(
     endpoint: &str,
@@ -724,7 +862,64 @@ async fn connect_to_celestia(endpoint: &str, token: &str) -> eyre::Result<HttpCl
 
 #[cfg(test)]
 mod tests {
-    use super::TrackHeights;
+    use astria_core::sequencer::v1::{
+        test_utils::make_cometbft_block,
+        PostedRollupBlob,
+        RollupId,
+        SequencerBlock,
+    };
+    use celestia_client::{
+        celestia_namespace_v0_from_rollup_id,
+        celestia_types::nmt::Namespace,
+    };
+
+    use super::{
+        rollup_blob_locations,
+        TrackHeights,
+    };
+
+    #[test]
+    fn rollup_blob_locations_try_posted_rollup_blobs_as_hint() {
+        let block = SequencerBlock::try_from_cometbft(make_cometbft_block()).unwrap();
+        let (sequencer_blob, _) = block.into_celestia_blobs();
+        let rollup_id = sequencer_blob.rollup_ids()[0];
+        let default_namespace = celestia_namespace_v0_from_rollup_id(rollup_id);
+        let custom_namespace = Namespace::const_v0([7; 10]);
+        let posted_rollup_blob =
+            PostedRollupBlob::new(rollup_id, custom_namespace.as_bytes().try_into().unwrap());
+
+        // legacy blobs without posted rollup blobs use the default namespace
+        assert_eq!(
+            vec![(10, default_namespace)],
+            rollup_blob_locations(&sequencer_blob, 10, rollup_id, default_namespace),
+        );
+
+        let posted = sequencer_blob
+            .clone()
+            .with_posted_rollup_blobs(vec![posted_rollup_blob])
+            .unwrap();
+        assert_eq!(
+            vec![(10, custom_namespace), (10, default_namespace)],
+            rollup_blob_locations(&posted, 10, rollup_id, default_namespace),
+        );
+
+        let split = sequencer_blob
+            .clone()
+            .with_posted_rollup_blobs(vec![posted_rollup_blob.with_celestia_height(8)])
+            .unwrap();
+        assert_eq!(
+            vec![(8, custom_namespace), (10, default_namespace)],
+            rollup_blob_locations(&split, 10, rollup_id, default_namespace),
+        );
+
+        // a rollup missing from the posted rollup blobs is still looked up at its default
+        // location rather than treated as having no transactions
+        let filtered = sequencer_blob.with_posted_rollup_blobs(vec![]).unwrap();
+        assert_eq!(
+            vec![(10, default_namespace)],
+            rollup_blob_locations(&filtered, 10, rollup_id, default_namespace),
+        );
+    }
 
     #[test]
     fn next_height_within_allowed_and_observed_is_some() {
@@ -1,14 +1,17 @@
 //! Various newtype-wrappers to emit serde-serialized tracing event fields.
+use astria_core::sequencer::v1::RollupId;
+use celestia_client::celestia_types::nmt::Namespace;
 use serde::ser::{
     Serialize,
+    SerializeMap,
     SerializeSeq,
     SerializeStruct,
 };
 use telemetry::display::base64;
 
 use super::{
-    ReconstructedBlock,
     ReconstructedBlocks,
+    ReconstructedSequencerBlock,
 };
 use crate::block_cache::GetSequencerHeight;
 
@@ -39,19 +42,22 @@ impl<'a> Serialize for ReportReconstructedBlocks<'a> {
         const FIELDS: [&str; 4] = [
             "celestia_height",
             "sequencer_namespace",
-            "rollup_namespace",
+            "rollup_namespaces",
             "reconstructed_blocks",
         ];
         let mut state = serializer.serialize_struct("ReconstructedBlocksInfo", FIELDS.len())?;
         state.serialize_field(FIELDS[0], &self.0.celestia_height)?;
         state.serialize_field(FIELDS[1], &base64(&self.0.sequencer_namespace.as_bytes()))?;
-        state.serialize_field(FIELDS[2], &base64(self.0.rollup_namespace.as_bytes()))?;
+        state.serialize_field(
+            FIELDS[2],
+            &ReportRollupNamespaces(&self.0.rollup_namespaces),
+        )?;
         state.serialize_field(FIELDS[3], &ReportReconstructedBlocksSeq(&self.0.blocks))?;
         state.end()
     }
 }
 
-struct ReportReconstructedBlocksSeq<'a>(&'a [ReconstructedBlock]);
+struct ReportReconstructedBlocksSeq<'a>(&'a [ReconstructedSequencerBlock]);
 impl<'a> Serialize for ReportReconstructedBlocksSeq<'a> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -65,7 +71,7 @@ impl<'a> Serialize for ReportReconstructedBlocksSeq<'a> {
     }
 }
 
-struct ReportReconstructedBlock<'a>(&'a ReconstructedBlock);
+struct ReportReconstructedBlock<'a>(&'a ReconstructedSequencerBlock);
 impl<'a> Serialize for ReportReconstructedBlock<'a> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -80,8 +86,31 @@ impl<'a> Serialize for ReportReconstructedBlock<'a> {
         let mut state = serializer.serialize_struct("ReconstructedBlockInfo", FIELDS.len())?;
         state.serialize_field(FIELDS[0], &self.0.celestia_height)?;
         state.serialize_field(FIELDS[1], &base64(&self.0.block_hash))?;
-        state.serialize_field(FIELDS[2], &self.0.transactions.len())?;
+        state.serialize_field(
+            FIELDS[2],
+            &self
+                .0
+                .rollup_transactions
+                .values()
+                .map(Vec::len)
+                .sum::<usize>(),
+        )?;
         state.serialize_field(FIELDS[3], &self.0.celestia_height)?;
         state.end()
     }
 }
+
+/// Reports rollup namespaces as a map of rollup IDs to their base64 encoded namespaces.
+pub(super) struct ReportRollupNamespaces<'a>(pub(super) &'a [(RollupId, Namespace)]);
+impl<'a> Serialize for ReportRollupNamespaces<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(self.0.len()))?;
+        for (rollup_id, namespace) in self.0 {
+            map.serialize_entry(&rollup_id.to_string(), &base64(namespace.as_bytes()))?;
+        }
+        map.end()
+    }
+}
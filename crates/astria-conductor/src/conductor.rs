@@ -1,7 +1,11 @@
-use std::time::Duration;
+use std::{
+    net::SocketAddr,
+    time::Duration,
+};
 
 use astria_eyre::eyre::{
     self,
+    ensure,
     eyre,
     WrapErr as _,
 };
@@ -27,9 +31,11 @@ use tracing::{
 };
 
 use crate::{
+    api,
     celestia,
     executor,
     sequencer,
+    status::Status,
     utils::flatten,
     Config,
 };
@@ -39,19 +45,23 @@ pub struct Conductor {
     shutdown: CancellationToken,
 
     /// The different long-running tasks that make up the conductor;
-    tasks: JoinMap<&'static str, eyre::Result<()>>,
+    tasks: JoinMap<String, eyre::Result<()>>,
 }
 
 impl Conductor {
+    const API: &'static str = "api";
     const CELESTIA: &'static str = "celestia";
     const EXECUTOR: &'static str = "executor";
     const SEQUENCER: &'static str = "sequencer";
 
     /// Create a new [`Conductor`] from a [`Config`].
     ///
+    /// Conductor spawns one executor for each of the configured execution RPC URLs. The
+    /// sequencer and data availability readers are shared by all executors.
+    ///
     /// # Errors
     /// Returns an error in the following cases if one of its constituent
-    /// actors could not be spawned (executor, sequencer reader, or data availability reader).
+    /// actors could not be spawned (executors, sequencer reader, or data availability reader).
     /// This usually happens if the actors failed to connect to their respective endpoints.
     pub async fn new(cfg: Config) -> eyre::Result<Self> {
         let mut tasks = JoinMap::new();
@@ -60,20 +70,47 @@ impl Conductor {
             .wrap_err("failed constructing sequencer cometbft RPC client")?;
 
         let shutdown = CancellationToken::new();
+        let status = Status::new();
+
+        let execution_rpc_urls: Vec<_> = cfg
+            .execution_rpc_urls
+            .split(',')
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .collect();
+        ensure!(
+            !execution_rpc_urls.is_empty(),
+            "at least one execution RPC URL must be provided"
+        );
 
-        // Spawn the executor task.
-        let executor_handle = {
+        let retry_config = executor::RetryConfig {
+            max_retries: cfg.execution_rpc_max_retries,
+            initial_delay: Duration::from_millis(cfg.execution_rpc_retry_initial_delay_ms),
+            max_delay: Duration::from_millis(cfg.execution_rpc_retry_max_delay_ms),
+        };
+
+        // Spawn one executor task per rollup.
+        let mut executor_handles = Vec::with_capacity(execution_rpc_urls.len());
+        let mut executor_states = Vec::with_capacity(execution_rpc_urls.len());
+        for rollup_address in execution_rpc_urls {
             let (executor, handle) = executor::Builder {
                 consider_commitment_spread: !cfg.execution_commit_level.is_soft_only(),
-                rollup_address: cfg.execution_rpc_url,
+                rollup_address: rollup_address.to_string(),
+                retry_config,
                 shutdown: shutdown.clone(),
+                status: status.clone(),
             }
             .build()
-            .wrap_err("failed constructing exectur")?;
+            .wrap_err_with(|| format!("failed constructing executor for `{rollup_address}`"))?;
 
-            tasks.spawn(Self::EXECUTOR, executor.run_until_stopped());
-            handle
-        };
+            tasks.spawn(
+                format!("{} ({rollup_address})", Self::EXECUTOR),
+                executor.run_until_stopped(),
+            );
+            executor_states.push((rollup_address.to_string(), handle.subscribe_to_state()));
+            executor_handles.push(handle);
+        }
+        let executors = executor::Handles::new(executor_handles);
 
         if !cfg.execution_commit_level.is_firm_only() {
             let sequencer_grpc_client =
@@ -88,10 +125,14 @@ impl Conductor {
                 sequencer_cometbft_client: sequencer_cometbft_client.clone(),
                 sequencer_block_time: Duration::from_millis(cfg.sequencer_block_time_ms),
                 shutdown: shutdown.clone(),
-                executor: executor_handle.clone(),
+                executors: executors.clone(),
+                status: status.clone(),
             }
             .build();
-            tasks.spawn(Self::SEQUENCER, sequencer_reader.run_until_stopped());
+            tasks.spawn(
+                Self::SEQUENCER.to_string(),
+                sequencer_reader.run_until_stopped(),
+            );
         }
 
         if !cfg.execution_commit_level.is_soft_only() {
@@ -105,16 +146,36 @@ impl Conductor {
                 celestia_http_endpoint: cfg.celestia_node_http_url,
                 celestia_websocket_endpoint: cfg.celestia_node_websocket_url,
                 celestia_token: cfg.celestia_bearer_token,
-                executor: executor_handle.clone(),
+                executors: executors.clone(),
                 sequencer_cometbft_client: sequencer_cometbft_client.clone(),
                 sequencer_namespace,
                 shutdown: shutdown.clone(),
+                status: status.clone(),
             }
             .build();
 
-            tasks.spawn(Self::CELESTIA, reader.run_until_stopped());
+            tasks.spawn(Self::CELESTIA.to_string(), reader.run_until_stopped());
         };
 
+        // Spawn the API server reporting the status of the executors and the readers.
+        {
+            let api_addr = cfg.api_addr;
+            let api_socket_addr = api_addr.parse::<SocketAddr>().wrap_err_with(|| {
+                format!(
+                    "failed to parse provided `api_addr` string as socket address: `{api_addr}`"
+                )
+            })?;
+            let api_server = api::start(api_socket_addr, executor_states, status.subscribe());
+            info!(local_addr = %api_server.local_addr(), "serving conductor API");
+            let shutdown = shutdown.clone();
+            tasks.spawn(Self::API.to_string(), async move {
+                api_server
+                    .with_graceful_shutdown(async move { shutdown.cancelled().await })
+                    .await
+                    .wrap_err("api server ended unexpectedly")
+            });
+        }
+
         Ok(Self {
             shutdown,
             tasks,
@@ -165,8 +226,8 @@ impl Conductor {
             while let Some((name, res)) = self.tasks.join_next().await {
                 let message = "task shut down";
                 match flatten(res) {
-                    Ok(()) => info!(name, message),
-                    Err(error) => error!(name, %error, message),
+                    Ok(()) => info!(%name, message),
+                    Err(error) => error!(%name, %error, message),
                 }
             }
         };
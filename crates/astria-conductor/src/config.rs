@@ -44,8 +44,18 @@ pub struct Config {
 
     pub sequencer_block_time_ms: u64,
 
-    /// Address of the RPC server for execution
-    pub execution_rpc_url: String,
+    /// A comma-separated list of the execution RPC servers of all rollups driven by Conductor.
+    pub execution_rpc_urls: String,
+
+    /// The number of times a failed call to execute a block or to update the commitment
+    /// state of a rollup is retried before Conductor gives up.
+    pub execution_rpc_max_retries: u32,
+
+    /// The delay before the first retry of a failed execution RPC. Doubles with every retry.
+    pub execution_rpc_retry_initial_delay_ms: u64,
+
+    /// The maximum delay between retries of a failed execution RPC.
+    pub execution_rpc_retry_max_delay_ms: u64,
 
     /// log directive to use for telemetry.
     pub log: String,
@@ -68,6 +78,9 @@ pub struct Config {
 
     /// Writes a human readable format to stdout instead of JSON formatted OTEL trace data.
     pub pretty_print: bool,
+
+    /// The address at which the status and health API is served.
+    pub api_addr: String,
 }
 
 impl config::Config for Config {
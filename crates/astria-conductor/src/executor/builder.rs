@@ -13,14 +13,18 @@ use tokio_util::sync::CancellationToken;
 use super::{
     Executor,
     Handle,
+    RetryConfig,
     State,
     StateNotInit,
 };
+use crate::status::Status;
 
 pub(crate) struct Builder {
     pub(crate) consider_commitment_spread: bool,
     pub(crate) rollup_address: String,
+    pub(crate) retry_config: RetryConfig,
     pub(crate) shutdown: CancellationToken,
+    pub(crate) status: Status,
 }
 
 impl Builder {
@@ -28,7 +32,9 @@ impl Builder {
         let Self {
             consider_commitment_spread,
             rollup_address,
+            retry_config,
             shutdown,
+            status,
         } = self;
 
         let rollup_address = rollup_address
@@ -46,9 +52,11 @@ impl Builder {
 
             consider_commitment_spread,
             rollup_address,
+            retry_config,
 
             shutdown,
             state: state_tx,
+            status,
             blocks_pending_finalization: HashMap::new(),
         };
         let handle = Handle {
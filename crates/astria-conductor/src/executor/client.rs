@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use astria_core::{
     execution::v1alpha2::{
         Block,
@@ -19,8 +21,37 @@ use astria_eyre::eyre::{
 };
 use bytes::Bytes;
 use pbjson_types::Timestamp;
-use tonic::transport::Channel;
-use tracing::instrument;
+use tonic::{
+    transport::Channel,
+    Code,
+};
+use tracing::{
+    instrument,
+    warn,
+    Instrument as _,
+};
+
+/// The policy by which [`Client`] retries failed `ExecuteBlock` and
+/// `UpdateCommitmentState` calls.
+///
+/// The delay between attempts starts at `initial_delay` and doubles after every
+/// failed attempt up to `max_delay`.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct RetryConfig {
+    /// The number of times a call is retried before giving up.
+    pub(crate) max_retries: u32,
+    pub(crate) initial_delay: Duration,
+    pub(crate) max_delay: Duration,
+}
+
+impl RetryConfig {
+    fn delay_for_attempt(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        self.initial_delay
+            .saturating_mul(factor)
+            .min(self.max_delay)
+    }
+}
 
 /// A newtype wrapper around [`ExecutionServiceClient`] to work with
 /// idiomatic types.
@@ -28,17 +59,27 @@ use tracing::instrument;
 pub(crate) struct Client {
     uri: tonic::transport::Uri,
     inner: ExecutionServiceClient<Channel>,
+    retry_config: RetryConfig,
 }
 
 impl Client {
+    /// Connects to the rollup's execution service at `uri`.
+    ///
+    /// The underlying [`Channel`] transparently re-establishes its connection if it
+    /// dropped (for example because the rollup node restarted), so that calls retried
+    /// per `retry_config` go out over a fresh connection once the node is back.
     #[instrument(skip_all, fields(rollup_uri = %uri))]
-    pub(crate) async fn connect(uri: tonic::transport::Uri) -> eyre::Result<Self> {
+    pub(crate) async fn connect(
+        uri: tonic::transport::Uri,
+        retry_config: RetryConfig,
+    ) -> eyre::Result<Self> {
         let inner = ExecutionServiceClient::connect(uri.clone())
             .await
             .wrap_err("failed constructing execution service client")?;
         Ok(Self {
             uri,
             inner,
+            retry_config,
         })
     }
 
@@ -85,8 +126,10 @@ impl Client {
             timestamp: Some(timestamp),
         };
         let response = self
-            .inner
-            .execute_block(request)
+            .call_with_retry("execute_block", |mut client| {
+                let request = request.clone();
+                async move { client.execute_block(request).await }
+            })
             .await
             .wrap_err("failed to execute block")?
             .into_inner();
@@ -125,8 +168,10 @@ impl Client {
             commitment_state: Some(commitment_state.into_raw()),
         };
         let response = self
-            .inner
-            .update_commitment_state(request)
+            .call_with_retry("update_commitment_state", |mut client| {
+                let request = request.clone();
+                async move { client.update_commitment_state(request).await }
+            })
             .await
             .wrap_err("failed to update commitment state")?
             .into_inner();
@@ -134,4 +179,132 @@ impl Client {
             .wrap_err("failed converting raw response to validated commitment state")?;
         Ok(commitment_state)
     }
+
+    /// Calls `rpc` until it succeeds, fails with a non-retryable status, or the retries
+    /// configured for this client are exhausted.
+    ///
+    /// Only the remote procedure call itself is retried. Validating the response (and
+    /// checking that it fulfills the execution API contract) happens after this returns,
+    /// so that a misbehaving rollup node leads to an immediate failure.
+    async fn call_with_retry<F, Fut, T>(
+        &self,
+        rpc: &'static str,
+        call: F,
+    ) -> Result<T, tonic::Status>
+    where
+        F: Fn(ExecutionServiceClient<Channel>) -> Fut,
+        Fut: std::future::Future<Output = Result<T, tonic::Status>>,
+    {
+        let span = tracing::Span::current();
+        let retry_config = self.retry_config;
+        let retry_cfg = tryhard::RetryFutureConfig::new(retry_config.max_retries)
+            .custom_backoff(move |attempt: u32, status: &tonic::Status| {
+                if is_retryable(status) {
+                    tryhard::RetryPolicy::Delay(retry_config.delay_for_attempt(attempt))
+                } else {
+                    tryhard::RetryPolicy::Break
+                }
+            })
+            .on_retry(
+                move |attempt: u32, next_delay: Option<Duration>, status: &tonic::Status| {
+                    let wait_duration = next_delay
+                        .map(humantime::format_duration)
+                        .map(tracing::field::display);
+                    warn!(
+                        parent: &span,
+                        rpc,
+                        attempt,
+                        wait_duration,
+                        error = status as &dyn std::error::Error,
+                        "call to rollup execution service failed; retrying after backoff",
+                    );
+                    futures::future::ready(())
+                },
+            );
+        tryhard::retry_fn(|| call(self.inner.clone()))
+            .with_config(retry_cfg)
+            .in_current_span()
+            .await
+    }
+}
+
+/// Returns if a call that failed with `status` can be retried.
+///
+/// These are the codes signaling that the rollup node is temporarily unavailable or
+/// overloaded, in which case it did not act on the request. `ExecuteBlock` is not idempotent,
+/// so codes that leave open whether the rollup node acted on the request are not retried:
+/// a call that hit [`Code::DeadlineExceeded`] or was [`Code::Cancelled`] may have executed the
+/// block already, and [`Code::Unknown`] is also what ordinary handler errors are reported as.
+///
+/// Failures to reach the rollup node are retried regardless of their code, see
+/// [`is_transport_error`].
+fn is_retryable(status: &tonic::Status) -> bool {
+    matches!(status.code(), Code::Unavailable | Code::ResourceExhausted)
+        || is_transport_error(status)
+}
+
+/// Returns if `status` was caused by the transport to the rollup node rather than returned by it.
+///
+/// Tonic reports a failed connection attempt (for example while the rollup node restarts) as
+/// [`Code::Unknown`], the code of ordinary handler errors, but keeps the underlying transport
+/// error in the source chain of the status.
+fn is_transport_error(status: &tonic::Status) -> bool {
+    let mut source = std::error::Error::source(status);
+    while let Some(error) = source {
+        if error.is::<tonic::transport::Error>()
+            || error
+                .downcast_ref::<hyper::Error>()
+                .is_some_and(hyper::Error::is_connect)
+        {
+            return true;
+        }
+        source = error.source();
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn retry_delay_doubles_up_to_max_delay() {
+        let retry_config = RetryConfig {
+            max_retries: 10,
+            initial_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(500),
+        };
+        assert_eq!(
+            Duration::from_millis(100),
+            retry_config.delay_for_attempt(1)
+        );
+        assert_eq!(
+            Duration::from_millis(200),
+            retry_config.delay_for_attempt(2)
+        );
+        assert_eq!(
+            Duration::from_millis(400),
+            retry_config.delay_for_attempt(3)
+        );
+        assert_eq!(
+            Duration::from_millis(500),
+            retry_config.delay_for_attempt(4)
+        );
+        assert_eq!(
+            Duration::from_millis(500),
+            retry_config.delay_for_attempt(u32::MAX)
+        );
+    }
+
+    #[test]
+    fn rejected_requests_are_not_retried() {
+        assert!(is_retryable(&tonic::Status::unavailable("")));
+        assert!(is_retryable(&tonic::Status::resource_exhausted("")));
+        assert!(!is_retryable(&tonic::Status::deadline_exceeded("")));
+        assert!(!is_retryable(&tonic::Status::cancelled("")));
+        assert!(!is_retryable(&tonic::Status::unknown("")));
+        assert!(!is_retryable(&tonic::Status::invalid_argument("")));
+        assert!(!is_retryable(&tonic::Status::failed_precondition("")));
+        assert!(!is_retryable(&tonic::Status::not_found("")));
+    }
 }
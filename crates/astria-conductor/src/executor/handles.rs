@@ -0,0 +1,357 @@
+//! Fans out blocks read from Sequencer and Celestia to the executors of all rollups.
+
+use astria_core::sequencer::v1::{
+    block::FilteredSequencerBlock,
+    RollupId,
+};
+use astria_eyre::eyre;
+use celestia_client::celestia_types::Height as CelestiaHeight;
+use futures::future;
+use sequencer_client::tendermint::block::Height as SequencerHeight;
+use tokio::sync::{
+    mpsc::error::TrySendError,
+    watch::error::RecvError,
+};
+use tracing::instrument;
+
+use super::{
+    channel,
+    Handle,
+    StateIsInit,
+    StateNotInit,
+};
+use crate::celestia::ReconstructedSequencerBlock;
+
+/// Handles to the executors of all rollups driven by Conductor.
+///
+/// The sequencer and Celestia readers fetch every height once and forward the blocks
+/// to all executors through this type. The heights it reports are those of the executor
+/// that lags the furthest behind, so that none of the executors misses a block.
+#[derive(Clone, Debug)]
+pub(crate) struct Handles<TStateInit = StateNotInit> {
+    inner: Vec<Handle<TStateInit>>,
+}
+
+impl Handles<StateNotInit> {
+    /// Creates a new set of executor handles.
+    ///
+    /// # Panics
+    /// Panics if `inner` is empty.
+    pub(crate) fn new(inner: Vec<Handle>) -> Self {
+        assert!(
+            !inner.is_empty(),
+            "conductor must drive at least one executor"
+        );
+        Self {
+            inner,
+        }
+    }
+
+    #[instrument(skip_all, err)]
+    pub(crate) async fn wait_for_init(&mut self) -> eyre::Result<Handles<StateIsInit>> {
+        let inner = future::try_join_all(self.inner.iter_mut().map(Handle::wait_for_init)).await?;
+        Ok(Handles {
+            inner,
+        })
+    }
+}
+
+impl Handles<StateIsInit> {
+    /// Returns the IDs of all rollups.
+    pub(crate) fn rollup_ids(&mut self) -> Vec<RollupId> {
+        self.inner.iter_mut().map(Handle::rollup_id).collect()
+    }
+
+    /// Returns the smallest next expected firm height of all executors.
+    pub(crate) fn next_expected_firm_height(&mut self) -> SequencerHeight {
+        self.inner
+            .iter_mut()
+            .map(Handle::next_expected_firm_height)
+            .min()
+            .expect("there is always at least one executor")
+    }
+
+    /// Returns the smallest next expected soft height of all executors.
+    pub(crate) fn next_expected_soft_height(&mut self) -> SequencerHeight {
+        self.inner
+            .iter_mut()
+            .map(Handle::next_expected_soft_height)
+            .min()
+            .expect("there is always at least one executor")
+    }
+
+    /// Waits until the state of any executor changed and returns the smallest next expected
+    /// soft height of all executors.
+    pub(crate) async fn next_expected_soft_height_if_changed(
+        &mut self,
+    ) -> Result<SequencerHeight, RecvError> {
+        let changes = self
+            .inner
+            .iter_mut()
+            .map(|handle| Box::pin(handle.state.changed()));
+        future::select_all(changes).await.0?;
+        Ok(self.next_expected_soft_height())
+    }
+
+    /// Returns the smallest Celestia base block height of all rollups.
+    pub(crate) fn celestia_base_block_height(&mut self) -> CelestiaHeight {
+        self.inner
+            .iter_mut()
+            .map(Handle::celestia_base_block_height)
+            .min()
+            .expect("there is always at least one executor")
+    }
+
+    /// Returns the largest Celestia block variance of all rollups.
+    pub(crate) fn celestia_block_variance(&mut self) -> u32 {
+        self.inner
+            .iter_mut()
+            .map(Handle::celestia_block_variance)
+            .max()
+            .expect("there is always at least one executor")
+    }
+
+    /// Attempts to send `block` to all executors that have not yet executed its height.
+    ///
+    /// Returns the handles of the executors that had no capacity to receive the block.
+    /// These must be sent the block through [`Handles::send_soft_block_owned`] before
+    /// any further blocks.
+    ///
+    /// Returns an error if the channel to any of the executors was closed.
+    pub(crate) fn try_send_soft_block(
+        &self,
+        block: &FilteredSequencerBlock,
+    ) -> Result<Option<Self>, channel::SendError> {
+        let mut full = Vec::new();
+        for handle in &self.inner {
+            if block.height() < handle.state.borrow().next_soft_sequencer_height() {
+                continue;
+            }
+            match handle.try_send_soft_block(block.clone()) {
+                Ok(()) => {}
+                Err(channel::TrySendError::Closed(_)) => return Err(channel::SendError),
+                Err(channel::TrySendError::NoPermits(_)) => full.push(handle.clone()),
+            }
+        }
+        Ok((!full.is_empty()).then_some(Self {
+            inner: full,
+        }))
+    }
+
+    pub(crate) async fn send_soft_block_owned(
+        self,
+        block: FilteredSequencerBlock,
+    ) -> Result<(), channel::SendError> {
+        future::try_join_all(
+            self.inner
+                .into_iter()
+                .map(|handle| handle.send_soft_block_owned(block.clone())),
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Attempts to send the transactions of each rollup in `block` to its executor if it has
+    /// not yet executed the block's height.
+    ///
+    /// Returns the handles of the executors that had no capacity to receive the block.
+    /// These must be sent the block through [`Handles::send_firm_block`] before any
+    /// further blocks.
+    ///
+    /// Returns an error if the channel to any of the executors was closed.
+    pub(crate) fn try_send_firm_block(
+        &self,
+        block: &ReconstructedSequencerBlock,
+    ) -> Result<Option<Self>, channel::SendError> {
+        let mut full = Vec::new();
+        for handle in &self.inner {
+            let rollup_id = {
+                let state = handle.state.borrow();
+                if block.sequencer_height() < state.next_firm_sequencer_height() {
+                    continue;
+                }
+                state.rollup_id()
+            };
+            match handle.try_send_firm_block(block.to_rollup_block(rollup_id)) {
+                Ok(()) => {}
+                Err(TrySendError::Closed(_)) => return Err(channel::SendError),
+                Err(TrySendError::Full(_)) => full.push(handle.clone()),
+            }
+        }
+        Ok((!full.is_empty()).then_some(Self {
+            inner: full,
+        }))
+    }
+
+    pub(crate) async fn send_firm_block(
+        self,
+        block: ReconstructedSequencerBlock,
+    ) -> Result<(), channel::SendError> {
+        future::try_join_all(self.inner.into_iter().map(|handle| {
+            let rollup_block = block.to_rollup_block(handle.state.borrow().rollup_id());
+            handle.send_firm_block(rollup_block)
+        }))
+        .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::{
+        execution::v1alpha2::{
+            CommitmentState,
+            GenesisInfo,
+        },
+        generated::execution::v1alpha2 as raw,
+        sequencer::v1::{
+            test_utils::ConfigureCometBftBlock,
+            SequencerBlock,
+        },
+        Protobuf as _,
+    };
+    use bytes::Bytes;
+    use futures::FutureExt as _;
+    use tokio_util::sync::CancellationToken;
+
+    use super::*;
+    use crate::executor::{
+        Builder,
+        Executor,
+    };
+
+    fn make_block(number: u32) -> raw::Block {
+        raw::Block {
+            number,
+            hash: Bytes::from_static(&[0u8; 32]),
+            parent_block_hash: Bytes::from_static(&[0u8; 32]),
+            timestamp: Some(pbjson_types::Timestamp {
+                seconds: 0,
+                nanos: 0,
+            }),
+        }
+    }
+
+    /// Returns an executor and its handle for `rollup_id` with its state initialized so
+    /// that it expects the soft block at sequencer height `next_soft_height` next.
+    fn make_initialized_executor(rollup_id: RollupId, next_soft_height: u32) -> (Executor, Handle) {
+        let (mut executor, handle) = Builder {
+            consider_commitment_spread: false,
+            rollup_address: "http://127.0.0.1:50051".to_string(),
+            retry_config: crate::executor::RetryConfig {
+                max_retries: 0,
+                initial_delay: std::time::Duration::ZERO,
+                max_delay: std::time::Duration::ZERO,
+            },
+            shutdown: CancellationToken::new(),
+            status: crate::status::Status::new(),
+        }
+        .build()
+        .unwrap();
+        let genesis_info = GenesisInfo::try_from_raw(raw::GenesisInfo {
+            rollup_id: Bytes::copy_from_slice(rollup_id.as_ref()),
+            sequencer_genesis_block_height: 1,
+            celestia_base_block_height: 1,
+            celestia_block_variance: 1,
+        })
+        .unwrap();
+        let commitment_state = CommitmentState::try_from_raw(raw::CommitmentState {
+            firm: Some(make_block(0)),
+            soft: Some(make_block(next_soft_height - 1)),
+        })
+        .unwrap();
+        executor
+            .state
+            .send_modify(|state| state.init(genesis_info, commitment_state));
+        executor.soft_blocks.set_capacity(1);
+        executor.soft_blocks.fill_permits();
+        (executor, handle)
+    }
+
+    #[tokio::test]
+    async fn soft_blocks_are_only_sent_to_executors_that_expect_them() {
+        let behind = RollupId::new([1u8; 32]);
+        let ahead = RollupId::new([2u8; 32]);
+        let (mut behind_executor, behind_handle) = make_initialized_executor(behind, 3);
+        let (mut ahead_executor, ahead_handle) = make_initialized_executor(ahead, 5);
+
+        let mut handles = Handles::new(vec![behind_handle, ahead_handle])
+            .wait_for_init()
+            .await
+            .unwrap();
+        assert_eq!(
+            SequencerHeight::from(3u32),
+            handles.next_expected_soft_height(),
+            "the next expected height must be that of the executor furthest behind",
+        );
+
+        let block = SequencerBlock::try_from_cometbft(
+            ConfigureCometBftBlock {
+                height: 3,
+                ..Default::default()
+            }
+            .make(),
+        )
+        .unwrap()
+        .into_filtered_block([behind, ahead]);
+        assert!(
+            handles.try_send_soft_block(&block).unwrap().is_none(),
+            "executors that expect the block have capacity to receive it",
+        );
+
+        assert_eq!(
+            block.height(),
+            behind_executor.soft_blocks.recv().await.unwrap().height(),
+        );
+        assert!(
+            ahead_executor.soft_blocks.recv().now_or_never().is_none(),
+            "the block must not be sent to the executor that already executed its height",
+        );
+    }
+
+    #[tokio::test]
+    async fn executors_without_capacity_are_returned() {
+        let first = RollupId::new([1u8; 32]);
+        let second = RollupId::new([2u8; 32]);
+        let (_first_executor, first_handle) = make_initialized_executor(first, 3);
+        let (_second_executor, second_handle) = make_initialized_executor(second, 3);
+
+        let handles = Handles::new(vec![first_handle, second_handle.clone()])
+            .wait_for_init()
+            .await
+            .unwrap();
+        // Exhaust the permit of the second executor.
+        second_handle
+            .clone()
+            .wait_for_init()
+            .await
+            .unwrap()
+            .try_send_soft_block(
+                SequencerBlock::try_from_cometbft(
+                    ConfigureCometBftBlock {
+                        height: 2,
+                        ..Default::default()
+                    }
+                    .make(),
+                )
+                .unwrap()
+                .into_filtered_block([second]),
+            )
+            .unwrap();
+
+        let block = SequencerBlock::try_from_cometbft(
+            ConfigureCometBftBlock {
+                height: 3,
+                ..Default::default()
+            }
+            .make(),
+        )
+        .unwrap()
+        .into_filtered_block([first, second]);
+        let full = handles
+            .try_send_soft_block(&block)
+            .unwrap()
+            .expect("the second executor has no capacity left");
+        assert_eq!(1, full.inner.len());
+    }
+}
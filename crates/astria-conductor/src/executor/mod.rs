@@ -35,10 +35,7 @@ use tokio::{
                 TrySendError,
             },
         },
-        watch::{
-            self,
-            error::RecvError,
-        },
+        watch,
     },
 };
 use tokio_util::sync::CancellationToken;
@@ -49,13 +46,19 @@ use tracing::{
     instrument,
 };
 
-use crate::celestia::ReconstructedBlock;
+use crate::{
+    celestia::ReconstructedBlock,
+    metrics_init,
+    status::Status,
+};
 
 mod builder;
 pub(crate) mod channel;
+mod handles;
 
 pub(crate) use builder::Builder;
 use channel::soft_block_channel;
+pub(crate) use handles::Handles;
 
 mod client;
 mod state;
@@ -63,6 +66,7 @@ mod state;
 mod tests;
 
 pub(super) use client::Client;
+pub(crate) use client::RetryConfig;
 pub(crate) use state::State;
 
 #[derive(Clone, Debug)]
@@ -104,6 +108,12 @@ impl<T: Clone> Handle<T> {
             _state_init: StateIsInit,
         })
     }
+
+    /// Returns a receiver of the executor state, which is unset until the executor was
+    /// initialized.
+    pub(crate) fn subscribe_to_state(&self) -> watch::Receiver<State> {
+        self.state.clone()
+    }
 }
 
 impl Handle<StateIsInit> {
@@ -147,13 +157,6 @@ impl Handle<StateIsInit> {
         self.state.borrow_and_update().next_soft_sequencer_height()
     }
 
-    pub(crate) async fn next_expected_soft_height_if_changed(
-        &mut self,
-    ) -> Result<SequencerHeight, RecvError> {
-        self.state.changed().await?;
-        Ok(self.state.borrow_and_update().next_soft_sequencer_height())
-    }
-
     pub(crate) fn rollup_id(&mut self) -> RollupId {
         self.state.borrow_and_update().rollup_id()
     }
@@ -176,9 +179,15 @@ pub(crate) struct Executor {
 
     rollup_address: tonic::transport::Uri,
 
+    /// How calls to execute blocks and update commitments on the rollup are retried.
+    retry_config: RetryConfig,
+
     /// Tracks SOFT and FIRM on the execution chain
     state: watch::Sender<State>,
 
+    /// Reports the number of executed blocks through the API.
+    status: Status,
+
     // If set, the executor will take into account the spread between firm
     // and soft commitments when executing blocks.
     consider_commitment_spread: bool,
@@ -193,7 +202,7 @@ pub(crate) struct Executor {
 impl Executor {
     #[instrument(skip_all)]
     pub(crate) async fn run_until_stopped(mut self) -> eyre::Result<()> {
-        let client = Client::connect(self.rollup_address.clone())
+        let client = Client::connect(self.rollup_address.clone(), self.retry_config)
             .await
             .wrap_err("failed connecting to rollup node")?;
 
@@ -315,7 +324,6 @@ impl Executor {
         client: Client,
         block: FilteredSequencerBlock,
     ) -> eyre::Result<()> {
-        // TODO(https://github.com/astriaorg/astria/issues/624): add retry logic before failing hard.
         let executable_block =
             ExecutableBlock::from_sequencer(block, self.state.borrow().rollup_id());
 
@@ -358,6 +366,12 @@ impl Executor {
         self.blocks_pending_finalization
             .insert(block_hash, executed_block);
 
+        self.status.increment_soft_blocks_executed();
+        metrics::counter!(
+            metrics_init::EXECUTED_SOFT_BLOCK_COUNT,
+            metrics_init::ROLLUP_ID_LABEL => self.state.borrow().rollup_id().to_string(),
+        )
+        .increment(1);
         Ok(())
     }
 
@@ -400,6 +414,13 @@ impl Executor {
         self.update_commitment_state(client.clone(), update_type)
             .await
             .wrap_err("failed to setting both commitment states to executed block")?;
+
+        self.status.increment_firm_blocks_executed();
+        metrics::counter!(
+            metrics_init::EXECUTED_FIRM_BLOCK_COUNT,
+            metrics_init::ROLLUP_ID_LABEL => self.state.borrow().rollup_id().to_string(),
+        )
+        .increment(1);
         Ok(())
     }
 
@@ -462,6 +483,10 @@ impl Executor {
         let (genesis_info, commitment_state) = tokio::try_join!(genesis_info, commitment_state)?;
         self.state
             .send_modify(move |state| state.init(genesis_info, commitment_state));
+        {
+            let state = self.state.borrow();
+            record_commitment_heights(state.rollup_id(), state.soft(), state.firm());
+        }
         info!(
             initial_state = serde_json::to_string(&*self.state.borrow())
                 .expect("writing json to a string should not fail"),
@@ -502,12 +527,31 @@ impl Executor {
             firm.hash = %telemetry::display::base64(&new_state.firm().hash()),
             "updated commitment state",
         );
+        record_commitment_heights(
+            self.state.borrow().rollup_id(),
+            new_state.soft(),
+            new_state.firm(),
+        );
         self.state
             .send_if_modified(move |state| state.update_if_modified(new_state));
         Ok(())
     }
 }
 
+fn record_commitment_heights(rollup_id: RollupId, soft: &Block, firm: &Block) {
+    let rollup_id = rollup_id.to_string();
+    metrics::gauge!(
+        metrics_init::SOFT_COMMITMENT_HEIGHT,
+        metrics_init::ROLLUP_ID_LABEL => rollup_id.clone(),
+    )
+    .set(f64::from(soft.number()));
+    metrics::gauge!(
+        metrics_init::FIRM_COMMITMENT_HEIGHT,
+        metrics_init::ROLLUP_ID_LABEL => rollup_id,
+    )
+    .set(f64::from(firm.number()));
+}
+
 enum Update {
     OnlyFirm(Block),
     OnlySoft(Block),
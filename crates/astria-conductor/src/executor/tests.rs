@@ -5,6 +5,7 @@ use std::{
         Arc,
         Mutex,
     },
+    time::Duration,
 };
 
 use astria_core::{
@@ -40,16 +41,25 @@ use astria_core::{
     },
     Protobuf,
 };
+use astria_grpc_mock::{
+    matcher,
+    response,
+    Mock,
+};
 use bytes::Bytes;
 use prost::Message;
 use tokio::task::JoinHandle;
 use tokio_util::sync::CancellationToken;
-use tonic::transport::Server;
+use tonic::{
+    transport::Server,
+    Code,
+};
 
 use super::{
     Client,
     Executor,
     ReconstructedBlock,
+    RetryConfig,
     RollupId,
     SequencerHeight,
 };
@@ -61,6 +71,12 @@ const GENESIS_HASH: Bytes = Bytes::from_static(&[0u8; 32]);
 
 const ROLLUP_ID: RollupId = RollupId::new([42u8; 32]);
 
+const RETRY_CONFIG: RetryConfig = RetryConfig {
+    max_retries: 3,
+    initial_delay: Duration::from_millis(1),
+    max_delay: Duration::from_millis(10),
+};
+
 #[derive(Debug)]
 struct MockExecutionServer {
     _server_handle: JoinHandle<()>,
@@ -248,12 +264,14 @@ async fn start_mock() -> MockEnvironment {
     let (executor, _) = crate::executor::Builder {
         consider_commitment_spread: false,
         rollup_address: server_url,
+        retry_config: RETRY_CONFIG,
         shutdown: shutdown_token.clone(),
+        status: crate::status::Status::new(),
     }
     .build()
     .unwrap();
 
-    let client = Client::connect(executor.rollup_address.clone())
+    let client = Client::connect(executor.rollup_address.clone(), RETRY_CONFIG)
         .await
         .unwrap();
 
@@ -554,6 +572,384 @@ async fn out_of_order_firm_blocks_are_rejected() {
     );
 }
 
+/// An execution service that forwards all requests to a [`astria_grpc_mock::MockServer`].
+struct GrpcMockExecutionService {
+    mock_server: astria_grpc_mock::MockServer,
+}
+
+#[tonic::async_trait]
+impl ExecutionService for GrpcMockExecutionService {
+    async fn get_block(
+        self: Arc<Self>,
+        request: tonic::Request<GetBlockRequest>,
+    ) -> std::result::Result<tonic::Response<raw::Block>, tonic::Status> {
+        self.mock_server.handle_request("get_block", request).await
+    }
+
+    async fn get_genesis_info(
+        self: Arc<Self>,
+        request: tonic::Request<GetGenesisInfoRequest>,
+    ) -> std::result::Result<tonic::Response<raw::GenesisInfo>, tonic::Status> {
+        self.mock_server
+            .handle_request("get_genesis_info", request)
+            .await
+    }
+
+    async fn batch_get_blocks(
+        self: Arc<Self>,
+        request: tonic::Request<BatchGetBlocksRequest>,
+    ) -> std::result::Result<tonic::Response<BatchGetBlocksResponse>, tonic::Status> {
+        self.mock_server
+            .handle_request("batch_get_blocks", request)
+            .await
+    }
+
+    async fn execute_block(
+        self: Arc<Self>,
+        request: tonic::Request<ExecuteBlockRequest>,
+    ) -> std::result::Result<tonic::Response<raw::Block>, tonic::Status> {
+        self.mock_server
+            .handle_request("execute_block", request)
+            .await
+    }
+
+    async fn get_commitment_state(
+        self: Arc<Self>,
+        request: tonic::Request<GetCommitmentStateRequest>,
+    ) -> std::result::Result<tonic::Response<raw::CommitmentState>, tonic::Status> {
+        self.mock_server
+            .handle_request("get_commitment_state", request)
+            .await
+    }
+
+    async fn update_commitment_state(
+        self: Arc<Self>,
+        request: tonic::Request<UpdateCommitmentStateRequest>,
+    ) -> std::result::Result<tonic::Response<raw::CommitmentState>, tonic::Status> {
+        self.mock_server
+            .handle_request("update_commitment_state", request)
+            .await
+    }
+}
+
+struct GrpcMockEnvironment {
+    server: JoinHandle<()>,
+    shutdown_server: CancellationToken,
+    rollup_addr: SocketAddr,
+    mocked: astria_grpc_mock::MockServer,
+    executor: Executor,
+    client: Client,
+}
+
+/// Serves the execution service backed by `mock_server` on `listener` until `shutdown` is
+/// cancelled.
+fn serve_grpc_mock(
+    listener: tokio::net::TcpListener,
+    mock_server: astria_grpc_mock::MockServer,
+    shutdown: CancellationToken,
+) -> JoinHandle<()> {
+    use tokio_stream::wrappers::TcpListenerStream;
+
+    tokio::spawn(async move {
+        let _ = Server::builder()
+            .add_service(ExecutionServiceServer::new(GrpcMockExecutionService {
+                mock_server,
+            }))
+            .serve_with_incoming_shutdown(TcpListenerStream::new(listener), async move {
+                shutdown.cancelled().await;
+            })
+            .await;
+    })
+}
+
+/// Starts an executor against an execution service whose responses are mocked.
+///
+/// The mocked rollup is at genesis, expecting the block at sequencer height 100 next.
+async fn start_grpc_mock() -> GrpcMockEnvironment {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let rollup_addr = listener.local_addr().unwrap();
+    let mock_server = astria_grpc_mock::MockServer::new();
+    let shutdown_server = CancellationToken::new();
+    let server = serve_grpc_mock(listener, mock_server.clone(), shutdown_server.clone());
+
+    Mock::for_rpc_given(
+        "get_genesis_info",
+        matcher::message_type::<GetGenesisInfoRequest>(),
+    )
+    .respond_with(response::constant_response(raw::GenesisInfo {
+        rollup_id: Bytes::copy_from_slice(ROLLUP_ID.as_ref()),
+        sequencer_genesis_block_height: 100,
+        celestia_base_block_height: 1,
+        celestia_block_variance: 1,
+    }))
+    .mount(&mock_server)
+    .await;
+    Mock::for_rpc_given(
+        "get_commitment_state",
+        matcher::message_type::<GetCommitmentStateRequest>(),
+    )
+    .respond_with(response::constant_response(raw::CommitmentState {
+        soft: Some(make_genesis_block()),
+        firm: Some(make_genesis_block()),
+    }))
+    .mount(&mock_server)
+    .await;
+
+    let (executor, _) = crate::executor::Builder {
+        consider_commitment_spread: false,
+        rollup_address: format!("http://{rollup_addr}"),
+        retry_config: RETRY_CONFIG,
+        shutdown: CancellationToken::new(),
+        status: crate::status::Status::new(),
+    }
+    .build()
+    .unwrap();
+    let client = Client::connect(executor.rollup_address.clone(), RETRY_CONFIG)
+        .await
+        .unwrap();
+    executor
+        .set_initial_node_state(client.clone())
+        .await
+        .unwrap();
+
+    GrpcMockEnvironment {
+        server,
+        shutdown_server,
+        rollup_addr,
+        mocked: mock_server,
+        executor,
+        client,
+    }
+}
+
+fn make_executed_block() -> raw::Block {
+    raw::Block {
+        number: 1,
+        hash: Bytes::from_static(&[1u8; 32]),
+        parent_block_hash: GENESIS_HASH,
+        timestamp: Some(chrono::Utc::now().into()),
+    }
+}
+
+fn make_soft_block() -> super::FilteredSequencerBlock {
+    SequencerBlock::try_from_cometbft(
+        ConfigureCometBftBlock {
+            height: 100,
+            rollup_transactions: vec![(ROLLUP_ID, b"hello_world".to_vec())],
+            ..Default::default()
+        }
+        .make(),
+    )
+    .unwrap()
+    .into_filtered_block([ROLLUP_ID])
+}
+
+#[tokio::test]
+async fn calls_are_retried_while_rollup_is_unavailable() {
+    let mut mock = start_grpc_mock().await;
+
+    Mock::for_rpc_given(
+        "execute_block",
+        matcher::message_type::<ExecuteBlockRequest>(),
+    )
+    .respond_with(response::error_response(Code::Unavailable))
+    .up_to_n_times(2)
+    .expect(2)
+    .mount(&mock.mocked)
+    .await;
+    Mock::for_rpc_given(
+        "execute_block",
+        matcher::message_type::<ExecuteBlockRequest>(),
+    )
+    .respond_with(response::constant_response(make_executed_block()))
+    .expect(1)
+    .mount(&mock.mocked)
+    .await;
+    Mock::for_rpc_given(
+        "update_commitment_state",
+        matcher::message_type::<UpdateCommitmentStateRequest>(),
+    )
+    .respond_with(response::error_response(Code::ResourceExhausted))
+    .up_to_n_times(1)
+    .expect(1)
+    .mount(&mock.mocked)
+    .await;
+    Mock::for_rpc_given(
+        "update_commitment_state",
+        matcher::message_type::<UpdateCommitmentStateRequest>(),
+    )
+    .respond_with(response::constant_response(raw::CommitmentState {
+        soft: Some(make_executed_block()),
+        firm: Some(make_genesis_block()),
+    }))
+    .expect(1)
+    .mount(&mock.mocked)
+    .await;
+
+    mock.executor
+        .execute_soft(mock.client.clone(), make_soft_block())
+        .await
+        .unwrap();
+    assert_eq!(1, mock.executor.state.borrow().soft().number());
+    mock.mocked.verify().await;
+}
+
+#[tokio::test]
+async fn calls_are_retried_while_rollup_restarts() {
+    let mut mock = start_grpc_mock().await;
+    let client = Client::connect(
+        mock.executor.rollup_address.clone(),
+        RetryConfig {
+            max_retries: 100,
+            initial_delay: Duration::from_millis(10),
+            max_delay: Duration::from_millis(10),
+        },
+    )
+    .await
+    .unwrap();
+
+    // the mocks are mounted only once the server is stopped, because the mock server verifies
+    // its expectations when the stopped server drops its handle to it.
+    mock.shutdown_server.cancel();
+    mock.server.await.unwrap();
+    Mock::for_rpc_given(
+        "execute_block",
+        matcher::message_type::<ExecuteBlockRequest>(),
+    )
+    .respond_with(response::constant_response(make_executed_block()))
+    .expect(1)
+    .mount(&mock.mocked)
+    .await;
+    Mock::for_rpc_given(
+        "update_commitment_state",
+        matcher::message_type::<UpdateCommitmentStateRequest>(),
+    )
+    .respond_with(response::constant_response(raw::CommitmentState {
+        soft: Some(make_executed_block()),
+        firm: Some(make_genesis_block()),
+    }))
+    .expect(1)
+    .mount(&mock.mocked)
+    .await;
+
+    let _restarted_server = tokio::spawn({
+        let mock_server = mock.mocked.clone();
+        let rollup_addr = mock.rollup_addr;
+        async move {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            let listener = tokio::net::TcpListener::bind(rollup_addr).await.unwrap();
+            serve_grpc_mock(listener, mock_server, CancellationToken::new())
+                .await
+                .unwrap();
+        }
+    });
+
+    // the first attempts fail to connect because the rollup node is down and are retried.
+    mock.executor
+        .execute_soft(client, make_soft_block())
+        .await
+        .unwrap();
+    assert_eq!(1, mock.executor.state.borrow().soft().number());
+    mock.mocked.verify().await;
+}
+
+#[tokio::test]
+async fn rejected_calls_are_not_retried() {
+    let mut mock = start_grpc_mock().await;
+
+    Mock::for_rpc_given(
+        "execute_block",
+        matcher::message_type::<ExecuteBlockRequest>(),
+    )
+    .respond_with(response::error_response(Code::InvalidArgument))
+    .expect(1)
+    .mount(&mock.mocked)
+    .await;
+
+    assert!(
+        mock.executor
+            .execute_soft(mock.client.clone(), make_soft_block())
+            .await
+            .is_err()
+    );
+    assert_eq!(0, mock.executor.state.borrow().soft().number());
+    mock.mocked.verify().await;
+}
+
+#[tokio::test]
+async fn execute_block_is_not_retried_if_it_may_have_executed() {
+    let mut mock = start_grpc_mock().await;
+
+    Mock::for_rpc_given(
+        "execute_block",
+        matcher::message_type::<ExecuteBlockRequest>(),
+    )
+    .respond_with(response::error_response(Code::DeadlineExceeded))
+    .expect(1)
+    .mount(&mock.mocked)
+    .await;
+
+    assert!(
+        mock.executor
+            .execute_soft(mock.client.clone(), make_soft_block())
+            .await
+            .is_err()
+    );
+    mock.mocked.verify().await;
+}
+
+#[tokio::test]
+async fn calls_fail_once_retries_are_exhausted() {
+    let mut mock = start_grpc_mock().await;
+
+    Mock::for_rpc_given(
+        "execute_block",
+        matcher::message_type::<ExecuteBlockRequest>(),
+    )
+    .respond_with(response::error_response(Code::Unavailable))
+    .expect(u64::from(RETRY_CONFIG.max_retries) + 1)
+    .mount(&mock.mocked)
+    .await;
+
+    assert!(
+        mock.executor
+            .execute_soft(mock.client.clone(), make_soft_block())
+            .await
+            .is_err()
+    );
+    mock.mocked.verify().await;
+}
+
+#[tokio::test]
+async fn contract_violations_are_not_retried() {
+    let mut mock = start_grpc_mock().await;
+
+    Mock::for_rpc_given(
+        "execute_block",
+        matcher::message_type::<ExecuteBlockRequest>(),
+    )
+    .respond_with(response::constant_response(raw::Block {
+        number: 2,
+        ..make_executed_block()
+    }))
+    .expect(1)
+    .mount(&mock.mocked)
+    .await;
+
+    let error = mock
+        .executor
+        .execute_soft(mock.client.clone(), make_soft_block())
+        .await
+        .unwrap_err();
+    assert!(
+        error
+            .chain()
+            .any(|cause| cause.is::<super::ContractViolation>()),
+        "execution must fail because of the violated contract, but failed with: {error:?}",
+    );
+    mock.mocked.verify().await;
+}
+
 fn make_block(number: u32) -> raw::Block {
     raw::Block {
         number,
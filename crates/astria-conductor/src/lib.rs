@@ -8,13 +8,16 @@
 //! blockchain. It's marked as a soft commitment; the block is not regarded as finalized on the
 //! execution layer until it's received from the data availability layer. In the second case, the
 //! execution layer is notified to mark the block as finalized.
+mod api;
 pub(crate) mod block_cache;
 mod build_info;
 pub(crate) mod celestia;
 pub mod conductor;
 pub mod config;
 pub(crate) mod executor;
+pub mod metrics_init;
 pub(crate) mod sequencer;
+pub(crate) mod status;
 pub(crate) mod utils;
 
 pub use build_info::BUILD_INFO;
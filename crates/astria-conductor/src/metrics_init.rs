@@ -0,0 +1,71 @@
+//! Crate-specific metrics functionality.
+//!
+//! Registers metrics & lists constants to be used as metric names throughout crate.
+
+use metrics::{
+    describe_counter,
+    describe_gauge,
+    Unit,
+};
+
+/// Registers all metrics used by this crate.
+pub fn register() {
+    describe_counter!(
+        EXECUTED_SOFT_BLOCK_COUNT,
+        Unit::Count,
+        "The number of blocks received from sequencer which were executed as soft commitments, \
+         labelled by rollup ID"
+    );
+
+    describe_counter!(
+        EXECUTED_FIRM_BLOCK_COUNT,
+        Unit::Count,
+        "The number of blocks received from Celestia which were executed or marked as firm \
+         commitments, labelled by rollup ID"
+    );
+
+    describe_gauge!(
+        SOFT_COMMITMENT_HEIGHT,
+        Unit::Count,
+        "The rollup block number of the latest soft commitment, labelled by rollup ID"
+    );
+
+    describe_gauge!(
+        FIRM_COMMITMENT_HEIGHT,
+        Unit::Count,
+        "The rollup block number of the latest firm commitment, labelled by rollup ID"
+    );
+
+    describe_counter!(
+        SEQUENCER_BLOBS_VERIFIED_COUNT,
+        Unit::Count,
+        "The number of sequencer blobs read from Celestia which passed verification"
+    );
+
+    describe_counter!(
+        SEQUENCER_BLOBS_REJECTED_COUNT,
+        Unit::Count,
+        "The number of sequencer blobs read from Celestia which failed verification"
+    );
+}
+
+/// The label attached to executor metrics identifying the rollup.
+pub const ROLLUP_ID_LABEL: &str = "rollup_id";
+
+pub const EXECUTED_SOFT_BLOCK_COUNT: &str =
+    concat!(env!("CARGO_CRATE_NAME"), "_executed_soft_block_count");
+
+pub const EXECUTED_FIRM_BLOCK_COUNT: &str =
+    concat!(env!("CARGO_CRATE_NAME"), "_executed_firm_block_count");
+
+pub const SOFT_COMMITMENT_HEIGHT: &str =
+    concat!(env!("CARGO_CRATE_NAME"), "_soft_commitment_height");
+
+pub const FIRM_COMMITMENT_HEIGHT: &str =
+    concat!(env!("CARGO_CRATE_NAME"), "_firm_commitment_height");
+
+pub const SEQUENCER_BLOBS_VERIFIED_COUNT: &str =
+    concat!(env!("CARGO_CRATE_NAME"), "_sequencer_blobs_verified_count");
+
+pub const SEQUENCER_BLOBS_REJECTED_COUNT: &str =
+    concat!(env!("CARGO_CRATE_NAME"), "_sequencer_blobs_rejected_count");
@@ -95,7 +95,7 @@ impl Heights {
 
 pin_project! {
     pub(super) struct BlocksFromHeightStream {
-        rollup_id: RollupId,
+        rollup_ids: Vec<RollupId>,
         heights: Heights,
         in_progress: FuturesMap<u64, eyre::Result<FilteredSequencerBlock>>,
         client: SequencerGrpcClient,
@@ -136,7 +136,7 @@ impl BlocksFromHeightStream {
     }
 
     pub(super) fn new(
-        rollup_id: RollupId,
+        rollup_ids: Vec<RollupId>,
         rollup_expects: Height,
         latest_sequencer_height: Height,
         client: SequencerGrpcClient,
@@ -148,7 +148,7 @@ impl BlocksFromHeightStream {
             max_ahead: 128,
         };
         Self {
-            rollup_id,
+            rollup_ids,
             heights,
             // NOTE: Gives Sequencer 1h to respond, and hard code it to use 20 max in flight
             // requests. XXX: This interacts with the retry-logic in the
@@ -177,7 +177,7 @@ impl Stream for BlocksFromHeightStream {
         while let Some(next_height) = this.heights.next_height_to_fetch() {
             match this.in_progress.try_push(
                 next_height,
-                fetch_block(this.client.clone(), next_height, *this.rollup_id),
+                fetch_block(this.client.clone(), next_height, this.rollup_ids.clone()),
             ) {
                 Err(PushError::BeyondCapacity(_)) => break,
                 Err(PushError::Replaced(_)) => {
@@ -217,7 +217,7 @@ impl Stream for BlocksFromHeightStream {
                 let res = {
                     this.in_progress.try_push(
                         height,
-                        fetch_block(this.client.clone(), height, *this.rollup_id),
+                        fetch_block(this.client.clone(), height, this.rollup_ids.clone()),
                     )
                 };
                 assert!(
@@ -243,16 +243,16 @@ impl Stream for BlocksFromHeightStream {
 
 #[instrument(
     skip_all,
-    fields(%height, %rollup_id),
+    fields(%height, rollup_ids = %json(&rollup_ids)),
     err,
 )]
 async fn fetch_block(
     mut client: SequencerGrpcClient,
     height: u64,
-    rollup_id: RollupId,
+    rollup_ids: Vec<RollupId>,
 ) -> eyre::Result<FilteredSequencerBlock> {
     let filtered_block = client
-        .get(height, rollup_id)
+        .get(height, &rollup_ids)
         .await
         .wrap_err("failed fetching filtered sequencer block")?;
     info!(
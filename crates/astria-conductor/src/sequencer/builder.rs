@@ -3,31 +3,37 @@ use std::time::Duration;
 use tokio_util::sync::CancellationToken;
 
 use super::SequencerGrpcClient;
-use crate::executor;
+use crate::{
+    executor,
+    status::Status,
+};
 
 pub(crate) struct Builder {
-    pub(crate) executor: executor::Handle,
+    pub(crate) executors: executor::Handles,
     pub(crate) sequencer_grpc_client: SequencerGrpcClient,
     pub(crate) sequencer_cometbft_client: sequencer_client::HttpClient,
     pub(crate) sequencer_block_time: Duration,
     pub(crate) shutdown: CancellationToken,
+    pub(crate) status: Status,
 }
 
 impl Builder {
     pub(crate) fn build(self) -> super::Reader {
         let Self {
-            executor,
+            executors,
             sequencer_grpc_client,
             sequencer_cometbft_client,
             sequencer_block_time,
             shutdown,
+            status,
         } = self;
         super::Reader {
-            executor,
+            executors,
             sequencer_grpc_client,
             sequencer_cometbft_client,
             sequencer_block_time,
             shutdown,
+            status,
         }
     }
 }
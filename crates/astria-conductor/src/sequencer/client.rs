@@ -16,6 +16,7 @@ use astria_eyre::eyre::{
     self,
     WrapErr as _,
 };
+use telemetry::display::json;
 use tonic::transport::{
     Channel,
     Endpoint,
@@ -47,7 +48,7 @@ impl SequencerGrpcClient {
         })
     }
 
-    /// Fetch a sequencer block filtered by `rollup_id`.
+    /// Fetch a sequencer block filtered by `rollup_ids`.
     ///
     /// This method includes retry logic with a maximum delay
     /// up to 10 seconds. The retry logic must live in this method rather
@@ -57,13 +58,13 @@ impl SequencerGrpcClient {
     #[instrument(skip_all, fields(
         uri = %self.uri,
         height,
-        %rollup_id,
+        rollup_ids = %json(&rollup_ids),
         err,
     ))]
     pub(super) async fn get(
         &mut self,
         height: u64,
-        rollup_id: RollupId,
+        rollup_ids: &[RollupId],
     ) -> eyre::Result<FilteredSequencerBlock> {
         let span = tracing::Span::current();
         let retry_cfg = tryhard::RetryFutureConfig::new(u32::MAX)
@@ -91,7 +92,10 @@ impl SequencerGrpcClient {
             let mut client = client.clone();
             let req = GetFilteredSequencerBlockRequest {
                 height,
-                rollup_ids: vec![rollup_id.to_vec()],
+                rollup_ids: rollup_ids
+                    .iter()
+                    .map(|rollup_id| rollup_id.to_vec())
+                    .collect(),
             };
             async move { client.get_filtered_sequencer_block(req).await }
         })
@@ -1,4 +1,5 @@
-//! [`Reader`] reads reads blocks from sequencer and forwards them to [`crate::executor::Executor`].
+//! [`Reader`] reads reads blocks from sequencer and forwards them to the
+//! [`crate::executor::Executor`]s of all rollups.
 
 use std::time::Duration;
 
@@ -32,6 +33,7 @@ use tracing::{
 use crate::{
     block_cache::BlockCache,
     executor,
+    status::Status,
 };
 
 mod block_stream;
@@ -42,7 +44,7 @@ pub(crate) use builder::Builder;
 pub(crate) use client::SequencerGrpcClient;
 
 pub(crate) struct Reader {
-    executor: executor::Handle,
+    executors: executor::Handles,
 
     sequencer_grpc_client: SequencerGrpcClient,
 
@@ -52,6 +54,9 @@ pub(crate) struct Reader {
 
     /// Token to listen for Conductor being shut down.
     shutdown: CancellationToken,
+
+    /// Reports if the reader is connected to sequencer through the API.
+    status: Status,
 }
 
 impl Reader {
@@ -59,18 +64,20 @@ impl Reader {
     pub(crate) async fn run_until_stopped(self) -> eyre::Result<()> {
         use futures::future::FusedFuture as _;
         let Self {
-            mut executor,
+            mut executors,
             sequencer_grpc_client,
             sequencer_cometbft_client,
             sequencer_block_time,
             shutdown,
+            status,
         } = self;
+        status.set_sequencer_connected(false);
 
-        let mut executor = executor
+        let mut executors = executors
             .wait_for_init()
             .await
-            .wrap_err("handle to executor failed while waiting for it being initialized")?;
-        let next_expected_height = executor.next_expected_soft_height();
+            .wrap_err("handles to executors failed while waiting for them being initialized")?;
+        let next_expected_height = executors.next_expected_soft_height();
 
         let mut latest_height_stream = {
             use sequencer_client::StreamLatestHeight as _;
@@ -84,16 +91,17 @@ impl Reader {
             }
             Some(Ok(height)) => height,
         };
+        status.set_sequencer_connected(true);
         let mut sequential_blocks = BlockCache::with_next_height(next_expected_height)
             .wrap_err("failed constructing sequential block cache")?;
         let mut blocks_from_heights = block_stream::BlocksFromHeightStream::new(
-            executor.rollup_id(),
+            executors.rollup_ids(),
             next_expected_height,
             latest_height,
             sequencer_grpc_client.clone(),
         );
 
-        // Enqueued block waiting for executor to free up. Set if the executor exhibits
+        // Enqueued block waiting for executors to free up. Set if any executor exhibits
         // backpressure.
         let mut enqueued_block: Fuse<BoxFuture<Result<_, _>>> = future::Fuse::terminated();
 
@@ -113,25 +121,22 @@ impl Reader {
                     }
                 }
 
-                // Skip heights that executor has already executed (e.g. firm blocks from Celestia)
-                Ok(next_height) = executor.next_expected_soft_height_if_changed() => {
+                // Skip heights that all executors have already executed (e.g. firm blocks from Celestia)
+                Ok(next_height) = executors.next_expected_soft_height_if_changed() => {
                     blocks_from_heights.set_next_expected_height_if_greater(next_height);
                     sequential_blocks.drop_obsolete(next_height);
                 }
 
-                // Forward the next block to executor. Enqueue if the executor channel is full.
+                // Forward the next block to the executors. Enqueue if any executor channel is full.
                 Some(block) = sequential_blocks.next_block(), if enqueued_block.is_terminated() => {
-                    if let Err(err) = executor.try_send_soft_block(block) {
-                        match err {
-                            // `Closed` contains the block. Dropping it because there is no use for it.
-                            executor::channel::TrySendError::Closed(_) => {
-                                break Err(Report::msg("could not send block to executor because its channel was closed"));
-                            }
-
-                            executor::channel::TrySendError::NoPermits(block) => {
-                                trace!("executor channel is full; scheduling block and stopping block fetch until a slot opens up");
-                                enqueued_block = executor.clone().send_soft_block_owned(block).boxed().fuse();
-                            }
+                    match executors.try_send_soft_block(&block) {
+                        Ok(None) => {}
+                        Ok(Some(full_executors)) => {
+                            trace!("executor channel is full; scheduling block and stopping block fetch until a slot opens up");
+                            enqueued_block = full_executors.send_soft_block_owned(block).boxed().fuse();
+                        }
+                        Err(_) => {
+                            break Err(Report::msg("could not send block to executor because its channel was closed"));
                         }
                     }
                 }
@@ -157,9 +162,11 @@ impl Reader {
                     match res {
                         Ok(height) => {
                             debug!(%height, "received latest height from sequencer");
+                            status.set_sequencer_connected(true);
                             blocks_from_heights.set_latest_observed_height_if_greater(height);
                         }
                         Err(error) => {
+                            status.set_sequencer_connected(false);
                             warn!(
                                 error = %Report::new(error),
                                 "failed fetching latest height from sequencer; waiting until next tick",
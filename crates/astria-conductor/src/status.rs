@@ -0,0 +1,133 @@
+//! Tracks the status of Conductor's readers and of its executors for reporting through its API.
+
+use std::sync::Arc;
+
+use tokio::sync::watch;
+
+/// A handle to update the status of Conductor.
+///
+/// Cloning this handle is cheap; all clones write to the same underlying status.
+#[derive(Clone, Debug)]
+pub(crate) struct Status {
+    inner: Arc<watch::Sender<StatusSnapshot>>,
+}
+
+impl Status {
+    pub(crate) fn new() -> Self {
+        let (inner, _) = watch::channel(StatusSnapshot::default());
+        Self {
+            inner: Arc::new(inner),
+        }
+    }
+
+    pub(crate) fn subscribe(&self) -> watch::Receiver<StatusSnapshot> {
+        self.inner.subscribe()
+    }
+
+    pub(crate) fn increment_soft_blocks_executed(&self) {
+        self.inner
+            .send_modify(|status| status.soft_blocks_executed += 1);
+    }
+
+    pub(crate) fn increment_firm_blocks_executed(&self) {
+        self.inner
+            .send_modify(|status| status.firm_blocks_executed += 1);
+    }
+}
+
+macro_rules! forward_setter {
+    ($([$fn:ident <- $val:ty]),*$(,)?) => {
+        impl Status {
+            $(
+            pub(crate) fn $fn(&self, val: $val) {
+                self.inner
+                    .send_if_modified(|status| status.$fn(val));
+            }
+            )*
+        }
+    };
+}
+
+forward_setter!(
+    [set_celestia_connected <- bool],
+    [set_sequencer_connected <- bool],
+    [set_next_celestia_height <- u64],
+);
+
+/// The status of Conductor's readers and the number of blocks its executors executed.
+///
+/// The connection status of a reader is `None` if the reader is not running because of the
+/// configured commit level.
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Serialize)]
+pub(crate) struct StatusSnapshot {
+    celestia_connected: Option<bool>,
+    sequencer_connected: Option<bool>,
+
+    next_celestia_height: Option<u64>,
+
+    soft_blocks_executed: u64,
+    firm_blocks_executed: u64,
+}
+
+impl StatusSnapshot {
+    /// Sets the celestia connected state to `connected`.
+    ///
+    /// Returns if the previous state was modified.
+    fn set_celestia_connected(&mut self, connected: bool) -> bool {
+        self.celestia_connected.replace(connected) != Some(connected)
+    }
+
+    /// Sets the sequencer connected state to `connected`.
+    ///
+    /// Returns if the previous state was modified.
+    fn set_sequencer_connected(&mut self, connected: bool) -> bool {
+        self.sequencer_connected.replace(connected) != Some(connected)
+    }
+
+    /// Sets the next Celestia height the Celestia reader will fetch.
+    ///
+    /// Returns if the previous state was modified.
+    fn set_next_celestia_height(&mut self, height: u64) -> bool {
+        self.next_celestia_height.replace(height) != Some(height)
+    }
+
+    /// Returns if at least one of the running readers is connected.
+    pub(crate) fn is_any_reader_connected(&self) -> bool {
+        self.celestia_connected == Some(true) || self.sequencer_connected == Some(true)
+    }
+
+    /// Returns if all running readers are connected.
+    pub(crate) fn is_healthy(&self) -> bool {
+        self.celestia_connected != Some(false) && self.sequencer_connected != Some(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn readers_not_running_do_not_affect_health() {
+        let mut status = StatusSnapshot::default();
+        assert!(!status.is_any_reader_connected());
+
+        assert!(status.set_sequencer_connected(true));
+        assert!(status.is_any_reader_connected());
+        assert!(status.is_healthy());
+
+        assert!(status.set_celestia_connected(false));
+        assert!(status.is_any_reader_connected());
+        assert!(!status.is_healthy());
+    }
+
+    #[test]
+    fn setters_report_modifications() {
+        let mut status = StatusSnapshot::default();
+        assert!(status.set_celestia_connected(false));
+        assert!(!status.set_celestia_connected(false));
+        assert!(status.set_celestia_connected(true));
+
+        assert!(status.set_next_celestia_height(5));
+        assert!(!status.set_next_celestia_height(5));
+    }
+}
@@ -9,6 +9,15 @@ pub struct SubmitRollupTransactionRequest {
     /// the raw data bytes of the rollup transaction
     #[prost(bytes = "vec", tag = "2")]
     pub data: ::prost::alloc::vec::Vec<u8>,
+    /// the denomination of the asset used to pay the sequencer fees for this transaction.
+    /// If empty, the fee asset configured for the rollup is used, or the sequencer's native
+    /// asset if the rollup has none configured.
+    #[prost(string, tag = "3")]
+    pub fee_asset: ::prost::alloc::string::String,
+    /// if set, the response is only sent once the bundle containing the transaction was accepted
+    /// by the Shared Sequencer Network, and contains the receipt of its submission.
+    #[prost(bool, tag = "4")]
+    pub wait_for_receipt: bool,
 }
 impl ::prost::Name for SubmitRollupTransactionRequest {
     const NAME: &'static str = "SubmitRollupTransactionRequest";
@@ -17,11 +26,37 @@ impl ::prost::Name for SubmitRollupTransactionRequest {
         ::prost::alloc::format!("astria.composer.v1alpha1.{}", Self::NAME)
     }
 }
+/// SubmissionReceipt identifies the sequencer transaction a rollup transaction was submitted in.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SubmissionReceipt {
+    /// the sha256 hash of the protobuf encoded signed sequencer transaction
+    #[prost(bytes = "vec", tag = "1")]
+    pub sequencer_transaction_hash: ::prost::alloc::vec::Vec<u8>,
+    /// the nonce the sequencer transaction was signed with
+    #[prost(uint32, tag = "2")]
+    pub nonce: u32,
+}
+impl ::prost::Name for SubmissionReceipt {
+    const NAME: &'static str = "SubmissionReceipt";
+    const PACKAGE: &'static str = "astria.composer.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("astria.composer.v1alpha1.{}", Self::NAME)
+    }
+}
 /// SubmitRollupTransactionResponse is a message that represents a response to a request to submit a rollup.
-/// It's currently an empty response which can be evolved in the future to include more information
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct SubmitRollupTransactionResponse {}
+pub struct SubmitRollupTransactionResponse {
+    /// the receipt of the sequencer transaction the rollup transaction was submitted in.
+    /// Only set if `wait_for_receipt` was set in the request.
+    #[prost(message, optional, tag = "1")]
+    pub receipt: ::core::option::Option<SubmissionReceipt>,
+    /// the reason the rollup transaction was not submitted to the sequencer. Only set in responses
+    /// to multiple rollup transactions; the receipt is unset if it is.
+    #[prost(string, tag = "2")]
+    pub error: ::prost::alloc::string::String,
+}
 impl ::prost::Name for SubmitRollupTransactionResponse {
     const NAME: &'static str = "SubmitRollupTransactionResponse";
     const PACKAGE: &'static str = "astria.composer.v1alpha1";
@@ -29,6 +64,72 @@ impl ::prost::Name for SubmitRollupTransactionResponse {
         ::prost::alloc::format!("astria.composer.v1alpha1.{}", Self::NAME)
     }
 }
+/// BatchSubmitRollupTransactionsRequest contains multiple rollup transactions to be submitted to
+/// the Shared Sequencer Network via the Composer
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BatchSubmitRollupTransactionsRequest {
+    #[prost(message, repeated, tag = "1")]
+    pub transactions: ::prost::alloc::vec::Vec<SubmitRollupTransactionRequest>,
+}
+impl ::prost::Name for BatchSubmitRollupTransactionsRequest {
+    const NAME: &'static str = "BatchSubmitRollupTransactionsRequest";
+    const PACKAGE: &'static str = "astria.composer.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("astria.composer.v1alpha1.{}", Self::NAME)
+    }
+}
+/// SubmitRollupTransactionsResponse is the response to submitting multiple rollup transactions.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SubmitRollupTransactionsResponse {
+    /// the responses to each submitted rollup transaction, in the order they were sent
+    #[prost(message, repeated, tag = "1")]
+    pub responses: ::prost::alloc::vec::Vec<SubmitRollupTransactionResponse>,
+}
+impl ::prost::Name for SubmitRollupTransactionsResponse {
+    const NAME: &'static str = "SubmitRollupTransactionsResponse";
+    const PACKAGE: &'static str = "astria.composer.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("astria.composer.v1alpha1.{}", Self::NAME)
+    }
+}
+/// GetRollupTransactionStatusRequest queries the status of a rollup transaction submitted via the
+/// Composer
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetRollupTransactionStatusRequest {
+    /// the keccak256 hash of the raw data bytes of the rollup transaction
+    #[prost(bytes = "vec", tag = "1")]
+    pub rollup_transaction_hash: ::prost::alloc::vec::Vec<u8>,
+}
+impl ::prost::Name for GetRollupTransactionStatusRequest {
+    const NAME: &'static str = "GetRollupTransactionStatusRequest";
+    const PACKAGE: &'static str = "astria.composer.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("astria.composer.v1alpha1.{}", Self::NAME)
+    }
+}
+/// GetRollupTransactionStatusResponse is the status of a rollup transaction tracked by the Composer.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetRollupTransactionStatusResponse {
+    /// the sha256 hash of the protobuf encoded signed sequencer transaction the rollup transaction
+    /// was submitted in
+    #[prost(bytes = "vec", tag = "1")]
+    pub sequencer_transaction_hash: ::prost::alloc::vec::Vec<u8>,
+    /// the height of the sequencer block the sequencer transaction was included in, or 0 if it was
+    /// submitted but not yet included in a block
+    #[prost(uint64, tag = "2")]
+    pub sequencer_height: u64,
+}
+impl ::prost::Name for GetRollupTransactionStatusResponse {
+    const NAME: &'static str = "GetRollupTransactionStatusResponse";
+    const PACKAGE: &'static str = "astria.composer.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("astria.composer.v1alpha1.{}", Self::NAME)
+    }
+}
 /// Generated client implementations.
 #[cfg(feature = "client")]
 pub mod grpc_collector_service_client {
@@ -148,6 +249,108 @@ pub mod grpc_collector_service_client {
                 );
             self.inner.unary(req, path, codec).await
         }
+        /// SubmitRollupTransactions submits a stream of rollup transactions to the Composer.
+        /// Each transaction is passed on for bundling as soon as it is received. The response is sent
+        /// once the client closed the stream, holding the receipt or error of each transaction. A stream
+        /// holds at most as many transactions as a batch; the call fails on the first one past that.
+        pub async fn submit_rollup_transactions(
+            &mut self,
+            request: impl tonic::IntoStreamingRequest<
+                Message = super::SubmitRollupTransactionRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::SubmitRollupTransactionsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/astria.composer.v1alpha1.GrpcCollectorService/SubmitRollupTransactions",
+            );
+            let mut req = request.into_streaming_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "astria.composer.v1alpha1.GrpcCollectorService",
+                        "SubmitRollupTransactions",
+                    ),
+                );
+            self.inner.client_streaming(req, path, codec).await
+        }
+        /// BatchSubmitRollupTransactions submits multiple rollup transactions to the Composer.
+        /// The transactions are only passed on for bundling if all of them are valid, and either all
+        /// or none of them are passed on. The response holds the receipt or error of each transaction.
+        pub async fn batch_submit_rollup_transactions(
+            &mut self,
+            request: impl tonic::IntoRequest<super::BatchSubmitRollupTransactionsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SubmitRollupTransactionsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/astria.composer.v1alpha1.GrpcCollectorService/BatchSubmitRollupTransactions",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "astria.composer.v1alpha1.GrpcCollectorService",
+                        "BatchSubmitRollupTransactions",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// GetRollupTransactionStatus returns whether a rollup transaction submitted by the Composer was
+        /// sequenced, and if so at which sequencer height. Returns NOT_FOUND if the Composer does not
+        /// track the transaction, either because it was not yet submitted or because it was evicted.
+        pub async fn get_rollup_transaction_status(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetRollupTransactionStatusRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetRollupTransactionStatusResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/astria.composer.v1alpha1.GrpcCollectorService/GetRollupTransactionStatus",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "astria.composer.v1alpha1.GrpcCollectorService",
+                        "GetRollupTransactionStatus",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -167,6 +370,39 @@ pub mod grpc_collector_service_server {
             tonic::Response<super::SubmitRollupTransactionResponse>,
             tonic::Status,
         >;
+        /// SubmitRollupTransactions submits a stream of rollup transactions to the Composer.
+        /// Each transaction is passed on for bundling as soon as it is received. The response is sent
+        /// once the client closed the stream, holding the receipt or error of each transaction. A stream
+        /// holds at most as many transactions as a batch; the call fails on the first one past that.
+        async fn submit_rollup_transactions(
+            self: std::sync::Arc<Self>,
+            request: tonic::Request<
+                tonic::Streaming<super::SubmitRollupTransactionRequest>,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::SubmitRollupTransactionsResponse>,
+            tonic::Status,
+        >;
+        /// BatchSubmitRollupTransactions submits multiple rollup transactions to the Composer.
+        /// The transactions are only passed on for bundling if all of them are valid, and either all
+        /// or none of them are passed on. The response holds the receipt or error of each transaction.
+        async fn batch_submit_rollup_transactions(
+            self: std::sync::Arc<Self>,
+            request: tonic::Request<super::BatchSubmitRollupTransactionsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SubmitRollupTransactionsResponse>,
+            tonic::Status,
+        >;
+        /// GetRollupTransactionStatus returns whether a rollup transaction submitted by the Composer was
+        /// sequenced, and if so at which sequencer height. Returns NOT_FOUND if the Composer does not
+        /// track the transaction, either because it was not yet submitted or because it was evicted.
+        async fn get_rollup_transaction_status(
+            self: std::sync::Arc<Self>,
+            request: tonic::Request<super::GetRollupTransactionStatusRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetRollupTransactionStatusResponse>,
+            tonic::Status,
+        >;
     }
     /// GrpcCollectorService is a service that defines the gRPC collector of the Composer
     #[derive(Debug)]
@@ -303,6 +539,171 @@ pub mod grpc_collector_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/astria.composer.v1alpha1.GrpcCollectorService/SubmitRollupTransactions" => {
+                    #[allow(non_camel_case_types)]
+                    struct SubmitRollupTransactionsSvc<T: GrpcCollectorService>(
+                        pub Arc<T>,
+                    );
+                    impl<
+                        T: GrpcCollectorService,
+                    > tonic::server::ClientStreamingService<
+                        super::SubmitRollupTransactionRequest,
+                    > for SubmitRollupTransactionsSvc<T> {
+                        type Response = super::SubmitRollupTransactionsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                tonic::Streaming<super::SubmitRollupTransactionRequest>,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as GrpcCollectorService>::submit_rollup_transactions(
+                                        inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = SubmitRollupTransactionsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.client_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/astria.composer.v1alpha1.GrpcCollectorService/BatchSubmitRollupTransactions" => {
+                    #[allow(non_camel_case_types)]
+                    struct BatchSubmitRollupTransactionsSvc<T: GrpcCollectorService>(
+                        pub Arc<T>,
+                    );
+                    impl<
+                        T: GrpcCollectorService,
+                    > tonic::server::UnaryService<
+                        super::BatchSubmitRollupTransactionsRequest,
+                    > for BatchSubmitRollupTransactionsSvc<T> {
+                        type Response = super::SubmitRollupTransactionsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::BatchSubmitRollupTransactionsRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as GrpcCollectorService>::batch_submit_rollup_transactions(
+                                        inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = BatchSubmitRollupTransactionsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/astria.composer.v1alpha1.GrpcCollectorService/GetRollupTransactionStatus" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetRollupTransactionStatusSvc<T: GrpcCollectorService>(
+                        pub Arc<T>,
+                    );
+                    impl<
+                        T: GrpcCollectorService,
+                    > tonic::server::UnaryService<
+                        super::GetRollupTransactionStatusRequest,
+                    > for GetRollupTransactionStatusSvc<T> {
+                        type Response = super::GetRollupTransactionStatusResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::GetRollupTransactionStatusRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as GrpcCollectorService>::get_rollup_transaction_status(
+                                        inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetRollupTransactionStatusSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         Ok(
@@ -286,6 +286,23 @@ impl ::prost::Name for Denom {
         ::prost::alloc::format!("astria.sequencer.v1.{}", Self::NAME)
     }
 }
+/// A response containing the IDs of all assets that are accepted as
+/// payment for fees by the sequencer.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AllowedFeeAssetIdsResponse {
+    #[prost(uint64, tag = "1")]
+    pub height: u64,
+    #[prost(bytes = "vec", repeated, tag = "2")]
+    pub fee_asset_ids: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+}
+impl ::prost::Name for AllowedFeeAssetIdsResponse {
+    const NAME: &'static str = "AllowedFeeAssetIdsResponse";
+    const PACKAGE: &'static str = "astria.sequencer.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("astria.sequencer.v1.{}", Self::NAME)
+    }
+}
 /// A collection of transactions belonging to a specific rollup that are submitted to celestia.
 ///
 /// The transactions contained in the item belong to a rollup identified
@@ -350,6 +367,13 @@ pub struct CelestiaSequencerBlob {
     /// Corresponds to `astria.sequencer.v1alpha.SequencerBlock.rollup_ids_proof`.
     #[prost(message, optional, tag = "5")]
     pub rollup_ids_proof: ::core::option::Option<Proof>,
+    /// The rollups whose `CelestiaRollupBlob`s were posted to celestia alongside this blob,
+    /// and the namespaces they were posted under.
+    ///
+    /// If not set, the `CelestiaRollupBlob`s of all rollups in `rollup_ids` were posted
+    /// under the namespace derived from their rollup IDs.
+    #[prost(message, optional, tag = "6")]
+    pub posted_rollup_blobs: ::core::option::Option<PostedRollupBlobs>,
 }
 impl ::prost::Name for CelestiaSequencerBlob {
     const NAME: &'static str = "CelestiaSequencerBlob";
@@ -358,6 +382,91 @@ impl ::prost::Name for CelestiaSequencerBlob {
         ::prost::alloc::format!("astria.sequencer.v1.{}", Self::NAME)
     }
 }
+/// The rollups whose `CelestiaRollupBlob`s were posted to celestia alongside a
+/// `CelestiaSequencerBlob`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PostedRollupBlobs {
+    #[prost(message, repeated, tag = "1")]
+    pub blobs: ::prost::alloc::vec::Vec<PostedRollupBlob>,
+}
+impl ::prost::Name for PostedRollupBlobs {
+    const NAME: &'static str = "PostedRollupBlobs";
+    const PACKAGE: &'static str = "astria.sequencer.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("astria.sequencer.v1.{}", Self::NAME)
+    }
+}
+/// The celestia namespace and height a rollup's `CelestiaRollupBlob` was posted
+/// under.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PostedRollupBlob {
+    /// The ID of the rollup. Always 32 bytes and one of
+    /// `astria.sequencer.v1.CelestiaSequencerBlob.rollup_ids`.
+    #[prost(bytes = "vec", tag = "1")]
+    pub rollup_id: ::prost::alloc::vec::Vec<u8>,
+    /// The celestia namespace. Always 29 bytes.
+    #[prost(bytes = "vec", tag = "2")]
+    pub celestia_namespace: ::prost::alloc::vec::Vec<u8>,
+    /// The celestia height the rollup blob was posted at. Set if the sequencer
+    /// block was too large for a single celestia transaction and the rollup blob
+    /// was posted at an earlier height than the sequencer blob. 0 if the rollup
+    /// blob was posted at the same height as the sequencer blob.
+    #[prost(uint64, tag = "3")]
+    pub celestia_height: u64,
+}
+impl ::prost::Name for PostedRollupBlob {
+    const NAME: &'static str = "PostedRollupBlob";
+    const PACKAGE: &'static str = "astria.sequencer.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("astria.sequencer.v1.{}", Self::NAME)
+    }
+}
+/// The fees charged by the sequencer for executing actions.
+///
+/// All fees are denominated in the fee asset chosen by the transaction
+/// signer for the respective action.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FeeSchedule {
+    /// the fee charged for a `TransferAction`, `BridgeLockAction`,
+    /// or `BridgeUnlockAction`
+    #[prost(message, optional, tag = "1")]
+    pub transfer_fee: ::core::option::Option<super::super::primitive::v1::Uint128>,
+    /// the fee charged per byte of data of a `SequenceAction`
+    #[prost(message, optional, tag = "2")]
+    pub sequence_byte_fee: ::core::option::Option<super::super::primitive::v1::Uint128>,
+    /// the fee charged for an `InitBridgeAccountAction`
+    #[prost(message, optional, tag = "3")]
+    pub init_bridge_account_fee: ::core::option::Option<super::super::primitive::v1::Uint128>,
+    /// the fee charged for an `Ics20Withdrawal`
+    #[prost(message, optional, tag = "4")]
+    pub ics20_withdrawal_fee: ::core::option::Option<super::super::primitive::v1::Uint128>,
+}
+impl ::prost::Name for FeeSchedule {
+    const NAME: &'static str = "FeeSchedule";
+    const PACKAGE: &'static str = "astria.sequencer.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("astria.sequencer.v1.{}", Self::NAME)
+    }
+}
+/// A response containing the fee schedule of the sequencer.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FeeScheduleResponse {
+    #[prost(uint64, tag = "1")]
+    pub height: u64,
+    #[prost(message, optional, tag = "2")]
+    pub fees: ::core::option::Option<FeeSchedule>,
+}
+impl ::prost::Name for FeeScheduleResponse {
+    const NAME: &'static str = "FeeScheduleResponse";
+    const PACKAGE: &'static str = "astria.sequencer.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("astria.sequencer.v1.{}", Self::NAME)
+    }
+}
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetSequencerBlockRequest {
@@ -807,10 +916,10 @@ impl ::prost::Name for SignedTransaction {
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct UnsignedTransaction {
-    #[prost(uint32, tag = "1")]
-    pub nonce: u32,
-    #[prost(message, repeated, tag = "2")]
+    #[prost(message, repeated, tag = "3")]
     pub actions: ::prost::alloc::vec::Vec<Action>,
+    #[prost(message, optional, tag = "4")]
+    pub params: ::core::option::Option<TransactionParams>,
 }
 impl ::prost::Name for UnsignedTransaction {
     const NAME: &'static str = "UnsignedTransaction";
@@ -819,10 +928,29 @@ impl ::prost::Name for UnsignedTransaction {
         ::prost::alloc::format!("astria.sequencer.v1.{}", Self::NAME)
     }
 }
+/// `TransactionParams` contains parameters that define the
+/// validity of the transaction.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransactionParams {
+    #[prost(uint32, tag = "1")]
+    pub nonce: u32,
+    /// the ID of the chain the transaction is intended for.
+    /// transactions are only valid on the chain with this ID.
+    #[prost(string, tag = "2")]
+    pub chain_id: ::prost::alloc::string::String,
+}
+impl ::prost::Name for TransactionParams {
+    const NAME: &'static str = "TransactionParams";
+    const PACKAGE: &'static str = "astria.sequencer.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("astria.sequencer.v1.{}", Self::NAME)
+    }
+}
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Action {
-    #[prost(oneof = "action::Value", tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11")]
+    #[prost(oneof = "action::Value", tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13")]
     pub value: ::core::option::Option<action::Value>,
 }
 /// Nested message and enum types in `Action`.
@@ -852,6 +980,10 @@ pub mod action {
         InitBridgeAccountAction(super::InitBridgeAccountAction),
         #[prost(message, tag = "11")]
         BridgeLockAction(super::BridgeLockAction),
+        #[prost(message, tag = "12")]
+        BridgeUnlockAction(super::BridgeUnlockAction),
+        #[prost(message, tag = "13")]
+        FeeChangeAction(super::FeeChangeAction),
     }
 }
 impl ::prost::Name for Action {
@@ -1053,12 +1185,49 @@ impl ::prost::Name for FeeAssetChangeAction {
         ::prost::alloc::format!("astria.sequencer.v1.{}", Self::NAME)
     }
 }
+/// `FeeChangeAction` represents a transaction that changes the fee
+/// charged for a specific action.
+///
+/// It can only be executed by the chain's sudo address.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FeeChangeAction {
+    /// the new value of the fee being changed
+    #[prost(oneof = "fee_change_action::Value", tags = "1, 2, 3, 4")]
+    pub value: ::core::option::Option<fee_change_action::Value>,
+}
+/// Nested message and enum types in `FeeChangeAction`.
+pub mod fee_change_action {
+    /// the new value of the fee being changed
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Value {
+        #[prost(message, tag = "1")]
+        TransferFee(super::super::super::primitive::v1::Uint128),
+        #[prost(message, tag = "2")]
+        SequenceByteFee(super::super::super::primitive::v1::Uint128),
+        #[prost(message, tag = "3")]
+        InitBridgeAccountFee(super::super::super::primitive::v1::Uint128),
+        #[prost(message, tag = "4")]
+        Ics20WithdrawalFee(super::super::super::primitive::v1::Uint128),
+    }
+}
+impl ::prost::Name for FeeChangeAction {
+    const NAME: &'static str = "FeeChangeAction";
+    const PACKAGE: &'static str = "astria.sequencer.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("astria.sequencer.v1.{}", Self::NAME)
+    }
+}
 /// `InitBridgeAccountAction` represents a transaction that initializes
 /// a bridge account for the given rollup on the chain.
 ///
 /// The sender of the transaction is used as the owner of the bridge account
 /// and is the only actor authorized to transfer out of this account via
 /// a `TransferAction`.
+///
+/// Funds can be withdrawn from the bridge account with a `BridgeUnlockAction`
+/// signed by the bridge account's withdrawer address.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct InitBridgeAccountAction {
@@ -1071,6 +1240,11 @@ pub struct InitBridgeAccountAction {
     /// the asset used to pay the transaction fee
     #[prost(bytes = "vec", tag = "3")]
     pub fee_asset_id: ::prost::alloc::vec::Vec<u8>,
+    /// the address authorized to withdraw funds from the bridge account
+    /// via a `BridgeUnlockAction`.
+    /// if unset, the bridge account itself is the withdrawer.
+    #[prost(bytes = "vec", tag = "4")]
+    pub withdrawer_address: ::prost::alloc::vec::Vec<u8>,
 }
 impl ::prost::Name for InitBridgeAccountAction {
     const NAME: &'static str = "InitBridgeAccountAction";
@@ -1111,3 +1285,39 @@ impl ::prost::Name for BridgeLockAction {
         ::prost::alloc::format!("astria.sequencer.v1.{}", Self::NAME)
     }
 }
+/// `BridgeUnlockAction` represents a transaction that transfers
+/// funds from a bridge account to a sequencer account.
+///
+/// It must be signed by the bridge account's withdrawer address.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BridgeUnlockAction {
+    /// the address of the bridge account to transfer from
+    #[prost(bytes = "vec", tag = "1")]
+    pub bridge_address: ::prost::alloc::vec::Vec<u8>,
+    /// the address to transfer to
+    #[prost(bytes = "vec", tag = "2")]
+    pub to: ::prost::alloc::vec::Vec<u8>,
+    /// the amount to transfer
+    #[prost(message, optional, tag = "3")]
+    pub amount: ::core::option::Option<super::super::primitive::v1::Uint128>,
+    /// the asset to be transferred
+    #[prost(bytes = "vec", tag = "4")]
+    pub asset_id: ::prost::alloc::vec::Vec<u8>,
+    /// the asset used to pay the transaction fee
+    #[prost(bytes = "vec", tag = "5")]
+    pub fee_asset_id: ::prost::alloc::vec::Vec<u8>,
+    /// the identifier of the withdrawal event on the rollup which
+    /// resulted in this unlock.
+    ///
+    /// each identifier can only be used once per bridge account.
+    #[prost(string, tag = "6")]
+    pub rollup_withdrawal_event_id: ::prost::alloc::string::String,
+}
+impl ::prost::Name for BridgeUnlockAction {
+    const NAME: &'static str = "BridgeUnlockAction";
+    const PACKAGE: &'static str = "astria.sequencer.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("astria.sequencer.v1.{}", Self::NAME)
+    }
+}
@@ -6,6 +6,8 @@ use std::{
     },
 };
 
+use super::raw;
+
 /// The default sequencer asset base denomination.
 pub const DEFAULT_NATIVE_ASSET_DENOM: &str = "nria";
 
@@ -186,3 +188,83 @@ impl Display for Id {
 pub struct IncorrectAssetIdLength {
     received: usize,
 }
+
+/// The sequencer response to a request for the assets accepted as fee payment at a given
+/// height.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AllowedFeeAssetIdsResponse {
+    pub height: u64,
+    pub fee_asset_ids: Vec<Id>,
+}
+
+impl AllowedFeeAssetIdsResponse {
+    /// Converts a protobuf [`raw::AllowedFeeAssetIdsResponse`] to an astria
+    /// native [`AllowedFeeAssetIdsResponse`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the asset IDs in the response is not 32 bytes long.
+    pub fn try_from_raw(
+        proto: &raw::AllowedFeeAssetIdsResponse,
+    ) -> Result<Self, IncorrectAssetIdLength> {
+        let raw::AllowedFeeAssetIdsResponse {
+            height,
+            fee_asset_ids,
+        } = proto;
+        let fee_asset_ids = fee_asset_ids
+            .iter()
+            .map(|id| Id::try_from_slice(id))
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
+            height: *height,
+            fee_asset_ids,
+        })
+    }
+
+    /// Converts an astria native [`AllowedFeeAssetIdsResponse`] to a
+    /// protobuf [`raw::AllowedFeeAssetIdsResponse`].
+    #[must_use]
+    pub fn into_raw(self) -> raw::AllowedFeeAssetIdsResponse {
+        let Self {
+            height,
+            fee_asset_ids,
+        } = self;
+        raw::AllowedFeeAssetIdsResponse {
+            height,
+            fee_asset_ids: fee_asset_ids
+                .into_iter()
+                .map(|id| id.get().to_vec())
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        default_native_asset_id,
+        AllowedFeeAssetIdsResponse,
+        Id,
+    };
+
+    #[test]
+    fn allowed_fee_asset_ids_response_roundtrip_is_correct() {
+        let expected = AllowedFeeAssetIdsResponse {
+            height: 42,
+            fee_asset_ids: vec![default_native_asset_id(), Id::from_denom("utia")],
+        };
+        let actual =
+            AllowedFeeAssetIdsResponse::try_from_raw(&expected.clone().into_raw()).unwrap();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn allowed_fee_asset_ids_response_with_short_id_is_rejected() {
+        let raw = crate::generated::sequencer::v1::AllowedFeeAssetIdsResponse {
+            height: 42,
+            fee_asset_ids: vec![vec![1u8; 31]],
+        };
+        AllowedFeeAssetIdsResponse::try_from_raw(&raw)
+            .expect_err("asset IDs that are not 32 bytes long must be rejected");
+    }
+}
@@ -29,6 +29,9 @@ use crate::{
 #[cfg(test)]
 mod tests;
 
+/// The maximum number of bytes of sequence action data in a sequencer block.
+pub const MAX_SEQUENCE_DATA_BYTES_PER_BLOCK: usize = 256_000;
+
 #[derive(Debug, thiserror::Error)]
 #[error(transparent)]
 pub struct RollupTransactionsError(RollupTransactionsErrorKind);
@@ -1,7 +1,10 @@
 use sha2::Digest as _;
 
 use super::*;
-use crate::sequencer::v1::test_utils::make_cometbft_block;
+use crate::sequencer::v1::{
+    test_utils::make_cometbft_block,
+    PostedRollupBlob,
+};
 
 #[test]
 fn sequencer_block_from_cometbft_block_gives_expected_merkle_proofs() {
@@ -60,3 +63,34 @@ fn block_to_filtered_roundtrip() {
 
     assert_eq!(filtered_sequencer_block, from_raw);
 }
+
+#[test]
+fn celestia_sequencer_blob_with_posted_rollup_blobs_roundtrip() {
+    let block = make_cometbft_block();
+    let sequencer_block = SequencerBlock::try_from_cometbft(block).unwrap();
+    let (sequencer_blob, _) = sequencer_block.into_celestia_blobs();
+    assert!(sequencer_blob.posted_rollup_blobs().is_none());
+
+    let posted = vec![
+        PostedRollupBlob::new(sequencer_blob.rollup_ids()[0], [42; 29]).with_celestia_height(5),
+    ];
+    let sequencer_blob = sequencer_blob
+        .with_posted_rollup_blobs(posted.clone())
+        .unwrap();
+
+    let from_raw = CelestiaSequencerBlob::try_from_raw(sequencer_blob.into_raw()).unwrap();
+    assert_eq!(from_raw.posted_rollup_blobs(), Some(posted.as_slice()));
+}
+
+#[test]
+fn posted_rollup_blob_of_unknown_rollup_is_rejected() {
+    let block = make_cometbft_block();
+    let sequencer_block = SequencerBlock::try_from_cometbft(block).unwrap();
+    let (sequencer_blob, _) = sequencer_block.into_celestia_blobs();
+
+    let posted = vec![PostedRollupBlob::new(
+        RollupId::from_unhashed_bytes(b"unknown-rollup"),
+        [42; 29],
+    )];
+    assert!(sequencer_blob.with_posted_rollup_blobs(posted).is_err());
+}
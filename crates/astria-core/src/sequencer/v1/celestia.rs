@@ -49,6 +49,7 @@ impl CelestiaBlobBundle {
             rollup_transactions_root,
             rollup_transactions_proof,
             rollup_ids_proof,
+            posted_rollup_blobs: None,
         };
 
         let mut tail = Vec::with_capacity(rollup_transactions.len());
@@ -350,6 +351,28 @@ impl CelestiaSequencerBlobError {
             kind: CelestiaSequencerBlobErrorKind::RollupIdsNotInCometBftBlock,
         }
     }
+
+    fn posted_rollup_id(source: IncorrectRollupIdLength) -> Self {
+        Self {
+            kind: CelestiaSequencerBlobErrorKind::PostedRollupId {
+                source,
+            },
+        }
+    }
+
+    fn posted_celestia_namespace(actual_len: usize) -> Self {
+        Self {
+            kind: CelestiaSequencerBlobErrorKind::PostedCelestiaNamespace(actual_len),
+        }
+    }
+
+    fn posted_rollup_not_in_rollup_ids(rollup_id: RollupId) -> Self {
+        Self {
+            kind: CelestiaSequencerBlobErrorKind::PostedRollupNotInRollupIds {
+                rollup_id,
+            },
+        }
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -388,6 +411,105 @@ enum CelestiaSequencerBlobErrorKind {
     RollupTransactiosnNotInCometBftBlock,
     #[error("the Merkle Tree Hash of the rollup IDs was not a leaf in the sequencer block data")]
     RollupIdsNotInCometBftBlock,
+    #[error("the rollup ID of one of the posted rollup blobs was invalid")]
+    PostedRollupId { source: IncorrectRollupIdLength },
+    #[error(
+        "the celestia namespace of one of the posted rollup blobs was invalid; expected: \
+         {CELESTIA_NAMESPACE_LEN} bytes, actual: {0} bytes"
+    )]
+    PostedCelestiaNamespace(usize),
+    #[error("the posted rollup blob of rollup `{rollup_id}` is not one of the blob's rollup IDs")]
+    PostedRollupNotInRollupIds { rollup_id: RollupId },
+}
+
+/// The length of a celestia namespace in bytes.
+pub const CELESTIA_NAMESPACE_LEN: usize = 29;
+
+/// The celestia namespace and height a rollup's [`CelestiaRollupBlob`] was posted under.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PostedRollupBlob {
+    rollup_id: RollupId,
+    celestia_namespace: [u8; CELESTIA_NAMESPACE_LEN],
+    celestia_height: Option<u64>,
+}
+
+impl PostedRollupBlob {
+    #[must_use]
+    pub fn new(rollup_id: RollupId, celestia_namespace: [u8; CELESTIA_NAMESPACE_LEN]) -> Self {
+        Self {
+            rollup_id,
+            celestia_namespace,
+            celestia_height: None,
+        }
+    }
+
+    /// Records that the rollup blob was posted at `celestia_height` instead of the height of the
+    /// sequencer blob.
+    #[must_use]
+    pub fn with_celestia_height(self, celestia_height: u64) -> Self {
+        Self {
+            celestia_height: Some(celestia_height),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn rollup_id(&self) -> RollupId {
+        self.rollup_id
+    }
+
+    #[must_use]
+    pub fn celestia_namespace(&self) -> [u8; CELESTIA_NAMESPACE_LEN] {
+        self.celestia_namespace
+    }
+
+    /// Returns the celestia height the rollup blob was posted at, or `None` if it was posted at
+    /// the same height as the sequencer blob.
+    #[must_use]
+    pub fn celestia_height(&self) -> Option<u64> {
+        self.celestia_height
+    }
+
+    fn into_raw(self) -> raw::PostedRollupBlob {
+        raw::PostedRollupBlob {
+            rollup_id: self.rollup_id.to_vec(),
+            celestia_namespace: self.celestia_namespace.to_vec(),
+            celestia_height: self.celestia_height.unwrap_or_default(),
+        }
+    }
+
+    fn try_from_raw(raw: raw::PostedRollupBlob) -> Result<Self, CelestiaSequencerBlobError> {
+        let raw::PostedRollupBlob {
+            rollup_id,
+            celestia_namespace,
+            celestia_height,
+        } = raw;
+        let rollup_id = RollupId::try_from_vec(rollup_id)
+            .map_err(CelestiaSequencerBlobError::posted_rollup_id)?;
+        let celestia_namespace = celestia_namespace.try_into().map_err(|bytes: Vec<u8>| {
+            CelestiaSequencerBlobError::posted_celestia_namespace(bytes.len())
+        })?;
+        Ok(Self {
+            rollup_id,
+            celestia_namespace,
+            celestia_height: (celestia_height != 0).then_some(celestia_height),
+        })
+    }
+}
+
+/// Checks that all posted rollup blobs belong to one of `rollup_ids`.
+fn ensure_posted_rollups_are_known(
+    rollup_ids: &[RollupId],
+    posted_rollup_blobs: Option<&[PostedRollupBlob]>,
+) -> Result<(), CelestiaSequencerBlobError> {
+    for posted in posted_rollup_blobs.unwrap_or_default() {
+        if !rollup_ids.contains(&posted.rollup_id) {
+            return Err(CelestiaSequencerBlobError::posted_rollup_not_in_rollup_ids(
+                posted.rollup_id,
+            ));
+        }
+    }
+    Ok(())
 }
 
 /// A shadow of [`CelestiaSequencerBlob`] with public access to its fields.
@@ -418,6 +540,10 @@ pub struct UncheckedCelestiaSequencerBlob {
     /// `astria.sequencer.v1alpha.SequencerBlock.header.data_hash`. This field corresponds to
     /// `astria.sequencer.v1alpha.SequencerBlock.rollup_ids_proof`.
     pub rollup_ids_proof: merkle::Proof,
+    /// The rollups whose `CelestiaRollupBlob`s were posted to celestia alongside this blob, and
+    /// the namespaces they were posted under. If `None`, the blobs of all rollups in
+    /// `rollup_ids` were posted under the namespaces derived from their rollup IDs.
+    pub posted_rollup_blobs: Option<Vec<PostedRollupBlob>>,
 }
 
 impl UncheckedCelestiaSequencerBlob {
@@ -444,6 +570,7 @@ impl UncheckedCelestiaSequencerBlob {
             rollup_transactions_root,
             rollup_transactions_proof,
             rollup_ids_proof,
+            posted_rollup_blobs,
         } = raw;
         let header = 'cometbft_header: {
             let Some(header) = header else {
@@ -485,12 +612,23 @@ impl UncheckedCelestiaSequencerBlob {
                 .map_err(CelestiaSequencerBlobError::rollup_ids_proof)
         }?;
 
+        let posted_rollup_blobs = posted_rollup_blobs
+            .map(|posted| {
+                posted
+                    .blobs
+                    .into_iter()
+                    .map(PostedRollupBlob::try_from_raw)
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .transpose()?;
+
         Ok(Self {
             header,
             rollup_ids,
             rollup_transactions_root,
             rollup_transactions_proof,
             rollup_ids_proof,
+            posted_rollup_blobs,
         })
     }
 }
@@ -522,6 +660,10 @@ pub struct CelestiaSequencerBlob {
     /// `astria.sequencer.v1alpha.SequencerBlock.header.data_hash`. This field corresponds to
     /// `astria.sequencer.v1alpha.SequencerBlock.rollup_ids_proof`.
     rollup_ids_proof: merkle::Proof,
+    /// The rollups whose `CelestiaRollupBlob`s were posted to celestia alongside this blob, and
+    /// the namespaces they were posted under. If `None`, the blobs of all rollups in
+    /// `rollup_ids` were posted under the namespaces derived from their rollup IDs.
+    posted_rollup_blobs: Option<Vec<PostedRollupBlob>>,
 }
 
 impl CelestiaSequencerBlob {
@@ -557,6 +699,38 @@ impl CelestiaSequencerBlob {
         self.rollup_transactions_root
     }
 
+    /// Returns the IDs of all rollups with transactions in the original [`SequencerBlock`] this
+    /// blob was derived from.
+    #[must_use]
+    pub fn rollup_ids(&self) -> &[RollupId] {
+        &self.rollup_ids
+    }
+
+    /// Returns the rollups whose [`CelestiaRollupBlob`]s were posted alongside this blob, and
+    /// the namespaces they were posted under.
+    ///
+    /// Returns `None` if the blobs of all rollups in [`Self::rollup_ids`] were posted under the
+    /// namespaces derived from their rollup IDs.
+    #[must_use]
+    pub fn posted_rollup_blobs(&self) -> Option<&[PostedRollupBlob]> {
+        self.posted_rollup_blobs.as_deref()
+    }
+
+    /// Records the rollups whose [`CelestiaRollupBlob`]s are posted alongside this blob.
+    ///
+    /// # Errors
+    /// Returns an error if one of `posted_rollup_blobs` is not for one of the blob's rollup IDs.
+    pub fn with_posted_rollup_blobs(
+        self,
+        posted_rollup_blobs: Vec<PostedRollupBlob>,
+    ) -> Result<Self, CelestiaSequencerBlobError> {
+        ensure_posted_rollups_are_known(&self.rollup_ids, Some(posted_rollup_blobs.as_slice()))?;
+        Ok(Self {
+            posted_rollup_blobs: Some(posted_rollup_blobs),
+            ..self
+        })
+    }
+
     /// Converts into the unchecked representation fo this type.
     #[must_use]
     pub fn into_unchecked(self) -> UncheckedCelestiaSequencerBlob {
@@ -566,6 +740,7 @@ impl CelestiaSequencerBlob {
             rollup_transactions_root,
             rollup_transactions_proof,
             rollup_ids_proof,
+            posted_rollup_blobs,
             ..
         } = self;
         UncheckedCelestiaSequencerBlob {
@@ -574,6 +749,7 @@ impl CelestiaSequencerBlob {
             rollup_transactions_root,
             rollup_transactions_proof,
             rollup_ids_proof,
+            posted_rollup_blobs,
         }
     }
 
@@ -590,6 +766,7 @@ impl CelestiaSequencerBlob {
             rollup_transactions_root,
             rollup_transactions_proof,
             rollup_ids_proof,
+            posted_rollup_blobs,
         } = unchecked;
         let tendermint::Hash::Sha256(block_hash) = header.hash() else {
             return Err(CelestiaSequencerBlobError::empty_cometbft_block_hash());
@@ -611,6 +788,8 @@ impl CelestiaSequencerBlob {
             return Err(CelestiaSequencerBlobError::rollup_ids_not_in_cometbft_block());
         }
 
+        ensure_posted_rollups_are_known(&rollup_ids, posted_rollup_blobs.as_deref())?;
+
         Ok(Self {
             block_hash,
             header,
@@ -618,6 +797,7 @@ impl CelestiaSequencerBlob {
             rollup_transactions_root,
             rollup_transactions_proof,
             rollup_ids_proof,
+            posted_rollup_blobs,
         })
     }
 
@@ -629,6 +809,7 @@ impl CelestiaSequencerBlob {
             rollup_transactions_root,
             rollup_transactions_proof,
             rollup_ids_proof,
+            posted_rollup_blobs,
             ..
         } = self;
         raw::CelestiaSequencerBlob {
@@ -637,6 +818,9 @@ impl CelestiaSequencerBlob {
             rollup_transactions_root: rollup_transactions_root.to_vec(),
             rollup_transactions_proof: Some(rollup_transactions_proof.into_raw()),
             rollup_ids_proof: Some(rollup_ids_proof.into_raw()),
+            posted_rollup_blobs: posted_rollup_blobs.map(|posted| raw::PostedRollupBlobs {
+                blobs: posted.into_iter().map(PostedRollupBlob::into_raw).collect(),
+            }),
         }
     }
 
@@ -0,0 +1,135 @@
+use super::raw;
+
+/// The fees charged by the sequencer for executing actions.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FeeSchedule {
+    /// The fee charged for transfer, bridge lock and bridge unlock actions.
+    pub transfer_fee: u128,
+    /// The fee charged per byte of data of a sequence action.
+    pub sequence_byte_fee: u128,
+    /// The fee charged for initializing a bridge account.
+    pub init_bridge_account_fee: u128,
+    /// The fee charged for an ics20 withdrawal.
+    pub ics20_withdrawal_fee: u128,
+}
+
+impl FeeSchedule {
+    /// Converts a protobuf [`raw::FeeSchedule`] to an astria
+    /// native [`FeeSchedule`].
+    #[must_use]
+    pub fn from_raw(proto: &raw::FeeSchedule) -> Self {
+        let raw::FeeSchedule {
+            transfer_fee,
+            sequence_byte_fee,
+            init_bridge_account_fee,
+            ics20_withdrawal_fee,
+        } = proto;
+        Self {
+            transfer_fee: transfer_fee.map_or(0, Into::into),
+            sequence_byte_fee: sequence_byte_fee.map_or(0, Into::into),
+            init_bridge_account_fee: init_bridge_account_fee.map_or(0, Into::into),
+            ics20_withdrawal_fee: ics20_withdrawal_fee.map_or(0, Into::into),
+        }
+    }
+
+    /// Converts an astria native [`FeeSchedule`] to a
+    /// protobuf [`raw::FeeSchedule`].
+    #[must_use]
+    pub fn into_raw(self) -> raw::FeeSchedule {
+        let Self {
+            transfer_fee,
+            sequence_byte_fee,
+            init_bridge_account_fee,
+            ics20_withdrawal_fee,
+        } = self;
+        raw::FeeSchedule {
+            transfer_fee: Some(transfer_fee.into()),
+            sequence_byte_fee: Some(sequence_byte_fee.into()),
+            init_bridge_account_fee: Some(init_bridge_account_fee.into()),
+            ics20_withdrawal_fee: Some(ics20_withdrawal_fee.into()),
+        }
+    }
+}
+
+impl raw::FeeScheduleResponse {
+    /// Converts an astria native [`FeeScheduleResponse`] to a
+    /// protobuf [`raw::FeeScheduleResponse`].
+    #[must_use]
+    pub fn from_native(native: FeeScheduleResponse) -> Self {
+        let FeeScheduleResponse {
+            height,
+            fees,
+        } = native;
+        Self {
+            height,
+            fees: Some(fees.into_raw()),
+        }
+    }
+
+    /// Converts a protobuf [`raw::FeeScheduleResponse`] to an astria
+    /// native [`FeeScheduleResponse`].
+    #[must_use]
+    pub fn into_native(self) -> FeeScheduleResponse {
+        FeeScheduleResponse::from_raw(&self)
+    }
+
+    /// Converts a protobuf [`raw::FeeScheduleResponse`] to an astria
+    /// native [`FeeScheduleResponse`] by allocating a new [`FeeScheduleResponse`].
+    #[must_use]
+    pub fn to_native(&self) -> FeeScheduleResponse {
+        self.clone().into_native()
+    }
+}
+
+/// The sequencer response to a fee schedule request at a given height.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FeeScheduleResponse {
+    pub height: u64,
+    pub fees: FeeSchedule,
+}
+
+impl FeeScheduleResponse {
+    /// Converts a protobuf [`raw::FeeScheduleResponse`] to an astria
+    /// native [`FeeScheduleResponse`].
+    #[must_use]
+    pub fn from_raw(proto: &raw::FeeScheduleResponse) -> Self {
+        let raw::FeeScheduleResponse {
+            height,
+            fees,
+        } = proto;
+        Self {
+            height: *height,
+            fees: fees.as_ref().map(FeeSchedule::from_raw).unwrap_or_default(),
+        }
+    }
+
+    /// Converts an astria native [`FeeScheduleResponse`] to a
+    /// protobuf [`raw::FeeScheduleResponse`].
+    #[must_use]
+    pub fn into_raw(self) -> raw::FeeScheduleResponse {
+        raw::FeeScheduleResponse::from_native(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        FeeSchedule,
+        FeeScheduleResponse,
+    };
+
+    #[test]
+    fn fee_schedule_response_roundtrip_is_correct() {
+        let expected = FeeScheduleResponse {
+            height: 42,
+            fees: FeeSchedule {
+                transfer_fee: 12,
+                sequence_byte_fee: 1,
+                init_bridge_account_fee: 48,
+                ics20_withdrawal_fee: 24,
+            },
+        };
+        let actual = expected.into_raw().into_native();
+        assert_eq!(expected, actual);
+    }
+}
@@ -18,6 +18,7 @@ pub mod account;
 pub mod asset;
 pub mod block;
 pub mod celestia;
+pub mod fees;
 #[cfg(any(feature = "test-utils", test))]
 pub mod test_utils;
 pub mod transaction;
@@ -31,9 +32,15 @@ pub use block::SequencerBlock;
 pub use celestia::{
     CelestiaRollupBlob,
     CelestiaSequencerBlob,
+    PostedRollupBlob,
+};
+pub use fees::{
+    FeeSchedule,
+    FeeScheduleResponse,
 };
 pub use transaction::{
     SignedTransaction,
+    TransactionParams,
     UnsignedTransaction,
 };
 
@@ -42,7 +49,7 @@ use self::block::RollupTransactions;
 pub const ADDRESS_LEN: usize = 20;
 pub const ROLLUP_ID_LEN: usize = 32;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Address([u8; ADDRESS_LEN]);
 
 impl Address {
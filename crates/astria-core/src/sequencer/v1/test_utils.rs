@@ -8,6 +8,7 @@ use super::{
     group_sequence_actions_in_signed_transaction_transactions_by_rollup_id,
     transaction::action::SequenceAction,
     RollupId,
+    TransactionParams,
     UnsignedTransaction,
 };
 
@@ -82,8 +83,11 @@ impl ConfigureCometBftBlock {
             })
             .collect();
         let unsigned_transaction = UnsignedTransaction {
-            nonce: 1,
             actions,
+            params: TransactionParams {
+                nonce: 1,
+                chain_id: "test".to_string(),
+            },
         };
 
         let signed_transaction = unsigned_transaction.into_signed(&signing_key);
@@ -36,6 +36,8 @@ pub enum Action {
     FeeAssetChange(FeeAssetChangeAction),
     InitBridgeAccount(InitBridgeAccountAction),
     BridgeLock(BridgeLockAction),
+    BridgeUnlock(BridgeUnlockAction),
+    FeeChange(FeeChangeAction),
 }
 
 impl Action {
@@ -54,6 +56,8 @@ impl Action {
             Action::FeeAssetChange(act) => Value::FeeAssetChangeAction(act.into_raw()),
             Action::InitBridgeAccount(act) => Value::InitBridgeAccountAction(act.into_raw()),
             Action::BridgeLock(act) => Value::BridgeLockAction(act.into_raw()),
+            Action::BridgeUnlock(act) => Value::BridgeUnlockAction(act.into_raw()),
+            Action::FeeChange(act) => Value::FeeChangeAction(act.into_raw()),
         };
         raw::Action {
             value: Some(kind),
@@ -77,6 +81,8 @@ impl Action {
             Action::FeeAssetChange(act) => Value::FeeAssetChangeAction(act.to_raw()),
             Action::InitBridgeAccount(act) => Value::InitBridgeAccountAction(act.to_raw()),
             Action::BridgeLock(act) => Value::BridgeLockAction(act.to_raw()),
+            Action::BridgeUnlock(act) => Value::BridgeUnlockAction(act.to_raw()),
+            Action::FeeChange(act) => Value::FeeChangeAction(act.to_raw()),
         };
         raw::Action {
             value: Some(kind),
@@ -134,6 +140,12 @@ impl Action {
             Value::BridgeLockAction(act) => Self::BridgeLock(
                 BridgeLockAction::try_from_raw(act).map_err(ActionError::bridge_lock)?,
             ),
+            Value::BridgeUnlockAction(act) => Self::BridgeUnlock(
+                BridgeUnlockAction::try_from_raw(act).map_err(ActionError::bridge_unlock)?,
+            ),
+            Value::FeeChangeAction(act) => Self::FeeChange(
+                FeeChangeAction::try_from_raw(act).map_err(ActionError::fee_change)?,
+            ),
         };
         Ok(action)
     }
@@ -215,6 +227,18 @@ impl From<BridgeLockAction> for Action {
     }
 }
 
+impl From<BridgeUnlockAction> for Action {
+    fn from(value: BridgeUnlockAction) -> Self {
+        Self::BridgeUnlock(value)
+    }
+}
+
+impl From<FeeChangeAction> for Action {
+    fn from(value: FeeChangeAction) -> Self {
+        Self::FeeChange(value)
+    }
+}
+
 #[allow(clippy::module_name_repetitions)]
 #[derive(Debug, thiserror::Error)]
 #[error(transparent)]
@@ -268,6 +292,14 @@ impl ActionError {
     fn bridge_lock(inner: BridgeLockActionError) -> Self {
         Self(ActionErrorKind::BridgeLock(inner))
     }
+
+    fn bridge_unlock(inner: BridgeUnlockActionError) -> Self {
+        Self(ActionErrorKind::BridgeUnlock(inner))
+    }
+
+    fn fee_change(inner: FeeChangeActionError) -> Self {
+        Self(ActionErrorKind::FeeChange(inner))
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -296,6 +328,10 @@ enum ActionErrorKind {
     InitBridgeAccount(#[source] InitBridgeAccountActionError),
     #[error("bridge lock action was not valid")]
     BridgeLock(#[source] BridgeLockActionError),
+    #[error("bridge unlock action was not valid")]
+    BridgeUnlock(#[source] BridgeUnlockActionError),
+    #[error("fee change action was not valid")]
+    FeeChange(#[source] FeeChangeActionError),
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -1014,6 +1050,72 @@ enum FeeAssetChangeActionErrorKind {
     MissingAssetId,
 }
 
+/// Changes the fee charged for a specific action to the contained value.
+#[derive(Debug, Clone, Copy)]
+pub enum FeeChangeAction {
+    TransferFee(u128),
+    SequenceByteFee(u128),
+    InitBridgeAccountFee(u128),
+    Ics20WithdrawalFee(u128),
+}
+
+impl FeeChangeAction {
+    #[must_use]
+    pub fn into_raw(self) -> raw::FeeChangeAction {
+        use raw::fee_change_action::Value;
+        let value = match self {
+            FeeChangeAction::TransferFee(fee) => Value::TransferFee(fee.into()),
+            FeeChangeAction::SequenceByteFee(fee) => Value::SequenceByteFee(fee.into()),
+            FeeChangeAction::InitBridgeAccountFee(fee) => Value::InitBridgeAccountFee(fee.into()),
+            FeeChangeAction::Ics20WithdrawalFee(fee) => Value::Ics20WithdrawalFee(fee.into()),
+        };
+        raw::FeeChangeAction {
+            value: Some(value),
+        }
+    }
+
+    #[must_use]
+    pub fn to_raw(&self) -> raw::FeeChangeAction {
+        self.into_raw()
+    }
+
+    /// Convert from a raw, unchecked protobuf [`raw::FeeChangeAction`].
+    ///
+    /// # Errors
+    ///
+    /// - if the `value` field is not set
+    pub fn try_from_raw(proto: raw::FeeChangeAction) -> Result<Self, FeeChangeActionError> {
+        use raw::fee_change_action::Value;
+        let Some(value) = proto.value else {
+            return Err(FeeChangeActionError::missing_value());
+        };
+        let action = match value {
+            Value::TransferFee(fee) => FeeChangeAction::TransferFee(fee.into()),
+            Value::SequenceByteFee(fee) => FeeChangeAction::SequenceByteFee(fee.into()),
+            Value::InitBridgeAccountFee(fee) => FeeChangeAction::InitBridgeAccountFee(fee.into()),
+            Value::Ics20WithdrawalFee(fee) => FeeChangeAction::Ics20WithdrawalFee(fee.into()),
+        };
+        Ok(action)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct FeeChangeActionError(FeeChangeActionErrorKind);
+
+impl FeeChangeActionError {
+    #[must_use]
+    fn missing_value() -> Self {
+        Self(FeeChangeActionErrorKind::MissingValue)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum FeeChangeActionErrorKind {
+    #[error("the new fee value was missing")]
+    MissingValue,
+}
+
 #[allow(clippy::module_name_repetitions)]
 #[derive(Debug, Clone)]
 pub struct InitBridgeAccountAction {
@@ -1023,6 +1125,9 @@ pub struct InitBridgeAccountAction {
     pub asset_ids: Vec<asset::Id>,
     // the fee asset which to pay this action's fees with
     pub fee_asset_id: asset::Id,
+    // the address authorized to withdraw funds from the bridge account.
+    // if `None`, the bridge account itself is the withdrawer.
+    pub withdrawer_address: Option<Address>,
 }
 
 impl InitBridgeAccountAction {
@@ -1032,6 +1137,11 @@ impl InitBridgeAccountAction {
             rollup_id: self.rollup_id.to_vec(),
             asset_ids: self.asset_ids.iter().map(|id| id.get().to_vec()).collect(),
             fee_asset_id: self.fee_asset_id.get().to_vec(),
+            withdrawer_address: self
+                .withdrawer_address
+                .as_ref()
+                .map(Address::to_vec)
+                .unwrap_or_default(),
         }
     }
 
@@ -1041,6 +1151,11 @@ impl InitBridgeAccountAction {
             rollup_id: self.rollup_id.to_vec(),
             asset_ids: self.asset_ids.iter().map(|id| id.get().to_vec()).collect(),
             fee_asset_id: self.fee_asset_id.get().to_vec(),
+            withdrawer_address: self
+                .withdrawer_address
+                .as_ref()
+                .map(Address::to_vec)
+                .unwrap_or_default(),
         }
     }
 
@@ -1049,6 +1164,7 @@ impl InitBridgeAccountAction {
     /// # Errors
     ///
     /// - if the `rollup_id` field is invalid
+    /// - if the `withdrawer_address` field is set but invalid
     pub fn try_from_raw(
         proto: raw::InitBridgeAccountAction,
     ) -> Result<Self, InitBridgeAccountActionError> {
@@ -1062,11 +1178,20 @@ impl InitBridgeAccountAction {
             .map_err(InitBridgeAccountActionError::invalid_asset_id)?;
         let fee_asset_id = asset::Id::try_from_slice(&proto.fee_asset_id)
             .map_err(InitBridgeAccountActionError::invalid_fee_asset_id)?;
+        let withdrawer_address = if proto.withdrawer_address.is_empty() {
+            None
+        } else {
+            Some(
+                Address::try_from_slice(&proto.withdrawer_address)
+                    .map_err(InitBridgeAccountActionError::invalid_withdrawer_address)?,
+            )
+        };
 
         Ok(Self {
             rollup_id,
             asset_ids,
             fee_asset_id,
+            withdrawer_address,
         })
     }
 }
@@ -1090,6 +1215,13 @@ impl InitBridgeAccountActionError {
     fn invalid_fee_asset_id(err: asset::IncorrectAssetIdLength) -> Self {
         Self(InitBridgeAccountActionErrorKind::InvalidFeeAssetId(err))
     }
+
+    #[must_use]
+    fn invalid_withdrawer_address(err: IncorrectAddressLength) -> Self {
+        Self(InitBridgeAccountActionErrorKind::InvalidWithdrawerAddress(
+            err,
+        ))
+    }
 }
 
 // allow pedantic clippy as the errors have the same prefix (for consistency
@@ -1104,6 +1236,8 @@ enum InitBridgeAccountActionErrorKind {
     InvalidAssetId(#[source] asset::IncorrectAssetIdLength),
     #[error("the `fee_asset_id` field was invalid")]
     InvalidFeeAssetId(#[source] asset::IncorrectAssetIdLength),
+    #[error("the `withdrawer_address` field was invalid")]
+    InvalidWithdrawerAddress(#[source] IncorrectAddressLength),
 }
 
 #[allow(clippy::module_name_repetitions)]
@@ -1206,3 +1340,121 @@ enum BridgeLockActionErrorKind {
     #[error("the `fee_asset_id` field was invalid")]
     InvalidFeeAssetId(#[source] asset::IncorrectAssetIdLength),
 }
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone)]
+pub struct BridgeUnlockAction {
+    // the bridge account to transfer funds out of.
+    pub bridge_address: Address,
+    // the address to transfer the funds to.
+    pub to: Address,
+    pub amount: u128,
+    // asset to be transferred.
+    pub asset_id: asset::Id,
+    // asset to use for fee payment.
+    pub fee_asset_id: asset::Id,
+    // the identifier of the rollup withdrawal event which resulted in this unlock.
+    pub rollup_withdrawal_event_id: String,
+}
+
+impl BridgeUnlockAction {
+    #[must_use]
+    pub fn into_raw(self) -> raw::BridgeUnlockAction {
+        raw::BridgeUnlockAction {
+            bridge_address: self.bridge_address.to_vec(),
+            to: self.to.to_vec(),
+            amount: Some(self.amount.into()),
+            asset_id: self.asset_id.get().to_vec(),
+            fee_asset_id: self.fee_asset_id.get().to_vec(),
+            rollup_withdrawal_event_id: self.rollup_withdrawal_event_id,
+        }
+    }
+
+    #[must_use]
+    pub fn to_raw(&self) -> raw::BridgeUnlockAction {
+        raw::BridgeUnlockAction {
+            bridge_address: self.bridge_address.to_vec(),
+            to: self.to.to_vec(),
+            amount: Some(self.amount.into()),
+            asset_id: self.asset_id.get().to_vec(),
+            fee_asset_id: self.fee_asset_id.get().to_vec(),
+            rollup_withdrawal_event_id: self.rollup_withdrawal_event_id.clone(),
+        }
+    }
+
+    /// Convert from a raw, unchecked protobuf [`raw::BridgeUnlockAction`].
+    ///
+    /// # Errors
+    ///
+    /// - if the `bridge_address` field is invalid
+    /// - if the `to` field is invalid
+    /// - if the `amount` field is not set
+    /// - if the `asset_id` field is invalid
+    /// - if the `fee_asset_id` field is invalid
+    pub fn try_from_raw(proto: raw::BridgeUnlockAction) -> Result<Self, BridgeUnlockActionError> {
+        let bridge_address = Address::try_from_slice(&proto.bridge_address)
+            .map_err(BridgeUnlockActionError::invalid_bridge_address)?;
+        let to =
+            Address::try_from_slice(&proto.to).map_err(BridgeUnlockActionError::invalid_address)?;
+        let amount = proto
+            .amount
+            .ok_or(BridgeUnlockActionError::missing_amount())?;
+        let asset_id = asset::Id::try_from_slice(&proto.asset_id)
+            .map_err(BridgeUnlockActionError::invalid_asset_id)?;
+        let fee_asset_id = asset::Id::try_from_slice(&proto.fee_asset_id)
+            .map_err(BridgeUnlockActionError::invalid_fee_asset_id)?;
+        Ok(Self {
+            bridge_address,
+            to,
+            amount: amount.into(),
+            asset_id,
+            fee_asset_id,
+            rollup_withdrawal_event_id: proto.rollup_withdrawal_event_id,
+        })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct BridgeUnlockActionError(BridgeUnlockActionErrorKind);
+
+impl BridgeUnlockActionError {
+    #[must_use]
+    fn invalid_bridge_address(err: IncorrectAddressLength) -> Self {
+        Self(BridgeUnlockActionErrorKind::InvalidBridgeAddress(err))
+    }
+
+    #[must_use]
+    fn invalid_address(err: IncorrectAddressLength) -> Self {
+        Self(BridgeUnlockActionErrorKind::InvalidAddress(err))
+    }
+
+    #[must_use]
+    fn missing_amount() -> Self {
+        Self(BridgeUnlockActionErrorKind::MissingAmount)
+    }
+
+    #[must_use]
+    fn invalid_asset_id(err: asset::IncorrectAssetIdLength) -> Self {
+        Self(BridgeUnlockActionErrorKind::InvalidAssetId(err))
+    }
+
+    #[must_use]
+    fn invalid_fee_asset_id(err: asset::IncorrectAssetIdLength) -> Self {
+        Self(BridgeUnlockActionErrorKind::InvalidFeeAssetId(err))
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum BridgeUnlockActionErrorKind {
+    #[error("the `bridge_address` field was invalid")]
+    InvalidBridgeAddress(#[source] IncorrectAddressLength),
+    #[error("the `to` field was invalid")]
+    InvalidAddress(#[source] IncorrectAddressLength),
+    #[error("the `amount` field was not set")]
+    MissingAmount,
+    #[error("the `asset_id` field was invalid")]
+    InvalidAssetId(#[source] asset::IncorrectAssetIdLength),
+    #[error("the `fee_asset_id` field was invalid")]
+    InvalidFeeAssetId(#[source] asset::IncorrectAssetIdLength),
+}
@@ -173,6 +173,16 @@ impl SignedTransaction {
         &self.transaction.actions
     }
 
+    #[must_use]
+    pub fn nonce(&self) -> u32 {
+        self.transaction.params.nonce
+    }
+
+    #[must_use]
+    pub fn chain_id(&self) -> &str {
+        &self.transaction.params.chain_id
+    }
+
     #[must_use]
     pub fn signature(&self) -> Signature {
         self.signature
@@ -192,8 +202,8 @@ impl SignedTransaction {
 #[derive(Clone, Debug)]
 #[allow(clippy::module_name_repetitions)]
 pub struct UnsignedTransaction {
-    pub nonce: u32,
     pub actions: Vec<Action>,
+    pub params: TransactionParams,
 }
 
 impl UnsignedTransaction {
@@ -211,25 +221,25 @@ impl UnsignedTransaction {
 
     pub fn into_raw(self) -> raw::UnsignedTransaction {
         let Self {
-            nonce,
             actions,
+            params,
         } = self;
         let actions = actions.into_iter().map(Action::into_raw).collect();
         raw::UnsignedTransaction {
-            nonce,
             actions,
+            params: Some(params.into_raw()),
         }
     }
 
     pub fn to_raw(&self) -> raw::UnsignedTransaction {
         let Self {
-            nonce,
             actions,
+            params,
         } = self;
         let actions = actions.iter().map(Action::to_raw).collect();
         raw::UnsignedTransaction {
-            nonce: *nonce,
             actions,
+            params: Some(params.to_raw()),
         }
     }
 
@@ -238,12 +248,16 @@ impl UnsignedTransaction {
     /// # Errors
     ///
     /// Returns an error if one of the inner raw actions could not be converted to a native
-    /// [`Action`].
+    /// [`Action`], or if the `params` field was not set.
     pub fn try_from_raw(proto: raw::UnsignedTransaction) -> Result<Self, UnsignedTransactionError> {
         let raw::UnsignedTransaction {
-            nonce,
             actions,
+            params,
         } = proto;
+        let Some(params) = params else {
+            return Err(UnsignedTransactionError::unset_params());
+        };
+        let params = TransactionParams::from_raw(params);
         let actions: Vec<_> = actions
             .into_iter()
             .map(Action::try_from_raw)
@@ -251,8 +265,8 @@ impl UnsignedTransaction {
             .map_err(UnsignedTransactionError::action)?;
 
         Ok(Self {
-            nonce,
             actions,
+            params,
         })
     }
 }
@@ -265,12 +279,63 @@ impl UnsignedTransactionError {
     fn action(inner: action::ActionError) -> Self {
         Self(UnsignedTransactionErrorKind::Action(inner))
     }
+
+    fn unset_params() -> Self {
+        Self(UnsignedTransactionErrorKind::UnsetParams)
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
 enum UnsignedTransactionErrorKind {
     #[error("`actions` field is invalid")]
     Action(#[source] action::ActionError),
+    #[error("`params` field is unset")]
+    UnsetParams,
+}
+
+/// Parameters that define the validity of an [`UnsignedTransaction`].
+///
+/// The `chain_id` binds the transaction to a single network, so that a transaction
+/// signed for one chain cannot be replayed on another.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransactionParams {
+    pub nonce: u32,
+    pub chain_id: String,
+}
+
+impl TransactionParams {
+    #[must_use]
+    pub fn into_raw(self) -> raw::TransactionParams {
+        let Self {
+            nonce,
+            chain_id,
+        } = self;
+        raw::TransactionParams {
+            nonce,
+            chain_id,
+        }
+    }
+
+    #[must_use]
+    pub fn to_raw(&self) -> raw::TransactionParams {
+        self.clone().into_raw()
+    }
+
+    /// Convert from a raw protobuf [`raw::TransactionParams`].
+    ///
+    /// This conversion is infallible: any chain ID is accepted here and is only
+    /// checked against the chain the transaction is submitted to.
+    #[must_use]
+    pub fn from_raw(proto: raw::TransactionParams) -> Self {
+        let raw::TransactionParams {
+            nonce,
+            chain_id,
+        } = proto;
+        Self {
+            nonce,
+            chain_id,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -296,8 +361,8 @@ mod test {
             227, 96, 127, 152, 22, 47, 146, 10,
         ]);
         let expected_hash: [u8; 32] = [
-            208, 68, 247, 226, 65, 50, 227, 180, 178, 51, 28, 119, 212, 205, 148, 83, 27, 229, 238,
-            45, 192, 139, 169, 239, 16, 3, 103, 132, 220, 87, 150, 229,
+            138, 81, 95, 9, 243, 145, 190, 66, 35, 242, 220, 189, 156, 19, 226, 29, 20, 57, 40,
+            115, 243, 38, 37, 83, 118, 5, 246, 87, 128, 118, 131, 173,
         ];
 
         let transfer = TransferAction {
@@ -308,8 +373,11 @@ mod test {
         };
 
         let unsigned = UnsignedTransaction {
-            nonce: 0,
             actions: vec![transfer.into()],
+            params: TransactionParams {
+                nonce: 0,
+                chain_id: "test".to_string(),
+            },
         };
 
         let tx = SignedTransaction {
@@ -320,4 +388,25 @@ mod test {
 
         assert_eq!(tx.sha256_of_proto_encoding(), expected_hash);
     }
+
+    #[test]
+    fn transaction_encoded_before_params_is_rejected() {
+        use prost::Message as _;
+
+        let transfer = TransferAction {
+            to: Address::from([0; 20]),
+            amount: 0,
+            asset_id: default_native_asset_id(),
+            fee_asset_id: default_native_asset_id(),
+        };
+        // the former encoding of `nonce = 1` and `repeated Action actions = 2`
+        let mut bytes = Vec::new();
+        prost::encoding::uint32::encode(1, &5, &mut bytes);
+        prost::encoding::message::encode(2, &Action::from(transfer).into_raw(), &mut bytes);
+
+        let raw = raw::UnsignedTransaction::decode(&*bytes).unwrap();
+        assert!(raw.actions.is_empty());
+        UnsignedTransaction::try_from_raw(raw)
+            .expect_err("a transaction without params must be rejected");
+    }
 }
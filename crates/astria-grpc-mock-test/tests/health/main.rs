@@ -268,3 +268,39 @@ async fn incorrect_mock_response_fails_guard() {
 
     let _ = join!(guard.wait_until_satisfied(), check);
 }
+
+#[tokio::test]
+async fn error_response_up_to_n_times_falls_through_to_next_mock() {
+    let server = start_mock_server().await;
+    let mut client = HealthClient::connect(format!("http://{}", server.local_addr))
+        .await
+        .unwrap();
+    let expected_response = HealthCheckResponse {
+        status: 1,
+    };
+    let error_mock = Mock::for_rpc_given("check", matcher::message_type::<HealthCheckRequest>())
+        .respond_with(response::error_response(tonic::Code::Unavailable))
+        .up_to_n_times(1)
+        .expect(1);
+    server.mocked.register(error_mock).await;
+    let mock = Mock::for_rpc_given("check", matcher::message_type::<HealthCheckRequest>())
+        .respond_with(response::constant_response(expected_response.clone()))
+        .expect(1);
+    server.mocked.register(mock).await;
+
+    let err = client
+        .check(HealthCheckRequest {
+            service: "helloworld".to_string(),
+        })
+        .await
+        .unwrap_err();
+    assert_eq!(tonic::Code::Unavailable, err.code());
+    let rsp = client
+        .check(HealthCheckRequest {
+            service: "helloworld".to_string(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(&expected_response, rsp.get_ref());
+    server.mocked.verify().await;
+}
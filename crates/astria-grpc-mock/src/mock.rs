@@ -34,6 +34,7 @@ pub struct Mock {
     pub(crate) matchers: Vec<Matcher>,
     pub(crate) response: Box<dyn Respond>,
     pub(crate) expectation_range: Times,
+    pub(crate) max_n_matches: Option<usize>,
     pub(crate) name: Option<String>,
 }
 
@@ -52,6 +53,16 @@ impl Mock {
         self
     }
 
+    /// Makes the mock stop matching requests after it has responded `n` times.
+    ///
+    /// Subsequent requests are handled by the other mocks registered on the server,
+    /// which allows responding differently to the first `n` requests.
+    #[must_use = "a mock must be mounted on a server to be useful"]
+    pub fn up_to_n_times(mut self, n: usize) -> Self {
+        self.max_n_matches.replace(n);
+        self
+    }
+
     #[must_use = "a mock must be mounted on a server to be useful"]
     pub fn with_name(mut self, name: impl Into<String>) -> Self {
         self.name.replace(name.into());
@@ -89,6 +100,7 @@ impl MockBuilder {
             response: Box::new(rsp),
             name: None,
             expectation_range: Times(TimesEnum::Unbounded(RangeFull)),
+            max_n_matches: None,
         }
     }
 }
@@ -122,6 +122,12 @@ impl MountedMock {
             return MockResult::NoMatch;
         }
 
+        if let Some(max_n_matches) = self.inner.max_n_matches {
+            if self.successful_response.len() + self.bad_responses.len() >= max_n_matches {
+                return MockResult::NoMatch;
+            }
+        }
+
         let response = match self.inner.response.respond(request) {
             Err(status) => {
                 self.successful_response
@@ -54,6 +54,23 @@ impl Respond for DefaultResponse {
     }
 }
 
+#[must_use]
+pub fn error_response(code: tonic::Code) -> ErrorResponse {
+    ErrorResponse {
+        status: tonic::Status::new(code, "error returned by mock"),
+    }
+}
+
+pub struct ErrorResponse {
+    status: tonic::Status,
+}
+
+impl Respond for ErrorResponse {
+    fn respond(&self, _req: &tonic::Request<AnyMessage>) -> ResponseResult {
+        Err(self.status.clone())
+    }
+}
+
 pub struct MockResponse {
     pub(crate) type_name: &'static str,
     pub(crate) inner: tonic::Response<AnyMessage>,
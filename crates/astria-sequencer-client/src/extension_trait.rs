@@ -27,6 +27,7 @@ use std::{
 };
 
 pub use astria_core::sequencer::v1::{
+    asset::AllowedFeeAssetIdsResponse,
     block::SequencerBlockError,
     Address,
     BalanceResponse,
@@ -87,6 +88,7 @@ impl std::error::Error for Error {
         match &self.inner {
             ErrorKind::AbciQueryDeserialization(e) => Some(e),
             ErrorKind::CometBftConversion(e) => Some(e),
+            ErrorKind::Deserialization(e) => Some(e),
             ErrorKind::TendermintRpc(e) => Some(e),
         }
     }
@@ -118,6 +120,19 @@ impl Error {
         }
     }
 
+    /// Convenience function to construct `Error` containing a `DeserializationError`.
+    fn deserialization(
+        target: &'static str,
+        inner: impl std::error::Error + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            inner: ErrorKind::Deserialization(DeserializationError {
+                inner: Arc::new(inner),
+                target,
+            }),
+        }
+    }
+
     fn cometbft_conversion(e: SequencerBlockError) -> Self {
         Self {
             inner: ErrorKind::CometBftConversion(e),
@@ -249,6 +264,7 @@ impl std::error::Error for DeserializationError {
 pub enum ErrorKind {
     AbciQueryDeserialization(AbciQueryDeserializationError),
     CometBftConversion(SequencerBlockError),
+    Deserialization(DeserializationError),
     TendermintRpc(TendermintRpcError),
 }
 
@@ -526,6 +542,73 @@ pub trait SequencerClientExt: Client {
         self.get_nonce(address, 0u32).await
     }
 
+    /// Returns the IDs of the assets accepted as fee payment at the given height.
+    ///
+    /// # Errors
+    ///
+    /// - If calling tendermint `abci_query` RPC fails.
+    /// - If the bytes contained in the abci query response cannot be read as an
+    ///   `astria.sequencer.v1.AllowedFeeAssetIdsResponse`.
+    /// - If any of the asset IDs in the response is not 32 bytes long.
+    async fn get_allowed_fee_asset_ids<HeightT>(
+        &self,
+        height: HeightT,
+    ) -> Result<AllowedFeeAssetIdsResponse, Error>
+    where
+        HeightT: Into<tendermint::block::Height> + Send,
+    {
+        let response = self
+            .abci_query(
+                Some("asset/allowed_fee_asset_ids".to_string()),
+                vec![],
+                Some(height.into()),
+                false,
+            )
+            .await
+            .map_err(|e| Error::tendermint_rpc("abci_query", e))?;
+
+        let proto_response =
+            astria_core::generated::sequencer::v1::AllowedFeeAssetIdsResponse::decode(
+                &*response.value,
+            )
+            .map_err(|e| {
+                Error::abci_query_deserialization(
+                    "astria.sequencer.v1.AllowedFeeAssetIdsResponse",
+                    response,
+                    e,
+                )
+            })?;
+        AllowedFeeAssetIdsResponse::try_from_raw(&proto_response)
+            .map_err(|e| Error::deserialization("AllowedFeeAssetIdsResponse", e))
+    }
+
+    /// Returns the IDs of the assets accepted as fee payment at the latest height.
+    ///
+    /// # Errors
+    ///
+    /// This has the same error conditions as [`SequencerClientExt::get_allowed_fee_asset_ids`].
+    async fn get_latest_allowed_fee_asset_ids(&self) -> Result<AllowedFeeAssetIdsResponse, Error> {
+        // This makes use of the fact that a height `None` and `Some(0)` are
+        // treated the same.
+        self.get_allowed_fee_asset_ids(0u32).await
+    }
+
+    /// Returns the ID of the chain the sequencer node is running.
+    ///
+    /// This is a convenience method that returns the network reported by [`Client::status`].
+    /// It is intended for filling in the chain ID of transactions before signing them.
+    ///
+    /// # Errors
+    ///
+    /// - If calling the tendermint RPC endpoint fails.
+    async fn get_chain_id(&self) -> Result<String, Error> {
+        let rsp = self
+            .status()
+            .await
+            .map_err(|e| Error::tendermint_rpc("status", e))?;
+        Ok(rsp.node_info.network.to_string())
+    }
+
     /// Get the latest sequencer block.
     ///
     /// This is a convenience method that converts the result [`Client::latest_block`]
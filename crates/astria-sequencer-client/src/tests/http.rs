@@ -3,6 +3,7 @@ use astria_core::sequencer::v1::{
     transaction::action::TransferAction,
     Address,
     SignedTransaction,
+    TransactionParams,
     UnsignedTransaction,
 };
 use ed25519_consensus::SigningKey;
@@ -133,8 +134,11 @@ fn create_signed_transaction() -> SignedTransaction {
         .into(),
     ];
     UnsignedTransaction {
-        nonce: 1,
         actions,
+        params: TransactionParams {
+            nonce: 1,
+            chain_id: "test".to_string(),
+        },
     }
     .into_signed(&alice_key)
 }
@@ -192,6 +196,34 @@ async fn get_latest_balance() {
     assert_eq!(expected_response, actual_response);
 }
 
+#[tokio::test]
+async fn get_latest_allowed_fee_asset_ids() {
+    use astria_core::generated::sequencer::v1::AllowedFeeAssetIdsResponse;
+
+    let MockSequencer {
+        server,
+        client,
+    } = MockSequencer::start().await;
+
+    let expected_response = AllowedFeeAssetIdsResponse {
+        height: 10,
+        fee_asset_ids: vec![default_native_asset_id().get().to_vec()],
+    };
+    let _guard = register_abci_query_response(
+        &server,
+        "asset/allowed_fee_asset_ids",
+        expected_response.clone(),
+    )
+    .await;
+
+    let actual_response = client
+        .get_latest_allowed_fee_asset_ids()
+        .await
+        .unwrap()
+        .into_raw();
+    assert_eq!(expected_response, actual_response);
+}
+
 #[tokio::test]
 async fn submit_tx_sync() {
     let MockSequencer {
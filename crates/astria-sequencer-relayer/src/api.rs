@@ -1,8 +1,12 @@
-use std::net::SocketAddr;
+use std::{
+    net::SocketAddr,
+    sync::Arc,
+};
 
 use axum::{
     extract::{
         FromRef,
+        Query,
         State,
     },
     response::{
@@ -18,8 +22,16 @@ use axum::{
 };
 use http::status::StatusCode;
 use hyper::server::conn::AddrIncoming;
-use serde::Serialize;
+use sequencer_client::tendermint::block::Height as SequencerHeight;
+use serde::{
+    Deserialize,
+    Serialize,
+};
 use tokio::sync::watch;
+use tracing::{
+    warn,
+    Instrument as _,
+};
 
 use crate::relayer;
 
@@ -31,6 +43,7 @@ type RelayerState = watch::Receiver<relayer::StateSnapshot>;
 /// `AppState` is used for as an axum extractor in its method handlers.
 struct AppState {
     relayer_state: RelayerState,
+    journal: Arc<relayer::Journal>,
 }
 
 impl FromRef<AppState> for RelayerState {
@@ -39,13 +52,25 @@ impl FromRef<AppState> for RelayerState {
     }
 }
 
-pub(crate) fn start(socket_addr: SocketAddr, relayer_state: RelayerState) -> ApiServer {
+impl FromRef<AppState> for Arc<relayer::Journal> {
+    fn from_ref(app_state: &AppState) -> Self {
+        app_state.journal.clone()
+    }
+}
+
+pub(crate) fn start(
+    socket_addr: SocketAddr,
+    relayer_state: RelayerState,
+    journal: Arc<relayer::Journal>,
+) -> ApiServer {
     let app = Router::new()
         .route("/healthz", get(get_healthz))
         .route("/readyz", get(get_readyz))
         .route("/status", get(get_status))
+        .route("/submissions", get(get_submissions))
         .with_state(AppState {
             relayer_state,
+            journal,
         });
     axum::Server::bind(&socket_addr).serve(app.into_make_service())
 }
@@ -80,6 +105,68 @@ async fn get_status(State(relayer_state): State<RelayerState>) -> Json<relayer::
     Json(relayer_state.borrow().clone())
 }
 
+#[derive(Debug, Deserialize)]
+struct SubmissionsQuery {
+    sequencer_height: u64,
+}
+
+/// Handler of a call to `/submissions?sequencer_height=<number>`.
+///
+/// Returns the submission to Celestia recorded in the journal that holds the sequencer block at
+/// the requested height.
+async fn get_submissions(
+    State(journal): State<Arc<relayer::Journal>>,
+    Query(query): Query<SubmissionsQuery>,
+) -> Submissions {
+    let Ok(sequencer_height) = SequencerHeight::try_from(query.sequencer_height) else {
+        return Submissions::InvalidHeight;
+    };
+    let submission = crate::utils::flatten(
+        tokio::task::spawn_blocking(move || journal.submission_at(sequencer_height))
+            .in_current_span()
+            .await,
+    );
+    match submission {
+        Ok(Some(submission)) => Submissions::Found(submission),
+        Ok(None) => Submissions::NotFound,
+        Err(error) => {
+            warn!(%sequencer_height, %error, "failed reading submission from journal");
+            Submissions::JournalUnreadable
+        }
+    }
+}
+
+enum Submissions {
+    Found(relayer::Submission),
+    NotFound,
+    InvalidHeight,
+    JournalUnreadable,
+}
+
+impl IntoResponse for Submissions {
+    fn into_response(self) -> Response {
+        #[derive(Debug, Serialize)]
+        struct SubmissionsBody {
+            status: &'static str,
+        }
+        let (status, msg) = match self {
+            Self::Found(submission) => return Json(submission).into_response(),
+            Self::NotFound => (StatusCode::NOT_FOUND, "not found"),
+            Self::InvalidHeight => (StatusCode::BAD_REQUEST, "invalid sequencer height"),
+            Self::JournalUnreadable => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "failed reading submission from journal",
+            ),
+        };
+        let mut response = Json(SubmissionsBody {
+            status: msg,
+        })
+        .into_response();
+        *response.status_mut() = status;
+        response
+    }
+}
+
 enum Healthz {
     Ok,
     Degraded,
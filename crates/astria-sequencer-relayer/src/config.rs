@@ -32,10 +32,45 @@ pub struct Config {
     pub metrics_http_listener_addr: String,
     /// Writes a human readable format to stdout instead of JSON formatted OTEL trace data.
     pub pretty_print: bool,
-    /// The path to which relayer will write its state prior to submitting to Celestia.
+    /// The path to which relayer wrote its state prior to submitting to Celestia before it kept
+    /// a journal. Only read to migrate its state into an empty journal.
     pub pre_submit_path: PathBuf,
-    /// The path to which relayer will write its state after submitting to Celestia.
+    /// The path to which relayer wrote its state after submitting to Celestia before it kept a
+    /// journal. Only read to migrate its state into an empty journal.
     pub post_submit_path: PathBuf,
+    /// The path to the append-only journal recording the submissions to Celestia.
+    pub journal_path: PathBuf,
+    /// A comma-separated list of hex encoded rollup IDs whose blobs are relayed. All rollups are
+    /// relayed if empty.
+    pub rollup_id_allow_list: String,
+    /// A comma-separated list of hex encoded rollup IDs whose blobs are never relayed.
+    pub rollup_id_deny_list: String,
+    /// A comma-separated list of `<rollup_id>::<namespace_id>` pairs setting the Celestia
+    /// namespaces rollup blobs are posted under.
+    pub rollup_namespaces: String,
+    /// The gas price in utia paid by the first attempt to submit blobs to Celestia.
+    pub celestia_base_gas_price: f64,
+    /// The factor the gas price is multiplied with each time a submission to Celestia failed or
+    /// timed out.
+    pub celestia_gas_price_multiplier: f64,
+    /// The max gas price in utia paid for submissions to Celestia.
+    pub celestia_max_gas_price: f64,
+    /// The duration in milliseconds after which a submission to Celestia is abandoned and
+    /// resubmitted.
+    pub celestia_submission_timeout_ms: u64,
+    /// The max number of bytes of blob data submitted to Celestia in a single transaction.
+    /// Sequencer blocks exceeding it are split across several transactions. Must be at least
+    /// 321536, the max size of a single rollup blob, which cannot be split.
+    pub celestia_max_bytes_per_tx: usize,
+    /// Whether blobs are zstd-compressed before being submitted to Celestia. Disabled by
+    /// default, in which case blobs are posted as plain protobuf.
+    pub compress_blobs: bool,
+    /// The first sequencer height to re-relay to Celestia alongside the regular relaying. Set
+    /// together with `backfill_end_height`; setting both to 0 disables the backfill.
+    pub backfill_start_height: u64,
+    /// The last sequencer height to re-relay to Celestia, inclusive. Must not be above the last
+    /// sequencer height submitted to Celestia.
+    pub backfill_end_height: u64,
 }
 
 impl config::Config for Config {
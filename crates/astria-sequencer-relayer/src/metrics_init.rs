@@ -56,6 +56,28 @@ pub fn register() {
          Celestia submission"
     );
 
+    describe_gauge!(
+        BLOB_COMPRESSION_RATIO,
+        "The ratio of the compressed to the uncompressed size of the blobs included in the last \
+         Celestia submission"
+    );
+
+    describe_gauge!(
+        CELESTIA_SUBMISSION_GAS_LIMIT,
+        Unit::Count,
+        "The gas limit of the last attempt to submit blobs to Celestia"
+    );
+
+    describe_gauge!(
+        CELESTIA_SUBMISSION_GAS_PRICE,
+        "The gas price in utia of the last attempt to submit blobs to Celestia"
+    );
+
+    describe_gauge!(
+        CELESTIA_SUBMISSION_FEE,
+        "The fee in utia of the last attempt to submit blobs to Celestia"
+    );
+
     describe_histogram!(
         CELESTIA_SUBMISSION_LATENCY,
         Unit::Seconds,
@@ -84,6 +106,18 @@ pub const BLOCKS_PER_CELESTIA_TX: &str =
 
 pub const BLOBS_PER_CELESTIA_TX: &str = concat!(env!("CARGO_CRATE_NAME"), "_blobs_per_celestia_tx");
 
+pub const BLOB_COMPRESSION_RATIO: &str =
+    concat!(env!("CARGO_CRATE_NAME"), "_blob_compression_ratio");
+
+pub const CELESTIA_SUBMISSION_GAS_LIMIT: &str =
+    concat!(env!("CARGO_CRATE_NAME"), "_celestia_submission_gas_limit");
+
+pub const CELESTIA_SUBMISSION_GAS_PRICE: &str =
+    concat!(env!("CARGO_CRATE_NAME"), "_celestia_submission_gas_price");
+
+pub const CELESTIA_SUBMISSION_FEE: &str =
+    concat!(env!("CARGO_CRATE_NAME"), "_celestia_submission_fee");
+
 pub const CELESTIA_SUBMISSION_LATENCY: &str =
     concat!(env!("CARGO_CRATE_NAME"), "_celestia_submission_latency");
 
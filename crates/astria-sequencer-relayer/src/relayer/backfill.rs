@@ -0,0 +1,354 @@
+//! Re-relays a range of sequencer blocks to Celestia.
+//!
+//! A backfill runs alongside the regular relaying of sequencer blocks. For each height in its
+//! range it fetches the sequencer block and looks up the Celestia height the block was posted at
+//! in the journal. If the journal holds no submission of the block, the Celestia heights between
+//! the submissions recorded around it are searched instead. If the block's sequencer blob is
+//! found, the block is skipped. Otherwise it is posted again and the new submission is recorded
+//! in the journal.
+//!
+//! Only sequencer blocks that were already submitted by the regular relaying can be backfilled,
+//! which is checked when the relayer starts.
+//!
+//! A backfill that fails or is interrupted by a shutdown is abandoned without affecting the
+//! regular relaying. Its progress is reported in the relayer's state.
+
+use std::{
+    sync::Arc,
+    time::Duration,
+};
+
+use astria_core::{
+    generated::sequencer::v1::sequencer_service_client::SequencerServiceClient,
+    sequencer::v1::SequencerBlock,
+};
+use astria_eyre::eyre::{
+    self,
+    bail,
+    ensure,
+    WrapErr as _,
+};
+use celestia_client::{
+    jsonrpsee::http_client::HttpClient as CelestiaClient,
+    CelestiaClientExt as _,
+};
+use sequencer_client::tendermint::block::Height as SequencerHeight;
+use tendermint::account;
+use tokio::select;
+use tokio_util::sync::CancellationToken;
+use tracing::{
+    error,
+    info,
+    instrument,
+    Instrument as _,
+};
+
+use super::{
+    read,
+    state::{
+        BackfillStatus,
+        State,
+    },
+    write,
+    Journal,
+};
+
+/// The max number of Celestia heights searched for a sequencer block whose submission was not
+/// recorded in the journal.
+const MAX_CELESTIA_HEIGHTS_TO_SEARCH: u64 = 1000;
+
+/// An inclusive range of sequencer heights to backfill.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) struct BackfillRange {
+    first: SequencerHeight,
+    last: SequencerHeight,
+}
+
+impl BackfillRange {
+    /// Returns the range from `start_height` to `end_height`, inclusive.
+    ///
+    /// Returns `None` if both heights are zero, which disables the backfill.
+    pub(super) fn new(start_height: u64, end_height: u64) -> eyre::Result<Option<Self>> {
+        if start_height == 0 && end_height == 0 {
+            return Ok(None);
+        }
+        ensure!(
+            start_height > 0,
+            "the backfill start height must not be zero if the end height is set"
+        );
+        ensure!(
+            start_height <= end_height,
+            "the backfill start height `{start_height}` is above the end height `{end_height}`"
+        );
+        let first = SequencerHeight::try_from(start_height)
+            .wrap_err("the backfill start height is not a valid sequencer height")?;
+        let last = SequencerHeight::try_from(end_height)
+            .wrap_err("the backfill end height is not a valid sequencer height")?;
+        Ok(Some(Self {
+            first,
+            last,
+        }))
+    }
+
+    /// Ensures that the regular relaying submitted the sequencer blocks up to the end of the
+    /// range, given the `last_submitted_height`.
+    pub(super) fn ensure_submitted(
+        &self,
+        last_submitted_height: Option<SequencerHeight>,
+    ) -> eyre::Result<()> {
+        let Some(last_submitted_height) = last_submitted_height else {
+            bail!(
+                "no sequencer block was submitted to celestia yet, so the sequencer blocks up to \
+                 the backfill end height `{}` cannot be backfilled",
+                self.last,
+            );
+        };
+        ensure!(
+            self.last <= last_submitted_height,
+            "the backfill end height `{}` is above the last sequencer height submitted to \
+             celestia `{last_submitted_height}`",
+            self.last,
+        );
+        Ok(())
+    }
+}
+
+enum Outcome {
+    Skipped,
+    Relayed,
+}
+
+pub(super) struct Backfill {
+    pub(super) range: BackfillRange,
+    pub(super) sequencer_grpc_client: SequencerServiceClient<tonic::transport::Channel>,
+    pub(super) sequencer_poll_period: Duration,
+    pub(super) celestia_client: CelestiaClient,
+    pub(super) validator_address: Option<account::Id>,
+    pub(super) converter: Arc<write::Converter>,
+    pub(super) gas_pricing: write::GasPricing,
+    pub(super) celestia_max_bytes_per_tx: usize,
+    pub(super) journal: Arc<Journal>,
+    pub(super) state: Arc<State>,
+    pub(super) shutdown_token: CancellationToken,
+}
+
+impl Backfill {
+    #[instrument(skip_all, fields(
+        first_sequencer_height = %self.range.first,
+        last_sequencer_height = %self.range.last,
+    ))]
+    pub(super) async fn run(self) {
+        let mut status = BackfillStatus {
+            first_sequencer_height: self.range.first.value(),
+            last_sequencer_height: self.range.last.value(),
+            latest_backfilled_sequencer_height: None,
+            num_skipped: 0,
+            num_relayed: 0,
+            done: false,
+            error: None,
+        };
+        self.state.set_backfill_status(status.clone());
+        info!("starting backfill of sequencer blocks");
+
+        let mut height = self.range.first;
+        loop {
+            let outcome = select!(
+                biased;
+
+                () = self.shutdown_token.cancelled() => {
+                    info!("received shutdown signal; abandoning backfill");
+                    return;
+                }
+
+                outcome = self.backfill_height(height) => outcome,
+            );
+            match outcome {
+                Ok(Outcome::Skipped) => status.num_skipped += 1,
+                Ok(Outcome::Relayed) => status.num_relayed += 1,
+                Err(error) => {
+                    error!(
+                        %height,
+                        %error,
+                        "failed backfilling sequencer block; abandoning backfill",
+                    );
+                    status.error = Some(format!("{error:#}"));
+                    self.state.set_backfill_status(status);
+                    return;
+                }
+            }
+            status.latest_backfilled_sequencer_height = Some(height.value());
+            if height >= self.range.last {
+                status.done = true;
+                info!(
+                    num_skipped = status.num_skipped,
+                    num_relayed = status.num_relayed,
+                    "finished backfill of sequencer blocks",
+                );
+                self.state.set_backfill_status(status);
+                return;
+            }
+            self.state.set_backfill_status(status.clone());
+            height = height.increment();
+        }
+    }
+
+    /// Relays the sequencer block at `height` unless it is found on Celestia.
+    #[instrument(skip_all, fields(%height))]
+    async fn backfill_height(&self, height: SequencerHeight) -> eyre::Result<Outcome> {
+        let block = read::fetch_block(
+            self.sequencer_grpc_client.clone(),
+            height,
+            self.sequencer_poll_period,
+            self.state.clone(),
+        )
+        .await
+        .wrap_err("failed fetching sequencer block")?;
+        let proposer_address = block.header().cometbft_header().proposer_address;
+        if self
+            .validator_address
+            .is_some_and(|address| address != proposer_address)
+        {
+            info!(
+                address.block_proposer = %proposer_address,
+                "block proposer does not match internal validator; skipping",
+            );
+            return Ok(Outcome::Skipped);
+        }
+        if self.is_on_celestia(&block).await? {
+            info!("sequencer block was found on Celestia; skipping");
+            return Ok(Outcome::Skipped);
+        }
+
+        let submission = write::submit_backfilled_block(
+            &self.celestia_client,
+            block,
+            self.converter.clone(),
+            self.celestia_max_bytes_per_tx,
+            &self.state,
+            self.gas_pricing,
+        )
+        .await?;
+        let journal = self.journal.clone();
+        crate::utils::flatten(
+            tokio::task::spawn_blocking(move || journal.record_backfill(submission))
+                .in_current_span()
+                .await,
+        )
+        .wrap_err("failed recording backfilled submission in journal")?;
+        Ok(Outcome::Relayed)
+    }
+
+    /// Returns if the sequencer blob of `block` is found on Celestia.
+    ///
+    /// If the journal holds a submission of `block`, only the Celestia height of its latest
+    /// submission is searched. Otherwise the Celestia heights between the submissions recorded
+    /// right below and above `block` are searched, up to [`MAX_CELESTIA_HEIGHTS_TO_SEARCH`] of
+    /// them.
+    ///
+    /// Celestia heights holding no blobs under the sequencer namespace, which is also the case
+    /// if the data was pruned, do not hold the block. Any other failure to fetch the sequencer
+    /// blobs is an error.
+    async fn is_on_celestia(&self, block: &SequencerBlock) -> eyre::Result<bool> {
+        let journal = self.journal.clone();
+        let sequencer_height = block.height();
+        let submission = crate::utils::flatten(
+            tokio::task::spawn_blocking(move || journal.submission_at(sequencer_height))
+                .in_current_span()
+                .await,
+        )
+        .wrap_err("failed reading submission of sequencer block from journal")?;
+        let celestia_heights = if let Some(submission) = submission {
+            submission.celestia_height()..=submission.celestia_height()
+        } else {
+            let celestia_heights = self.celestia_heights_to_search(sequencer_height).await?;
+            info!(
+                first_celestia_height = celestia_heights.start(),
+                last_celestia_height = celestia_heights.end(),
+                "no submission of the sequencer block was recorded in the journal; searching \
+                 Celestia for it",
+            );
+            celestia_heights
+        };
+        let namespace = celestia_client::celestia_namespace_v0_from_cometbft_header(
+            block.header().cometbft_header(),
+        );
+        for celestia_height in celestia_heights {
+            match self
+                .celestia_client
+                .get_sequencer_blobs(celestia_height, namespace)
+                .await
+            {
+                Ok(response) => {
+                    if response.sequencer_blobs.iter().any(|sequencer_blob| {
+                        sequencer_blob.height() == block.height()
+                            && sequencer_blob.block_hash() == block.block_hash()
+                    }) {
+                        return Ok(true);
+                    }
+                }
+                Err(error) if celestia_client::is_blob_not_found(&error) => {}
+                Err(error) => {
+                    return Err(error).wrap_err_with(|| {
+                        format!(
+                            "failed fetching sequencer blobs from celestia at height \
+                             `{celestia_height}`"
+                        )
+                    });
+                }
+            }
+        }
+        Ok(false)
+    }
+
+    /// Returns the Celestia heights to search for the sequencer block at `sequencer_height`
+    /// whose submission was not recorded in the journal.
+    async fn celestia_heights_to_search(
+        &self,
+        sequencer_height: SequencerHeight,
+    ) -> eyre::Result<std::ops::RangeInclusive<u64>> {
+        use celestia_client::celestia_rpc::HeaderClient as _;
+
+        let (below, above) = self.journal.celestia_heights_around(sequencer_height);
+        let last = if let Some(above) = above {
+            above
+        } else {
+            self.celestia_client
+                .header_network_head()
+                .await
+                .wrap_err("failed fetching celestia network head")?
+                .height()
+                .value()
+        };
+        let first = below
+            .unwrap_or(1)
+            .max(last.saturating_sub(MAX_CELESTIA_HEIGHTS_TO_SEARCH - 1));
+        Ok(first..=last)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BackfillRange;
+
+    #[test]
+    fn zero_heights_disable_backfill() {
+        assert_eq!(BackfillRange::new(0, 0).unwrap(), None);
+    }
+
+    #[test]
+    fn range_is_inclusive_and_ordered() {
+        let range = BackfillRange::new(3, 3).unwrap().unwrap();
+        assert_eq!(range.first.value(), 3);
+        assert_eq!(range.last.value(), 3);
+        assert!(BackfillRange::new(4, 3).is_err());
+        assert!(BackfillRange::new(0, 3).is_err());
+    }
+
+    #[test]
+    fn range_must_have_been_submitted() {
+        let range = BackfillRange::new(3, 5).unwrap().unwrap();
+        assert!(range.ensure_submitted(None).is_err());
+        assert!(range.ensure_submitted(Some(4u32.into())).is_err());
+        range.ensure_submitted(Some(5u32.into())).unwrap();
+    }
+}
@@ -7,6 +7,7 @@ use std::{
 use astria_core::generated::sequencer::v1::sequencer_service_client::SequencerServiceClient;
 use astria_eyre::eyre::{
     self,
+    ensure,
     WrapErr as _,
 };
 use celestia_client::jsonrpsee::http_client::HttpClient as CelestiaClient;
@@ -16,7 +17,16 @@ use tonic::transport::{
     Uri,
 };
 
-use super::state::State;
+use super::{
+    backfill::BackfillRange,
+    routing::Routing,
+    state::State,
+    submission,
+    write::{
+        self,
+        GasPricing,
+    },
+};
 use crate::validator::Validator;
 
 pub(crate) struct Builder {
@@ -27,8 +37,20 @@ pub(crate) struct Builder {
     pub(crate) sequencer_poll_period: Duration,
     pub(crate) sequencer_grpc_endpoint: String,
     pub(crate) validator_key_path: Option<String>,
+    pub(crate) journal_path: PathBuf,
     pub(crate) pre_submit_path: PathBuf,
     pub(crate) post_submit_path: PathBuf,
+    pub(crate) rollup_id_allow_list: String,
+    pub(crate) rollup_id_deny_list: String,
+    pub(crate) rollup_namespaces: String,
+    pub(crate) celestia_base_gas_price: f64,
+    pub(crate) celestia_gas_price_multiplier: f64,
+    pub(crate) celestia_max_gas_price: f64,
+    pub(crate) celestia_submission_timeout: Duration,
+    pub(crate) celestia_max_bytes_per_tx: usize,
+    pub(crate) compress_blobs: bool,
+    pub(crate) backfill_start_height: u64,
+    pub(crate) backfill_end_height: u64,
 }
 
 impl Builder {
@@ -42,8 +64,20 @@ impl Builder {
             sequencer_grpc_endpoint,
             validator_key_path,
             sequencer_poll_period,
+            journal_path,
             pre_submit_path,
             post_submit_path,
+            rollup_id_allow_list,
+            rollup_id_deny_list,
+            rollup_namespaces,
+            celestia_base_gas_price,
+            celestia_gas_price_multiplier,
+            celestia_max_gas_price,
+            celestia_submission_timeout,
+            celestia_max_bytes_per_tx,
+            compress_blobs,
+            backfill_start_height,
+            backfill_end_height,
         } = self;
         let sequencer_cometbft_client = SequencerClient::new(&*cometbft_endpoint)
             .wrap_err("failed constructing cometbft http client")?;
@@ -64,6 +98,39 @@ impl Builder {
         let celestia_client = create_celestia_client(celestia_endpoint, &celestia_bearer_token)
             .wrap_err("failed creating client to interact with Celestia Node JSONRPC")?;
 
+        let routing = Routing::parse(
+            &rollup_id_allow_list,
+            &rollup_id_deny_list,
+            &rollup_namespaces,
+        )
+        .wrap_err("failed parsing the rollups to relay and their celestia namespaces")?;
+
+        let gas_pricing = GasPricing::new(
+            celestia_base_gas_price,
+            celestia_gas_price_multiplier,
+            celestia_max_gas_price,
+            celestia_submission_timeout,
+        )
+        .wrap_err("invalid celestia gas pricing")?;
+
+        ensure!(
+            celestia_max_bytes_per_tx >= write::MAX_ROLLUP_BLOB_BYTES,
+            "the max bytes per celestia transaction must not be below {}, the max size of a \
+             single rollup blob",
+            write::MAX_ROLLUP_BLOB_BYTES,
+        );
+
+        let backfill_range = BackfillRange::new(backfill_start_height, backfill_end_height)
+            .wrap_err("invalid range of sequencer heights to backfill")?;
+
+        let journal = submission::open_journal(journal_path, pre_submit_path, post_submit_path)
+            .wrap_err("failed opening the journal of submissions to celestia")?;
+        if let Some(backfill_range) = backfill_range {
+            backfill_range
+                .ensure_submitted(journal.last_submitted_height())
+                .wrap_err("invalid range of sequencer heights to backfill")?;
+        }
+
         let state = Arc::new(State::new());
 
         Ok(super::Relayer {
@@ -74,8 +141,11 @@ impl Builder {
             celestia_client,
             validator,
             state,
-            pre_submit_path,
-            post_submit_path,
+            converter: Arc::new(write::Converter::new(routing, compress_blobs)),
+            gas_pricing,
+            celestia_max_bytes_per_tx,
+            journal: Arc::new(journal),
+            backfill_range,
         })
     }
 }
@@ -1,8 +1,4 @@
 use std::{
-    path::{
-        Path,
-        PathBuf,
-    },
     sync::Arc,
     time::Duration,
 };
@@ -51,8 +47,10 @@ use tracing::{
 
 use crate::validator::Validator;
 
+mod backfill;
 mod builder;
 mod read;
+mod routing;
 mod state;
 mod submission;
 mod write;
@@ -60,6 +58,10 @@ mod write;
 pub(crate) use builder::Builder;
 use state::State;
 pub(crate) use state::StateSnapshot;
+pub(crate) use submission::{
+    Journal,
+    Submission,
+};
 
 use self::submission::SubmissionState;
 
@@ -85,8 +87,20 @@ pub(crate) struct Relayer {
     // A watch channel to track the state of the relayer. Used by the API service.
     state: Arc<State>,
 
-    pre_submit_path: PathBuf,
-    post_submit_path: PathBuf,
+    // Converts sequencer blocks to blobs, selecting and namespacing the relayed rollup blobs.
+    converter: Arc<write::Converter>,
+
+    // The pricing of gas for submissions to celestia.
+    gas_pricing: write::GasPricing,
+
+    // The max number of bytes of blob data submitted to celestia in a single transaction.
+    celestia_max_bytes_per_tx: usize,
+
+    // The journal of submissions to celestia.
+    journal: Arc<Journal>,
+
+    // The range of sequencer heights to re-relay alongside the regular relaying, if any.
+    backfill_range: Option<backfill::BackfillRange>,
 }
 
 impl Relayer {
@@ -94,6 +108,10 @@ impl Relayer {
         self.state.subscribe()
     }
 
+    pub(crate) fn journal(&self) -> Arc<Journal> {
+        self.journal.clone()
+    }
+
     /// Runs the relayer worker.
     ///
     /// # Errors
@@ -102,9 +120,7 @@ impl Relayer {
     /// failed catastrophically (after `u32::MAX` retries).
     #[instrument(skip_all)]
     pub(crate) async fn run(self) -> eyre::Result<()> {
-        let submission_state = read_submission_state(&self.pre_submit_path, &self.post_submit_path)
-            .await
-            .wrap_err("failed reading submission state from files")?;
+        let submission_state = SubmissionState::new(self.journal.clone());
 
         let last_submitted_sequencer_height = submission_state.last_submitted_height();
 
@@ -118,6 +134,9 @@ impl Relayer {
             self.celestia_client.clone(),
             self.state.clone(),
             submission_state,
+            self.converter.clone(),
+            self.gas_pricing,
+            self.celestia_max_bytes_per_tx,
             self.shutdown_token.clone(),
         );
 
@@ -134,6 +153,25 @@ impl Relayer {
             BoxFuture<Result<(), tokio::sync::mpsc::error::SendError<SequencerBlock>>>,
         > = Fuse::terminated();
 
+        let backfill_task = self.backfill_range.map(|range| {
+            tokio::spawn(
+                backfill::Backfill {
+                    range,
+                    sequencer_grpc_client: self.sequencer_grpc_client.clone(),
+                    sequencer_poll_period: self.sequencer_poll_period,
+                    celestia_client: self.celestia_client.clone(),
+                    validator_address: self.validator.as_ref().map(|validator| validator.address),
+                    converter: self.converter.clone(),
+                    gas_pricing: self.gas_pricing,
+                    celestia_max_bytes_per_tx: self.celestia_max_bytes_per_tx,
+                    journal: self.journal.clone(),
+                    state: self.state.clone(),
+                    shutdown_token: self.shutdown_token.clone(),
+                }
+                .run(),
+            )
+        });
+
         self.state.set_ready();
 
         let reason = loop {
@@ -211,6 +249,12 @@ impl Relayer {
         if let Err(error) = submitter_task.await {
             error!(%error, "Celestia submission task failed while waiting for it to exit before shutdown");
         }
+        if let Some(backfill_task) = backfill_task {
+            debug!("waiting for backfill task to exit");
+            if let Err(error) = backfill_task.await {
+                error!(%error, "backfill task failed while waiting for it to exit before shutdown");
+            }
+        }
 
         reason.map(|_| ())
     }
@@ -274,33 +318,24 @@ impl Relayer {
     }
 }
 
-async fn read_submission_state<P1: AsRef<Path>, P2: AsRef<Path>>(
-    pre: P1,
-    post: P2,
-) -> eyre::Result<SubmissionState> {
-    const LEANIENT_CONSISTENCY_CHECK: bool = true;
-    let pre = pre.as_ref().to_path_buf();
-    let post = post.as_ref().to_path_buf();
-    crate::utils::flatten(
-        tokio::task::spawn_blocking(move || {
-            submission::SubmissionState::from_paths::<LEANIENT_CONSISTENCY_CHECK, _, _>(pre, post)
-        })
-        .await,
-    )
-    .wrap_err(
-        "failed reading submission state from the configured pre- and post-submit files. Refer to \
-         the values documented in `local.env.example` of the astria-sequencer-relayer service",
-    )
-}
-
 fn spawn_submitter(
     client: CelestiaClient,
     state: Arc<State>,
     submission_state: submission::SubmissionState,
+    converter: Arc<write::Converter>,
+    gas_pricing: write::GasPricing,
+    celestia_max_bytes_per_tx: usize,
     shutdown_token: CancellationToken,
 ) -> (JoinHandle<eyre::Result<()>>, write::BlobSubmitterHandle) {
-    let (submitter, handle) =
-        write::BlobSubmitter::new(client, state, submission_state, shutdown_token);
+    let (submitter, handle) = write::BlobSubmitter::new(
+        client,
+        state,
+        submission_state,
+        converter,
+        gas_pricing,
+        celestia_max_bytes_per_tx,
+        shutdown_token,
+    );
     (tokio::spawn(submitter.run()), handle)
 }
 
@@ -0,0 +1,177 @@
+//! Selects the rollups whose blobs are relayed to Celestia, and the namespaces they are posted
+//! under.
+//!
+//! All rollups are relayed unless an allow list is configured, in which case only the rollups on
+//! it are relayed. Rollups on the deny list are never relayed, even if they are also allowed.
+//!
+//! Rollup blobs are posted under the v0 namespace derived from their rollup ID unless a custom
+//! namespace is configured for the rollup.
+
+use std::collections::{
+    HashMap,
+    HashSet,
+};
+
+use astria_core::sequencer::v1::RollupId;
+use astria_eyre::eyre::{
+    self,
+    eyre,
+    WrapErr as _,
+};
+use celestia_client::celestia_types::nmt::{
+    Namespace,
+    NS_ID_V0_SIZE,
+};
+
+#[derive(Debug)]
+pub(super) struct Routing {
+    /// The rollups that are relayed. All rollups are relayed if unset.
+    allowed: Option<HashSet<RollupId>>,
+    /// The rollups that are never relayed.
+    denied: HashSet<RollupId>,
+    /// The custom celestia namespaces of rollups.
+    namespaces: HashMap<RollupId, Namespace>,
+}
+
+impl Routing {
+    /// Parses the routing from its config values.
+    ///
+    /// `allow_list` and `deny_list` are comma-separated lists of hex encoded rollup IDs, with an
+    /// empty `allow_list` allowing all rollups. `namespaces` is a comma-separated list of
+    /// `<rollup_id>::<namespace_id>` pairs, with both the rollup ID and the 10 byte v0 namespace
+    /// ID hex encoded.
+    pub(super) fn parse(allow_list: &str, deny_list: &str, namespaces: &str) -> eyre::Result<Self> {
+        let allowed = if allow_list.trim().is_empty() {
+            None
+        } else {
+            Some(parse_rollup_ids(allow_list).wrap_err("failed parsing rollup ID allow list")?)
+        };
+        let denied = parse_rollup_ids(deny_list).wrap_err("failed parsing rollup ID deny list")?;
+        let namespaces = split_list(namespaces)
+            .map(|entry| {
+                parse_namespace_entry(entry)
+                    .wrap_err_with(|| format!("failed parsing rollup namespace entry `{entry}`"))
+            })
+            .collect::<eyre::Result<_>>()?;
+        Ok(Self {
+            allowed,
+            denied,
+            namespaces,
+        })
+    }
+
+    /// Returns if the blobs of `rollup_id` are relayed to Celestia.
+    pub(super) fn is_relayed(&self, rollup_id: RollupId) -> bool {
+        !self.denied.contains(&rollup_id)
+            && self
+                .allowed
+                .as_ref()
+                .map_or(true, |allowed| allowed.contains(&rollup_id))
+    }
+
+    /// Returns the Celestia namespace the blobs of `rollup_id` are posted under.
+    pub(super) fn namespace(&self, rollup_id: RollupId) -> Namespace {
+        self.namespaces
+            .get(&rollup_id)
+            .copied()
+            .unwrap_or_else(|| celestia_client::celestia_namespace_v0_from_rollup_id(rollup_id))
+    }
+}
+
+fn split_list(list: &str) -> impl Iterator<Item = &str> {
+    list.split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+}
+
+fn parse_rollup_ids(list: &str) -> eyre::Result<HashSet<RollupId>> {
+    split_list(list).map(parse_rollup_id).collect()
+}
+
+fn parse_rollup_id(hex_id: &str) -> eyre::Result<RollupId> {
+    let bytes =
+        hex::decode(hex_id).wrap_err_with(|| format!("rollup ID `{hex_id}` is not hex encoded"))?;
+    RollupId::try_from_vec(bytes).wrap_err_with(|| format!("rollup ID `{hex_id}` is invalid"))
+}
+
+fn parse_namespace_entry(entry: &str) -> eyre::Result<(RollupId, Namespace)> {
+    let (rollup_id, namespace_id) = entry
+        .split_once("::")
+        .ok_or_else(|| eyre!("entry is not of the form `<rollup_id>::<namespace_id>`"))?;
+    let rollup_id = parse_rollup_id(rollup_id)?;
+    let namespace_id: [u8; NS_ID_V0_SIZE] = hex::decode(namespace_id)
+        .wrap_err("namespace ID is not hex encoded")?
+        .try_into()
+        .map_err(|bytes: Vec<u8>| {
+            eyre!(
+                "namespace ID must be {NS_ID_V0_SIZE} bytes, but was {} bytes",
+                bytes.len()
+            )
+        })?;
+    Ok((rollup_id, Namespace::const_v0(namespace_id)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rollup_id(byte: u8) -> RollupId {
+        RollupId::new([byte; 32])
+    }
+
+    fn hex_id(byte: u8) -> String {
+        hex::encode([byte; 32])
+    }
+
+    #[test]
+    fn all_rollups_are_relayed_by_default() {
+        let routing = Routing::parse("", "", "").unwrap();
+        assert!(routing.is_relayed(rollup_id(1)));
+        assert_eq!(
+            routing.namespace(rollup_id(1)),
+            celestia_client::celestia_namespace_v0_from_rollup_id(rollup_id(1)),
+        );
+    }
+
+    #[test]
+    fn only_allowed_and_not_denied_rollups_are_relayed() {
+        let allow_list = format!("{},{}", hex_id(1), hex_id(2));
+        let routing = Routing::parse(&allow_list, &hex_id(2), "").unwrap();
+        assert!(routing.is_relayed(rollup_id(1)));
+        assert!(!routing.is_relayed(rollup_id(2)));
+        assert!(!routing.is_relayed(rollup_id(3)));
+
+        let routing = Routing::parse("", &hex_id(2), "").unwrap();
+        assert!(routing.is_relayed(rollup_id(1)));
+        assert!(!routing.is_relayed(rollup_id(2)));
+    }
+
+    #[test]
+    fn configured_namespaces_are_used() {
+        let namespaces = format!("{}::{}", hex_id(1), hex::encode([7u8; 10]));
+        let routing = Routing::parse("", "", &namespaces).unwrap();
+        assert_eq!(
+            routing.namespace(rollup_id(1)),
+            Namespace::const_v0([7; 10])
+        );
+        assert_eq!(
+            routing.namespace(rollup_id(2)),
+            celestia_client::celestia_namespace_v0_from_rollup_id(rollup_id(2)),
+        );
+    }
+
+    #[test]
+    fn malformed_entries_are_rejected() {
+        assert!(Routing::parse("not-hex", "", "").is_err());
+        assert!(Routing::parse("", &hex::encode([1u8; 31]), "").is_err());
+        assert!(Routing::parse("", "", &hex_id(1)).is_err());
+        assert!(
+            Routing::parse(
+                "",
+                "",
+                &format!("{}::{}", hex_id(1), hex::encode([7u8; 11]))
+            )
+            .is_err()
+        );
+    }
+}
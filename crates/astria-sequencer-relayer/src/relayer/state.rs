@@ -41,8 +41,26 @@ forward_setter!(
     [set_latest_fetched_sequencer_height <- u64],
     [set_latest_observed_sequencer_height <- u64],
     [set_latest_requested_sequencer_height <- u64],
+    [set_backfill_status <- BackfillStatus],
 );
 
+/// The progress of re-relaying a range of sequencer blocks to Celestia.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize)]
+pub(super) struct BackfillStatus {
+    pub(super) first_sequencer_height: u64,
+    pub(super) last_sequencer_height: u64,
+    /// The latest sequencer height that was either skipped or relayed.
+    pub(super) latest_backfilled_sequencer_height: Option<u64>,
+    /// The number of sequencer blocks that were found on Celestia or not proposed by the
+    /// validator whose blocks are relayed.
+    pub(super) num_skipped: u64,
+    /// The number of sequencer blocks that were posted to Celestia.
+    pub(super) num_relayed: u64,
+    pub(super) done: bool,
+    /// The reason the backfill was abandoned, if it failed.
+    pub(super) error: Option<String>,
+}
+
 #[derive(Clone, Debug, Default, PartialEq, Eq, serde::Serialize)]
 pub(crate) struct StateSnapshot {
     ready: bool,
@@ -55,6 +73,8 @@ pub(crate) struct StateSnapshot {
     latest_fetched_sequencer_height: Option<u64>,
     latest_observed_sequencer_height: Option<u64>,
     latest_requested_sequencer_height: Option<u64>,
+
+    backfill: Option<BackfillStatus>,
 }
 
 impl StateSnapshot {
@@ -94,6 +114,12 @@ impl StateSnapshot {
         changed
     }
 
+    fn set_backfill_status(&mut self, status: BackfillStatus) -> bool {
+        let changed = self.backfill.as_ref() != Some(&status);
+        self.backfill.replace(status);
+        changed
+    }
+
     /// Sets the celestia connected state to `connected`.
     ///
     /// Returns if the previous state was modified.
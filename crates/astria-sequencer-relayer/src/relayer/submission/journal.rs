@@ -0,0 +1,905 @@
+//! An append-only journal of the submissions of sequencer blocks to Celestia.
+//!
+//! The journal is a file holding one JSON encoded entry per line. Starting a submission, posting
+//! a part of a split sequencer block, and finalizing a submission each append an entry, which is
+//! synced to disk before the relayer continues. Opening the journal replays its entries to
+//! restore the submission that was started but not finalized, if any, and an index of the
+//! finalized submissions. Only the index, which maps sequencer heights to the location of the
+//! entries in the file, is held in memory; looking up a submission reads its entry from disk.
+//!
+//! Entries that no longer take effect, such as those of a submission that was finalized since, are
+//! compacted once they make up at least half of the journal: the entries still in effect are
+//! written to a temporary file that is synced to disk and atomically renamed over the journal. A
+//! crash during compaction leaves either the old or the compacted journal, never a mix of both.
+//!
+//! Sequencer blocks that are re-relayed by a backfill are recorded as separate entries. They do
+//! not take part in the ordering of the regular submissions, but take precedence over them when
+//! looking up where a sequencer block was posted.
+//!
+//! A crash while an entry is written can leave a torn last line. It is truncated when the journal
+//! is opened, which loses nothing that the relayer relied on: an entry only takes effect once it
+//! was fully synced to disk.
+
+use std::{
+    collections::BTreeMap,
+    ffi::OsString,
+    fs::File,
+    io::{
+        BufRead as _,
+        BufReader,
+        BufWriter,
+        Read as _,
+        Seek as _,
+        SeekFrom,
+        Write,
+    },
+    path::{
+        Path,
+        PathBuf,
+    },
+    sync::Mutex,
+};
+
+use astria_core::sequencer::v1::RollupId;
+use astria_eyre::eyre::{
+    self,
+    bail,
+    ensure,
+    WrapErr as _,
+};
+use base64_serde::base64_serde_type;
+use celestia_client::celestia_types::Blob;
+use sequencer_client::tendermint::block::Height as SequencerHeight;
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use tracing::{
+    info,
+    warn,
+};
+
+use super::as_number;
+
+base64_serde_type!(Base64Standard, base64::engine::general_purpose::STANDARD);
+
+/// The number of entries that no longer take effect below which the journal is not compacted.
+const MIN_OBSOLETE_ENTRIES_TO_COMPACT: usize = 1024;
+
+/// A blob posted to Celestia.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub(crate) struct PostedBlob {
+    celestia_height: u64,
+    #[serde(with = "Base64Standard")]
+    namespace: Vec<u8>,
+    #[serde(with = "Base64Standard")]
+    commitment: Vec<u8>,
+}
+
+impl PostedBlob {
+    pub(crate) fn new(celestia_height: u64, blob: &Blob) -> Self {
+        Self {
+            celestia_height,
+            namespace: blob.namespace.as_bytes().to_vec(),
+            commitment: blob.commitment.0.to_vec(),
+        }
+    }
+}
+
+/// A part of a sequencer block that was split across several Celestia transactions, posted
+/// ahead of the block's head blob.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub(crate) struct SubmittedPart {
+    celestia_height: u64,
+    #[serde(with = "rollup_ids_as_hex")]
+    rollup_ids: Vec<RollupId>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    blobs: Vec<PostedBlob>,
+}
+
+impl SubmittedPart {
+    pub(crate) fn new(
+        celestia_height: u64,
+        rollup_ids: Vec<RollupId>,
+        blobs: Vec<PostedBlob>,
+    ) -> Self {
+        Self {
+            celestia_height,
+            rollup_ids,
+            blobs,
+        }
+    }
+
+    pub(crate) fn celestia_height(&self) -> u64 {
+        self.celestia_height
+    }
+
+    pub(crate) fn rollup_ids(&self) -> &[RollupId] {
+        &self.rollup_ids
+    }
+
+    pub(crate) fn blobs(&self) -> &[PostedBlob] {
+        &self.blobs
+    }
+}
+
+/// A finalized submission of a range of sequencer blocks to Celestia.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub(crate) struct Submission {
+    #[serde(with = "as_number")]
+    first_sequencer_height: SequencerHeight,
+    #[serde(with = "as_number")]
+    last_sequencer_height: SequencerHeight,
+    /// The Celestia height of the transaction that finalized the submission.
+    celestia_height: u64,
+    /// The blobs of the submission, including those of the parts of a split sequencer block.
+    ///
+    /// Empty for submissions migrated from the post-submit file, which did not record blobs.
+    blobs: Vec<PostedBlob>,
+}
+
+impl Submission {
+    pub(crate) fn new(
+        first_sequencer_height: SequencerHeight,
+        last_sequencer_height: SequencerHeight,
+        celestia_height: u64,
+        blobs: Vec<PostedBlob>,
+    ) -> Self {
+        Self {
+            first_sequencer_height,
+            last_sequencer_height,
+            celestia_height,
+            blobs,
+        }
+    }
+
+    /// Returns the Celestia height of the transaction that finalized the submission.
+    pub(crate) fn celestia_height(&self) -> u64 {
+        self.celestia_height
+    }
+}
+
+/// A submission that was started but not finalized.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Pending {
+    pub(crate) first_sequencer_height: SequencerHeight,
+    pub(crate) last_sequencer_height: SequencerHeight,
+    pub(crate) parts: Vec<SubmittedPart>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(tag = "entry")]
+enum Entry {
+    Started {
+        #[serde(with = "as_number")]
+        first_sequencer_height: SequencerHeight,
+        #[serde(with = "as_number")]
+        last_sequencer_height: SequencerHeight,
+    },
+    Part(SubmittedPart),
+    Submitted(Submission),
+    Backfilled(Submission),
+}
+
+#[derive(Debug)]
+pub(crate) struct Journal {
+    inner: Mutex<Inner>,
+}
+
+/// The location of an entry in the journal file.
+#[derive(Clone, Copy, Debug)]
+struct Location {
+    offset: u64,
+    len: usize,
+}
+
+/// The index of a finalized submission, locating its entry in the journal file.
+#[derive(Clone, Copy, Debug)]
+struct Indexed {
+    first_sequencer_height: SequencerHeight,
+    celestia_height: u64,
+    location: Location,
+}
+
+#[derive(Debug)]
+struct Inner {
+    path: PathBuf,
+    /// The journal file opened for appending entries.
+    file: File,
+    /// The journal file opened for reading the entries of submissions.
+    reader: File,
+    /// The length of the journal file, which is the offset of the next entry.
+    len: u64,
+    num_entries: usize,
+    /// The number of entries that no longer take effect and are dropped by a compaction.
+    num_obsolete: usize,
+    /// The finalized submissions, keyed by their last sequencer height.
+    submissions: BTreeMap<SequencerHeight, Indexed>,
+    /// The submissions of backfilled sequencer blocks, keyed by their last sequencer height.
+    backfills: BTreeMap<SequencerHeight, Indexed>,
+    pending: Option<Pending>,
+}
+
+impl Inner {
+    /// Loads the journal at `path` by replaying its entries, creating it if it does not exist.
+    fn load(path: &Path) -> eyre::Result<Self> {
+        let file = File::options()
+            .create(true)
+            .append(true)
+            .open(path)
+            .wrap_err("failed opening journal file")?;
+        let reader = File::open(path).wrap_err("failed opening journal file for reading")?;
+
+        let mut inner = Self {
+            path: path.to_path_buf(),
+            file,
+            reader,
+            len: 0,
+            num_entries: 0,
+            num_obsolete: 0,
+            submissions: BTreeMap::new(),
+            backfills: BTreeMap::new(),
+            pending: None,
+        };
+        let mut lines = BufReader::new(
+            inner
+                .reader
+                .try_clone()
+                .wrap_err("failed cloning journal file handle")?,
+        );
+        let mut line = Vec::new();
+        let mut line_number = 0;
+        let mut is_torn = false;
+        loop {
+            line.clear();
+            if lines
+                .read_until(b'\n', &mut line)
+                .wrap_err("failed reading journal file")?
+                == 0
+            {
+                break;
+            }
+            line_number += 1;
+            if line.last() != Some(&b'\n') {
+                warn!(
+                    line = line_number,
+                    "journal ends in a torn line; truncating it",
+                );
+                is_torn = true;
+                break;
+            }
+            let location = Location {
+                offset: inner.len,
+                len: line.len(),
+            };
+            inner.len += line.len() as u64;
+            if line.iter().all(u8::is_ascii_whitespace) {
+                continue;
+            }
+            let entry = serde_json::from_slice(&line)
+                .wrap_err_with(|| format!("failed decoding line {line_number} of journal"))?;
+            inner
+                .check(&entry)
+                .wrap_err_with(|| format!("line {line_number} of journal is inconsistent"))?;
+            inner.apply(entry, location);
+        }
+        if is_torn {
+            inner
+                .file
+                .set_len(inner.len)
+                .wrap_err("failed truncating torn line of journal")?;
+            inner
+                .file
+                .sync_all()
+                .wrap_err("failed fully syncing truncated journal to disk")?;
+        }
+        Ok(inner)
+    }
+
+    /// Ensures that `entry` can follow the entries applied so far.
+    fn check(&self, entry: &Entry) -> eyre::Result<()> {
+        match entry {
+            Entry::Started {
+                first_sequencer_height,
+                last_sequencer_height,
+            } => ensure_ordered(*first_sequencer_height, *last_sequencer_height),
+            Entry::Part(_) => {
+                ensure!(
+                    self.pending.is_some(),
+                    "part of a split sequencer block was recorded without starting a submission"
+                );
+                Ok(())
+            }
+            Entry::Submitted(submission) => {
+                ensure_ordered(
+                    submission.first_sequencer_height,
+                    submission.last_sequencer_height,
+                )?;
+                if let Some(last) = self.submissions.keys().next_back() {
+                    ensure!(
+                        submission.first_sequencer_height > *last,
+                        "submission starting at sequencer height `{}` does not follow the last \
+                         submitted sequencer height `{last}`",
+                        submission.first_sequencer_height,
+                    );
+                }
+                Ok(())
+            }
+            Entry::Backfilled(submission) => ensure_ordered(
+                submission.first_sequencer_height,
+                submission.last_sequencer_height,
+            ),
+        }
+    }
+
+    /// Applies `entry` found at `location` in the journal file, which must have passed
+    /// [`Inner::check`].
+    fn apply(&mut self, entry: Entry, location: Location) {
+        match entry {
+            Entry::Started {
+                first_sequencer_height,
+                last_sequencer_height,
+            } => {
+                let previous = self.pending.replace(Pending {
+                    first_sequencer_height,
+                    last_sequencer_height,
+                    parts: Vec::new(),
+                });
+                self.num_obsolete += previous.map_or(0, |pending| pending.num_entries());
+            }
+            Entry::Part(part) => {
+                if let Some(pending) = &mut self.pending {
+                    pending.parts.push(part);
+                }
+            }
+            Entry::Submitted(submission) => {
+                self.submissions.insert(
+                    submission.last_sequencer_height,
+                    Indexed {
+                        first_sequencer_height: submission.first_sequencer_height,
+                        celestia_height: submission.celestia_height,
+                        location,
+                    },
+                );
+                self.num_obsolete += self
+                    .pending
+                    .take()
+                    .map_or(0, |pending| pending.num_entries());
+            }
+            Entry::Backfilled(submission) => {
+                let previous = self.backfills.insert(
+                    submission.last_sequencer_height,
+                    Indexed {
+                        first_sequencer_height: submission.first_sequencer_height,
+                        celestia_height: submission.celestia_height,
+                        location,
+                    },
+                );
+                self.num_obsolete += usize::from(previous.is_some());
+            }
+        }
+        self.num_entries += 1;
+    }
+
+    /// Reads the line of the entry at `location` from the journal file.
+    fn read_line(&self, location: Location) -> eyre::Result<Vec<u8>> {
+        let mut reader = &self.reader;
+        reader
+            .seek(SeekFrom::Start(location.offset))
+            .wrap_err("failed seeking to entry in journal file")?;
+        let mut line = vec![0; location.len];
+        reader
+            .read_exact(&mut line)
+            .wrap_err("failed reading entry from journal file")?;
+        Ok(line)
+    }
+
+    /// Reads the submission indexed by `indexed` from the journal file.
+    fn read_submission(&self, indexed: Indexed) -> eyre::Result<Submission> {
+        let line = self.read_line(indexed.location)?;
+        let entry = serde_json::from_slice(&line).wrap_err_with(|| {
+            format!(
+                "failed decoding journal entry at offset {}",
+                indexed.location.offset
+            )
+        })?;
+        let (Entry::Submitted(submission) | Entry::Backfilled(submission)) = entry else {
+            bail!(
+                "journal entry at offset {} is not a submission",
+                indexed.location.offset
+            );
+        };
+        Ok(submission)
+    }
+
+    /// Compacts the journal if at least half of its entries and at least
+    /// [`MIN_OBSOLETE_ENTRIES_TO_COMPACT`] no longer take effect.
+    fn compact_if_needed(&mut self) -> eyre::Result<()> {
+        if self.num_obsolete < MIN_OBSOLETE_ENTRIES_TO_COMPACT
+            || self.num_obsolete < self.num_entries - self.num_obsolete
+        {
+            return Ok(());
+        }
+        let num_obsolete = self.num_obsolete;
+        write_atomically(&self.path, |writer| {
+            for indexed in self.submissions.values().chain(self.backfills.values()) {
+                writer
+                    .write_all(&self.read_line(indexed.location)?)
+                    .wrap_err("failed writing entry to compacted journal")?;
+            }
+            if let Some(pending) = &self.pending {
+                for entry in pending.entries() {
+                    write_entry(writer, &entry)?;
+                }
+            }
+            Ok(())
+        })
+        .wrap_err("failed writing compacted journal")?;
+        *self = Self::load(&self.path).wrap_err("failed loading compacted journal")?;
+        info!(
+            num_obsolete,
+            num_entries = self.num_entries,
+            "compacted journal by dropping entries that no longer take effect",
+        );
+        Ok(())
+    }
+}
+
+impl Pending {
+    /// Returns the number of journal entries recording the pending submission.
+    fn num_entries(&self) -> usize {
+        1 + self.parts.len()
+    }
+
+    /// Returns the journal entries recording the pending submission.
+    fn entries(&self) -> impl Iterator<Item = Entry> + '_ {
+        std::iter::once(Entry::Started {
+            first_sequencer_height: self.first_sequencer_height,
+            last_sequencer_height: self.last_sequencer_height,
+        })
+        .chain(self.parts.iter().cloned().map(Entry::Part))
+    }
+}
+
+/// Replaces the file at `path` with what `write` writes.
+///
+/// The contents are written to a temporary file that is synced to disk and renamed over `path`,
+/// so that a crash leaves either the previous file or the complete new one.
+fn write_atomically<F>(path: &Path, write: F) -> eyre::Result<()>
+where
+    F: FnOnce(&mut BufWriter<File>) -> eyre::Result<()>,
+{
+    let mut temp_path = OsString::from(path.as_os_str());
+    temp_path.push(".tmp");
+    let temp_path = PathBuf::from(temp_path);
+    let file = File::create(&temp_path).wrap_err_with(|| {
+        format!(
+            "failed creating temporary file at `{}`",
+            temp_path.display()
+        )
+    })?;
+    let mut writer = BufWriter::new(file);
+    write(&mut writer)?;
+    let file = writer
+        .into_inner()
+        .map_err(std::io::IntoInnerError::into_error)
+        .wrap_err("failed flushing temporary file")?;
+    file.sync_all()
+        .wrap_err("failed fully syncing temporary file to disk")?;
+    std::fs::rename(&temp_path, path)
+        .wrap_err_with(|| format!("failed renaming temporary file to `{}`", path.display()))?;
+    // the rename only survives a crash once the directory holding the file is synced
+    let dir = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+    File::open(dir)
+        .and_then(|dir| dir.sync_all())
+        .wrap_err_with(|| format!("failed syncing directory `{}` to disk", dir.display()))
+}
+
+/// Writes `entry` as a line to `writer`.
+fn write_entry<W: Write>(writer: &mut W, entry: &Entry) -> eyre::Result<()> {
+    serde_json::to_writer(&mut *writer, entry).wrap_err("failed json-serializing journal entry")?;
+    writer
+        .write_all(b"\n")
+        .wrap_err("failed writing journal entry")
+}
+
+fn ensure_ordered(
+    first_sequencer_height: SequencerHeight,
+    last_sequencer_height: SequencerHeight,
+) -> eyre::Result<()> {
+    ensure!(
+        first_sequencer_height <= last_sequencer_height,
+        "first sequencer height `{first_sequencer_height}` is above last sequencer height \
+         `{last_sequencer_height}`"
+    );
+    Ok(())
+}
+
+impl Journal {
+    /// Opens the journal at `path`, creating it if it does not exist.
+    ///
+    /// The journal is compacted if enough of its entries no longer take effect.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file could not be opened or compacted, or if a complete line of it
+    /// does not hold a valid entry.
+    pub(crate) fn open<P: AsRef<Path>>(path: P) -> eyre::Result<Self> {
+        let mut inner = Inner::load(path.as_ref())?;
+        inner
+            .compact_if_needed()
+            .wrap_err("failed compacting journal")?;
+        Ok(Self {
+            inner: Mutex::new(inner),
+        })
+    }
+
+    /// Creates the journal at `path` holding the finalized `submission` and the `pending`
+    /// submission, replacing the journal at `path`, if any.
+    ///
+    /// The journal is written atomically: a crash leaves either the journal previously at `path`
+    /// or the complete new one.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the journal could not be written, or if `pending` does not follow
+    /// `submission`.
+    pub(crate) fn create<P: AsRef<Path>>(
+        path: P,
+        submission: Option<Submission>,
+        pending: Option<Pending>,
+    ) -> eyre::Result<Self> {
+        let path = path.as_ref();
+        write_atomically(path, |writer| {
+            if let Some(submission) = submission {
+                write_entry(writer, &Entry::Submitted(submission))?;
+            }
+            for entry in pending.iter().flat_map(Pending::entries) {
+                write_entry(writer, &entry)?;
+            }
+            Ok(())
+        })
+        .wrap_err("failed writing journal")?;
+        Self::open(path)
+    }
+
+    /// Returns if the journal holds no entries.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.lock().num_entries == 0
+    }
+
+    pub(crate) fn last_submitted_height(&self) -> Option<SequencerHeight> {
+        self.lock().submissions.keys().next_back().copied()
+    }
+
+    /// Returns the submission that was started but not finalized, if any.
+    pub(crate) fn pending(&self) -> Option<Pending> {
+        self.lock().pending.clone()
+    }
+
+    /// Returns the finalized submission holding the sequencer block at `sequencer_height`.
+    ///
+    /// The latest backfill of the block takes precedence over its regular submission.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the entry of the submission could not be read from the journal file.
+    pub(crate) fn submission_at(
+        &self,
+        sequencer_height: SequencerHeight,
+    ) -> eyre::Result<Option<Submission>> {
+        let inner = self.lock();
+        let Some(indexed) = find_submission(&inner.backfills, sequencer_height)
+            .or_else(|| find_submission(&inner.submissions, sequencer_height))
+        else {
+            return Ok(None);
+        };
+        inner.read_submission(indexed).map(Some)
+    }
+
+    /// Returns the Celestia heights of the finalized submissions of the sequencer blocks right
+    /// below and right at or above `sequencer_height`, if any.
+    ///
+    /// A sequencer block at `sequencer_height` that was submitted by the regular relaying was
+    /// included in Celestia between the two heights.
+    pub(crate) fn celestia_heights_around(
+        &self,
+        sequencer_height: SequencerHeight,
+    ) -> (Option<u64>, Option<u64>) {
+        let inner = self.lock();
+        let below = inner
+            .submissions
+            .range(..sequencer_height)
+            .next_back()
+            .map(|(_, indexed)| indexed.celestia_height);
+        let above = inner
+            .submissions
+            .range(sequencer_height..)
+            .next()
+            .map(|(_, indexed)| indexed.celestia_height);
+        (below, above)
+    }
+
+    pub(crate) fn start(
+        &self,
+        first_sequencer_height: SequencerHeight,
+        last_sequencer_height: SequencerHeight,
+    ) -> eyre::Result<()> {
+        self.append(Entry::Started {
+            first_sequencer_height,
+            last_sequencer_height,
+        })
+    }
+
+    pub(crate) fn record_part(&self, part: SubmittedPart) -> eyre::Result<()> {
+        self.append(Entry::Part(part))
+    }
+
+    pub(crate) fn finalize(&self, submission: Submission) -> eyre::Result<()> {
+        self.append(Entry::Submitted(submission))
+    }
+
+    /// Records the submission of sequencer blocks that were re-relayed by a backfill.
+    pub(crate) fn record_backfill(&self, submission: Submission) -> eyre::Result<()> {
+        self.append(Entry::Backfilled(submission))
+    }
+
+    /// Appends `entry` to the journal file, syncing it to disk before applying it.
+    ///
+    /// The journal is compacted afterwards if enough of its entries no longer take effect.
+    fn append(&self, entry: Entry) -> eyre::Result<()> {
+        let mut line =
+            serde_json::to_vec(&entry).wrap_err("failed json-serializing journal entry")?;
+        line.push(b'\n');
+        let mut inner = self.lock();
+        inner
+            .check(&entry)
+            .wrap_err("refusing to append inconsistent entry to journal")?;
+        let written = inner
+            .file
+            .write_all(&line)
+            .wrap_err("failed writing entry to journal")
+            .and_then(|()| {
+                inner
+                    .file
+                    .sync_data()
+                    .wrap_err("failed fully syncing journal entry to disk")
+            });
+        if let Err(error) = written {
+            // drop the partially written entry so that the next one does not follow a torn line
+            if let Err(truncate_error) = inner.file.set_len(inner.len) {
+                warn!(
+                    error = %truncate_error,
+                    "failed truncating partially written entry of journal",
+                );
+            }
+            return Err(error);
+        }
+        let location = Location {
+            offset: inner.len,
+            len: line.len(),
+        };
+        inner.len += line.len() as u64;
+        inner.apply(entry, location);
+        inner
+            .compact_if_needed()
+            .wrap_err("failed compacting journal")
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Inner> {
+        self.inner
+            .lock()
+            .expect("the journal lock is never held across a panic")
+    }
+}
+
+fn find_submission(
+    submissions: &BTreeMap<SequencerHeight, Indexed>,
+    sequencer_height: SequencerHeight,
+) -> Option<Indexed> {
+    submissions
+        .range(sequencer_height..)
+        .next()
+        .map(|(_, indexed)| *indexed)
+        .filter(|indexed| indexed.first_sequencer_height <= sequencer_height)
+}
+
+mod rollup_ids_as_hex {
+    //! Logic to serialize rollup IDs as hex strings, deserialize hex strings as rollup IDs.
+    use astria_core::sequencer::v1::RollupId;
+    use serde::{
+        Deserialize as _,
+        Deserializer,
+        Serializer,
+    };
+
+    // Allow: the function signature is dictated by the serde(with) attribute.
+    #[allow(clippy::ptr_arg)]
+    pub(super) fn serialize<S>(rollup_ids: &Vec<RollupId>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(rollup_ids.iter().map(hex::encode))
+    }
+
+    pub(super) fn deserialize<'de, D>(deserializer: D) -> Result<Vec<RollupId>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        use serde::de::Error;
+        Vec::<String>::deserialize(deserializer)?
+            .into_iter()
+            .map(|hex_id| {
+                let bytes = hex::decode(&hex_id).map_err(Error::custom)?;
+                RollupId::try_from_vec(bytes).map_err(Error::custom)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write as _;
+
+    use super::*;
+
+    fn submission(first: u32, last: u32, celestia_height: u64) -> Submission {
+        Submission::new(first.into(), last.into(), celestia_height, Vec::new())
+    }
+
+    #[test]
+    fn entries_are_replayed_on_open() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("journal.jsonl");
+        let journal = Journal::open(&path).unwrap();
+        assert!(journal.is_empty());
+        journal.start(1u32.into(), 2u32.into()).unwrap();
+        journal.finalize(submission(1, 2, 5)).unwrap();
+        journal.start(3u32.into(), 3u32.into()).unwrap();
+        journal
+            .record_part(SubmittedPart::new(
+                6,
+                vec![RollupId::new([1; 32])],
+                Vec::new(),
+            ))
+            .unwrap();
+        drop(journal);
+
+        let journal = Journal::open(&path).unwrap();
+        assert_eq!(journal.last_submitted_height(), Some(2u32.into()));
+        assert_eq!(
+            journal.submission_at(1u32.into()).unwrap(),
+            Some(submission(1, 2, 5))
+        );
+        assert_eq!(journal.submission_at(3u32.into()).unwrap(), None);
+        let pending = journal.pending().unwrap();
+        assert_eq!(pending.first_sequencer_height.value(), 3);
+        assert_eq!(pending.parts.len(), 1);
+    }
+
+    #[test]
+    fn torn_last_line_is_truncated() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("journal.jsonl");
+        let journal = Journal::open(&path).unwrap();
+        journal.finalize(submission(1, 1, 5)).unwrap();
+        drop(journal);
+        let mut file = File::options().append(true).open(&path).unwrap();
+        file.write_all(br#"{"entry":"submitted","first_seq"#)
+            .unwrap();
+        drop(file);
+
+        let journal = Journal::open(&path).unwrap();
+        assert_eq!(journal.last_submitted_height(), Some(1u32.into()));
+        journal.finalize(submission(2, 2, 6)).unwrap();
+        drop(journal);
+        let journal = Journal::open(&path).unwrap();
+        assert_eq!(journal.last_submitted_height(), Some(2u32.into()));
+    }
+
+    #[test]
+    fn corrupted_or_inconsistent_entries_are_rejected() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("journal.jsonl");
+        std::fs::write(&path, "not json\n").unwrap();
+        assert!(Journal::open(&path).is_err());
+
+        let journal_path = dir.path().join("other.jsonl");
+        let journal = Journal::open(&journal_path).unwrap();
+        journal.finalize(submission(2, 3, 5)).unwrap();
+        assert!(journal.finalize(submission(3, 4, 6)).is_err());
+        assert!(
+            journal
+                .record_part(SubmittedPart::new(6, Vec::new(), Vec::new()))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn obsolete_entries_are_compacted() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("journal.jsonl");
+        let journal = Journal::open(&path).unwrap();
+        let num_submissions = u32::try_from(MIN_OBSOLETE_ENTRIES_TO_COMPACT).unwrap();
+        for height in 1..=num_submissions {
+            journal.start(height.into(), height.into()).unwrap();
+            journal
+                .finalize(submission(height, height, u64::from(height)))
+                .unwrap();
+        }
+        journal
+            .start((num_submissions + 1).into(), (num_submissions + 1).into())
+            .unwrap();
+        drop(journal);
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(
+            contents.lines().count(),
+            MIN_OBSOLETE_ENTRIES_TO_COMPACT + 1,
+            "only the submissions and the pending start should remain after compaction",
+        );
+        let journal = Journal::open(&path).unwrap();
+        assert_eq!(
+            journal.last_submitted_height(),
+            Some(num_submissions.into())
+        );
+        assert_eq!(
+            journal.submission_at(7u32.into()).unwrap(),
+            Some(submission(7, 7, 7))
+        );
+        assert_eq!(
+            journal.pending().unwrap().first_sequencer_height.value(),
+            u64::from(num_submissions + 1)
+        );
+    }
+
+    #[test]
+    fn create_replaces_journal_atomically() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("journal.jsonl");
+        let pending = Pending {
+            first_sequencer_height: 3u32.into(),
+            last_sequencer_height: 3u32.into(),
+            parts: vec![SubmittedPart::new(
+                6,
+                vec![RollupId::new([1; 32])],
+                Vec::new(),
+            )],
+        };
+        let journal =
+            Journal::create(&path, Some(submission(1, 2, 5)), Some(pending.clone())).unwrap();
+        assert_eq!(journal.last_submitted_height(), Some(2u32.into()));
+        assert_eq!(journal.pending(), Some(pending));
+        assert!(!dir.path().join("journal.jsonl.tmp").exists());
+    }
+
+    #[test]
+    fn backfills_take_precedence_without_affecting_submissions() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("journal.jsonl");
+        let journal = Journal::open(&path).unwrap();
+        journal.finalize(submission(1, 3, 5)).unwrap();
+        journal.record_backfill(submission(2, 2, 9)).unwrap();
+        assert_eq!(
+            journal.celestia_heights_around(2u32.into()),
+            (None, Some(5))
+        );
+        drop(journal);
+
+        let journal = Journal::open(&path).unwrap();
+        assert_eq!(journal.last_submitted_height(), Some(3u32.into()));
+        assert_eq!(
+            journal.submission_at(2u32.into()).unwrap(),
+            Some(submission(2, 2, 9))
+        );
+        assert_eq!(
+            journal.submission_at(3u32.into()).unwrap(),
+            Some(submission(1, 3, 5))
+        );
+        journal.finalize(submission(4, 4, 10)).unwrap();
+    }
+}
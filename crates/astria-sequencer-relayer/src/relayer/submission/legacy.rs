@@ -0,0 +1,442 @@
+//! Reads the pre- and post-submit files that tracked the submission state before the journal.
+//!
+//! The files are only read to migrate their state into an empty journal on first start.
+
+use std::path::Path;
+
+use astria_eyre::eyre::{
+    self,
+    bail,
+    ensure,
+    WrapErr as _,
+};
+use sequencer_client::tendermint::block::Height as SequencerHeight;
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use tracing::warn;
+
+use super::{
+    as_number,
+    journal::SubmittedPart,
+};
+
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+#[serde(tag = "state")]
+enum PostSubmission {
+    Fresh,
+    Submitted {
+        celestia_height: u64,
+        #[serde(with = "as_number")]
+        sequencer_height: SequencerHeight,
+    },
+}
+
+impl PostSubmission {
+    fn is_fresh(&self) -> bool {
+        matches!(self, PostSubmission::Fresh)
+    }
+
+    fn is_submitted(&self) -> bool {
+        matches!(self, PostSubmission::Submitted { .. })
+    }
+
+    fn from_path<P: AsRef<Path>>(path: P) -> eyre::Result<Self> {
+        let file = std::fs::File::open(&path)
+            .wrap_err("failed opening provided file path for reading post-submission state")?;
+        let state = serde_json::from_reader(file)
+            .wrap_err("failed reading contents of post-submission file")?;
+        Ok(state)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(tag = "state")]
+enum PreSubmission {
+    Ignore,
+    Started {
+        #[serde(with = "as_number")]
+        sequencer_height: SequencerHeight,
+        last_submission: PostSubmission,
+        /// The parts of a split sequencer block at `sequencer_height` that were already posted.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        parts: Vec<SubmittedPart>,
+    },
+}
+impl PreSubmission {
+    fn from_path<P: AsRef<Path>>(path: P) -> eyre::Result<Self> {
+        let file = std::fs::File::open(&path)
+            .wrap_err("failed opening provided file path for reading pre-submission state")?;
+        let state = serde_json::from_reader(file)
+            .wrap_err("failed reading contents of pre-submission file")?;
+        Ok(state)
+    }
+}
+
+/// The submission state read from the pre- and post-submit files.
+#[derive(Debug)]
+pub(super) struct LegacyState {
+    /// The sequencer height and Celestia height of the last finalized submission.
+    pub(super) last_submission: Option<(SequencerHeight, u64)>,
+    /// The sequencer height and posted parts of a split sequencer block whose submission was not
+    /// finalized.
+    pub(super) unfinished_parts: Option<(SequencerHeight, Vec<SubmittedPart>)>,
+}
+
+pub(super) fn read<const LEANIENT: bool, P1: AsRef<Path>, P2: AsRef<Path>>(
+    pre_path: P1,
+    post_path: P2,
+) -> eyre::Result<LegacyState> {
+    let pre = PreSubmission::from_path(&pre_path).wrap_err(
+        "failed constructing post submit state from provided path; if the post-submit state is
+            otherwise present and correctly formatted - and contains the correct information about \
+         the
+            last submitted sequencer height and its inclusion height on Celestia - then override \
+         the
+            contents of the pre-submission file with `{{\"state\": \"ignore\"}}`",
+    )?;
+    let post = PostSubmission::from_path(&post_path).wrap_err(
+        "failed constructing post submit state from provided path; if the pre-submit state is \
+         otherwise present and correctly formatted then this indicates a file corruption. Because \
+         the post-submit state is critical for determining the starting height for relaying \
+         sequencer blocks, make sure it's set correctly.",
+    )?;
+
+    let unfinished_parts = match pre {
+        PreSubmission::Ignore => None,
+
+        PreSubmission::Started {
+            sequencer_height,
+            last_submission,
+            parts,
+        } => {
+            let unfinished_parts = (!parts.is_empty() && is_unfinished(sequencer_height, post))
+                .then_some((sequencer_height, parts));
+            if let Err(error) = ensure_consistent(sequencer_height, last_submission, post) {
+                if LEANIENT && unfinished_parts.is_some() {
+                    warn!(%error, "pre- and post-submission states were inconsistent because a split sequencer block was only partially submitted. Continuing from last post-state and resuming the split block from its posted parts.");
+                } else if LEANIENT {
+                    warn!(%error, "pre- and post-submission states were inconsistent. Ignoring the pre-state and continuing from last post-state. This could to double submission!!");
+                } else {
+                    return Err(error).wrap_err("on-disk states are inconsistent");
+                }
+            }
+            unfinished_parts
+        }
+    };
+
+    let last_submission = match post {
+        PostSubmission::Fresh => None,
+        PostSubmission::Submitted {
+            celestia_height,
+            sequencer_height,
+        } => Some((sequencer_height, celestia_height)),
+    };
+    Ok(LegacyState {
+        last_submission,
+        unfinished_parts,
+    })
+}
+
+/// Returns if the submission of `sequencer_height_started` was not finalized in
+/// `current_submission`.
+fn is_unfinished(
+    sequencer_height_started: SequencerHeight,
+    current_submission: PostSubmission,
+) -> bool {
+    match current_submission {
+        PostSubmission::Fresh => true,
+        PostSubmission::Submitted {
+            sequencer_height, ..
+        } => sequencer_height < sequencer_height_started,
+    }
+}
+
+fn ensure_consistent(
+    sequencer_height_started: SequencerHeight,
+    last_submission: PostSubmission,
+    current_submission: PostSubmission,
+) -> eyre::Result<()> {
+    ensure_height_pre_submission_is_height_post_submission(
+        sequencer_height_started,
+        current_submission,
+    )?;
+    ensure_last_and_current_are_different(last_submission, current_submission)?;
+    ensure_last_is_not_submitted_while_current_is_fresh(last_submission, current_submission)?;
+    ensure_height_in_last_is_less_than_height_in_current(last_submission, current_submission)?;
+    Ok(())
+}
+
+fn ensure_height_pre_submission_is_height_post_submission(
+    sequencer_height_started: SequencerHeight,
+    current_submission: PostSubmission,
+) -> eyre::Result<()> {
+    let PostSubmission::Submitted {
+        sequencer_height, ..
+    } = current_submission
+    else {
+        bail!(
+            "the pre-submit file indicated that a new submission was started, but the post-submit \
+             file still contained a \"fresh\" state. This indicates that the submission was not \
+             finalized."
+        );
+    };
+    ensure!(
+        sequencer_height_started == sequencer_height,
+        "the initialized `sequencer_height` in the pre-submit file does not match the sequencer \
+         height in the post-submit file. This indicates that a new submission to Celestia was \
+         started but not finalized. This is becasue a succesful submission records the very same \
+         `sequencer_height` in the post-submit file."
+    );
+    Ok(())
+}
+
+fn ensure_last_and_current_are_different(
+    last_submission: PostSubmission,
+    current_submission: PostSubmission,
+) -> eyre::Result<()> {
+    ensure!(
+        last_submission != current_submission,
+        "the `last_submission` field of the pre-submit file matches the object found in the \
+         post-submit file. This indicates that a new submission to Celestia was started but not \
+         finalized. This is because when starting a new submission the object in the post-submit \
+         file is written to `last_submission`."
+    );
+    Ok(())
+}
+
+fn ensure_last_is_not_submitted_while_current_is_fresh(
+    last_submission: PostSubmission,
+    current_submission: PostSubmission,
+) -> eyre::Result<()> {
+    ensure!(
+        !(last_submission.is_submitted() && current_submission.is_fresh()),
+        "the submission recorded in the post-submit file cannot be `fresh` while \
+         `last_submission` in the pre-submit file is `submitted`",
+    );
+    Ok(())
+}
+
+fn ensure_height_in_last_is_less_than_height_in_current(
+    last_submission: PostSubmission,
+    current_submission: PostSubmission,
+) -> eyre::Result<()> {
+    let PostSubmission::Submitted {
+        sequencer_height: height_in_last,
+        ..
+    } = last_submission
+    else {
+        return Ok(());
+    };
+    let PostSubmission::Submitted {
+        sequencer_height: height_in_current,
+        ..
+    } = current_submission
+    else {
+        return Ok(());
+    };
+    ensure!(
+        height_in_last < height_in_current,
+        "the `sequencer_height` in the post-submit file is not greater than the \
+         `sequencer_height` stored in the `last_submission` field of the pre-submit file.
+        This indicates that a new submission was started not but finalized."
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use tempfile::NamedTempFile;
+
+    use super::read;
+
+    const STRICT_CONCISTENCY_CHECK: bool = false;
+
+    #[track_caller]
+    fn create_files() -> (NamedTempFile, NamedTempFile) {
+        let pre = NamedTempFile::new()
+            .expect("must be able to create an empty pre submit state file to run tests");
+        let post = NamedTempFile::new()
+            .expect("must be able to create an empty post submit state file to run tests");
+        (pre, post)
+    }
+
+    fn write(f: &NamedTempFile, val: &serde_json::Value) {
+        serde_json::to_writer(f, val).expect("must be able to write state to run tests");
+    }
+
+    #[test]
+    fn fresh_with_ignored_is_ok() {
+        let (pre, post) = create_files();
+        write(&pre, &json!({ "state": "ignore" }));
+        write(&post, &json!({ "state": "fresh" }));
+        read::<STRICT_CONCISTENCY_CHECK, _, _>(pre.path(), post.path())
+            .expect("states `ignore` and `fresh` give a working submission state");
+    }
+
+    #[test]
+    fn submitted_with_ignored_is_ok() {
+        let (pre, post) = create_files();
+        write(&pre, &json!({ "state": "ignore" }));
+        write(
+            &post,
+            &json!({ "state": "submitted", "celestia_height": 5, "sequencer_height": 2 }),
+        );
+        read::<STRICT_CONCISTENCY_CHECK, _, _>(pre.path(), post.path())
+            .expect("states `ignore` and `submitted` give a working submission state");
+    }
+
+    #[test]
+    fn started_with_same_fresh_in_last_and_current_is_err() {
+        let (pre, post) = create_files();
+        write(
+            &pre,
+            &json!({ "state": "started", "sequencer_height": 5, "last_submission": { "state": "fresh"} }),
+        );
+        write(&post, &json!({ "state": "fresh" }));
+        let _ = read::<STRICT_CONCISTENCY_CHECK, _, _>(pre.path(), post.path())
+            .expect_err("started state with `fresh` in last and current gives error");
+    }
+
+    #[test]
+    fn started_with_height_before_current_is_err() {
+        let (pre, post) = create_files();
+        write(
+            &pre,
+            &json!({ "state": "started", "sequencer_height": 5, "last_submission": { "state": "fresh"} }),
+        );
+        write(
+            &post,
+            &json!({ "state": "submitted", "sequencer_height": 6, "celestia_height": 2 }),
+        );
+        let _ = read::<STRICT_CONCISTENCY_CHECK, _, _>(pre.path(), post.path()).expect_err(
+            "started state with sequencer height less then sequencer height recorded submitted \
+             gives error",
+        );
+    }
+
+    #[test]
+    fn started_with_same_submitted_in_last_and_current_is_err() {
+        let (pre, post) = create_files();
+        write(
+            &pre,
+            &json!({ "state": "started", "sequencer_height": 2, "last_submission": { "state": "submitted", "celestia_height": 5, "sequencer_height": 2} }),
+        );
+        write(
+            &post,
+            &json!({ "state": "submitted", "celestia_height": 5, "sequencer_height": 2 }),
+        );
+        let _ = read::<STRICT_CONCISTENCY_CHECK, _, _>(pre.path(), post.path()).expect_err(
+            "started state with the same `submitted` in last and current give an error",
+        );
+    }
+
+    #[test]
+    fn started_with_different_last_fresh_and_current_submitted_is_ok() {
+        let (pre, post) = create_files();
+        write(
+            &pre,
+            &json!({ "state": "started", "sequencer_height": 2, "last_submission": { "state": "fresh" }}),
+        );
+        write(
+            &post,
+            &json!({ "state": "submitted", "celestia_height": 5, "sequencer_height": 2 }),
+        );
+        let _ = read::<STRICT_CONCISTENCY_CHECK, _, _>(pre.path(), post.path()).expect(
+            "started state with the `fresh` in last and `submitted` in current gives working \
+             submission state",
+        );
+    }
+
+    mod leanient {
+        //! These test the same scenarios as the tests of the same name in the super module, but
+        //! whereas those are strict and should fail, the tests in this module should pass
+
+        use astria_core::sequencer::v1::RollupId;
+
+        use super::{
+            create_files,
+            json,
+            read,
+            write,
+        };
+
+        const LEANIENT_CONCISTENCY_CHECK: bool = true;
+
+        #[test]
+        fn started_with_same_fresh_in_last_and_current_is_err() {
+            let (pre, post) = create_files();
+            write(
+                &pre,
+                &json!({ "state": "started", "sequencer_height": 5, "last_submission": { "state": "fresh"} }),
+            );
+            write(&post, &json!({ "state": "fresh" }));
+            let _ = read::<LEANIENT_CONCISTENCY_CHECK, _, _>(pre.path(), post.path())
+                .expect("this test should not fail when doing a leaning concistency check");
+        }
+
+        #[test]
+        fn started_with_height_before_current_is_err() {
+            let (pre, post) = create_files();
+            write(
+                &pre,
+                &json!({ "state": "started", "sequencer_height": 5, "last_submission": { "state": "fresh"} }),
+            );
+            write(
+                &post,
+                &json!({ "state": "submitted", "sequencer_height": 6, "celestia_height": 2 }),
+            );
+            let _ = read::<LEANIENT_CONCISTENCY_CHECK, _, _>(pre.path(), post.path())
+                .expect("this test should not fail when doing a leaning concistency check");
+        }
+
+        #[test]
+        fn started_with_same_submitted_in_last_and_current_is_err() {
+            let (pre, post) = create_files();
+            write(
+                &pre,
+                &json!({ "state": "started", "sequencer_height": 2, "last_submission": { "state": "submitted", "celestia_height": 5, "sequencer_height": 2} }),
+            );
+            write(
+                &post,
+                &json!({ "state": "submitted", "celestia_height": 5, "sequencer_height": 2 }),
+            );
+            let _ = read::<LEANIENT_CONCISTENCY_CHECK, _, _>(pre.path(), post.path())
+                .expect("this test should not fail when doing a leaning concistency check");
+        }
+
+        #[test]
+        fn parts_of_unfinished_split_block_are_read() {
+            let (pre, post) = create_files();
+            write(
+                &pre,
+                &json!({ "state": "started", "sequencer_height": 4, "last_submission": { "state": "submitted", "celestia_height": 5, "sequencer_height": 3 }, "parts": [{ "celestia_height": 6, "rollup_ids": [hex::encode([1u8; 32])] }] }),
+            );
+            write(
+                &post,
+                &json!({ "state": "submitted", "celestia_height": 5, "sequencer_height": 3 }),
+            );
+            let state = read::<LEANIENT_CONCISTENCY_CHECK, _, _>(pre.path(), post.path())
+                .expect("this test should not fail when doing a leaning concistency check");
+            assert_eq!(
+                state
+                    .last_submission
+                    .map(|(sequencer_height, celestia_height)| (
+                        sequencer_height.value(),
+                        celestia_height
+                    )),
+                Some((3, 5)),
+            );
+            let (sequencer_height, parts) = state
+                .unfinished_parts
+                .expect("the parts of the unfinished split block should have been read");
+            assert_eq!(sequencer_height.value(), 4);
+            assert_eq!(parts[0].celestia_height(), 6);
+            assert_eq!(parts[0].rollup_ids(), &[RollupId::new([1; 32])]);
+        }
+    }
+}
@@ -0,0 +1,420 @@
+//! Tracks the submission state of sequencer-relayer in a journal on disk.
+//!
+//! Each submission of sequencer blocks to Celestia is recorded in the [`Journal`], which is
+//! migrated from the pre- and post-submit files that preceded it on first start. The migrated
+//! journal is written atomically, so that an interrupted migration is retried on the next start.
+
+use std::{
+    path::Path,
+    sync::Arc,
+};
+
+use astria_core::sequencer::v1::RollupId;
+use astria_eyre::eyre::{
+    self,
+    ensure,
+    WrapErr as _,
+};
+use sequencer_client::tendermint::block::Height as SequencerHeight;
+use tracing::{
+    debug,
+    info,
+};
+
+mod journal;
+mod legacy;
+
+pub(crate) use journal::{
+    Journal,
+    PostedBlob,
+    Submission,
+    SubmittedPart,
+};
+
+/// Opens the journal at `journal_path`.
+///
+/// If the journal is empty, the state of the pre- and post-submit files at `pre_submit_path` and
+/// `post_submit_path` is migrated into it. The files are not required to exist.
+///
+/// The migrated journal replaces the empty one in a single atomic step, so that a crash during
+/// the migration leaves the journal empty and the migration is retried on the next start.
+pub(crate) fn open_journal<P1: AsRef<Path>, P2: AsRef<Path>, P3: AsRef<Path>>(
+    journal_path: P1,
+    pre_submit_path: P2,
+    post_submit_path: P3,
+) -> eyre::Result<Journal> {
+    let journal_path = journal_path.as_ref();
+    let journal = Journal::open(journal_path).wrap_err_with(|| {
+        format!(
+            "failed opening submission journal at `{}`",
+            journal_path.display()
+        )
+    })?;
+    if !journal.is_empty() {
+        return Ok(journal);
+    }
+    migrate(
+        journal,
+        journal_path,
+        pre_submit_path.as_ref(),
+        post_submit_path.as_ref(),
+    )
+    .wrap_err(
+        "failed migrating the pre- and post-submit files into the journal. Refer to the values \
+         documented in `local.env.example` of the astria-sequencer-relayer service",
+    )
+}
+
+fn migrate(
+    journal: Journal,
+    journal_path: &Path,
+    pre_submit_path: &Path,
+    post_submit_path: &Path,
+) -> eyre::Result<Journal> {
+    const LEANIENT_CONSISTENCY_CHECK: bool = true;
+    if !pre_submit_path.exists() || !post_submit_path.exists() {
+        info!("no pre- and post-submit files to migrate; starting with an empty journal");
+        return Ok(journal);
+    }
+    let legacy =
+        legacy::read::<LEANIENT_CONSISTENCY_CHECK, _, _>(pre_submit_path, post_submit_path)?;
+    let submission = legacy
+        .last_submission
+        .map(|(sequencer_height, celestia_height)| {
+            Submission::new(
+                sequencer_height,
+                sequencer_height,
+                celestia_height,
+                Vec::new(),
+            )
+        });
+    let pending = legacy
+        .unfinished_parts
+        .map(|(sequencer_height, parts)| journal::Pending {
+            first_sequencer_height: sequencer_height,
+            last_sequencer_height: sequencer_height,
+            parts,
+        });
+    if submission.is_none() && pending.is_none() {
+        info!("pre- and post-submit files hold no submissions; starting with an empty journal");
+        return Ok(journal);
+    }
+    drop(journal);
+    let journal = Journal::create(journal_path, submission, pending)
+        .wrap_err("failed writing migrated journal")?;
+    info!(
+        last_submitted_sequencer_height =
+            journal.last_submitted_height().map(tracing::field::display),
+        "migrated pre- and post-submit files into the journal; the files are no longer read once \
+         the journal holds entries",
+    );
+    Ok(journal)
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct SubmissionState {
+    journal: Arc<Journal>,
+}
+
+#[derive(Debug)]
+pub(super) struct Started {
+    state: SubmissionState,
+    first_sequencer_height: SequencerHeight,
+    last_sequencer_height: SequencerHeight,
+    parts: Vec<SubmittedPart>,
+}
+
+impl Started {
+    /// Returns the parts of the split sequencer block that were already posted.
+    pub(super) fn parts(&self) -> &[SubmittedPart] {
+        &self.parts
+    }
+
+    /// Records that the blobs of `rollup_ids` of the split sequencer block were posted at
+    /// `celestia_height`, appending the part to the journal.
+    pub(super) fn record_part(
+        mut self,
+        celestia_height: u64,
+        rollup_ids: Vec<RollupId>,
+        blobs: Vec<PostedBlob>,
+    ) -> eyre::Result<Self> {
+        let part = SubmittedPart::new(celestia_height, rollup_ids, blobs);
+        debug!(
+            celestia_height,
+            "recording submitted part of split sequencer block in journal",
+        );
+        self.state
+            .journal
+            .record_part(part.clone())
+            .wrap_err("failed recording submitted part in journal")?;
+        self.parts.push(part);
+        Ok(self)
+    }
+
+    /// Finalizes the submission whose last transaction was included at `celestia_height` with
+    /// `blobs`, appending it to the journal.
+    pub(super) fn finalize(
+        self,
+        celestia_height: u64,
+        blobs: Vec<PostedBlob>,
+    ) -> eyre::Result<SubmissionState> {
+        let Self {
+            state,
+            first_sequencer_height,
+            last_sequencer_height,
+            parts,
+        } = self;
+        let blobs = parts
+            .iter()
+            .flat_map(|part| part.blobs().iter().cloned())
+            .chain(blobs)
+            .collect();
+        debug!(
+            %first_sequencer_height,
+            %last_sequencer_height,
+            celestia_height,
+            "finalizing submission by recording it in journal",
+        );
+        state
+            .journal
+            .finalize(Submission::new(
+                first_sequencer_height,
+                last_sequencer_height,
+                celestia_height,
+                blobs,
+            ))
+            .wrap_err("failed recording finalized submission in journal")?;
+        Ok(state)
+    }
+}
+
+impl SubmissionState {
+    pub(super) fn new(journal: Arc<Journal>) -> Self {
+        Self {
+            journal,
+        }
+    }
+
+    pub(super) fn last_submitted_height(&self) -> Option<SequencerHeight> {
+        self.journal.last_submitted_height()
+    }
+
+    /// Starts the submission of the sequencer blocks from `first_sequencer_height` to
+    /// `last_sequencer_height`.
+    ///
+    /// If the same blocks were started but not finalized before, the submission is resumed with
+    /// the parts that were already posted.
+    pub(super) fn initialize(
+        self,
+        first_sequencer_height: SequencerHeight,
+        last_sequencer_height: SequencerHeight,
+    ) -> eyre::Result<Started> {
+        if let Some(latest_submitted) = self.last_submitted_height() {
+            ensure!(
+                first_sequencer_height > latest_submitted,
+                "refusing to submit a sequencer block at heights below or at what was already \
+                 submitted"
+            );
+        }
+        let parts = match self.journal.pending() {
+            Some(pending)
+                if pending.first_sequencer_height == first_sequencer_height
+                    && pending.last_sequencer_height == last_sequencer_height =>
+            {
+                debug!(
+                    parts = pending.parts.len(),
+                    "resuming unfinished submission recorded in journal"
+                );
+                pending.parts
+            }
+            _ => {
+                debug!("initializing submission by recording it in journal");
+                self.journal
+                    .start(first_sequencer_height, last_sequencer_height)
+                    .wrap_err("failed recording started submission in journal")?;
+                Vec::new()
+            }
+        };
+        Ok(Started {
+            state: self,
+            first_sequencer_height,
+            last_sequencer_height,
+            parts,
+        })
+    }
+}
+
+mod as_number {
+    //! Logic to serialize sequencer heights as number, deserialize numbers as sequencer heights.
+    //!
+    //! This is unfortunately necessary because the [`serde::Serialize`], [`serde::Deserialize`]
+    //! implementations for [`sequencer_client::tendermint::block::Height`] write the integer as
+    //! string, probably due to tendermint's/cometbft's go-legacy.
+    use serde::{
+        Deserialize as _,
+        Deserializer,
+        Serializer,
+    };
+
+    use super::SequencerHeight;
+    // Allow: the function signature is dictated by the serde(with) attribute.
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    pub(super) fn serialize<S>(height: &SequencerHeight, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_u64(height.value())
+    }
+
+    pub(super) fn deserialize<'de, D>(deserializer: D) -> Result<SequencerHeight, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        use serde::de::Error;
+        let height = u64::deserialize(deserializer)?;
+        SequencerHeight::try_from(height).map_err(Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use astria_core::sequencer::v1::RollupId;
+    use serde_json::json;
+
+    use super::{
+        open_journal,
+        Submission,
+        SubmissionState,
+    };
+
+    fn open_state(dir: &tempfile::TempDir) -> SubmissionState {
+        let journal = open_journal(
+            dir.path().join("journal.jsonl"),
+            dir.path().join("presubmit.json"),
+            dir.path().join("postsubmit.json"),
+        )
+        .unwrap();
+        SubmissionState::new(Arc::new(journal))
+    }
+
+    #[test]
+    fn submit_initialize_finalize_flow_works() {
+        let dir = tempfile::tempdir().unwrap();
+        let state = open_state(&dir);
+        assert_eq!(state.last_submitted_height(), None);
+        let started = state.initialize(2u32.into(), 3u32.into()).unwrap();
+        let finalized = started.finalize(6, Vec::new()).unwrap();
+        assert_eq!(finalized.last_submitted_height(), Some(3u32.into()));
+
+        let state = open_state(&dir);
+        assert_eq!(state.last_submitted_height(), Some(3u32.into()));
+        assert_eq!(
+            state.journal.submission_at(2u32.into()).unwrap(),
+            Some(Submission::new(2u32.into(), 3u32.into(), 6, Vec::new())),
+        );
+    }
+
+    #[test]
+    fn submit_old_blocks_gives_error() {
+        let dir = tempfile::tempdir().unwrap();
+        let state = open_state(&dir)
+            .initialize(2u32.into(), 2u32.into())
+            .unwrap()
+            .finalize(5, Vec::new())
+            .unwrap();
+        let _ = state
+            .initialize(2u32.into(), 3u32.into())
+            .expect_err("trying to submit the same sequencer height is an error");
+    }
+
+    #[test]
+    fn parts_of_unfinished_split_block_are_resumed() {
+        let dir = tempfile::tempdir().unwrap();
+        let rollup_id = RollupId::new([1; 32]);
+        let started = open_state(&dir)
+            .initialize(4u32.into(), 4u32.into())
+            .unwrap()
+            .record_part(6, vec![rollup_id], Vec::new())
+            .unwrap();
+        assert_eq!(started.parts().len(), 1);
+
+        // the relayer restarts before the head blob of the split block was posted
+        let started = open_state(&dir)
+            .initialize(4u32.into(), 4u32.into())
+            .unwrap();
+        let [part] = started.parts() else {
+            panic!("the part of the unfinished split block should have been resumed");
+        };
+        assert_eq!(part.celestia_height(), 6);
+        assert_eq!(part.rollup_ids(), &[rollup_id]);
+
+        let started = started
+            .finalize(7, Vec::new())
+            .unwrap()
+            .initialize(5u32.into(), 5u32.into())
+            .unwrap();
+        assert!(started.parts().is_empty());
+    }
+
+    #[test]
+    fn pre_and_post_submit_files_are_migrated() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("presubmit.json"),
+            json!({ "state": "ignore" }).to_string(),
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join("postsubmit.json"),
+            json!({ "state": "submitted", "celestia_height": 5, "sequencer_height": 2 })
+                .to_string(),
+        )
+        .unwrap();
+        let state = open_state(&dir);
+        assert_eq!(state.last_submitted_height(), Some(2u32.into()));
+
+        // the files are not migrated again once the journal holds entries
+        std::fs::write(
+            dir.path().join("postsubmit.json"),
+            json!({ "state": "fresh" }).to_string(),
+        )
+        .unwrap();
+        let state = open_state(&dir);
+        assert_eq!(state.last_submitted_height(), Some(2u32.into()));
+    }
+
+    #[test]
+    fn interrupted_migration_is_retried() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("presubmit.json"),
+            json!({
+                "state": "started",
+                "sequencer_height": 4,
+                "last_submission": { "state": "submitted", "celestia_height": 5, "sequencer_height": 3 },
+                "parts": [{ "celestia_height": 6, "rollup_ids": [hex::encode([1u8; 32])] }],
+            })
+            .to_string(),
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join("postsubmit.json"),
+            json!({ "state": "submitted", "celestia_height": 5, "sequencer_height": 3 })
+                .to_string(),
+        )
+        .unwrap();
+        // a crash before the migrated journal was renamed into place leaves it empty
+        std::fs::write(dir.path().join("journal.jsonl"), "").unwrap();
+        std::fs::write(dir.path().join("journal.jsonl.tmp"), "{\"entry\":").unwrap();
+
+        let state = open_state(&dir);
+        assert_eq!(state.last_submitted_height(), Some(3u32.into()));
+        let started = state.initialize(4u32.into(), 4u32.into()).unwrap();
+        let [part] = started.parts() else {
+            panic!("the part of the unfinished split block should have been migrated");
+        };
+        assert_eq!(part.celestia_height(), 6);
+    }
+}
@@ -1,16 +1,41 @@
-use astria_core::sequencer::v1::RollupId;
+use std::collections::HashMap;
+
+use astria_core::sequencer::v1::{
+    block::MAX_SEQUENCE_DATA_BYTES_PER_BLOCK,
+    CelestiaSequencerBlob,
+    PostedRollupBlob,
+    RollupId,
+};
 use astria_eyre::eyre::{
     self,
     WrapErr as _,
 };
-use celestia_client::celestia_types::{
-    nmt::Namespace,
-    Blob,
+use celestia_client::{
+    celestia_types::{
+        nmt::Namespace,
+        Blob,
+    },
+    envelope,
 };
 use prost::Message as _;
 use sequencer_client::SequencerBlock;
 use tendermint::block::Height as SequencerHeight;
 
+use crate::relayer::routing::Routing;
+
+/// The headroom for the encoding of a rollup blob on top of its sequence action data: the
+/// protobuf framing of its transactions, the sequencer block hash, the rollup ID, the inclusion
+/// proof and the deposits. It also covers the envelope and the worst-case growth of data that zstd
+/// cannot compress, so uncompressed blobs bound the size of compressed ones.
+const ROLLUP_BLOB_OVERHEAD_BYTES: usize = 64 * 1024;
+
+/// The max number of bytes of the data of a single rollup blob.
+///
+/// Rollup blobs cannot be split across Celestia transactions, so the max bytes per Celestia
+/// transaction must not be below this.
+pub(crate) const MAX_ROLLUP_BLOB_BYTES: usize =
+    MAX_SEQUENCE_DATA_BYTES_PER_BLOCK + ROLLUP_BLOB_OVERHEAD_BYTES;
+
 // allow: the signature is dictated by the `serde(serialize_with = ...)` attribute.
 #[allow(clippy::trivially_copy_pass_by_ref)]
 fn serialize_height<S>(height: &SequencerHeight, serializer: S) -> Result<S::Ok, S::Error>
@@ -44,50 +69,212 @@ pub(super) struct ConversionInfo {
     #[serde(serialize_with = "serialize_namespace")]
     pub(super) sequencer_namespace: Namespace,
     pub(super) rollups: Vec<RollupInfo>,
+    /// The rollups in the block whose blobs were not relayed.
+    pub(super) skipped_rollups: Vec<RollupId>,
+    /// The total size of the protobuf encoded blobs before compression.
+    pub(super) uncompressed_size: usize,
+    /// The total size of the blobs after compression, which equals `uncompressed_size` if blobs
+    /// are not compressed.
+    pub(super) compressed_size: usize,
+}
+
+/// A rollup blob of a converted block.
+pub(super) struct RollupBlob {
+    pub(super) rollup_id: RollupId,
+    pub(super) blob: Blob,
 }
 
 /// The result of a block that was converted to blobs.
 pub(super) struct Converted {
-    pub(super) blobs: Vec<Blob>,
+    /// The sequencer blob the head blob was encoded from.
+    sequencer_blob: CelestiaSequencerBlob,
+    /// Whether the blobs are compressed.
+    compress_blobs: bool,
+    /// The head blob, encoded on the assumption that it is posted in the same Celestia
+    /// transaction as all rollup blobs.
+    pub(super) head_blob: Blob,
+    pub(super) rollup_blobs: Vec<RollupBlob>,
     pub(super) info: ConversionInfo,
 }
 
-pub(super) fn convert(block: SequencerBlock) -> eyre::Result<Converted> {
+impl Converted {
+    /// Returns the total size of the data of all blobs of the block.
+    pub(super) fn size(&self) -> usize {
+        self.head_blob.data.len()
+            + self
+                .rollup_blobs
+                .iter()
+                .map(|rollup_blob| rollup_blob.blob.data.len())
+                .sum::<usize>()
+    }
+
+    pub(super) fn num_blobs(&self) -> usize {
+        self.rollup_blobs.len() + 1
+    }
+
+    /// Returns all blobs of the block, starting with the head blob.
+    pub(super) fn into_blobs(self) -> impl Iterator<Item = Blob> {
+        std::iter::once(self.head_blob).chain(
+            self.rollup_blobs
+                .into_iter()
+                .map(|rollup_blob| rollup_blob.blob),
+        )
+    }
+
+    /// Encodes the head blob again, recording the Celestia heights in `celestia_heights` of the
+    /// rollup blobs that were posted in earlier Celestia transactions than the head blob.
+    pub(super) fn head_blob_with_celestia_heights(
+        &self,
+        celestia_heights: &HashMap<RollupId, u64>,
+    ) -> eyre::Result<Blob> {
+        let posted_rollup_blobs = self
+            .sequencer_blob
+            .posted_rollup_blobs()
+            .unwrap_or_default()
+            .iter()
+            .map(|posted| match celestia_heights.get(&posted.rollup_id()) {
+                Some(&celestia_height) => posted.with_celestia_height(celestia_height),
+                None => *posted,
+            })
+            .collect();
+        let sequencer_blob = self
+            .sequencer_blob
+            .clone()
+            .with_posted_rollup_blobs(posted_rollup_blobs)
+            .wrap_err("failed recording the celestia heights of the posted rollup blobs")?;
+        let data = encode(
+            &sequencer_blob.into_raw(),
+            self.compress_blobs,
+            &mut 0,
+            &mut 0,
+        )
+        .wrap_err("failed encoding head Celestia blob")?;
+        Blob::new(self.info.sequencer_namespace, data)
+            .wrap_err("failed creating head Celestia blob")
+    }
+}
+
+/// Encodes `blob` as protobuf and, if `compress` is set, compresses it into a versioned
+/// envelope.
+///
+/// Adds the size of the encoded and the resulting blob data to `uncompressed_size` and
+/// `compressed_size`, respectively.
+fn encode<T: prost::Message>(
+    blob: &T,
+    compress: bool,
+    uncompressed_size: &mut usize,
+    compressed_size: &mut usize,
+) -> eyre::Result<Vec<u8>> {
+    let encoded = blob.encode_to_vec();
+    *uncompressed_size += encoded.len();
+    let data = if compress {
+        envelope::compress(&encoded)?
+    } else {
+        encoded
+    };
+    *compressed_size += data.len();
+    Ok(data)
+}
+
+/// Converts sequencer blocks to Celestia blobs.
+pub(crate) struct Converter {
+    /// Selects the rollups whose blobs are relayed and the namespaces they are posted under.
+    routing: Routing,
+    /// Whether blobs are compressed into a versioned envelope, or posted as plain protobuf.
+    compress_blobs: bool,
+}
+
+impl Converter {
+    pub(crate) fn new(routing: Routing, compress_blobs: bool) -> Self {
+        Self {
+            routing,
+            compress_blobs,
+        }
+    }
+
+    /// Converts `block` to Celestia blobs, with the rollup blobs selected and namespaced by the
+    /// converter's routing.
+    ///
+    /// The rollups whose blobs are posted and their namespaces are recorded in the sequencer
+    /// blob.
+    pub(super) fn convert(&self, block: SequencerBlock) -> eyre::Result<Converted> {
+        convert(block, &self.routing, self.compress_blobs)
+    }
+}
+
+fn convert(
+    block: SequencerBlock,
+    routing: &Routing,
+    compress_blobs: bool,
+) -> eyre::Result<Converted> {
     let sequencer_height = block.height();
+    let mut uncompressed_size = 0;
+    let mut compressed_size = 0;
 
     let (sequencer_blob, rollup_blobs) = block.into_celestia_blobs();
-    // Allocate extra space: one blob for the sequencer blob "header",
-    // the rest for the rollup blobs.
-    let mut blobs = Vec::with_capacity(rollup_blobs.len() + 1);
     let sequencer_namespace =
         celestia_client::celestia_namespace_v0_from_cometbft_header(sequencer_blob.header());
 
-    let header_blob = Blob::new(
-        sequencer_namespace,
-        sequencer_blob.into_raw().encode_to_vec(),
-    )
-    .wrap_err("failed creating head Celestia blob")?;
-    blobs.push(header_blob);
+    let mut rollup_celestia_blobs = Vec::with_capacity(rollup_blobs.len());
+    let mut posted_rollup_blobs = Vec::with_capacity(rollup_blobs.len());
     let mut rollups = Vec::new();
+    let mut skipped_rollups = Vec::new();
     for blob in rollup_blobs {
         let rollup_id = blob.rollup_id();
-        let namespace = celestia_client::celestia_namespace_v0_from_rollup_id(rollup_id);
+        if !routing.is_relayed(rollup_id) {
+            skipped_rollups.push(rollup_id);
+            continue;
+        }
+        let namespace = routing.namespace(rollup_id);
+        let namespace_bytes = namespace.as_bytes().try_into().wrap_err_with(|| {
+            format!("celestia namespace of rollup `{rollup_id}` has an unexpected length")
+        })?;
+        posted_rollup_blobs.push(PostedRollupBlob::new(rollup_id, namespace_bytes));
         let info = RollupInfo {
             number_of_transactions: blob.transactions().len(),
             celestia_namespace: namespace,
             sequencer_rollup_id: blob.rollup_id(),
         };
-        let blob = Blob::new(namespace, blob.into_raw().encode_to_vec())
+        let data = encode(
+            &blob.into_raw(),
+            compress_blobs,
+            &mut uncompressed_size,
+            &mut compressed_size,
+        )
+        .wrap_err_with(|| format!("failed encoding blob for rollup `{rollup_id}`"))?;
+        let blob = Blob::new(namespace, data)
             .wrap_err_with(|| format!("failed creating blob for rollup `{rollup_id}`"))?;
-        blobs.push(blob);
+        rollup_celestia_blobs.push(RollupBlob {
+            rollup_id,
+            blob,
+        });
         rollups.push(info);
     }
+
+    let sequencer_blob = sequencer_blob
+        .with_posted_rollup_blobs(posted_rollup_blobs)
+        .wrap_err("failed recording the posted rollup blobs in the head Celestia blob")?;
+    let header_data = encode(
+        &sequencer_blob.clone().into_raw(),
+        compress_blobs,
+        &mut uncompressed_size,
+        &mut compressed_size,
+    )
+    .wrap_err("failed encoding head Celestia blob")?;
+    let header_blob = Blob::new(sequencer_namespace, header_data)
+        .wrap_err("failed creating head Celestia blob")?;
     Ok(Converted {
-        blobs,
+        sequencer_blob,
+        compress_blobs,
+        head_blob: header_blob,
+        rollup_blobs: rollup_celestia_blobs,
         info: ConversionInfo {
             sequencer_height,
             sequencer_namespace,
             rollups,
+            skipped_rollups,
+            uncompressed_size,
+            compressed_size,
         },
     })
 }
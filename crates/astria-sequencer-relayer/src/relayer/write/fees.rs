@@ -0,0 +1,226 @@
+//! Gas estimation and fee escalation for submitting blobs to Celestia.
+//!
+//! The gas of a `MsgPayForBlobs` transaction is estimated from the sizes of its blobs following
+//! celestia-app's `DefaultEstimateGas`. The fee paid is the estimated gas multiplied with the gas
+//! price. Each time a submission times out or is rejected for its fee, the gas price is raised by
+//! the configured multiplier for the next attempt, up to the configured max gas price.
+
+use std::time::Duration;
+
+use astria_eyre::eyre::{
+    self,
+    ensure,
+};
+use celestia_client::{
+    celestia_types::{
+        blob::SubmitOptions,
+        Blob,
+    },
+    jsonrpsee,
+};
+
+/// The size of a Celestia share in bytes.
+const SHARE_SIZE: u64 = 512;
+
+/// The number of bytes of blob data held by the first share of a blob.
+///
+/// The first share is prefixed by a 29 byte namespace, a 1 byte info byte, and the 4 byte length
+/// of the blob.
+const FIRST_SPARSE_SHARE_CONTENT_SIZE: u64 = 478;
+
+/// The number of bytes of blob data held by each continuation share of a blob.
+///
+/// Continuation shares are prefixed by a 29 byte namespace and a 1 byte info byte.
+const CONTINUATION_SPARSE_SHARE_CONTENT_SIZE: u64 = 482;
+
+/// The gas charged per byte of the shares occupied by a blob.
+const GAS_PER_BLOB_BYTE: u64 = 8;
+
+/// The gas charged per byte of the transaction.
+const TX_SIZE_COST_PER_BYTE: u64 = 10;
+
+/// The estimated number of bytes per blob in a `MsgPayForBlobs` transaction.
+const BYTES_PER_BLOB_INFO: u64 = 70;
+
+/// The fixed gas cost of a `MsgPayForBlobs` transaction.
+const PFB_GAS_FIXED_COST: u64 = 75_000;
+
+/// Returns the number of shares a blob of `blob_size` bytes occupies.
+fn sparse_shares_needed(blob_size: u64) -> u64 {
+    if blob_size == 0 {
+        return 0;
+    }
+    if blob_size <= FIRST_SPARSE_SHARE_CONTENT_SIZE {
+        return 1;
+    }
+    1 + (blob_size - FIRST_SPARSE_SHARE_CONTENT_SIZE)
+        .div_ceil(CONTINUATION_SPARSE_SHARE_CONTENT_SIZE)
+}
+
+/// Estimates the gas required to submit `blobs` to Celestia in a single transaction.
+pub(super) fn estimate_gas(blobs: &[Blob]) -> u64 {
+    let blob_gas: u64 = blobs
+        .iter()
+        .map(|blob| sparse_shares_needed(blob.data.len() as u64) * SHARE_SIZE * GAS_PER_BLOB_BYTE)
+        .sum();
+    let tx_size_gas = TX_SIZE_COST_PER_BYTE * BYTES_PER_BLOB_INFO * blobs.len() as u64;
+    blob_gas + tx_size_gas + PFB_GAS_FIXED_COST
+}
+
+/// Returns if Celestia rejected a submission because its fee or gas price was too low.
+pub(super) fn is_fee_rejection(error: &jsonrpsee::core::Error) -> bool {
+    let jsonrpsee::core::Error::Call(error) = error else {
+        return false;
+    };
+    let message = error.message();
+    message.contains("insufficient fee") || message.contains("gas price")
+}
+
+/// The pricing of gas for Celestia submissions.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct GasPricing {
+    /// The gas price in utia of the first attempt to submit blobs.
+    base_price: f64,
+    /// The factor the gas price is multiplied with after each failed attempt.
+    multiplier: f64,
+    /// The max gas price in utia.
+    max_price: f64,
+    /// The duration after which an attempt to submit blobs is abandoned.
+    submission_timeout: Duration,
+}
+
+impl GasPricing {
+    pub(crate) fn new(
+        base_price: f64,
+        multiplier: f64,
+        max_price: f64,
+        submission_timeout: Duration,
+    ) -> eyre::Result<Self> {
+        ensure!(
+            base_price.is_finite() && base_price > 0.0,
+            "base gas price must be a positive number, but was `{base_price}`"
+        );
+        ensure!(
+            multiplier.is_finite() && multiplier >= 1.0,
+            "gas price multiplier must be at least 1, but was `{multiplier}`"
+        );
+        ensure!(
+            max_price.is_finite() && max_price >= base_price,
+            "max gas price must be at least the base gas price `{base_price}`, but was \
+             `{max_price}`"
+        );
+        ensure!(
+            !submission_timeout.is_zero(),
+            "celestia submission timeout must not be zero"
+        );
+        Ok(Self {
+            base_price,
+            multiplier,
+            max_price,
+            submission_timeout,
+        })
+    }
+
+    pub(super) fn submission_timeout(&self) -> Duration {
+        self.submission_timeout
+    }
+
+    /// Returns the gas price of the first attempt to submit blobs.
+    pub(super) fn base_price(&self) -> f64 {
+        self.base_price
+    }
+
+    /// Returns the gas price of the attempt following a failed attempt at `price`.
+    pub(super) fn escalate(&self, price: f64) -> f64 {
+        (price * self.multiplier).min(self.max_price)
+    }
+}
+
+/// The gas and fee of one attempt to submit blobs.
+#[derive(Clone, Copy, Debug)]
+pub(super) struct Fee {
+    pub(super) gas_limit: u64,
+    pub(super) gas_price: f64,
+    /// The fee in utia.
+    pub(super) amount: u64,
+}
+
+impl Fee {
+    pub(super) fn new(gas_limit: u64, gas_price: f64) -> Self {
+        // allow: the gas limit and fee of celestia transactions are far below 2^52 and positive
+        #[allow(
+            clippy::cast_precision_loss,
+            clippy::cast_possible_truncation,
+            clippy::cast_sign_loss
+        )]
+        let amount = (gas_limit as f64 * gas_price).ceil() as u64;
+        Self {
+            gas_limit,
+            gas_price,
+            amount,
+        }
+    }
+
+    pub(super) fn submit_options(&self) -> SubmitOptions {
+        SubmitOptions {
+            fee: Some(self.amount),
+            gas_limit: Some(self.gas_limit),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shares_needed_follow_share_layout() {
+        assert_eq!(0, sparse_shares_needed(0));
+        assert_eq!(1, sparse_shares_needed(1));
+        assert_eq!(1, sparse_shares_needed(478));
+        assert_eq!(2, sparse_shares_needed(479));
+        assert_eq!(2, sparse_shares_needed(478 + 482));
+        assert_eq!(3, sparse_shares_needed(478 + 482 + 1));
+    }
+
+    #[test]
+    fn gas_price_is_escalated_up_to_max() {
+        let pricing = GasPricing::new(0.002, 1.5, 0.004, Duration::from_secs(1)).unwrap();
+        let price = pricing.escalate(pricing.base_price());
+        assert!((price - 0.003).abs() < f64::EPSILON);
+        assert!((pricing.escalate(price) - 0.004).abs() < f64::EPSILON);
+        assert!((pricing.escalate(0.004) - 0.004).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn invalid_gas_pricing_is_rejected() {
+        let timeout = Duration::from_secs(1);
+        assert!(GasPricing::new(0.0, 1.5, 1.0, timeout).is_err());
+        assert!(GasPricing::new(0.002, 0.5, 1.0, timeout).is_err());
+        assert!(GasPricing::new(0.002, 1.5, 0.001, timeout).is_err());
+        assert!(GasPricing::new(0.002, 1.5, 1.0, Duration::ZERO).is_err());
+    }
+
+    #[test]
+    fn only_fee_rejections_escalate_the_gas_price() {
+        use jsonrpsee::types::ErrorObject;
+
+        let call_error = |message: &str| {
+            jsonrpsee::core::Error::Call(ErrorObject::owned(1, message.to_string(), None::<()>))
+        };
+        assert!(is_fee_rejection(&call_error(
+            "insufficient fees; got: 100utia required: 200utia"
+        )));
+        assert!(is_fee_rejection(&call_error(
+            "insufficient minimum gas price for this node"
+        )));
+        assert!(!is_fee_rejection(&call_error("account sequence mismatch")));
+        assert!(!is_fee_rejection(&jsonrpsee::core::Error::RequestTimeout));
+    }
+
+    #[test]
+    fn fee_is_rounded_up() {
+        let fee = Fee::new(100_001, 0.002);
+        assert_eq!(201, fee.amount);
+    }
+}
@@ -8,7 +8,11 @@
 //! receives blocks and imposes no extra ordering. This means that if
 //! another task sends sequencer blocks ordered by their heights, then
 //! they will be written in that order.
+//!
+//! [`submit_backfilled_block`] writes a single sequencer block to Celestia outside of the
+//! [`BlobSubmitter`], to re-relay it without disturbing the order of its submissions.
 use std::{
+    collections::HashMap,
     future::Future,
     mem,
     sync::Arc,
@@ -16,8 +20,10 @@ use std::{
     time::Duration,
 };
 
+use astria_core::sequencer::v1::RollupId;
 use astria_eyre::eyre::{
     self,
+    bail,
     WrapErr as _,
 };
 use celestia_client::{
@@ -58,61 +64,60 @@ use tracing::{
     instrument,
     warn,
     Instrument,
-    Span,
 };
 
-use super::submission::SubmissionState;
+use super::submission::{
+    PostedBlob,
+    Started,
+    Submission,
+    SubmissionState,
+};
 
 mod conversion;
+mod fees;
+mod packing;
 
 use conversion::{
-    convert,
-    ConversionInfo,
     Converted,
+    RollupBlob,
+};
+pub(super) use conversion::{
+    Converter,
+    MAX_ROLLUP_BLOB_BYTES,
 };
+use fees::Fee;
+pub(super) use fees::GasPricing;
 
 struct QueuedConvertedBlocks {
     // The maximum number of blobs permitted to sit in the blob queue.
     max_blobs: usize,
-    blobs: Vec<Blob>,
-    infos: Vec<ConversionInfo>,
-    greatest_sequencer_height: Option<SequencerHeight>,
+    // The maximum number of bytes of blob data permitted in a single Celestia transaction.
+    max_bytes_per_tx: usize,
+    blocks: Vec<Converted>,
+    num_blobs: usize,
 }
 
 impl QueuedConvertedBlocks {
     fn is_empty(&self) -> bool {
-        self.blobs.is_empty()
-    }
-
-    fn num_blobs(&self) -> usize {
-        self.blobs.len()
-    }
-
-    fn num_converted(&self) -> usize {
-        self.infos.len()
+        self.blocks.is_empty()
     }
 
-    fn with_max_blobs(max_blobs: usize) -> Self {
+    fn new(max_blobs: usize, max_bytes_per_tx: usize) -> Self {
         Self {
             max_blobs,
-            blobs: Vec::new(),
-            infos: Vec::new(),
-            greatest_sequencer_height: None,
+            max_bytes_per_tx,
+            blocks: Vec::new(),
+            num_blobs: 0,
         }
     }
 
     fn has_capacity(&self) -> bool {
-        self.blobs.len() < self.max_blobs
+        self.num_blobs < self.max_blobs
     }
 
-    fn push(&mut self, mut converted: Converted) {
-        self.blobs.append(&mut converted.blobs);
-        let info = converted.info;
-        let greatest_height = self
-            .greatest_sequencer_height
-            .get_or_insert(info.sequencer_height);
-        *greatest_height = std::cmp::max(*greatest_height, info.sequencer_height);
-        self.infos.push(info);
+    fn push(&mut self, converted: Converted) {
+        self.num_blobs += converted.num_blobs();
+        self.blocks.push(converted);
     }
 
     /// Lazily move the currently queued blobs out of the queue.
@@ -142,7 +147,7 @@ impl<'a> Future for TakeQueued<'a> {
             .inner
             .take()
             .expect("this future must not be polled twice");
-        let empty = QueuedConvertedBlocks::with_max_blobs(queued.max_blobs);
+        let empty = QueuedConvertedBlocks::new(queued.max_blobs, queued.max_bytes_per_tx);
         let queued = mem::replace(queued, empty);
         if queued.is_empty() {
             Poll::Ready(None)
@@ -203,6 +208,9 @@ pub(super) struct BlobSubmitter {
     // Tracks the submission state and writes it to disk before and after each Celestia submission.
     submission_state: super::SubmissionState,
 
+    // The pricing of gas for Celestia submissions.
+    gas_pricing: GasPricing,
+
     // The shutdown token to signal that blob submitter should finish its current submission and
     // exit.
     shutdown_token: CancellationToken,
@@ -213,6 +221,9 @@ impl BlobSubmitter {
         client: HttpClient,
         state: Arc<super::State>,
         submission_state: super::SubmissionState,
+        converter: Arc<Converter>,
+        gas_pricing: GasPricing,
+        max_bytes_per_tx: usize,
         shutdown_token: CancellationToken,
     ) -> (Self, BlobSubmitterHandle) {
         // XXX: The channel size here is just a number. It should probably be based on some
@@ -221,10 +232,11 @@ impl BlobSubmitter {
         let submitter = Self {
             client,
             blocks: rx,
-            conversions: Conversions::new(8),
-            blobs: QueuedConvertedBlocks::with_max_blobs(128),
+            conversions: Conversions::new(8, converter),
+            blobs: QueuedConvertedBlocks::new(128, max_bytes_per_tx),
             state,
             submission_state,
+            gas_pricing,
             shutdown_token,
         };
         let handle = BlobSubmitterHandle {
@@ -267,6 +279,7 @@ impl BlobSubmitter {
                         blobs,
                         self.state.clone(),
                         self.submission_state.clone(),
+                        self.gas_pricing,
                     ).boxed().fuse();
                 }
 
@@ -325,6 +338,10 @@ impl BlobSubmitter {
 
 /// Submits new blobs Celestia.
 ///
+/// The blocks are packed into Celestia transactions of at most the queue's max bytes per
+/// transaction. Each transaction is its own submission, except for the transactions of a block
+/// that is split across several of them.
+///
 /// # Panics
 /// Panics if `blocks` is empty. This function should only be called if there is something to
 /// submit.
@@ -333,137 +350,615 @@ async fn submit_blobs(
     client: HttpClient,
     blocks: QueuedConvertedBlocks,
     state: Arc<super::State>,
-    submission_state: SubmissionState,
+    mut submission_state: SubmissionState,
+    gas_pricing: GasPricing,
 ) -> eyre::Result<SubmissionState> {
+    let QueuedConvertedBlocks {
+        max_bytes_per_tx,
+        blocks,
+        ..
+    } = blocks;
+    assert!(
+        !blocks.is_empty(),
+        "there should always be blobs and accompanying sequencer heights when this function is \
+         called",
+    );
+    let infos: Vec<_> = blocks.iter().map(|block| &block.info).collect();
     info!(
-        blocks = %telemetry::display::json(&blocks.infos),
+        blocks = %telemetry::display::json(&infos),
         "initiated submission of sequencer blocks converted to Celestia blobs",
     );
 
-    let start = std::time::Instant::now();
+    let (uncompressed_size, compressed_size) =
+        infos
+            .iter()
+            .fold((0, 0), |(uncompressed, compressed), info| {
+                (
+                    uncompressed + info.uncompressed_size,
+                    compressed + info.compressed_size,
+                )
+            });
+    if uncompressed_size > 0 {
+        // allow: the blob sizes are low enough to not cause relevant precision loss
+        #[allow(clippy::cast_precision_loss)]
+        let compression_ratio = compressed_size as f64 / uncompressed_size as f64;
+        metrics::gauge!(crate::metrics_init::BLOB_COMPRESSION_RATIO).set(compression_ratio);
+    }
 
-    metrics::counter!(crate::metrics_init::CELESTIA_SUBMISSION_COUNT).increment(1);
-    // XXX: The number of blocks per celestia tx is equal to the number of heights passed
-    // into this function. This comes from the way that `QueuedBlocks::take` is implemented.
-    //
-    // allow: the number of blocks should always be low enough to not cause precision loss
-    #[allow(clippy::cast_precision_loss)]
-    let blocks_per_celestia_tx = blocks.num_converted() as f64;
-    metrics::gauge!(crate::metrics_init::BLOCKS_PER_CELESTIA_TX).set(blocks_per_celestia_tx);
+    for mut chunk in packing::chunk_by_size(blocks, max_bytes_per_tx, Converted::size) {
+        submission_state = if chunk.len() == 1 && chunk[0].size() > max_bytes_per_tx {
+            let block = chunk.pop().expect("the chunk contains exactly one block");
+            submit_split_block(
+                &client,
+                block,
+                max_bytes_per_tx,
+                &state,
+                submission_state,
+                gas_pricing,
+            )
+            .await?
+        } else {
+            submit_whole_blocks(&client, chunk, &state, submission_state, gas_pricing).await?
+        };
+    }
+    Ok(submission_state)
+}
 
-    // allow: the number of blobs should always be low enough to not cause precision loss
-    #[allow(clippy::cast_precision_loss)]
-    let blobs_per_celestia_tx = blocks.num_blobs() as f64;
-    metrics::gauge!(crate::metrics_init::BLOBS_PER_CELESTIA_TX).set(blobs_per_celestia_tx);
+/// Submits `blocks` to Celestia in a single transaction.
+async fn submit_whole_blocks(
+    client: &HttpClient,
+    blocks: Vec<Converted>,
+    state: &Arc<super::State>,
+    submission_state: SubmissionState,
+    gas_pricing: GasPricing,
+) -> eyre::Result<SubmissionState> {
+    let heights = blocks.iter().map(|block| block.info.sequencer_height);
+    let smallest_height = heights
+        .clone()
+        .min()
+        .expect("blocks are only submitted in non-empty chunks");
+    let largest_height = heights
+        .max()
+        .expect("blocks are only submitted in non-empty chunks");
+    let num_blocks = blocks.len();
+
+    let submission_started =
+        initialize_submission(submission_state, smallest_height, largest_height).await?;
+    let blobs = blocks.into_iter().flat_map(Converted::into_blobs).collect();
+    let (celestia_height, posted_blobs) =
+        submit_transaction(client, blobs, num_blocks, state, gas_pricing).await?;
+    finalize_submission(submission_started, celestia_height, posted_blobs).await
+}
 
-    let largest_height = blocks.greatest_sequencer_height.expect(
-        "there should always be blobs and accompanying sequencer heights when this function is \
-         called",
+/// Submits `block`, whose blobs exceed `max_bytes_per_tx`, across several Celestia transactions.
+///
+/// Each transaction of rollup blobs is recorded as a part of the submission. Rollup blobs that
+/// were already posted by an unfinished submission of the same block are not posted again.
+async fn submit_split_block(
+    client: &HttpClient,
+    block: Converted,
+    max_bytes_per_tx: usize,
+    state: &Arc<super::State>,
+    submission_state: SubmissionState,
+    gas_pricing: GasPricing,
+) -> eyre::Result<SubmissionState> {
+    let sequencer_height = block.info.sequencer_height;
+    let submission_started =
+        initialize_submission(submission_state, sequencer_height, sequencer_height).await?;
+
+    let celestia_heights: HashMap<RollupId, u64> = submission_started
+        .parts()
+        .iter()
+        .flat_map(|part| {
+            part.rollup_ids()
+                .iter()
+                .map(|rollup_id| (*rollup_id, part.celestia_height()))
+        })
+        .collect();
+    let (submission_started, celestia_height, posted_blobs) = post_split_block(
+        client,
+        block,
+        max_bytes_per_tx,
+        state,
+        gas_pricing,
+        submission_started,
+        celestia_heights,
+    )
+    .await?;
+    finalize_submission(submission_started, celestia_height, posted_blobs).await
+}
+
+/// Records the parts of a split sequencer block as they are posted to Celestia.
+trait RecordPart: Sized + Send + 'static {
+    fn record_part(
+        self,
+        celestia_height: u64,
+        rollup_ids: Vec<RollupId>,
+        blobs: Vec<PostedBlob>,
+    ) -> eyre::Result<Self>;
+}
+
+impl RecordPart for Started {
+    fn record_part(
+        self,
+        celestia_height: u64,
+        rollup_ids: Vec<RollupId>,
+        blobs: Vec<PostedBlob>,
+    ) -> eyre::Result<Self> {
+        Started::record_part(self, celestia_height, rollup_ids, blobs)
+    }
+}
+
+/// Collects the blobs of the parts of a backfilled sequencer block in memory.
+///
+/// A backfill that is interrupted starts over, so its parts are not written to the journal.
+struct BackfilledParts(Vec<PostedBlob>);
+
+impl RecordPart for BackfilledParts {
+    fn record_part(
+        mut self,
+        _celestia_height: u64,
+        _rollup_ids: Vec<RollupId>,
+        blobs: Vec<PostedBlob>,
+    ) -> eyre::Result<Self> {
+        self.0.extend(blobs);
+        Ok(self)
+    }
+}
+
+/// Posts `block`, whose blobs exceed `max_bytes_per_tx`, across several Celestia transactions.
+///
+/// The rollup blobs are posted first, and the head blob is posted last with the Celestia heights
+/// of the rollup blobs posted before it. Rollup blobs in `celestia_heights` were already posted
+/// and are not posted again. Returns the Celestia height and blobs of the last transaction.
+///
+/// Fails without posting anything if a single rollup blob exceeds `max_bytes_per_tx`, as it could
+/// never be posted and skipping it would leave its rollup without transactions.
+async fn post_split_block<R: RecordPart>(
+    client: &HttpClient,
+    mut block: Converted,
+    max_bytes_per_tx: usize,
+    state: &Arc<super::State>,
+    gas_pricing: GasPricing,
+    mut recorder: R,
+    mut celestia_heights: HashMap<RollupId, u64>,
+) -> eyre::Result<(R, u64, Vec<PostedBlob>)> {
+    let sequencer_height = block.info.sequencer_height;
+    let size = block.size();
+    if let Some(rollup_blob) = block
+        .rollup_blobs
+        .iter()
+        .find(|rollup_blob| rollup_blob.blob.data.len() > max_bytes_per_tx)
+    {
+        bail!(
+            "the blob of rollup `{}` in sequencer block at height `{sequencer_height}` is {} \
+             bytes and exceeds the max bytes per celestia transaction of {max_bytes_per_tx}; it \
+             can never be posted",
+            rollup_blob.rollup_id,
+            rollup_blob.blob.data.len(),
+        );
+    }
+    let rollup_blobs = mem::take(&mut block.rollup_blobs)
+        .into_iter()
+        .filter(|rollup_blob| !celestia_heights.contains_key(&rollup_blob.rollup_id));
+    let mut parts = packing::chunk_by_size(rollup_blobs, max_bytes_per_tx, |rollup_blob| {
+        rollup_blob.blob.data.len()
+    });
+    info!(
+        %sequencer_height,
+        size,
+        max_bytes_per_tx,
+        already_posted = celestia_heights.len(),
+        remaining_parts = parts.len(),
+        "sequencer block exceeds the max bytes per Celestia transaction; splitting it",
+    );
+
+    // The last part is posted together with the head blob if both fit into one transaction.
+    let last_part = parts.pop();
+    for part in parts {
+        recorder = submit_part(
+            client,
+            part,
+            state,
+            gas_pricing,
+            recorder,
+            &mut celestia_heights,
+        )
+        .await?;
+    }
+    let mut blobs = vec![block.head_blob_with_celestia_heights(&celestia_heights)?];
+    if let Some(last_part) = last_part {
+        let last_part_size: usize = last_part
+            .iter()
+            .map(|rollup_blob| rollup_blob.blob.data.len())
+            .sum();
+        if blobs[0].data.len() + last_part_size <= max_bytes_per_tx {
+            blobs.extend(last_part.into_iter().map(|rollup_blob| rollup_blob.blob));
+        } else {
+            recorder = submit_part(
+                client,
+                last_part,
+                state,
+                gas_pricing,
+                recorder,
+                &mut celestia_heights,
+            )
+            .await?;
+            blobs = vec![block.head_blob_with_celestia_heights(&celestia_heights)?];
+        }
+    }
+
+    let (celestia_height, posted_blobs) =
+        submit_transaction(client, blobs, 1, state, gas_pricing).await?;
+    Ok((recorder, celestia_height, posted_blobs))
+}
+
+/// Submits the rollup blobs in `part` of a split sequencer block to Celestia, recording the
+/// Celestia height they were posted at in `celestia_heights` and with `recorder`.
+async fn submit_part<R: RecordPart>(
+    client: &HttpClient,
+    part: Vec<RollupBlob>,
+    state: &Arc<super::State>,
+    gas_pricing: GasPricing,
+    recorder: R,
+    celestia_heights: &mut HashMap<RollupId, u64>,
+) -> eyre::Result<R> {
+    let (rollup_ids, blobs): (Vec<_>, Vec<_>) = part
+        .into_iter()
+        .map(|rollup_blob| (rollup_blob.rollup_id, rollup_blob.blob))
+        .unzip();
+    let (celestia_height, posted_blobs) =
+        submit_transaction(client, blobs, 0, state, gas_pricing).await?;
+    celestia_heights.extend(
+        rollup_ids
+            .iter()
+            .map(|rollup_id| (*rollup_id, celestia_height)),
     );
+    crate::utils::flatten(
+        tokio::task::spawn_blocking(move || {
+            recorder.record_part(celestia_height, rollup_ids, posted_blobs)
+        })
+        .in_current_span()
+        .await,
+    )
+    .map_err(|error| {
+        error!(%error, "failed to record submitted part of split sequencer block; abandoning");
+        error
+    })
+}
 
-    let submission_started = match crate::utils::flatten(
-        tokio::task::spawn_blocking(move || submission_state.initialize(largest_height))
+/// Converts `block` to Celestia blobs and submits them, independently of the [`BlobSubmitter`].
+///
+/// Used to re-relay a sequencer block that was submitted before. The block is split across
+/// several Celestia transactions if its blobs exceed `max_bytes_per_tx`. Returns the submission
+/// to record in the journal.
+#[instrument(skip_all, fields(sequencer_height = %block.height()))]
+pub(super) async fn submit_backfilled_block(
+    client: &HttpClient,
+    block: SequencerBlock,
+    converter: Arc<Converter>,
+    max_bytes_per_tx: usize,
+    state: &Arc<super::State>,
+    gas_pricing: GasPricing,
+) -> eyre::Result<Submission> {
+    let sequencer_height = block.height();
+    let converted = crate::utils::flatten(
+        tokio::task::spawn_blocking(move || converter.convert(block))
             .in_current_span()
             .await,
+    )
+    .wrap_err("failed converting sequencer block to celestia blobs")?;
+    info!(
+        block = %telemetry::display::json(&converted.info),
+        "initiated backfill of sequencer block converted to Celestia blobs",
+    );
+    let (celestia_height, blobs) = if converted.size() > max_bytes_per_tx {
+        let (BackfilledParts(mut blobs), celestia_height, posted_blobs) = post_split_block(
+            client,
+            converted,
+            max_bytes_per_tx,
+            state,
+            gas_pricing,
+            BackfilledParts(Vec::new()),
+            HashMap::new(),
+        )
+        .await?;
+        blobs.extend(posted_blobs);
+        (celestia_height, blobs)
+    } else {
+        let blobs = converted.into_blobs().collect();
+        submit_transaction(client, blobs, 1, state, gas_pricing).await?
+    };
+    Ok(Submission::new(
+        sequencer_height,
+        sequencer_height,
+        celestia_height,
+        blobs,
+    ))
+}
+
+async fn initialize_submission(
+    submission_state: SubmissionState,
+    first_sequencer_height: SequencerHeight,
+    last_sequencer_height: SequencerHeight,
+) -> eyre::Result<Started> {
+    match crate::utils::flatten(
+        tokio::task::spawn_blocking(move || {
+            submission_state.initialize(first_sequencer_height, last_sequencer_height)
+        })
+        .in_current_span()
+        .await,
     ) {
         Err(error) => {
             error!(%error, "failed to initialize submission; abandoning");
-            return Err(error);
+            Err(error)
         }
-        Ok(state) => state,
-    };
+        Ok(state) => Ok(state),
+    }
+}
 
-    let celestia_height = match submit_with_retry(client, blocks.blobs, state.clone()).await {
+async fn finalize_submission(
+    submission_started: Started,
+    celestia_height: u64,
+    posted_blobs: Vec<PostedBlob>,
+) -> eyre::Result<SubmissionState> {
+    match crate::utils::flatten(
+        tokio::task::spawn_blocking(move || {
+            submission_started.finalize(celestia_height, posted_blobs)
+        })
+        .in_current_span()
+        .await,
+    ) {
         Err(error) => {
-            let message = "failed submitting blobs to Celestia";
-            error!(%error, message);
-            return Err(error.wrap_err(message));
+            error!(%error, "failed to finalize submission; abandoning");
+            Err(error)
         }
-        Ok(height) => height,
-    };
+        Ok(state) => Ok(state),
+    }
+}
+
+/// Submits `blobs`, holding `num_blocks` whole sequencer blocks, to Celestia in a single
+/// transaction and returns the Celestia height they were included at, along with the posted
+/// blobs to record in the journal.
+async fn submit_transaction(
+    client: &HttpClient,
+    blobs: Vec<Blob>,
+    num_blocks: usize,
+    state: &Arc<super::State>,
+    gas_pricing: GasPricing,
+) -> eyre::Result<(u64, Vec<PostedBlob>)> {
+    let start = std::time::Instant::now();
+
+    metrics::counter!(crate::metrics_init::CELESTIA_SUBMISSION_COUNT).increment(1);
+    // allow: the number of blocks should always be low enough to not cause precision loss
+    #[allow(clippy::cast_precision_loss)]
+    let blocks_per_celestia_tx = num_blocks as f64;
+    metrics::gauge!(crate::metrics_init::BLOCKS_PER_CELESTIA_TX).set(blocks_per_celestia_tx);
+
+    // allow: the number of blobs should always be low enough to not cause precision loss
+    #[allow(clippy::cast_precision_loss)]
+    let blobs_per_celestia_tx = blobs.len() as f64;
+    metrics::gauge!(crate::metrics_init::BLOBS_PER_CELESTIA_TX).set(blobs_per_celestia_tx);
+
+    let blobs = Arc::new(blobs);
+    let celestia_height =
+        match submit_with_retry(client.clone(), blobs.clone(), state.clone(), gas_pricing).await {
+            Err(error) => {
+                let message = "failed submitting blobs to Celestia";
+                error!(%error, message);
+                return Err(error.wrap_err(message));
+            }
+            Ok(height) => height,
+        };
     metrics::counter!(crate::metrics_init::CELESTIA_SUBMISSION_HEIGHT).absolute(celestia_height);
     metrics::histogram!(crate::metrics_init::CELESTIA_SUBMISSION_LATENCY).record(start.elapsed());
 
-    info!(%celestia_height, "successfully submitted blocks to Celestia");
+    info!(%celestia_height, "successfully submitted blobs to Celestia");
 
     state.set_celestia_connected(true);
     state.set_latest_confirmed_celestia_height(celestia_height);
-
-    let final_state = match crate::utils::flatten(
-        tokio::task::spawn_blocking(move || submission_started.finalize(celestia_height))
-            .in_current_span()
-            .await,
-    ) {
-        Err(error) => {
-            error!(%error, "failed to finalize submission; abandoning");
-            return Err(error);
-        }
-        Ok(state) => state,
-    };
-    Ok(final_state)
+    let posted_blobs = blobs
+        .iter()
+        .map(|blob| PostedBlob::new(celestia_height, blob))
+        .collect();
+    Ok((celestia_height, posted_blobs))
 }
 
+/// Submits `blobs` to Celestia, retrying until they are included.
+///
+/// The first attempt pays the base gas price of `gas_pricing`. An attempt that timed out or was
+/// rejected for its fee is followed by an attempt at an escalated gas price, while other failures,
+/// such as the Celestia node being unreachable, are retried at the same gas price.
+///
+/// A transaction whose submission timed out might still be included. Before each attempt
+/// following a timeout, the Celestia blocks produced since the first attempt are searched for the
+/// blobs, so that they are not posted twice.
 async fn submit_with_retry(
     client: HttpClient,
-    blobs: Vec<Blob>,
+    blobs: Arc<Vec<Blob>>,
     state: Arc<super::State>,
+    gas_pricing: GasPricing,
 ) -> eyre::Result<u64> {
-    use celestia_client::{
-        celestia_rpc::BlobClient as _,
-        celestia_types::blob::SubmitOptions,
-    };
-    // Moving the span into `on_retry`, because tryhard spawns these in a tokio
-    // task, losing the span.
-    let span = Span::current();
-    let retry_config = tryhard::RetryFutureConfig::new(u32::MAX)
-        .exponential_backoff(Duration::from_millis(100))
-        // 12 seconds is the Celestia block time
-        .max_delay(Duration::from_secs(12))
-        .on_retry(
-            |attempt: u32, next_delay: Option<Duration>, error: &eyre::Report| {
-                metrics::counter!(crate::metrics_init::CELESTIA_SUBMISSION_FAILURE_COUNT)
-                    .increment(1);
-
-                let state = Arc::clone(&state);
-                state.set_celestia_connected(false);
-
-                let wait_duration = next_delay
-                    .map(humantime::format_duration)
-                    .map(tracing::field::display);
-
-                warn!(
-                    parent: &span,
-                    attempt,
-                    wait_duration,
-                    %error,
-                    "failed submitting blobs to Celestia; retrying after backoff",
-                );
-                futures::future::ready(())
-            },
+    let gas_limit = fees::estimate_gas(&blobs);
+    let mut gas_price = gas_pricing.base_price();
+    // The lowest Celestia height a timed out attempt could have been included at.
+    let mut search_from = None;
+    let mut timed_out = false;
+    let mut delay = Duration::from_millis(100);
+    for attempt in 1..u32::MAX {
+        let error = match submit_attempt(
+            &client,
+            &blobs,
+            Fee::new(gas_limit, gas_price),
+            gas_pricing.submission_timeout(),
+            &mut search_from,
+            timed_out,
+        )
+        .await
+        {
+            Ok(height) => return Ok(height),
+            Err(error) => error,
+        };
+
+        metrics::counter!(crate::metrics_init::CELESTIA_SUBMISSION_FAILURE_COUNT).increment(1);
+        state.set_celestia_connected(false);
+        let escalate = match &error {
+            AttemptError::TimedOut(_) => {
+                timed_out = true;
+                true
+            }
+            AttemptError::FeeRejected(_) => true,
+            AttemptError::Other(_) => false,
+        };
+        if escalate {
+            gas_price = gas_pricing.escalate(gas_price);
+        }
+        warn!(
+            attempt,
+            wait_duration = %humantime::format_duration(delay),
+            gas_price.next = gas_price,
+            error = %error.into_report(),
+            "failed submitting blobs to Celestia; retrying after backoff",
         );
+        tokio::time::sleep(delay).await;
+        // 12 seconds is the Celestia block time
+        delay = delay.saturating_mul(2).min(Duration::from_secs(12));
+    }
+    Err(eyre::eyre!("retry attempts exhausted; bailing"))
+}
 
-    let blobs = Arc::new(blobs);
-    let height = tryhard::retry_fn(move || {
-        let client = client.clone();
-        let blobs = blobs.clone();
-        async move {
-            client
-                .blob_submit(
-                    &blobs,
-                    SubmitOptions {
-                        fee: None,
-                        gas_limit: None,
-                    },
-                )
+/// The reason an attempt to submit blobs to Celestia failed.
+enum AttemptError {
+    /// The submission timed out, so the transaction might still be included.
+    TimedOut(eyre::Report),
+    /// Celestia rejected the transaction because its fee was too low.
+    FeeRejected(eyre::Report),
+    Other(eyre::Report),
+}
+
+impl AttemptError {
+    fn into_report(self) -> eyre::Report {
+        match self {
+            Self::TimedOut(report) | Self::FeeRejected(report) | Self::Other(report) => report,
+        }
+    }
+}
+
+/// Makes one attempt to submit `blobs` to Celestia, paying `fee`.
+///
+/// Before the first attempt, `search_from` is set to the height of the next Celestia block. If an
+/// earlier attempt `timed_out`, the Celestia blocks from `search_from` are searched for the blobs
+/// first, returning the height they were included at instead of submitting them again.
+async fn submit_attempt(
+    client: &HttpClient,
+    blobs: &[Blob],
+    fee: Fee,
+    submission_timeout: Duration,
+    search_from: &mut Option<u64>,
+    timed_out: bool,
+) -> Result<u64, AttemptError> {
+    use celestia_client::celestia_rpc::BlobClient as _;
+
+    let from_height = match *search_from {
+        Some(from_height) => from_height,
+        None => {
+            let from_height = fetch_network_head_height(client)
                 .await
-                .wrap_err("failed submitting sequencer blocks to celestia")
+                .map_err(AttemptError::Other)?
+                .saturating_add(1);
+            *search_from = Some(from_height);
+            from_height
         }
-    })
-    .with_config(retry_config)
-    .in_current_span()
+    };
+    if timed_out {
+        let (included_height, next_from_height) =
+            find_included_blob(client, &blobs[0], from_height)
+                .await
+                .map_err(AttemptError::Other)?;
+        if let Some(height) = included_height {
+            info!(
+                celestia_height = height,
+                "blobs of a timed out submission were included in Celestia; not submitting them \
+                 again",
+            );
+            return Ok(height);
+        }
+        *search_from = Some(next_from_height);
+    }
+
+    record_fee(fee);
+    debug!(
+        gas_limit = fee.gas_limit,
+        gas_price = fee.gas_price,
+        fee = fee.amount,
+        "submitting blobs to Celestia",
+    );
+    match tokio::time::timeout(
+        submission_timeout,
+        client.blob_submit(blobs, fee.submit_options()),
+    )
     .await
-    .wrap_err("retry attempts exhausted; bailing")?;
-    Ok(height)
+    {
+        Err(_) => Err(AttemptError::TimedOut(eyre::eyre!(
+            "submission to celestia timed out after {}",
+            humantime::format_duration(submission_timeout)
+        ))),
+        Ok(Err(error)) if fees::is_fee_rejection(&error) => Err(AttemptError::FeeRejected(
+            eyre::Report::new(error).wrap_err("celestia rejected the fee of the submission"),
+        )),
+        Ok(Err(error)) => Err(AttemptError::Other(
+            eyre::Report::new(error).wrap_err("failed submitting sequencer blocks to celestia"),
+        )),
+        Ok(Ok(height)) => Ok(height),
+    }
+}
+
+async fn fetch_network_head_height(client: &HttpClient) -> eyre::Result<u64> {
+    use celestia_client::celestia_rpc::HeaderClient as _;
+
+    let head = client
+        .header_network_head()
+        .await
+        .wrap_err("failed fetching celestia network head")?;
+    Ok(head.height().value())
+}
+
+/// Searches the Celestia blocks from `from_height` up to the network head for `blob`.
+///
+/// Returns the height `blob` was included at, if any, and the height to continue searching from.
+/// Blocks that were searched are final, so they need not be searched again.
+async fn find_included_blob(
+    client: &HttpClient,
+    blob: &Blob,
+    from_height: u64,
+) -> eyre::Result<(Option<u64>, u64)> {
+    use celestia_client::celestia_rpc::BlobClient as _;
+
+    let head_height = fetch_network_head_height(client).await?;
+    for height in from_height..=head_height {
+        match client
+            .blob_get(height, blob.namespace, blob.commitment)
+            .await
+        {
+            Ok(_) => return Ok((Some(height), height + 1)),
+            Err(error) if celestia_client::is_blob_not_found(&error) => {}
+            Err(error) => {
+                return Err(error).wrap_err_with(|| {
+                    format!("failed looking up submitted blob at celestia height {height}")
+                });
+            }
+        }
+    }
+    Ok((None, from_height.max(head_height.saturating_add(1))))
+}
+
+fn record_fee(fee: Fee) {
+    // allow: the gas limit and fee are far below 2^52 and do not cause precision loss
+    #[allow(clippy::cast_precision_loss)]
+    let (gas_limit, amount) = (fee.gas_limit as f64, fee.amount as f64);
+    metrics::gauge!(crate::metrics_init::CELESTIA_SUBMISSION_GAS_LIMIT).set(gas_limit);
+    metrics::gauge!(crate::metrics_init::CELESTIA_SUBMISSION_GAS_PRICE).set(fee.gas_price);
+    metrics::gauge!(crate::metrics_init::CELESTIA_SUBMISSION_FEE).set(amount);
 }
 
 /// Currently running conversions of Sequencer blocks to Celestia blobs.
@@ -483,13 +978,17 @@ struct Conversions {
 
     // The maximum number of conversions that can be active at the same time.
     max_conversions: usize,
+
+    // Converts sequencer blocks to blobs, selecting and namespacing the relayed rollup blobs.
+    converter: Arc<Converter>,
 }
 
 impl Conversions {
-    fn new(max_conversions: usize) -> Self {
+    fn new(max_conversions: usize, converter: Arc<Converter>) -> Self {
         Self {
             active: FuturesOrdered::new(),
             max_conversions,
+            converter,
         }
     }
 
@@ -499,7 +998,8 @@ impl Conversions {
 
     fn push(&mut self, block: SequencerBlock) {
         let height = block.height();
-        let conversion = tokio::task::spawn_blocking(move || convert(block));
+        let converter = self.converter.clone();
+        let conversion = tokio::task::spawn_blocking(move || converter.convert(block));
         let fut = async move {
             let res = crate::utils::flatten(conversion.await);
             (height, res)
@@ -0,0 +1,60 @@
+//! Packs blobs into Celestia transactions by their size.
+//!
+//! Converted sequencer blocks are packed into as few transactions as possible without exceeding
+//! the configured max number of bytes per transaction. A block whose blobs alone exceed the max
+//! is split: its rollup blobs are packed into transactions of their own, and its head blob is
+//! posted last, recording the Celestia heights of the rollup blobs posted before it.
+
+/// Groups `items` into consecutive chunks whose total size as given by `size` does not exceed
+/// `max_bytes`.
+///
+/// The order of `items` is preserved. An item larger than `max_bytes` is put in a chunk of its
+/// own.
+pub(super) fn chunk_by_size<T>(
+    items: impl IntoIterator<Item = T>,
+    max_bytes: usize,
+    size: impl Fn(&T) -> usize,
+) -> Vec<Vec<T>> {
+    let mut chunks = Vec::new();
+    let mut chunk = Vec::new();
+    let mut chunk_size = 0;
+    for item in items {
+        let item_size = size(&item);
+        if !chunk.is_empty() && chunk_size + item_size > max_bytes {
+            chunks.push(std::mem::take(&mut chunk));
+            chunk_size = 0;
+        }
+        chunk_size += item_size;
+        chunk.push(item);
+    }
+    if !chunk.is_empty() {
+        chunks.push(chunk);
+    }
+    chunks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chunk(sizes: &[usize], max_bytes: usize) -> Vec<Vec<usize>> {
+        chunk_by_size(sizes.iter().copied(), max_bytes, |size| *size)
+    }
+
+    #[test]
+    fn items_are_packed_up_to_max_bytes() {
+        assert_eq!(
+            chunk(&[3, 4, 3, 5, 5, 1], 10),
+            vec![vec![3, 4, 3], vec![5, 5], vec![1]],
+        );
+        assert!(chunk(&[], 10).is_empty());
+    }
+
+    #[test]
+    fn oversized_items_get_a_chunk_of_their_own() {
+        assert_eq!(
+            chunk(&[2, 11, 3, 12], 10),
+            vec![vec![2], vec![11], vec![3], vec![12]],
+        );
+    }
+}
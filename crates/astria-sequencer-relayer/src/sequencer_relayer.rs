@@ -56,6 +56,18 @@ impl SequencerRelayer {
             api_addr,
             pre_submit_path,
             post_submit_path,
+            journal_path,
+            rollup_id_allow_list,
+            rollup_id_deny_list,
+            rollup_namespaces,
+            celestia_base_gas_price,
+            celestia_gas_price_multiplier,
+            celestia_max_gas_price,
+            celestia_submission_timeout_ms,
+            celestia_max_bytes_per_tx,
+            compress_blobs,
+            backfill_start_height,
+            backfill_end_height,
             ..
         } = cfg;
 
@@ -68,8 +80,20 @@ impl SequencerRelayer {
             sequencer_poll_period: Duration::from_millis(block_time),
             sequencer_grpc_endpoint,
             validator_key_path,
+            journal_path,
             pre_submit_path,
             post_submit_path,
+            rollup_id_allow_list,
+            rollup_id_deny_list,
+            rollup_namespaces,
+            celestia_base_gas_price,
+            celestia_gas_price_multiplier,
+            celestia_max_gas_price,
+            celestia_submission_timeout: Duration::from_millis(celestia_submission_timeout_ms),
+            celestia_max_bytes_per_tx,
+            compress_blobs,
+            backfill_start_height,
+            backfill_end_height,
         }
         .build()
         .wrap_err("failed to create relayer")?;
@@ -78,7 +102,7 @@ impl SequencerRelayer {
         let api_socket_addr = api_addr.parse::<SocketAddr>().wrap_err_with(|| {
             format!("failed to parse provided `api_addr` string as socket address: `{api_addr}`",)
         })?;
-        let api_server = api::start(api_socket_addr, state_rx);
+        let api_server = api::start(api_socket_addr, state_rx, relayer.journal());
         let relayer = Self {
             api_server,
             relayer,
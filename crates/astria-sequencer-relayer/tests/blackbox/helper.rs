@@ -1,5 +1,8 @@
 use std::{
-    collections::VecDeque,
+    collections::{
+        HashMap,
+        VecDeque,
+    },
     mem,
     net::SocketAddr,
     sync::{
@@ -31,9 +34,13 @@ use astria_sequencer_relayer::{
     SequencerRelayer,
     ShutdownHandle,
 };
-use celestia_client::celestia_types::{
-    blob::SubmitOptions,
-    Blob,
+use celestia_client::{
+    celestia_types::{
+        blob::SubmitOptions,
+        nmt::Namespace,
+        Blob,
+    },
+    submission::ToBlobs as _,
 };
 use ed25519_consensus::SigningKey;
 use once_cell::sync::Lazy;
@@ -186,6 +193,7 @@ pub struct TestSequencerRelayer {
 
     pub pre_submit_file: NamedTempFile,
     pub post_submit_file: NamedTempFile,
+    pub journal_file: NamedTempFile,
 }
 
 impl Drop for TestSequencerRelayer {
@@ -233,6 +241,31 @@ impl TestSequencerRelayer {
     }
 
     pub fn mount_block_response<const RELAY_SELF: bool>(&mut self, height: u32) -> BlockGuard {
+        let block = self.make_block::<RELAY_SELF>(height);
+        self.push_block(block)
+    }
+
+    /// Mounts the block at `height` like [`Self::mount_block_response`], after storing its blobs
+    /// in the mocked celestia node at `celestia_height`.
+    pub fn mount_block_on_celestia<const RELAY_SELF: bool>(
+        &mut self,
+        height: u32,
+        celestia_height: u64,
+    ) -> BlockGuard {
+        let block = self.make_block::<RELAY_SELF>(height);
+        let mut blobs = Vec::new();
+        block.clone().try_to_blobs(&mut blobs).unwrap();
+        self.celestia
+            .blobs
+            .lock()
+            .unwrap()
+            .entry(celestia_height)
+            .or_default()
+            .extend(blobs);
+        self.push_block(block)
+    }
+
+    fn make_block<const RELAY_SELF: bool>(&self, height: u32) -> SequencerBlock {
         let proposer = if RELAY_SELF {
             self.account
         } else {
@@ -243,9 +276,12 @@ impl TestSequencerRelayer {
         cometbft_block.header.height = height.into();
         cometbft_block.header.proposer_address = proposer;
 
+        SequencerBlock::try_from_cometbft(cometbft_block).unwrap()
+    }
+
+    fn push_block(&self, block: SequencerBlock) -> BlockGuard {
         let (tx, rx) = oneshot::channel();
 
-        let block = SequencerBlock::try_from_cometbft(cometbft_block).unwrap();
         let mut blocks = self.sequencer_server_blocks.lock().unwrap();
         blocks.push_back((tx, block.into_raw()));
         BlockGuard {
@@ -287,31 +323,53 @@ impl TestSequencerRelayer {
         }
     }
 
-    #[track_caller]
-    pub fn assert_state_files_are_as_expected(
+    /// Asserts that the relayer API serves the journaled submission of the sequencer blocks
+    /// from `first_sequencer_height` to `last_sequencer_height`.
+    pub async fn assert_submission_is_journaled(
         &self,
-        pre_sequencer_height: u64,
-        post_sequencer_height: u64,
+        first_sequencer_height: u64,
+        last_sequencer_height: u64,
     ) {
-        let pre_submit_state: serde_json::Value =
-            serde_json::from_str(&std::fs::read_to_string(&self.config.pre_submit_path).unwrap())
-                .unwrap();
+        let submission: serde_json::Value = reqwest::get(format!(
+            "http://{}/submissions?sequencer_height={last_sequencer_height}",
+            self.api_address
+        ))
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
         assert_json_include!(
-            actual: pre_submit_state,
+            actual: submission,
             expected: json!({
-                "sequencer_height": pre_sequencer_height
+                "first_sequencer_height": first_sequencer_height,
+                "last_sequencer_height": last_sequencer_height,
             }),
         );
+    }
 
-        let post_submit_state: serde_json::Value =
-            serde_json::from_str(&std::fs::read_to_string(&self.config.post_submit_path).unwrap())
-                .unwrap();
-        assert_json_include!(
-            actual: post_submit_state,
-            expected: json!({
-                "sequencer_height": post_sequencer_height,
-            }),
-        );
+    /// Waits until the relayer API reports the backfill as done or abandoned and returns its
+    /// status.
+    pub async fn wait_for_backfill_status(&self) -> serde_json::Value {
+        let wait_for_status = async {
+            loop {
+                let status: serde_json::Value =
+                    reqwest::get(format!("http://{}/status", self.api_address))
+                        .await
+                        .unwrap()
+                        .json()
+                        .await
+                        .unwrap();
+                let backfill = &status["backfill"];
+                if backfill["done"] == json!(true) || !backfill["error"].is_null() {
+                    break backfill.clone();
+                }
+                tokio::time::sleep(Duration::from_millis(100)).await;
+            }
+        };
+        timeout(Duration::from_secs(5), wait_for_status)
+            .await
+            .expect("the backfill must have finished")
     }
 }
 
@@ -322,6 +380,8 @@ pub struct TestSequencerRelayerConfig {
     // Sets the start height of relayer and configures the on-disk pre- and post-submit files to
     // look accordingly.
     pub last_written_sequencer_height: Option<u64>,
+    // Sets the inclusive range of sequencer heights to backfill.
+    pub backfill_range: Option<(u64, u64)>,
 }
 
 impl TestSequencerRelayerConfig {
@@ -386,6 +446,11 @@ impl TestSequencerRelayerConfig {
                 create_files_for_fresh_start()
             };
 
+        let journal_file = NamedTempFile::new()
+            .expect("must be able to create an empty journal file to run tests");
+
+        let (backfill_start_height, backfill_end_height) = self.backfill_range.unwrap_or((0, 0));
+
         let config = Config {
             cometbft_endpoint: cometbft.uri(),
             sequencer_grpc_endpoint: format!("http://{grpc_addr}"),
@@ -403,6 +468,18 @@ impl TestSequencerRelayerConfig {
             pretty_print: true,
             pre_submit_path: pre_submit_file.path().to_owned(),
             post_submit_path: post_submit_file.path().to_owned(),
+            journal_path: journal_file.path().to_owned(),
+            rollup_id_allow_list: String::new(),
+            rollup_id_deny_list: String::new(),
+            rollup_namespaces: String::new(),
+            celestia_base_gas_price: 0.002,
+            celestia_gas_price_multiplier: 1.5,
+            celestia_max_gas_price: 0.1,
+            celestia_submission_timeout_ms: 10_000,
+            celestia_max_bytes_per_tx: 1_500_000,
+            compress_blobs: false,
+            backfill_start_height,
+            backfill_end_height,
         };
 
         info!(config = serde_json::to_string(&config).unwrap());
@@ -425,6 +502,7 @@ impl TestSequencerRelayerConfig {
             keyfile,
             pre_submit_file,
             post_submit_file,
+            journal_file,
         }
     }
 }
@@ -489,6 +567,8 @@ use jsonrpsee::{
 pub struct MockCelestia {
     pub addr_rx: oneshot::Receiver<SocketAddr>,
     pub state_rpc_confirmed_rx: mpsc::UnboundedReceiver<Vec<Blob>>,
+    /// The blobs stored by the mocked celestia node, keyed by celestia height.
+    pub blobs: Arc<Mutex<HashMap<u64, Vec<Blob>>>>,
     pub server_handle: ServerHandle,
 }
 
@@ -507,8 +587,10 @@ impl MockCelestia {
         let addr = server.local_addr().unwrap();
         addr_tx.send(addr).unwrap();
         let (state_rpc_confirmed_tx, state_rpc_confirmed_rx) = mpsc::unbounded_channel();
+        let blobs = Arc::new(Mutex::new(HashMap::new()));
         let state_celestia = BlobServerImpl {
             rpc_confirmed_tx: state_rpc_confirmed_tx,
+            blobs: blobs.clone(),
         };
         let header_celestia = HeaderServerImpl;
         let mut merged_celestia = state_celestia.into_rpc();
@@ -517,6 +599,7 @@ impl MockCelestia {
         Self {
             addr_rx,
             state_rpc_confirmed_rx,
+            blobs,
             server_handle,
         }
     }
@@ -563,6 +646,7 @@ impl HeaderServer for HeaderServerImpl {
 
 struct BlobServerImpl {
     rpc_confirmed_tx: mpsc::UnboundedSender<Vec<Blob>>,
+    blobs: Arc<Mutex<HashMap<u64, Vec<Blob>>>>,
 }
 
 #[async_trait]
@@ -572,8 +656,37 @@ impl BlobServer for BlobServerImpl {
         blobs: Vec<Blob>,
         _opts: SubmitOptions,
     ) -> Result<u64, ErrorObjectOwned> {
+        const CELESTIA_HEIGHT: u64 = 100;
+        self.blobs
+            .lock()
+            .unwrap()
+            .entry(CELESTIA_HEIGHT)
+            .or_default()
+            .extend(blobs.iter().cloned());
         self.rpc_confirmed_tx.send(blobs).unwrap();
-        Ok(100)
+        Ok(CELESTIA_HEIGHT)
+    }
+
+    async fn blob_get_all(
+        &self,
+        height: u64,
+        namespaces: Vec<Namespace>,
+    ) -> Result<Vec<Blob>, ErrorObjectOwned> {
+        let blobs: Vec<_> = self
+            .blobs
+            .lock()
+            .unwrap()
+            .get(&height)
+            .into_iter()
+            .flatten()
+            .filter(|blob| namespaces.contains(&blob.namespace))
+            .cloned()
+            .collect();
+        if blobs.is_empty() {
+            // celestia-node fails the request if no blobs are stored under the namespaces
+            return Err(ErrorObjectOwned::owned(1, "blob: not found", None::<()>));
+        }
+        Ok(blobs)
     }
 }
 
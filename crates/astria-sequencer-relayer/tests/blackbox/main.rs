@@ -21,6 +21,7 @@ async fn report_degraded_if_block_fetch_fails() {
     let mut sequencer_relayer = TestSequencerRelayerConfig {
         relay_only_self: false,
         last_written_sequencer_height: None,
+        backfill_range: None,
     }
     .spawn_relayer()
     .await;
@@ -87,6 +88,7 @@ async fn one_block_is_relayed_to_celestia() {
     let mut sequencer_relayer = TestSequencerRelayerConfig {
         relay_only_self: false,
         last_written_sequencer_height: None,
+        backfill_range: None,
     }
     .spawn_relayer()
     .await;
@@ -124,6 +126,7 @@ async fn later_height_in_state_leads_to_expected_relay() {
     let mut sequencer_relayer = TestSequencerRelayerConfig {
         relay_only_self: false,
         last_written_sequencer_height: Some(5),
+        backfill_range: None,
     }
     .spawn_relayer()
     .await;
@@ -159,7 +162,9 @@ async fn later_height_in_state_leads_to_expected_relay() {
     // of orchestrating this test, for example by updating the sequencer relayer status API,
     // or observing graceful shutdown.
     tokio::time::sleep(Duration::from_secs(1)).await;
-    sequencer_relayer.assert_state_files_are_as_expected(6, 6);
+    // the submission at height 5 was migrated from the post-submit file
+    sequencer_relayer.assert_submission_is_journaled(5, 5).await;
+    sequencer_relayer.assert_submission_is_journaled(6, 6).await;
 }
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
@@ -167,6 +172,7 @@ async fn three_blocks_are_relayed() {
     let mut sequencer_relayer = TestSequencerRelayerConfig {
         relay_only_self: false,
         last_written_sequencer_height: None,
+        backfill_range: None,
     }
     .spawn_relayer()
     .await;
@@ -218,6 +224,7 @@ async fn block_from_other_proposer_is_skipped() {
     let mut sequencer_relayer = TestSequencerRelayerConfig {
         relay_only_self: true,
         last_written_sequencer_height: None,
+        backfill_range: None,
     }
     .spawn_relayer()
     .await;
@@ -263,3 +270,83 @@ async fn block_from_other_proposer_is_skipped() {
         "expected 4 blobs in total, 1 header blob and 1 rollup blob per block"
     );
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn backfill_skips_block_found_on_celestia() {
+    let mut sequencer_relayer = TestSequencerRelayerConfig {
+        relay_only_self: false,
+        last_written_sequencer_height: Some(5),
+        backfill_range: Some((5, 5)),
+    }
+    .spawn_relayer()
+    .await;
+
+    // the journal migrated from the post-submit file records block 5 at celestia height 5
+    let block_guard = sequencer_relayer.mount_block_on_celestia::<RELAY_ALL>(5, 5);
+    timeout(
+        Duration::from_millis(2 * sequencer_relayer.config.block_time),
+        block_guard.wait_until_satisfied(),
+    )
+    .await
+    .expect("the backfill must have requested the block")
+    .unwrap();
+
+    let backfill = sequencer_relayer.wait_for_backfill_status().await;
+    assert_json_include!(
+        actual: backfill,
+        expected: json!({
+            "done": true,
+            "num_skipped": 1,
+            "num_relayed": 0,
+        }),
+    );
+    assert!(
+        sequencer_relayer
+            .celestia
+            .state_rpc_confirmed_rx
+            .try_recv()
+            .is_err(),
+        "a block found on celestia must not be posted again"
+    );
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn backfill_relays_block_missing_on_celestia() {
+    let mut sequencer_relayer = TestSequencerRelayerConfig {
+        relay_only_self: false,
+        last_written_sequencer_height: Some(5),
+        backfill_range: Some((5, 5)),
+    }
+    .spawn_relayer()
+    .await;
+
+    // the block is not stored at celestia height 5 recorded in the journal, as if it was pruned
+    let block_guard = sequencer_relayer.mount_block_response::<RELAY_ALL>(5);
+    timeout(
+        Duration::from_millis(2 * sequencer_relayer.config.block_time),
+        block_guard.wait_until_satisfied(),
+    )
+    .await
+    .expect("the backfill must have requested the block")
+    .unwrap();
+
+    let blobs_seen_by_celestia = timeout(
+        Duration::from_secs(1),
+        sequencer_relayer.celestia.state_rpc_confirmed_rx.recv(),
+    )
+    .await
+    .expect("the backfill must have posted the block")
+    .expect("celestia must have seen blobs");
+    assert_eq!(blobs_seen_by_celestia.len(), 2);
+
+    let backfill = sequencer_relayer.wait_for_backfill_status().await;
+    assert_json_include!(
+        actual: backfill,
+        expected: json!({
+            "done": true,
+            "num_skipped": 0,
+            "num_relayed": 1,
+        }),
+    );
+    sequencer_relayer.assert_submission_is_journaled(5, 5).await;
+}
@@ -15,6 +15,7 @@ use crate::{
         StateWriteExt,
     },
     bridge::state_ext::StateReadExt as _,
+    fees::state_ext::StateReadExt as _,
     state_ext::{
         StateReadExt as _,
         StateWriteExt as _,
@@ -22,9 +23,6 @@ use crate::{
     transaction::action_handler::ActionHandler,
 };
 
-/// Fee charged for a `Transfer` action.
-pub(crate) const TRANSFER_FEE: u128 = 12;
-
 pub(crate) async fn transfer_check_stateful<S: StateReadExt + 'static>(
     action: &TransferAction,
     state: &S,
@@ -38,6 +36,10 @@ pub(crate) async fn transfer_check_stateful<S: StateReadExt + 'static>(
         "invalid fee asset",
     );
 
+    let fee = state
+        .get_transfer_fee()
+        .await
+        .context("failed to get transfer fee from state")?;
     let transfer_asset_id = action.asset_id;
 
     let from_fee_balance = state
@@ -50,7 +52,7 @@ pub(crate) async fn transfer_check_stateful<S: StateReadExt + 'static>(
     if action.fee_asset_id == transfer_asset_id {
         let payment_amount = action
             .amount
-            .checked_add(TRANSFER_FEE)
+            .checked_add(fee)
             .context("transfer amount plus fee overflowed")?;
 
         ensure!(
@@ -61,7 +63,7 @@ pub(crate) async fn transfer_check_stateful<S: StateReadExt + 'static>(
         // otherwise, check the fee asset account has enough to cover the fees,
         // and the transfer asset account has enough to cover the transfer
         ensure!(
-            from_fee_balance >= TRANSFER_FEE,
+            from_fee_balance >= fee,
             "insufficient funds for fee payment"
         );
 
@@ -109,8 +111,12 @@ impl ActionHandler for TransferAction {
         )
     )]
     async fn execute<S: StateWriteExt>(&self, state: &mut S, from: Address) -> Result<()> {
+        let fee = state
+            .get_transfer_fee()
+            .await
+            .context("failed to get transfer fee from state")?;
         state
-            .get_and_increase_block_fees(self.fee_asset_id, TRANSFER_FEE)
+            .get_and_increase_block_fees(self.fee_asset_id, fee)
             .await
             .context("failed to add to block fees")?;
 
@@ -122,7 +128,7 @@ impl ActionHandler for TransferAction {
             // check_stateful should have already checked this arithmetic
             let payment_amount = self
                 .amount
-                .checked_add(TRANSFER_FEE)
+                .checked_add(fee)
                 .expect("transfer amount plus fee should not overflow");
 
             state
@@ -147,7 +153,7 @@ impl ActionHandler for TransferAction {
 
             // deduct fee from fee asset balance
             state
-                .decrease_balance(from, self.fee_asset_id, TRANSFER_FEE)
+                .decrease_balance(from, self.fee_asset_id, fee)
                 .await
                 .context("failed decreasing `from` account balance for fee payment")?;
         }
@@ -98,7 +98,7 @@ pub(crate) async fn nonce_request(
     }
 }
 
-async fn get_snapshot_and_height(
+pub(crate) async fn get_snapshot_and_height(
     storage: &Storage,
     height: Height,
 ) -> anyhow::Result<(Snapshot, Height)> {
@@ -14,7 +14,10 @@ use anyhow::{
 use astria_core::{
     generated::sequencer::v1 as raw,
     sequencer::v1::{
-        block::Deposit,
+        block::{
+            Deposit,
+            MAX_SEQUENCE_DATA_BYTES_PER_BLOCK,
+        },
         transaction::Action,
         Address,
         RollupId,
@@ -46,6 +49,7 @@ use tracing::{
     debug,
     info,
     instrument,
+    warn,
 };
 
 use crate::{
@@ -71,8 +75,14 @@ use crate::{
         StateWriteExt,
     },
     component::Component as _,
+    fees::state_ext::StateWriteExt as _,
     genesis::GenesisState,
     ibc::component::IbcComponent,
+    mempool::{
+        encoded_tx_len,
+        Mempool,
+    },
+    metrics_init,
     proposal::commitment::{
         generate_rollup_datas_commitment,
         GeneratedCommitments,
@@ -87,9 +97,6 @@ use crate::{
 /// The inter-block state being written to by the application.
 type InterBlockState = Arc<StateDelta<Snapshot>>;
 
-/// The maximum number of bytes allowed in sequencer action data.
-const MAX_SEQUENCE_DATA_BYTES_PER_BLOCK: usize = 256_000;
-
 /// The Sequencer application, written as a bundle of [`Component`]s.
 ///
 /// Note: this is called `App` because this is a Tendermint ABCI application,
@@ -102,6 +109,10 @@ const MAX_SEQUENCE_DATA_BYTES_PER_BLOCK: usize = 256_000;
 pub(crate) struct App {
     state: InterBlockState,
 
+    // the app-side mempool, shared with the mempool service which inserts transactions
+    // passing `CheckTx`. block proposals are built from it in `prepare_proposal`.
+    mempool: Mempool,
+
     // set to true when `prepare_proposal` is called, indicating we are the proposer for this
     // block. set to false when `process_proposal` is called, as it's called during the prevote
     // phase for that block.
@@ -147,7 +158,7 @@ pub(crate) struct App {
 }
 
 impl App {
-    pub(crate) fn new(snapshot: Snapshot) -> Self {
+    pub(crate) fn new(snapshot: Snapshot, mempool: Mempool) -> Self {
         tracing::debug!("initializing App instance");
 
         // We perform the `Arc` wrapping of `State` here to ensure
@@ -156,6 +167,7 @@ impl App {
 
         Self {
             state,
+            mempool,
             is_proposer: false,
             executed_proposal_hash: Hash::default(),
             execution_result: HashMap::new(),
@@ -185,6 +197,9 @@ impl App {
         for fee_asset in &genesis_state.allowed_fee_assets {
             state_tx.put_allowed_fee_asset(fee_asset.id());
         }
+        state_tx
+            .put_fee_schedule(genesis_state.fees)
+            .context("failed to write fee schedule to state")?;
 
         // call init_chain on all components
         AccountsComponent::init_chain(&mut state_tx, &genesis_state)
@@ -228,6 +243,9 @@ impl App {
     /// It puts this special "commitment" as the first transaction in a block.
     /// When other validators receive the block, they know the first transaction is
     /// supposed to be the commitment, and verifies that is it correct.
+    ///
+    /// The transactions of the proposal are taken from the app-side mempool rather than
+    /// from the request, see [`Mempool::select_for_block`].
     #[instrument(name = "App::prepare_proposal", skip_all)]
     pub(crate) async fn prepare_proposal(
         &mut self,
@@ -237,7 +255,31 @@ impl App {
         self.is_proposer = true;
         self.update_state_for_new_round(&storage);
 
-        let (signed_txs, txs_to_include) = self.execute_block_data(prepare_proposal.txs).await;
+        // leave room for the two 32 byte commitments prepended to the block data.
+        let max_tx_bytes = usize::try_from(prepare_proposal.max_tx_bytes)
+            .unwrap_or(usize::MAX)
+            .saturating_sub(2 * encoded_tx_len(32));
+        let txs = self
+            .mempool
+            .select_for_block(
+                &*self.state,
+                MAX_SEQUENCE_DATA_BYTES_PER_BLOCK,
+                max_tx_bytes,
+            )
+            .await
+            .context("failed to select transactions from mempool")?;
+
+        let (signed_txs, txs_to_include) = self.execute_block_data(txs).await;
+
+        // transactions which failed execution would fail again in the next proposal,
+        // so they are dropped from the mempool.
+        for (tx_hash, _) in self
+            .execution_result
+            .iter()
+            .filter(|(_, result)| result.is_err())
+        {
+            self.mempool.remove(tx_hash).await;
+        }
 
         let deposits = self
             .state
@@ -357,6 +399,11 @@ impl App {
                         error = AsRef::<dyn std::error::Error>::as_ref(&e),
                         "failed to decode deliver tx payload to signed transaction; ignoring it",
                     );
+                    metrics::counter!(
+                        metrics_init::PROPOSAL_EXCLUDED_TRANSACTIONS_COUNT,
+                        "reason" => metrics_init::EXCLUSION_REASON_DECODING_FAILED,
+                    )
+                    .increment(1);
                     excluded_tx_count += 1;
                     continue;
                 }
@@ -383,6 +430,11 @@ impl App {
                     max_data_bytes = MAX_SEQUENCE_DATA_BYTES_PER_BLOCK,
                     "excluding transaction: max block sequenced data limit reached"
                 );
+                metrics::counter!(
+                    metrics_init::PROPOSAL_EXCLUDED_TRANSACTIONS_COUNT,
+                    "reason" => metrics_init::EXCLUSION_REASON_SEQUENCE_DATA_LIMIT,
+                )
+                .increment(1);
                 excluded_tx_count += 1;
                 continue;
             }
@@ -401,12 +453,27 @@ impl App {
                         error = AsRef::<dyn std::error::Error>::as_ref(&e),
                         "failed to execute transaction, not including in block"
                     );
+                    metrics::counter!(
+                        metrics_init::PROPOSAL_EXCLUDED_TRANSACTIONS_COUNT,
+                        "reason" => metrics_init::EXCLUSION_REASON_EXECUTION_FAILED,
+                    )
+                    .increment(1);
                     excluded_tx_count += 1;
                     self.execution_result.insert(tx_hash.into(), Err(e));
                 }
             }
         }
 
+        // allow: the number of transactions in a block should always be low enough to not cause
+        // precision loss
+        #[allow(clippy::cast_precision_loss)]
+        let included_tx_count = validated_txs.len() as f64;
+        metrics::histogram!(metrics_init::PROPOSAL_TRANSACTIONS).record(included_tx_count);
+        // allow: the sequence data bytes are bounded by `MAX_SEQUENCE_DATA_BYTES_PER_BLOCK`
+        #[allow(clippy::cast_precision_loss)]
+        let sequence_data_bytes = block_sequence_data_bytes as f64;
+        metrics::histogram!(metrics_init::PROPOSAL_SEQUENCE_DATA_BYTES).record(sequence_data_bytes);
+
         if excluded_tx_count > 0 {
             info!(
                 excluded_tx_count = excluded_tx_count,
@@ -708,6 +775,16 @@ impl App {
         // Get the latest version of the state, now that we've committed it.
         self.state = Arc::new(StateDelta::new(storage.latest_snapshot()));
 
+        // evict transactions whose nonces were used in the committed block, and parked
+        // transactions that expired.
+        match self.mempool.run_maintenance(&*self.state, height).await {
+            Ok(evicted_tx_count) => debug!(evicted_tx_count, "ran mempool maintenance"),
+            Err(e) => warn!(
+                error = AsRef::<dyn std::error::Error>::as_ref(&e),
+                "failed to run mempool maintenance",
+            ),
+        }
+
         app_hash
     }
 
@@ -776,6 +853,7 @@ fn signed_transaction_from_bytes(bytes: &[u8]) -> anyhow::Result<SignedTransacti
 pub(crate) mod test_utils {
     use astria_core::sequencer::v1::{
         Address,
+        FeeSchedule,
         ADDRESS_LEN,
     };
     use ed25519_consensus::SigningKey;
@@ -802,6 +880,15 @@ pub(crate) mod test_utils {
         let alice = Address::from_verification_key(alice_signing_key.verification_key());
         (alice_signing_key, alice)
     }
+
+    pub(crate) fn default_fees() -> FeeSchedule {
+        FeeSchedule {
+            transfer_fee: 12,
+            sequence_byte_fee: 1,
+            init_bridge_account_fee: 48,
+            ics20_withdrawal_fee: 24,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -817,6 +904,8 @@ mod test {
             SudoAddressChangeAction,
             TransferAction,
         },
+        FeeSchedule,
+        TransactionParams,
         UnsignedTransaction,
     };
     use ed25519_consensus::SigningKey;
@@ -835,14 +924,16 @@ mod test {
 
     use super::*;
     use crate::{
-        accounts::action::TRANSFER_FEE,
         app::test_utils::*,
         asset::get_native_asset,
         authority::state_ext::ValidatorSet,
         genesis::Account,
         ibc::state_ext::StateReadExt as _,
         sequence::calculate_fee,
-        transaction::InvalidNonce,
+        transaction::{
+            InvalidChainId,
+            InvalidNonce,
+        },
     };
 
     fn default_genesis_accounts() -> Vec<Account> {
@@ -892,7 +983,7 @@ mod test {
             .await
             .expect("failed to create temp storage backing chain state");
         let snapshot = storage.latest_snapshot();
-        let mut app = App::new(snapshot);
+        let mut app = App::new(snapshot, Mempool::new());
 
         let genesis_state = genesis_state.unwrap_or_else(|| GenesisState {
             accounts: default_genesis_accounts(),
@@ -902,6 +993,7 @@ mod test {
             native_asset_base_denomination: DEFAULT_NATIVE_ASSET_DENOM.to_string(),
             ibc_params: IBCParameters::default(),
             allowed_fee_assets: vec![DEFAULT_NATIVE_ASSET_DENOM.to_owned().into()],
+            fees: default_fees(),
         });
 
         app.init_chain(genesis_state, genesis_validators, "test".to_string())
@@ -1037,7 +1129,6 @@ mod test {
         let bob_address = address_from_hex_string(BOB_ADDRESS);
         let value = 333_333;
         let tx = UnsignedTransaction {
-            nonce: 0,
             actions: vec![
                 TransferAction {
                     to: bob_address,
@@ -1047,6 +1138,10 @@ mod test {
                 }
                 .into(),
             ],
+            params: TransactionParams {
+                nonce: 0,
+                chain_id: "test".to_string(),
+            },
         };
 
         let signed_tx = tx.into_signed(&alice_signing_key);
@@ -1065,7 +1160,7 @@ mod test {
                 .get_account_balance(alice_address, native_asset)
                 .await
                 .unwrap(),
-            10u128.pow(19) - (value + TRANSFER_FEE),
+            10u128.pow(19) - (value + default_fees().transfer_fee),
         );
         assert_eq!(app.state.get_account_nonce(bob_address).await.unwrap(), 0);
         assert_eq!(app.state.get_account_nonce(alice_address).await.unwrap(), 1);
@@ -1090,7 +1185,6 @@ mod test {
         // transfer funds from Alice to Bob; use native token for fee payment
         let bob_address = address_from_hex_string(BOB_ADDRESS);
         let tx = UnsignedTransaction {
-            nonce: 0,
             actions: vec![
                 TransferAction {
                     to: bob_address,
@@ -1100,6 +1194,10 @@ mod test {
                 }
                 .into(),
             ],
+            params: TransactionParams {
+                nonce: 0,
+                chain_id: "test".to_string(),
+            },
         };
 
         let signed_tx = tx.into_signed(&alice_signing_key);
@@ -1126,7 +1224,7 @@ mod test {
                 .get_account_balance(alice_address, native_asset)
                 .await
                 .unwrap(),
-            10u128.pow(19) - TRANSFER_FEE, // genesis balance - fee
+            10u128.pow(19) - default_fees().transfer_fee, // genesis balance - fee
         );
         assert_eq!(
             app.state
@@ -1152,7 +1250,6 @@ mod test {
 
         // 0-value transfer; only fee is deducted from sender
         let tx = UnsignedTransaction {
-            nonce: 0,
             actions: vec![
                 TransferAction {
                     to: bob,
@@ -1162,6 +1259,10 @@ mod test {
                 }
                 .into(),
             ],
+            params: TransactionParams {
+                nonce: 0,
+                chain_id: "test".to_string(),
+            },
         };
 
         let signed_tx = tx.into_signed(&keypair);
@@ -1180,10 +1281,9 @@ mod test {
 
         let (alice_signing_key, alice_address) = get_alice_signing_key_and_address();
         let data = b"hello world".to_vec();
-        let fee = calculate_fee(&data).unwrap();
+        let fee = calculate_fee(&data, default_fees().sequence_byte_fee).unwrap();
 
         let tx = UnsignedTransaction {
-            nonce: 0,
             actions: vec![
                 SequenceAction {
                     rollup_id: RollupId::from_unhashed_bytes(b"testchainid"),
@@ -1192,6 +1292,10 @@ mod test {
                 }
                 .into(),
             ],
+            params: TransactionParams {
+                nonce: 0,
+                chain_id: "test".to_string(),
+            },
         };
 
         let signed_tx = tx.into_signed(&alice_signing_key);
@@ -1217,7 +1321,6 @@ mod test {
         let fee_asset_id = asset::Id::from_denom("test");
 
         let tx = UnsignedTransaction {
-            nonce: 0,
             actions: vec![
                 SequenceAction {
                     rollup_id: RollupId::from_unhashed_bytes(b"testchainid"),
@@ -1226,6 +1329,10 @@ mod test {
                 }
                 .into(),
             ],
+            params: TransactionParams {
+                nonce: 0,
+                chain_id: "test".to_string(),
+            },
         };
 
         let signed_tx = tx.into_signed(&alice_signing_key);
@@ -1244,6 +1351,7 @@ mod test {
             native_asset_base_denomination: DEFAULT_NATIVE_ASSET_DENOM.to_string(),
             ibc_params: IBCParameters::default(),
             allowed_fee_assets: vec![DEFAULT_NATIVE_ASSET_DENOM.to_owned().into()],
+            fees: default_fees(),
         };
         let mut app = initialize_app(Some(genesis_state), vec![]).await;
 
@@ -1254,8 +1362,11 @@ mod test {
         };
 
         let tx = UnsignedTransaction {
-            nonce: 0,
             actions: vec![Action::ValidatorUpdate(update.clone())],
+            params: TransactionParams {
+                nonce: 0,
+                chain_id: "test".to_string(),
+            },
         };
 
         let signed_tx = tx.into_signed(&alice_signing_key);
@@ -1279,12 +1390,16 @@ mod test {
             native_asset_base_denomination: DEFAULT_NATIVE_ASSET_DENOM.to_string(),
             allowed_fee_assets: vec![DEFAULT_NATIVE_ASSET_DENOM.to_owned().into()],
             ibc_params: IBCParameters::default(),
+            fees: default_fees(),
         };
         let mut app = initialize_app(Some(genesis_state), vec![]).await;
 
         let tx = UnsignedTransaction {
-            nonce: 0,
             actions: vec![IbcRelayerChangeAction::Addition(alice_address).into()],
+            params: TransactionParams {
+                nonce: 0,
+                chain_id: "test".to_string(),
+            },
         };
 
         let signed_tx = tx.into_signed(&alice_signing_key);
@@ -1305,12 +1420,16 @@ mod test {
             native_asset_base_denomination: DEFAULT_NATIVE_ASSET_DENOM.to_string(),
             allowed_fee_assets: vec![DEFAULT_NATIVE_ASSET_DENOM.to_owned().into()],
             ibc_params: IBCParameters::default(),
+            fees: default_fees(),
         };
         let mut app = initialize_app(Some(genesis_state), vec![]).await;
 
         let tx = UnsignedTransaction {
-            nonce: 0,
             actions: vec![IbcRelayerChangeAction::Removal(alice_address).into()],
+            params: TransactionParams {
+                nonce: 0,
+                chain_id: "test".to_string(),
+            },
         };
 
         let signed_tx = tx.into_signed(&alice_signing_key);
@@ -1331,12 +1450,16 @@ mod test {
             native_asset_base_denomination: DEFAULT_NATIVE_ASSET_DENOM.to_string(),
             allowed_fee_assets: vec![DEFAULT_NATIVE_ASSET_DENOM.to_owned().into()],
             ibc_params: IBCParameters::default(),
+            fees: default_fees(),
         };
         let mut app = initialize_app(Some(genesis_state), vec![]).await;
 
         let tx = UnsignedTransaction {
-            nonce: 0,
             actions: vec![IbcRelayerChangeAction::Removal(alice_address).into()],
+            params: TransactionParams {
+                nonce: 0,
+                chain_id: "test".to_string(),
+            },
         };
 
         let signed_tx = tx.into_signed(&alice_signing_key);
@@ -1355,16 +1478,20 @@ mod test {
             native_asset_base_denomination: DEFAULT_NATIVE_ASSET_DENOM.to_string(),
             ibc_params: IBCParameters::default(),
             allowed_fee_assets: vec![DEFAULT_NATIVE_ASSET_DENOM.to_owned().into()],
+            fees: default_fees(),
         };
         let mut app = initialize_app(Some(genesis_state), vec![]).await;
 
         let new_address = address_from_hex_string(BOB_ADDRESS);
 
         let tx = UnsignedTransaction {
-            nonce: 0,
             actions: vec![Action::SudoAddressChange(SudoAddressChangeAction {
                 new_address,
             })],
+            params: TransactionParams {
+                nonce: 0,
+                chain_id: "test".to_string(),
+            },
         };
 
         let signed_tx = tx.into_signed(&alice_signing_key);
@@ -1388,14 +1515,18 @@ mod test {
             native_asset_base_denomination: DEFAULT_NATIVE_ASSET_DENOM.to_string(),
             ibc_params: IBCParameters::default(),
             allowed_fee_assets: vec![DEFAULT_NATIVE_ASSET_DENOM.to_owned().into()],
+            fees: default_fees(),
         };
         let mut app = initialize_app(Some(genesis_state), vec![]).await;
 
         let tx = UnsignedTransaction {
-            nonce: 0,
             actions: vec![Action::SudoAddressChange(SudoAddressChangeAction {
                 new_address: alice_address,
             })],
+            params: TransactionParams {
+                nonce: 0,
+                chain_id: "test".to_string(),
+            },
         };
 
         let signed_tx = tx.into_signed(&alice_signing_key);
@@ -1408,6 +1539,45 @@ mod test {
         assert!(res.contains("signer is not the sudo key"));
     }
 
+    #[tokio::test]
+    async fn app_deliver_tx_fee_change() {
+        use astria_core::sequencer::v1::transaction::action::FeeChangeAction;
+
+        use crate::fees::state_ext::StateReadExt as _;
+
+        let (alice_signing_key, alice_address) = get_alice_signing_key_and_address();
+
+        let genesis_state = GenesisState {
+            accounts: default_genesis_accounts(),
+            authority_sudo_address: alice_address,
+            ibc_sudo_address: alice_address,
+            ibc_relayer_addresses: vec![],
+            native_asset_base_denomination: DEFAULT_NATIVE_ASSET_DENOM.to_string(),
+            ibc_params: IBCParameters::default(),
+            allowed_fee_assets: vec![DEFAULT_NATIVE_ASSET_DENOM.to_owned().into()],
+            fees: default_fees(),
+        };
+        let mut app = initialize_app(Some(genesis_state), vec![]).await;
+
+        let tx = UnsignedTransaction {
+            actions: vec![Action::FeeChange(FeeChangeAction::TransferFee(1))],
+            params: TransactionParams {
+                nonce: 0,
+                chain_id: "test".to_string(),
+            },
+        };
+
+        let signed_tx = tx.into_signed(&alice_signing_key);
+        app.deliver_tx(signed_tx).await.unwrap();
+        assert_eq!(app.state.get_account_nonce(alice_address).await.unwrap(), 1);
+
+        let expected_fees = FeeSchedule {
+            transfer_fee: 1,
+            ..default_fees()
+        };
+        assert_eq!(app.state.get_fee_schedule().await.unwrap(), expected_fees);
+    }
+
     #[tokio::test]
     async fn app_deliver_tx_fee_asset_change_addition() {
         use astria_core::sequencer::v1::transaction::action::FeeAssetChangeAction;
@@ -1422,16 +1592,20 @@ mod test {
             native_asset_base_denomination: DEFAULT_NATIVE_ASSET_DENOM.to_string(),
             ibc_params: IBCParameters::default(),
             allowed_fee_assets: vec![DEFAULT_NATIVE_ASSET_DENOM.to_owned().into()],
+            fees: default_fees(),
         };
         let mut app = initialize_app(Some(genesis_state), vec![]).await;
 
         let new_asset = asset::Id::from_denom("test");
 
         let tx = UnsignedTransaction {
-            nonce: 0,
             actions: vec![Action::FeeAssetChange(FeeAssetChangeAction::Addition(
                 new_asset,
             ))],
+            params: TransactionParams {
+                nonce: 0,
+                chain_id: "test".to_string(),
+            },
         };
 
         let signed_tx = tx.into_signed(&alice_signing_key);
@@ -1459,14 +1633,18 @@ mod test {
                 DEFAULT_NATIVE_ASSET_DENOM.to_owned().into(),
                 test_asset.clone(),
             ],
+            fees: default_fees(),
         };
         let mut app = initialize_app(Some(genesis_state), vec![]).await;
 
         let tx = UnsignedTransaction {
-            nonce: 0,
             actions: vec![Action::FeeAssetChange(FeeAssetChangeAction::Removal(
                 test_asset.id(),
             ))],
+            params: TransactionParams {
+                nonce: 0,
+                chain_id: "test".to_string(),
+            },
         };
 
         let signed_tx = tx.into_signed(&alice_signing_key);
@@ -1495,14 +1673,18 @@ mod test {
             native_asset_base_denomination: DEFAULT_NATIVE_ASSET_DENOM.to_string(),
             ibc_params: IBCParameters::default(),
             allowed_fee_assets: vec![DEFAULT_NATIVE_ASSET_DENOM.to_owned().into()],
+            fees: default_fees(),
         };
         let mut app = initialize_app(Some(genesis_state), vec![]).await;
 
         let tx = UnsignedTransaction {
-            nonce: 0,
             actions: vec![Action::FeeAssetChange(FeeAssetChangeAction::Removal(
                 get_native_asset().id(),
             ))],
+            params: TransactionParams {
+                nonce: 0,
+                chain_id: "test".to_string(),
+            },
         };
 
         let signed_tx = tx.into_signed(&alice_signing_key);
@@ -1519,8 +1701,6 @@ mod test {
     async fn app_deliver_tx_init_bridge_account_ok() {
         use astria_core::sequencer::v1::transaction::action::InitBridgeAccountAction;
 
-        use crate::bridge::init_bridge_account_action::INIT_BRIDGE_ACCOUNT_FEE;
-
         let (alice_signing_key, alice_address) = get_alice_signing_key_and_address();
         let mut app = initialize_app(None, vec![]).await;
 
@@ -1530,10 +1710,14 @@ mod test {
             rollup_id,
             asset_ids: vec![asset_id],
             fee_asset_id: asset_id,
+            withdrawer_address: None,
         };
         let tx = UnsignedTransaction {
-            nonce: 0,
             actions: vec![action.into()],
+            params: TransactionParams {
+                nonce: 0,
+                chain_id: "test".to_string(),
+            },
         };
 
         let signed_tx = tx.into_signed(&alice_signing_key);
@@ -1560,12 +1744,20 @@ mod test {
                 .unwrap(),
             vec![asset_id]
         );
+        assert_eq!(
+            app.state
+                .get_bridge_account_withdrawer_address(&alice_address)
+                .await
+                .unwrap()
+                .unwrap(),
+            alice_address
+        );
         assert_eq!(
             app.state
                 .get_account_balance(alice_address, asset_id)
                 .await
                 .unwrap(),
-            before_balance - INIT_BRIDGE_ACCOUNT_FEE
+            before_balance - default_fees().init_bridge_account_fee
         );
     }
 
@@ -1582,10 +1774,14 @@ mod test {
             rollup_id,
             asset_ids: vec![],
             fee_asset_id: asset_id,
+            withdrawer_address: None,
         };
         let tx = UnsignedTransaction {
-            nonce: 0,
             actions: vec![action.into()],
+            params: TransactionParams {
+                nonce: 0,
+                chain_id: "test".to_string(),
+            },
         };
 
         let signed_tx = tx.into_signed(&alice_signing_key);
@@ -1605,10 +1801,14 @@ mod test {
             rollup_id,
             asset_ids: vec![asset_id],
             fee_asset_id: asset_id,
+            withdrawer_address: None,
         };
         let tx = UnsignedTransaction {
-            nonce: 0,
             actions: vec![action.into()],
+            params: TransactionParams {
+                nonce: 0,
+                chain_id: "test".to_string(),
+            },
         };
 
         let signed_tx = tx.into_signed(&alice_signing_key);
@@ -1618,10 +1818,14 @@ mod test {
             rollup_id,
             asset_ids: vec![asset_id],
             fee_asset_id: asset_id,
+            withdrawer_address: None,
         };
         let tx = UnsignedTransaction {
-            nonce: 1,
             actions: vec![action.into()],
+            params: TransactionParams {
+                nonce: 1,
+                chain_id: "test".to_string(),
+            },
         };
 
         let signed_tx = tx.into_signed(&alice_signing_key);
@@ -1658,8 +1862,11 @@ mod test {
             destination_chain_address: "nootwashere".to_string(),
         };
         let tx = UnsignedTransaction {
-            nonce: 0,
             actions: vec![action.into()],
+            params: TransactionParams {
+                nonce: 0,
+                chain_id: "test".to_string(),
+            },
         };
 
         let signed_tx = tx.into_signed(&alice_signing_key);
@@ -1682,7 +1889,7 @@ mod test {
                 .get_account_balance(alice_address, asset_id)
                 .await
                 .unwrap(),
-            alice_before_balance - (amount + TRANSFER_FEE)
+            alice_before_balance - (amount + default_fees().transfer_fee)
         );
         assert_eq!(
             app.state
@@ -1725,10 +1932,90 @@ mod test {
             destination_chain_address: "nootwashere".to_string(),
         };
         let tx = UnsignedTransaction {
-            nonce: 0,
             actions: vec![action.into()],
+            params: TransactionParams {
+                nonce: 0,
+                chain_id: "test".to_string(),
+            },
+        };
+
+        let signed_tx = tx.into_signed(&alice_signing_key);
+        assert!(app.deliver_tx(signed_tx).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn app_deliver_tx_bridge_unlock_action_ok() {
+        use astria_core::sequencer::v1::transaction::action::BridgeUnlockAction;
+
+        let (alice_signing_key, alice_address) = get_alice_signing_key_and_address();
+        let mut app = initialize_app(None, vec![]).await;
+
+        let bridge_address = Address::from([99; 20]);
+        let rollup_id = RollupId::from_unhashed_bytes(b"testchainid");
+        let asset_id = get_native_asset().id();
+        let bridge_balance = 1000;
+
+        // alice is the withdrawer of the bridge account
+        let mut state_tx = StateDelta::new(app.state.clone());
+        state_tx.put_bridge_account_rollup_id(&bridge_address, &rollup_id);
+        state_tx
+            .put_bridge_account_asset_ids(&bridge_address, &[asset_id])
+            .unwrap();
+        state_tx.put_bridge_account_withdrawer_address(&bridge_address, &alice_address);
+        state_tx
+            .put_account_balance(bridge_address, asset_id, bridge_balance)
+            .unwrap();
+        app.apply(state_tx);
+
+        let bob_address = address_from_hex_string(BOB_ADDRESS);
+        let bob_before_balance = app
+            .state
+            .get_account_balance(bob_address, asset_id)
+            .await
+            .unwrap();
+
+        let amount = 100;
+        let action = BridgeUnlockAction {
+            bridge_address,
+            to: bob_address,
+            amount,
+            asset_id,
+            fee_asset_id: asset_id,
+            rollup_withdrawal_event_id: "0xdeadbeef".to_string(),
+        };
+        let tx = UnsignedTransaction {
+            actions: vec![action.clone().into()],
+            params: TransactionParams {
+                nonce: 0,
+                chain_id: "test".to_string(),
+            },
         };
 
+        let signed_tx = tx.into_signed(&alice_signing_key);
+        app.deliver_tx(signed_tx).await.unwrap();
+        assert_eq!(
+            app.state
+                .get_account_balance(bridge_address, asset_id)
+                .await
+                .unwrap(),
+            bridge_balance - amount - default_fees().transfer_fee
+        );
+        assert_eq!(
+            app.state
+                .get_account_balance(bob_address, asset_id)
+                .await
+                .unwrap(),
+            bob_before_balance + amount
+        );
+
+        // the same withdrawal event cannot be used twice
+        let tx = UnsignedTransaction {
+            actions: vec![action.into()],
+            params: TransactionParams {
+                nonce: 1,
+                chain_id: "test".to_string(),
+            },
+        };
         let signed_tx = tx.into_signed(&alice_signing_key);
         assert!(app.deliver_tx(signed_tx).await.is_err());
     }
@@ -1757,8 +2044,11 @@ mod test {
             fee_asset_id: asset_id,
         };
         let tx = UnsignedTransaction {
-            nonce: 0,
             actions: vec![action.into()],
+            params: TransactionParams {
+                nonce: 0,
+                chain_id: "test".to_string(),
+            },
         };
 
         let signed_tx = tx.into_signed(&alice_signing_key);
@@ -1778,13 +2068,13 @@ mod test {
             native_asset_base_denomination: DEFAULT_NATIVE_ASSET_DENOM.to_string(),
             ibc_params: IBCParameters::default(),
             allowed_fee_assets: vec![DEFAULT_NATIVE_ASSET_DENOM.to_owned().into()],
+            fees: default_fees(),
         };
         let mut app = initialize_app(Some(genesis_state), vec![]).await;
 
         let bob_address = address_from_hex_string(BOB_ADDRESS);
         let value = 333_333;
         let tx = UnsignedTransaction {
-            nonce: 0,
             actions: vec![
                 MintAction {
                     to: bob_address,
@@ -1792,6 +2082,10 @@ mod test {
                 }
                 .into(),
             ],
+            params: TransactionParams {
+                nonce: 0,
+                chain_id: "test".to_string(),
+            },
         };
 
         let signed_tx = tx.into_signed(&alice_signing_key);
@@ -1887,7 +2181,6 @@ mod test {
         // create tx with invalid nonce 1
         let data = b"hello world".to_vec();
         let tx = UnsignedTransaction {
-            nonce: 1,
             actions: vec![
                 SequenceAction {
                     rollup_id: RollupId::from_unhashed_bytes(b"testchainid"),
@@ -1896,6 +2189,10 @@ mod test {
                 }
                 .into(),
             ],
+            params: TransactionParams {
+                nonce: 1,
+                chain_id: "test".to_string(),
+            },
         };
 
         let signed_tx = tx.into_signed(&alice_signing_key);
@@ -1921,6 +2218,52 @@ mod test {
         );
     }
 
+    #[tokio::test]
+    async fn app_deliver_tx_invalid_chain_id() {
+        let mut app = initialize_app(None, vec![]).await;
+
+        let (alice_signing_key, alice_address) = get_alice_signing_key_and_address();
+
+        // create tx signed for a different chain
+        let data = b"hello world".to_vec();
+        let tx = UnsignedTransaction {
+            actions: vec![
+                SequenceAction {
+                    rollup_id: RollupId::from_unhashed_bytes(b"testchainid"),
+                    data,
+                    fee_asset_id: get_native_asset().id(),
+                }
+                .into(),
+            ],
+            params: TransactionParams {
+                nonce: 0,
+                chain_id: "wrong-chain".to_string(),
+            },
+        };
+
+        let signed_tx = tx.into_signed(&alice_signing_key);
+        let response = app.deliver_tx(signed_tx).await;
+
+        // check that tx was not executed by checking nonce and balance are unchanged
+        assert_eq!(app.state.get_account_nonce(alice_address).await.unwrap(), 0);
+        assert_eq!(
+            app.state
+                .get_account_balance(alice_address, get_native_asset().id())
+                .await
+                .unwrap(),
+            10u128.pow(19),
+        );
+
+        assert_eq!(
+            response
+                .unwrap_err()
+                .downcast_ref::<InvalidChainId>()
+                .map(|chain_id_err| chain_id_err.0.clone())
+                .unwrap(),
+            "wrong-chain"
+        );
+    }
+
     #[tokio::test]
     async fn app_commit() {
         let genesis_state = GenesisState {
@@ -1931,6 +2274,7 @@ mod test {
             native_asset_base_denomination: DEFAULT_NATIVE_ASSET_DENOM.to_string(),
             ibc_params: IBCParameters::default(),
             allowed_fee_assets: vec![DEFAULT_NATIVE_ASSET_DENOM.to_owned().into()],
+            fees: default_fees(),
         };
 
         let (mut app, storage) = initialize_app_with_storage(Some(genesis_state), vec![]).await;
@@ -1981,7 +2325,6 @@ mod test {
         let bob_address = address_from_hex_string(BOB_ADDRESS);
         let amount = 333_333;
         let tx = UnsignedTransaction {
-            nonce: 0,
             actions: vec![
                 TransferAction {
                     to: bob_address,
@@ -1991,6 +2334,10 @@ mod test {
                 }
                 .into(),
             ],
+            params: TransactionParams {
+                nonce: 0,
+                chain_id: "test".to_string(),
+            },
         };
 
         let signed_tx = tx.into_signed(&alice_signing_key);
@@ -2036,7 +2383,7 @@ mod test {
                 .get_account_balance(proposer_address, native_asset)
                 .await
                 .unwrap(),
-            TRANSFER_FEE,
+            default_fees().transfer_fee,
         );
         assert_eq!(app.state.get_block_fees().await.unwrap().len(), 0);
     }
@@ -2076,8 +2423,11 @@ mod test {
             fee_asset_id: asset_id,
         };
         let tx = UnsignedTransaction {
-            nonce: 0,
             actions: vec![lock_action.into(), sequence_action.into()],
+            params: TransactionParams {
+                nonce: 0,
+                chain_id: "test".to_string(),
+            },
         };
 
         let signed_tx = tx.into_signed(&alice_signing_key);
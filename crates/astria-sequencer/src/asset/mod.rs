@@ -1,3 +1,4 @@
+pub(crate) mod query;
 pub(crate) mod state_ext;
 
 use std::sync::OnceLock;
@@ -0,0 +1,60 @@
+use astria_core::sequencer::v1::{
+    asset::AllowedFeeAssetIdsResponse,
+    AbciErrorCode,
+};
+use cnidarium::Storage;
+use prost::Message as _;
+use tendermint::abci::{
+    request,
+    response,
+};
+
+use crate::{
+    accounts::query::get_snapshot_and_height,
+    state_ext::StateReadExt as _,
+};
+
+pub(crate) async fn allowed_fee_asset_ids_request(
+    storage: Storage,
+    request: request::Query,
+    _params: Vec<(String, String)>,
+) -> response::Query {
+    let (snapshot, height) = match get_snapshot_and_height(&storage, request.height).await {
+        Ok(tup) => tup,
+        Err(err) => {
+            return response::Query {
+                code: AbciErrorCode::INTERNAL_ERROR.into(),
+                info: AbciErrorCode::INTERNAL_ERROR.to_string(),
+                log: format!("failed to query internal storage for snapshot and height: {err:?}"),
+                ..response::Query::default()
+            };
+        }
+    };
+
+    let fee_asset_ids = match snapshot.get_allowed_fee_assets().await {
+        Ok(fee_asset_ids) => fee_asset_ids,
+        Err(err) => {
+            return response::Query {
+                code: AbciErrorCode::INTERNAL_ERROR.into(),
+                info: AbciErrorCode::INTERNAL_ERROR.to_string(),
+                log: format!("failed getting allowed fee assets: {err:?}"),
+                height,
+                ..response::Query::default()
+            };
+        }
+    };
+    let payload = AllowedFeeAssetIdsResponse {
+        height: height.value(),
+        fee_asset_ids,
+    }
+    .into_raw()
+    .encode_to_vec()
+    .into();
+    response::Query {
+        code: tendermint::abci::Code::Ok,
+        key: request.path.clone().into_bytes().into(),
+        value: payload,
+        height,
+        ..response::Query::default()
+    }
+}
@@ -0,0 +1,315 @@
+use anyhow::{
+    ensure,
+    Context as _,
+    Result,
+};
+use astria_core::sequencer::v1::{
+    transaction::action::{
+        BridgeUnlockAction,
+        TransferAction,
+    },
+    Address,
+};
+use tracing::instrument;
+
+use crate::{
+    accounts::action::transfer_check_stateful,
+    bridge::state_ext::{
+        StateReadExt as _,
+        StateWriteExt as _,
+    },
+    state_ext::{
+        StateReadExt,
+        StateWriteExt,
+    },
+    transaction::action_handler::ActionHandler,
+};
+
+#[async_trait::async_trait]
+impl ActionHandler for BridgeUnlockAction {
+    async fn check_stateless(&self) -> Result<()> {
+        ensure!(
+            !self.rollup_withdrawal_event_id.is_empty(),
+            "rollup withdrawal event ID must be set",
+        );
+        Ok(())
+    }
+
+    async fn check_stateful<S: StateReadExt + 'static>(
+        &self,
+        state: &S,
+        from: Address,
+    ) -> Result<()> {
+        // ensure the account being withdrawn from is a bridge account.
+        ensure!(
+            state
+                .get_bridge_account_rollup_id(&self.bridge_address)
+                .await
+                .context("failed to get bridge account rollup ID from state")?
+                .is_some(),
+            "bridge unlock must be sent from a bridge account",
+        );
+
+        // bridge accounts initialized without an explicit withdrawer
+        // can only be withdrawn from by the bridge account itself.
+        let withdrawer_address = state
+            .get_bridge_account_withdrawer_address(&self.bridge_address)
+            .await
+            .context("failed to get bridge account withdrawer address from state")?
+            .unwrap_or(self.bridge_address);
+        ensure!(
+            withdrawer_address == from,
+            "unauthorized to unlock bridge account funds",
+        );
+
+        ensure!(
+            state
+                .get_bridge_account_withdrawal_event(
+                    &self.bridge_address,
+                    &self.rollup_withdrawal_event_id,
+                )
+                .await
+                .context("failed to get bridge account withdrawal event from state")?
+                .is_none(),
+            "rollup withdrawal event was already used to unlock funds",
+        );
+
+        // ensure the recipient is not a bridge account,
+        // as the explicit `BridgeLockAction` should be used to transfer to a bridge account.
+        ensure!(
+            state
+                .get_bridge_account_rollup_id(&self.to)
+                .await
+                .context("failed to get bridge account rollup ID from state")?
+                .is_none(),
+            "cannot unlock funds to a bridge account",
+        );
+
+        // the unlocked funds and the fee are both paid by the bridge account,
+        // so this performs the same checks as a `TransferAction` sent by it.
+        transfer_check_stateful(&to_transfer_action(self), state, self.bridge_address)
+            .await
+            .context("stateful transfer check failed")
+    }
+
+    #[instrument(
+        skip_all,
+        fields(
+            bridge_address = %self.bridge_address,
+            rollup_withdrawal_event_id = %self.rollup_withdrawal_event_id,
+        )
+    )]
+    async fn execute<S: StateWriteExt>(&self, state: &mut S, _from: Address) -> Result<()> {
+        // checked again here as multiple unlocks with the same withdrawal event
+        // could be contained in the same transaction.
+        ensure!(
+            state
+                .get_bridge_account_withdrawal_event(
+                    &self.bridge_address,
+                    &self.rollup_withdrawal_event_id,
+                )
+                .await
+                .context("failed to get bridge account withdrawal event from state")?
+                .is_none(),
+            "rollup withdrawal event was already used to unlock funds",
+        );
+
+        to_transfer_action(self)
+            .execute(state, self.bridge_address)
+            .await
+            .context("failed to execute bridge unlock action as transfer action")?;
+
+        state
+            .put_bridge_account_withdrawal_event(
+                &self.bridge_address,
+                &self.rollup_withdrawal_event_id,
+                self.amount,
+            )
+            .context("failed to put withdrawal event into state")?;
+        Ok(())
+    }
+}
+
+fn to_transfer_action(action: &BridgeUnlockAction) -> TransferAction {
+    TransferAction {
+        to: action.to,
+        asset_id: action.asset_id,
+        amount: action.amount,
+        fee_asset_id: action.fee_asset_id,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use astria_core::sequencer::v1::{
+        asset::default_native_asset_id,
+        RollupId,
+    };
+    use cnidarium::StateDelta;
+
+    use super::*;
+    use crate::{
+        accounts::state_ext::{
+            StateReadExt as _,
+            StateWriteExt as _,
+        },
+        fees::state_ext::StateWriteExt as _,
+    };
+
+    const TRANSFER_FEE: u128 = 12;
+
+    fn unlock_action(bridge_address: Address, to: Address) -> BridgeUnlockAction {
+        BridgeUnlockAction {
+            bridge_address,
+            to,
+            amount: 100,
+            asset_id: default_native_asset_id(),
+            fee_asset_id: default_native_asset_id(),
+            rollup_withdrawal_event_id: "event-0".to_string(),
+        }
+    }
+
+    fn init_bridge_account<S: StateWriteExt>(
+        state: &mut S,
+        bridge_address: Address,
+        withdrawer_address: Address,
+    ) {
+        let asset_id = default_native_asset_id();
+        state.put_bridge_account_rollup_id(
+            &bridge_address,
+            &RollupId::from_unhashed_bytes(b"rollup"),
+        );
+        state
+            .put_bridge_account_asset_ids(&bridge_address, &[asset_id])
+            .unwrap();
+        state.put_bridge_account_withdrawer_address(&bridge_address, &withdrawer_address);
+        state.put_allowed_fee_asset(asset_id);
+        state.put_transfer_fee(TRANSFER_FEE).unwrap();
+        state
+            .put_account_balance(bridge_address, asset_id, 1000)
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn bridge_unlock_check_stateless_rejects_empty_withdrawal_event_id() {
+        let mut action = unlock_action(
+            Address::try_from_slice(&[1; 20]).unwrap(),
+            Address::try_from_slice(&[2; 20]).unwrap(),
+        );
+        action.rollup_withdrawal_event_id = String::new();
+        assert!(action.check_stateless().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn bridge_unlock_by_withdrawer_ok() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let bridge_address = Address::try_from_slice(&[1; 20]).unwrap();
+        let withdrawer_address = Address::try_from_slice(&[2; 20]).unwrap();
+        let to = Address::try_from_slice(&[3; 20]).unwrap();
+        init_bridge_account(&mut state, bridge_address, withdrawer_address);
+
+        let action = unlock_action(bridge_address, to);
+        action
+            .check_stateful(&state, withdrawer_address)
+            .await
+            .unwrap();
+        action
+            .execute(&mut state, withdrawer_address)
+            .await
+            .unwrap();
+
+        let asset_id = default_native_asset_id();
+        assert_eq!(state.get_account_balance(to, asset_id).await.unwrap(), 100);
+        assert_eq!(
+            state
+                .get_account_balance(bridge_address, asset_id)
+                .await
+                .unwrap(),
+            1000 - 100 - TRANSFER_FEE
+        );
+        assert_eq!(
+            state
+                .get_bridge_account_withdrawal_event(&bridge_address, "event-0")
+                .await
+                .unwrap(),
+            Some(100)
+        );
+    }
+
+    #[tokio::test]
+    async fn bridge_unlock_by_non_withdrawer_fails() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let bridge_address = Address::try_from_slice(&[1; 20]).unwrap();
+        let withdrawer_address = Address::try_from_slice(&[2; 20]).unwrap();
+        let to = Address::try_from_slice(&[3; 20]).unwrap();
+        init_bridge_account(&mut state, bridge_address, withdrawer_address);
+
+        let action = unlock_action(bridge_address, to);
+        // not even the bridge account itself may unlock if it has a different withdrawer
+        assert!(
+            action
+                .check_stateful(&state, bridge_address)
+                .await
+                .unwrap_err()
+                .to_string()
+                .contains("unauthorized")
+        );
+        assert!(action.check_stateful(&state, to).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn bridge_unlock_replayed_withdrawal_event_fails() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let bridge_address = Address::try_from_slice(&[1; 20]).unwrap();
+        let withdrawer_address = Address::try_from_slice(&[2; 20]).unwrap();
+        let to = Address::try_from_slice(&[3; 20]).unwrap();
+        init_bridge_account(&mut state, bridge_address, withdrawer_address);
+
+        let action = unlock_action(bridge_address, to);
+        action
+            .execute(&mut state, withdrawer_address)
+            .await
+            .unwrap();
+
+        assert!(
+            action
+                .check_stateful(&state, withdrawer_address)
+                .await
+                .unwrap_err()
+                .to_string()
+                .contains("already used")
+        );
+        assert!(
+            action
+                .execute(&mut state, withdrawer_address)
+                .await
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn bridge_unlock_from_non_bridge_account_fails() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let address = Address::try_from_slice(&[1; 20]).unwrap();
+        let to = Address::try_from_slice(&[3; 20]).unwrap();
+        let asset_id = default_native_asset_id();
+        state.put_allowed_fee_asset(asset_id);
+        state.put_transfer_fee(TRANSFER_FEE).unwrap();
+        state.put_account_balance(address, asset_id, 1000).unwrap();
+
+        let action = unlock_action(address, to);
+        assert!(action.check_stateful(&state, address).await.is_err());
+    }
+}
@@ -19,6 +19,7 @@ use crate::{
         StateReadExt as _,
         StateWriteExt as _,
     },
+    fees::state_ext::StateReadExt as _,
     state_ext::{
         StateReadExt,
         StateWriteExt,
@@ -26,9 +27,6 @@ use crate::{
     transaction::action_handler::ActionHandler,
 };
 
-/// Fee charged for a `InitBridgeAccountAction`.
-pub(crate) const INIT_BRIDGE_ACCOUNT_FEE: u128 = 48;
-
 #[async_trait::async_trait]
 impl ActionHandler for InitBridgeAccountAction {
     async fn check_stateful<S: StateReadExt + 'static>(
@@ -61,13 +59,17 @@ impl ActionHandler for InitBridgeAccountAction {
             "must initialize with at least one asset ID",
         );
 
+        let fee = state
+            .get_init_bridge_account_fee()
+            .await
+            .context("failed to get init bridge account fee from state")?;
         let balance = state
             .get_account_balance(from, self.fee_asset_id)
             .await
             .context("failed getting `from` account balance for fee payment")?;
 
         ensure!(
-            balance >= INIT_BRIDGE_ACCOUNT_FEE,
+            balance >= fee,
             "insufficient funds for bridge account initialization",
         );
 
@@ -76,13 +78,19 @@ impl ActionHandler for InitBridgeAccountAction {
 
     #[instrument(skip_all)]
     async fn execute<S: StateWriteExt>(&self, state: &mut S, from: Address) -> Result<()> {
+        let fee = state
+            .get_init_bridge_account_fee()
+            .await
+            .context("failed to get init bridge account fee from state")?;
         state.put_bridge_account_rollup_id(&from, &self.rollup_id);
         state
             .put_bridge_account_asset_ids(&from, &self.asset_ids)
             .context("failed to put asset IDs")?;
+        state
+            .put_bridge_account_withdrawer_address(&from, &self.withdrawer_address.unwrap_or(from));
 
         state
-            .decrease_balance(from, self.fee_asset_id, INIT_BRIDGE_ACCOUNT_FEE)
+            .decrease_balance(from, self.fee_asset_id, fee)
             .await
             .context("failed to deduct fee from account balance")?;
         Ok(())
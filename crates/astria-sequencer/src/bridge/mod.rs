@@ -1,3 +1,4 @@
 mod bridge_lock_action;
+mod bridge_unlock_action;
 pub(crate) mod init_bridge_account_action;
 pub(crate) mod state_ext;
@@ -67,6 +67,20 @@ fn asset_ids_storage_key(address: &Address) -> String {
     format!("{}/assetids", storage_key(&address.encode_hex::<String>()))
 }
 
+fn withdrawer_address_storage_key(address: &Address) -> String {
+    format!(
+        "{}/withdrawer",
+        storage_key(&address.encode_hex::<String>())
+    )
+}
+
+fn withdrawal_event_storage_key(address: &Address, withdrawal_event_id: &str) -> String {
+    format!(
+        "{}/withdrawalevent/{withdrawal_event_id}",
+        storage_key(&address.encode_hex::<String>())
+    )
+}
+
 fn deposit_storage_key_prefix(rollup_id: &RollupId) -> String {
     format!("{DEPOSIT_PREFIX}/{}", rollup_id.encode_hex::<String>())
 }
@@ -108,6 +122,43 @@ pub(crate) trait StateReadExt: StateRead {
         Ok(asset_ids.0.into_iter().map(asset::Id::from).collect())
     }
 
+    #[instrument(skip(self))]
+    async fn get_bridge_account_withdrawer_address(
+        &self,
+        address: &Address,
+    ) -> Result<Option<Address>> {
+        let Some(withdrawer_address_bytes) = self
+            .get_raw(&withdrawer_address_storage_key(address))
+            .await
+            .context("failed reading raw bridge account withdrawer address from state")?
+        else {
+            debug!("bridge account withdrawer address not found, returning None");
+            return Ok(None);
+        };
+
+        let withdrawer_address = Address::try_from_slice(&withdrawer_address_bytes)
+            .context("invalid withdrawer address bytes")?;
+        Ok(Some(withdrawer_address))
+    }
+
+    #[instrument(skip(self))]
+    async fn get_bridge_account_withdrawal_event(
+        &self,
+        address: &Address,
+        withdrawal_event_id: &str,
+    ) -> Result<Option<u128>> {
+        let Some(bytes) = self
+            .get_raw(&withdrawal_event_storage_key(address, withdrawal_event_id))
+            .await
+            .context("failed reading raw bridge account withdrawal event from state")?
+        else {
+            return Ok(None);
+        };
+        let Balance(amount) =
+            Balance::try_from_slice(&bytes).context("invalid withdrawal event bytes")?;
+        Ok(Some(amount))
+    }
+
     #[instrument(skip(self))]
     async fn get_deposit_nonce(&self, rollup_id: &RollupId) -> Result<u32> {
         let bytes = self
@@ -201,6 +252,34 @@ pub(crate) trait StateWriteExt: StateWrite {
         Ok(())
     }
 
+    #[instrument(skip(self))]
+    fn put_bridge_account_withdrawer_address(
+        &mut self,
+        address: &Address,
+        withdrawer_address: &Address,
+    ) {
+        self.put_raw(
+            withdrawer_address_storage_key(address),
+            withdrawer_address.to_vec(),
+        );
+    }
+
+    // records that the rollup withdrawal event with the given ID was used to unlock
+    // `amount` from the bridge account, so that it cannot be used again.
+    #[instrument(skip(self))]
+    fn put_bridge_account_withdrawal_event(
+        &mut self,
+        address: &Address,
+        withdrawal_event_id: &str,
+        amount: u128,
+    ) -> Result<()> {
+        self.put_raw(
+            withdrawal_event_storage_key(address, withdrawal_event_id),
+            borsh::to_vec(&Balance(amount)).context("failed to serialize withdrawal amount")?,
+        );
+        Ok(())
+    }
+
     // the deposit "nonce" for a given rollup ID during a given block.
     // this is only used to generate storage keys for each of the deposits within a block,
     // and is reset to 0 at the beginning of each block.
@@ -414,6 +493,101 @@ mod test {
         );
     }
 
+    #[tokio::test]
+    async fn get_bridge_account_withdrawer_address_uninitialized_ok() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let state = StateDelta::new(snapshot);
+
+        let address = Address::try_from_slice(&[42u8; 20]).unwrap();
+
+        assert_eq!(
+            state
+                .get_bridge_account_withdrawer_address(&address)
+                .await
+                .expect(
+                    "call to get withdrawer address should not fail for uninitialized accounts"
+                ),
+            None,
+            "stored withdrawer address for bridge not what was expected"
+        );
+    }
+
+    #[tokio::test]
+    async fn put_bridge_account_withdrawer_address() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let address = Address::try_from_slice(&[42u8; 20]).unwrap();
+        let withdrawer_address = Address::try_from_slice(&[43u8; 20]).unwrap();
+
+        state.put_bridge_account_withdrawer_address(&address, &withdrawer_address);
+        assert_eq!(
+            state
+                .get_bridge_account_withdrawer_address(&address)
+                .await
+                .expect("a withdrawer address was written and must exist inside the database")
+                .expect("expecting return value"),
+            withdrawer_address,
+            "stored withdrawer address for bridge not what was expected"
+        );
+
+        // other accounts are unaffected
+        let address_1 = Address::try_from_slice(&[41u8; 20]).unwrap();
+        assert_eq!(
+            state
+                .get_bridge_account_withdrawer_address(&address_1)
+                .await
+                .expect(
+                    "call to get withdrawer address should not fail for uninitialized accounts"
+                ),
+            None,
+            "withdrawer address was not written for this account"
+        );
+    }
+
+    #[tokio::test]
+    async fn put_bridge_account_withdrawal_event() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let address = Address::try_from_slice(&[42u8; 20]).unwrap();
+        let address_1 = Address::try_from_slice(&[41u8; 20]).unwrap();
+
+        assert_eq!(
+            state
+                .get_bridge_account_withdrawal_event(&address, "event-0")
+                .await
+                .expect("call to get withdrawal event should not fail for unused events"),
+            None,
+            "withdrawal event should not be recorded yet"
+        );
+
+        state
+            .put_bridge_account_withdrawal_event(&address, "event-0", 100)
+            .expect("writing withdrawal event should not fail");
+        assert_eq!(
+            state
+                .get_bridge_account_withdrawal_event(&address, "event-0")
+                .await
+                .expect("a withdrawal event was written and must exist inside the database"),
+            Some(100),
+            "stored withdrawal amount not what was expected"
+        );
+
+        // the same event ID is tracked independently per bridge account
+        assert_eq!(
+            state
+                .get_bridge_account_withdrawal_event(&address_1, "event-0")
+                .await
+                .expect("call to get withdrawal event should not fail for unused events"),
+            None,
+            "withdrawal event was not recorded for this account"
+        );
+    }
+
     #[tokio::test]
     async fn get_deposit_nonce_uninitialized_ok() {
         let storage = cnidarium::TempStorage::new().await.unwrap();
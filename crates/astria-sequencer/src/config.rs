@@ -31,6 +31,13 @@ pub struct Config {
     pub metrics_http_listener_addr: String,
     /// Writes a human readable format to stdout instead of JSON formatted OTEL trace data.
     pub pretty_print: bool,
+    /// The directory in which state-sync snapshots are stored.
+    pub snapshot_dir: PathBuf,
+    /// The interval in blocks at which state-sync snapshots are taken. Set to 0 to disable
+    /// taking snapshots.
+    pub snapshot_interval: u64,
+    /// The number of most recent state-sync snapshots to keep on disk.
+    pub snapshot_keep_recent: usize,
 }
 
 impl config::Config for Config {
@@ -0,0 +1,113 @@
+use anyhow::{
+    ensure,
+    Context as _,
+    Result,
+};
+use astria_core::sequencer::v1::{
+    transaction::action::FeeChangeAction,
+    Address,
+};
+use async_trait::async_trait;
+use cnidarium::{
+    StateRead,
+    StateWrite,
+};
+use tracing::instrument;
+
+use crate::{
+    authority::state_ext::StateReadExt as _,
+    fees::state_ext::StateWriteExt as _,
+    transaction::action_handler::ActionHandler,
+};
+
+#[async_trait]
+impl ActionHandler for FeeChangeAction {
+    async fn check_stateful<S: StateRead + 'static>(&self, state: &S, from: Address) -> Result<()> {
+        let authority_sudo_address = state
+            .get_sudo_address()
+            .await
+            .context("failed to get authority sudo address")?;
+        ensure!(
+            authority_sudo_address == from,
+            "unauthorized address for fee change"
+        );
+        Ok(())
+    }
+
+    #[instrument(skip_all)]
+    async fn execute<S: StateWrite>(&self, state: &mut S, _from: Address) -> Result<()> {
+        match *self {
+            FeeChangeAction::TransferFee(fee) => state.put_transfer_fee(fee),
+            FeeChangeAction::SequenceByteFee(fee) => state.put_sequence_byte_fee(fee),
+            FeeChangeAction::InitBridgeAccountFee(fee) => state.put_init_bridge_account_fee(fee),
+            FeeChangeAction::Ics20WithdrawalFee(fee) => state.put_ics20_withdrawal_fee(fee),
+        }
+        .context("failed to write new fee to state")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use astria_core::sequencer::v1::FeeSchedule;
+    use cnidarium::StateDelta;
+
+    use super::*;
+    use crate::{
+        authority::state_ext::StateWriteExt as _,
+        fees::state_ext::StateReadExt as _,
+    };
+
+    #[tokio::test]
+    async fn fee_change_action_by_sudo_address_ok() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let sudo_address = Address::from([1; 20]);
+        state.put_sudo_address(sudo_address).unwrap();
+        state
+            .put_fee_schedule(FeeSchedule {
+                transfer_fee: 12,
+                sequence_byte_fee: 1,
+                init_bridge_account_fee: 48,
+                ics20_withdrawal_fee: 24,
+            })
+            .unwrap();
+
+        let action = FeeChangeAction::TransferFee(10);
+        action.check_stateful(&state, sudo_address).await.unwrap();
+        action.execute(&mut state, sudo_address).await.unwrap();
+        assert_eq!(state.get_transfer_fee().await.unwrap(), 10);
+
+        let action = FeeChangeAction::SequenceByteFee(2);
+        action.execute(&mut state, sudo_address).await.unwrap();
+        assert_eq!(state.get_sequence_byte_fee().await.unwrap(), 2);
+
+        let action = FeeChangeAction::InitBridgeAccountFee(3);
+        action.execute(&mut state, sudo_address).await.unwrap();
+        assert_eq!(state.get_init_bridge_account_fee().await.unwrap(), 3);
+
+        let action = FeeChangeAction::Ics20WithdrawalFee(4);
+        action.execute(&mut state, sudo_address).await.unwrap();
+        assert_eq!(state.get_ics20_withdrawal_fee().await.unwrap(), 4);
+    }
+
+    #[tokio::test]
+    async fn fee_change_action_by_non_sudo_address_fails() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        state.put_sudo_address(Address::from([1; 20])).unwrap();
+
+        let action = FeeChangeAction::TransferFee(10);
+        assert!(
+            action
+                .check_stateful(&state, Address::from([2; 20]))
+                .await
+                .unwrap_err()
+                .to_string()
+                .contains("unauthorized address for fee change")
+        );
+    }
+}
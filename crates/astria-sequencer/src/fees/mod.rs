@@ -0,0 +1,16 @@
+pub(crate) mod action;
+pub(crate) mod query;
+pub(crate) mod state_ext;
+
+use astria_core::sequencer::v1::FeeSchedule;
+
+/// The fees that were hard-coded before the fee schedule was stored in state.
+///
+/// They apply to each fee missing from state, as is the case for chains started before the fee
+/// schedule was stored, and to genesis files without a fee schedule.
+pub(crate) const DEFAULT_FEE_SCHEDULE: FeeSchedule = FeeSchedule {
+    transfer_fee: 12,
+    sequence_byte_fee: 1,
+    init_bridge_account_fee: 48,
+    ics20_withdrawal_fee: 24,
+};
@@ -0,0 +1,60 @@
+use astria_core::sequencer::v1::{
+    AbciErrorCode,
+    FeeScheduleResponse,
+};
+use cnidarium::Storage;
+use prost::Message as _;
+use tendermint::abci::{
+    request,
+    response,
+};
+
+use crate::{
+    accounts::query::get_snapshot_and_height,
+    fees::state_ext::StateReadExt as _,
+};
+
+pub(crate) async fn fee_schedule_request(
+    storage: Storage,
+    request: request::Query,
+    _params: Vec<(String, String)>,
+) -> response::Query {
+    let (snapshot, height) = match get_snapshot_and_height(&storage, request.height).await {
+        Ok(tup) => tup,
+        Err(err) => {
+            return response::Query {
+                code: AbciErrorCode::INTERNAL_ERROR.into(),
+                info: AbciErrorCode::INTERNAL_ERROR.to_string(),
+                log: format!("failed to query internal storage for snapshot and height: {err:?}"),
+                ..response::Query::default()
+            };
+        }
+    };
+
+    let fees = match snapshot.get_fee_schedule().await {
+        Ok(fees) => fees,
+        Err(err) => {
+            return response::Query {
+                code: AbciErrorCode::INTERNAL_ERROR.into(),
+                info: AbciErrorCode::INTERNAL_ERROR.to_string(),
+                log: format!("failed getting fee schedule: {err:?}"),
+                height,
+                ..response::Query::default()
+            };
+        }
+    };
+    let payload = FeeScheduleResponse {
+        height: height.value(),
+        fees,
+    }
+    .into_raw()
+    .encode_to_vec()
+    .into();
+    response::Query {
+        code: tendermint::abci::Code::Ok,
+        key: request.path.clone().into_bytes().into(),
+        value: payload,
+        height,
+        ..response::Query::default()
+    }
+}
@@ -0,0 +1,214 @@
+use anyhow::{
+    Context as _,
+    Result,
+};
+use astria_core::sequencer::v1::FeeSchedule;
+use async_trait::async_trait;
+use borsh::{
+    BorshDeserialize,
+    BorshSerialize,
+};
+use cnidarium::{
+    StateRead,
+    StateWrite,
+};
+use tracing::instrument;
+
+use super::DEFAULT_FEE_SCHEDULE;
+
+/// Newtype wrapper to read and write a u128 from rocksdb.
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+struct Fee(u128);
+
+const TRANSFER_FEE_KEY: &str = "fees/transfer";
+const SEQUENCE_BYTE_FEE_KEY: &str = "fees/sequencebyte";
+const INIT_BRIDGE_ACCOUNT_FEE_KEY: &str = "fees/initbridgeaccount";
+const ICS20_WITHDRAWAL_FEE_KEY: &str = "fees/ics20withdrawal";
+
+/// Reads the fee stored under `key`, falling back to `default` if it was never written.
+async fn get_fee<S: StateRead + ?Sized>(state: &S, key: &str, default: u128) -> Result<u128> {
+    let Some(bytes) = state
+        .get_raw(key)
+        .await
+        .context("failed reading raw fee from state")?
+    else {
+        return Ok(default);
+    };
+    let Fee(fee) = Fee::try_from_slice(&bytes).context("invalid fee bytes")?;
+    Ok(fee)
+}
+
+fn put_fee<S: StateWrite + ?Sized>(state: &mut S, key: &str, fee: u128) -> Result<()> {
+    state.put_raw(
+        key.to_string(),
+        borsh::to_vec(&Fee(fee)).context("failed to serialize fee")?,
+    );
+    Ok(())
+}
+
+#[async_trait]
+pub(crate) trait StateReadExt: StateRead {
+    #[instrument(skip(self))]
+    async fn get_transfer_fee(&self) -> Result<u128> {
+        get_fee(self, TRANSFER_FEE_KEY, DEFAULT_FEE_SCHEDULE.transfer_fee).await
+    }
+
+    #[instrument(skip(self))]
+    async fn get_sequence_byte_fee(&self) -> Result<u128> {
+        get_fee(
+            self,
+            SEQUENCE_BYTE_FEE_KEY,
+            DEFAULT_FEE_SCHEDULE.sequence_byte_fee,
+        )
+        .await
+    }
+
+    #[instrument(skip(self))]
+    async fn get_init_bridge_account_fee(&self) -> Result<u128> {
+        get_fee(
+            self,
+            INIT_BRIDGE_ACCOUNT_FEE_KEY,
+            DEFAULT_FEE_SCHEDULE.init_bridge_account_fee,
+        )
+        .await
+    }
+
+    #[instrument(skip(self))]
+    async fn get_ics20_withdrawal_fee(&self) -> Result<u128> {
+        get_fee(
+            self,
+            ICS20_WITHDRAWAL_FEE_KEY,
+            DEFAULT_FEE_SCHEDULE.ics20_withdrawal_fee,
+        )
+        .await
+    }
+
+    #[instrument(skip(self))]
+    async fn get_fee_schedule(&self) -> Result<FeeSchedule> {
+        Ok(FeeSchedule {
+            transfer_fee: self.get_transfer_fee().await?,
+            sequence_byte_fee: self.get_sequence_byte_fee().await?,
+            init_bridge_account_fee: self.get_init_bridge_account_fee().await?,
+            ics20_withdrawal_fee: self.get_ics20_withdrawal_fee().await?,
+        })
+    }
+}
+
+impl<T: StateRead + ?Sized> StateReadExt for T {}
+
+#[async_trait]
+pub(crate) trait StateWriteExt: StateWrite {
+    #[instrument(skip(self))]
+    fn put_transfer_fee(&mut self, fee: u128) -> Result<()> {
+        put_fee(self, TRANSFER_FEE_KEY, fee)
+    }
+
+    #[instrument(skip(self))]
+    fn put_sequence_byte_fee(&mut self, fee: u128) -> Result<()> {
+        put_fee(self, SEQUENCE_BYTE_FEE_KEY, fee)
+    }
+
+    #[instrument(skip(self))]
+    fn put_init_bridge_account_fee(&mut self, fee: u128) -> Result<()> {
+        put_fee(self, INIT_BRIDGE_ACCOUNT_FEE_KEY, fee)
+    }
+
+    #[instrument(skip(self))]
+    fn put_ics20_withdrawal_fee(&mut self, fee: u128) -> Result<()> {
+        put_fee(self, ICS20_WITHDRAWAL_FEE_KEY, fee)
+    }
+
+    #[instrument(skip(self))]
+    fn put_fee_schedule(&mut self, fees: FeeSchedule) -> Result<()> {
+        let FeeSchedule {
+            transfer_fee,
+            sequence_byte_fee,
+            init_bridge_account_fee,
+            ics20_withdrawal_fee,
+        } = fees;
+        self.put_transfer_fee(transfer_fee)?;
+        self.put_sequence_byte_fee(sequence_byte_fee)?;
+        self.put_init_bridge_account_fee(init_bridge_account_fee)?;
+        self.put_ics20_withdrawal_fee(ics20_withdrawal_fee)?;
+        Ok(())
+    }
+}
+
+impl<T: StateWrite> StateWriteExt for T {}
+
+#[cfg(test)]
+mod test {
+    use astria_core::sequencer::v1::FeeSchedule;
+    use cnidarium::StateDelta;
+
+    use super::{
+        StateReadExt as _,
+        StateWriteExt as _,
+    };
+    use crate::fees::DEFAULT_FEE_SCHEDULE;
+
+    #[tokio::test]
+    async fn fee_schedule_uninitialized_falls_back_to_defaults() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        assert_eq!(
+            state.get_fee_schedule().await.unwrap(),
+            DEFAULT_FEE_SCHEDULE,
+            "fees missing from state should fall back to the former constants"
+        );
+
+        // fees written to state take precedence over the defaults of the others
+        state.put_transfer_fee(3).unwrap();
+        assert_eq!(
+            state.get_fee_schedule().await.unwrap(),
+            FeeSchedule {
+                transfer_fee: 3,
+                ..DEFAULT_FEE_SCHEDULE
+            },
+        );
+    }
+
+    #[tokio::test]
+    async fn put_fee_schedule() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let fees = FeeSchedule {
+            transfer_fee: 12,
+            sequence_byte_fee: 1,
+            init_bridge_account_fee: 48,
+            ics20_withdrawal_fee: 24,
+        };
+        state.put_fee_schedule(fees).unwrap();
+        assert_eq!(
+            state
+                .get_fee_schedule()
+                .await
+                .expect("a fee schedule was written and must exist inside the database"),
+            fees,
+            "stored fee schedule was not what was expected"
+        );
+
+        // individual fees can be updated
+        state.put_sequence_byte_fee(3).unwrap();
+        assert_eq!(
+            state
+                .get_sequence_byte_fee()
+                .await
+                .expect("a sequence byte fee was written and must exist inside the database"),
+            3,
+            "updated sequence byte fee was not what was expected"
+        );
+        assert_eq!(
+            state
+                .get_transfer_fee()
+                .await
+                .expect("a transfer fee was written and must exist inside the database"),
+            12,
+            "other fees should be unaffected by the update"
+        );
+    }
+}
@@ -1,6 +1,7 @@
 use astria_core::sequencer::v1::{
     asset,
     Address,
+    FeeSchedule,
 };
 use penumbra_ibc::params::IBCParameters;
 use serde::{
@@ -22,6 +23,10 @@ pub(crate) struct GenesisState {
     pub(crate) ibc_params: IBCParameters,
     #[serde(deserialize_with = "deserialize_assets")]
     pub(crate) allowed_fee_assets: Vec<asset::Denom>,
+    /// The fee schedule, defaulting to the fees that were hard-coded before it was stored in
+    /// state.
+    #[serde(default = "default_fees", deserialize_with = "deserialize_fees")]
+    pub(crate) fees: FeeSchedule,
 }
 
 #[derive(Debug, Deserialize)]
@@ -72,6 +77,36 @@ where
     Ok(strings.into_iter().map(asset::Denom::from).collect())
 }
 
+fn default_fees() -> FeeSchedule {
+    crate::fees::DEFAULT_FEE_SCHEDULE
+}
+
+fn deserialize_fees<'de, D>(deserializer: D) -> Result<FeeSchedule, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    struct Fees {
+        transfer_fee: u128,
+        sequence_byte_fee: u128,
+        init_bridge_account_fee: u128,
+        ics20_withdrawal_fee: u128,
+    }
+
+    let Fees {
+        transfer_fee,
+        sequence_byte_fee,
+        init_bridge_account_fee,
+        ics20_withdrawal_fee,
+    } = Fees::deserialize(deserializer)?;
+    Ok(FeeSchedule {
+        transfer_fee,
+        sequence_byte_fee,
+        init_bridge_account_fee,
+        ics20_withdrawal_fee,
+    })
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -102,10 +137,38 @@ mod test {
                 "outbound_ics20_transfers_enabled": true
             },
             "native_asset_base_denomination": "nria",
-            "allowed_fee_assets": ["nria"]
+            "allowed_fee_assets": ["nria"],
+            "fees": {
+                "transfer_fee": 12,
+                "sequence_byte_fee": 1,
+                "init_bridge_account_fee": 48,
+                "ics20_withdrawal_fee": 24
+            }
           }
           "#;
         let genesis: GenesisState = serde_json::from_str(genesis_str).unwrap();
         assert_eq!(genesis.ibc_relayer_addresses.len(), 2);
+        assert_eq!(genesis.fees.transfer_fee, 12);
+        assert_eq!(genesis.fees.ics20_withdrawal_fee, 24);
+    }
+
+    #[test]
+    fn genesis_without_fees_uses_default_fees() {
+        let genesis_str = r#"{
+            "accounts": [],
+            "authority_sudo_address": "1c0c490f1b5528d8173c5de46d131160e4b2c0c3",
+            "ibc_sudo_address": "1c0c490f1b5528d8173c5de46d131160e4b2c0c3",
+            "ibc_relayer_addresses": [],
+            "ibc_params": {
+                "ibc_enabled": true,
+                "inbound_ics20_transfers_enabled": true,
+                "outbound_ics20_transfers_enabled": true
+            },
+            "native_asset_base_denomination": "nria",
+            "allowed_fee_assets": ["nria"]
+          }
+          "#;
+        let genesis: GenesisState = serde_json::from_str(genesis_str).unwrap();
+        assert_eq!(genesis.fees, crate::fees::DEFAULT_FEE_SCHEDULE);
     }
 }
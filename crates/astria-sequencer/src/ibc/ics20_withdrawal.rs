@@ -26,6 +26,7 @@ use crate::{
         StateReadExt,
         StateWriteExt,
     },
+    fees::state_ext::StateReadExt as _,
     ibc::state_ext::{
         StateReadExt as _,
         StateWriteExt as _,
@@ -33,9 +34,6 @@ use crate::{
     transaction::action_handler::ActionHandler,
 };
 
-/// Fee charged for a `Ics20Withdrawal` action.
-pub(crate) const ICS20_WITHDRAWAL_FEE: u128 = 24;
-
 fn withdrawal_to_unchecked_ibc_packet(
     withdrawal: &action::Ics20Withdrawal,
 ) -> IBCPacket<Unchecked> {
@@ -76,6 +74,10 @@ impl ActionHandler for action::Ics20Withdrawal {
             .await
             .context("packet failed send check")?;
 
+        let fee = state
+            .get_ics20_withdrawal_fee()
+            .await
+            .context("failed to get ics20 withdrawal fee from state")?;
         let transfer_asset_id = self.denom().id();
 
         let from_fee_balance = state
@@ -88,7 +90,7 @@ impl ActionHandler for action::Ics20Withdrawal {
         if self.fee_asset_id() == &transfer_asset_id {
             let payment_amount = self
                 .amount()
-                .checked_add(ICS20_WITHDRAWAL_FEE)
+                .checked_add(fee)
                 .ok_or(anyhow!("transfer amount plus fee overflowed"))?;
 
             ensure!(
@@ -99,7 +101,7 @@ impl ActionHandler for action::Ics20Withdrawal {
             // otherwise, check the fee asset account has enough to cover the fees,
             // and the transfer asset account has enough to cover the transfer
             ensure!(
-                from_fee_balance >= ICS20_WITHDRAWAL_FEE,
+                from_fee_balance >= fee,
                 "insufficient funds for fee payment"
             );
 
@@ -119,6 +121,10 @@ impl ActionHandler for action::Ics20Withdrawal {
     #[instrument(skip(self, state))]
     async fn execute<S: StateWriteExt>(&self, state: &mut S, from: Address) -> Result<()> {
         let checked_packet = withdrawal_to_unchecked_ibc_packet(self).assume_checked();
+        let fee = state
+            .get_ics20_withdrawal_fee()
+            .await
+            .context("failed to get ics20 withdrawal fee from state")?;
 
         state
             .decrease_balance(from, self.denom().id(), self.amount())
@@ -126,7 +132,7 @@ impl ActionHandler for action::Ics20Withdrawal {
             .context("failed to decrease sender balance")?;
 
         state
-            .decrease_balance(from, *self.fee_asset_id(), ICS20_WITHDRAWAL_FEE)
+            .decrease_balance(from, *self.fee_asset_id(), fee)
             .await
             .context("failed to subtract fee from sender balance")?;
 
@@ -8,15 +8,19 @@ mod build_info;
 pub(crate) mod component;
 pub mod config;
 pub(crate) mod fee_asset_change;
+pub(crate) mod fees;
 pub(crate) mod genesis;
 pub(crate) mod grpc;
 pub(crate) mod ibc;
+pub(crate) mod mempool;
+pub mod metrics_init;
 #[cfg(feature = "mint")]
 pub(crate) mod mint;
 pub(crate) mod proposal;
 pub(crate) mod sequence;
 mod sequencer;
 pub(crate) mod service;
+pub(crate) mod snapshot;
 pub(crate) mod state_ext;
 pub(crate) mod transaction;
 mod utils;
@@ -0,0 +1,845 @@
+//! The app-side mempool of the sequencer.
+//!
+//! Transactions that passed `CheckTx` are held here per signer, ordered by nonce. A transaction
+//! is *pending* if all nonces between the signer's current account nonce and its own nonce are
+//! present in the mempool, and *parked* otherwise. Parked transactions become pending once the
+//! nonce gap in front of them is filled.
+//!
+//! When proposing a block, pending transactions are selected by their fee-per-byte priority while
+//! respecting the nonce order of each signer, see [`Mempool::select_for_block`].
+//!
+//! The nonce gap a transaction may leave is bounded, and transactions that stay parked for
+//! [`PARKED_TRANSACTION_TTL_BLOCKS`] blocks are evicted after a commit. Once the mempool is full,
+//! a new transaction evicts the parked transaction, or else the pending transaction of lower
+//! priority, that is last in its signer's nonce order.
+use std::{
+    cmp::Ordering,
+    collections::{
+        BTreeMap,
+        BinaryHeap,
+        HashMap,
+    },
+    sync::Arc,
+};
+
+use anyhow::{
+    bail,
+    ensure,
+    Context as _,
+};
+use astria_core::sequencer::v1::{
+    transaction::action::Action,
+    Address,
+    SignedTransaction,
+};
+use bytes::Bytes;
+use sha2::{
+    Digest as _,
+    Sha256,
+};
+use tokio::sync::RwLock;
+use tracing::debug;
+
+use crate::accounts::state_ext::StateReadExt;
+
+/// The maximum number of transactions a single signer can hold in the mempool.
+const MAX_TRANSACTIONS_PER_ACCOUNT: usize = 64;
+
+/// The maximum number of transactions the mempool holds across all signers.
+const MAX_TRANSACTIONS: usize = 4096;
+
+/// The maximum distance between a signer's account nonce and the nonce of a transaction it
+/// inserts, matching [`MAX_TRANSACTIONS_PER_ACCOUNT`].
+const MAX_NONCE_GAP: u32 = 64;
+
+/// The number of blocks after which a transaction that is still parked is evicted.
+const PARKED_TRANSACTION_TTL_BLOCKS: u64 = 16;
+
+/// The priority of a transaction in the mempool.
+///
+/// Transactions are prioritized by the total fee they pay per byte of their encoding.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct TransactionPriority {
+    fee: u128,
+    size: u128,
+}
+
+impl TransactionPriority {
+    pub(crate) fn new(fee: u128, size: usize) -> Self {
+        Self {
+            fee,
+            // a zero-sized transaction cannot be decoded, but guard against dividing by zero.
+            size: (size as u128).max(1),
+        }
+    }
+}
+
+impl Ord for TransactionPriority {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // compares `self.fee / self.size` with `other.fee / other.size` without losing precision.
+        self.fee
+            .saturating_mul(other.size)
+            .cmp(&other.fee.saturating_mul(self.size))
+    }
+}
+
+impl PartialOrd for TransactionPriority {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for TransactionPriority {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for TransactionPriority {}
+
+/// Whether an inserted transaction can be included in the next block.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum InsertionStatus {
+    /// All transactions of the signer up to this one are in the mempool.
+    Pending,
+    /// There is a nonce gap between the signer's account nonce and this transaction.
+    Parked,
+}
+
+#[derive(Clone, Debug)]
+struct EnqueuedTransaction {
+    tx_hash: [u8; 32],
+    tx_bytes: Bytes,
+    priority: TransactionPriority,
+    sequence_data_bytes: usize,
+    /// The latest committed block height when the transaction was inserted.
+    inserted_at_height: u64,
+}
+
+#[derive(Debug, Default)]
+struct Account {
+    /// The signer's account nonce in the latest state seen by the mempool.
+    nonce: u32,
+    /// The transactions of the signer, keyed by their nonce.
+    txs: BTreeMap<u32, EnqueuedTransaction>,
+}
+
+impl Account {
+    /// Returns if there is a gap between the account nonce and `nonce`.
+    fn is_parked(&self, nonce: u32) -> bool {
+        !(self.nonce..nonce).all(|nonce| self.txs.contains_key(&nonce))
+    }
+}
+
+/// A transaction that can be evicted to make room for another one: the last transaction in the
+/// nonce order of its signer, so that no other transaction is parked by evicting it.
+struct EvictionCandidate {
+    tx_hash: [u8; 32],
+    is_parked: bool,
+    priority: TransactionPriority,
+}
+
+#[derive(Debug, Default)]
+struct Inner {
+    accounts: HashMap<Address, Account>,
+    /// Maps the hash of each transaction in the mempool to its signer and nonce.
+    hashes: HashMap<[u8; 32], (Address, u32)>,
+    /// The latest committed block height seen by the mempool.
+    height: u64,
+}
+
+impl Inner {
+    fn remove(&mut self, tx_hash: &[u8; 32]) -> bool {
+        let Some((signer, nonce)) = self.hashes.remove(tx_hash) else {
+            return false;
+        };
+        if let Some(account) = self.accounts.get_mut(&signer) {
+            account.txs.remove(&nonce);
+            if account.txs.is_empty() {
+                self.accounts.remove(&signer);
+            }
+        }
+        true
+    }
+
+    /// Returns the transaction to evict to make room for a transaction of `signer`.
+    ///
+    /// Parked transactions are evicted before pending ones, and transactions of lower priority
+    /// before those of higher priority. Transactions of `signer` are never evicted.
+    fn eviction_candidate(&self, signer: &Address) -> Option<EvictionCandidate> {
+        self.accounts
+            .iter()
+            .filter(|(address, _)| *address != signer)
+            .filter_map(|(address, account)| {
+                let (nonce, tx) = account.txs.last_key_value()?;
+                Some((
+                    *address,
+                    EvictionCandidate {
+                        tx_hash: tx.tx_hash,
+                        is_parked: account.is_parked(*nonce),
+                        priority: tx.priority,
+                    },
+                ))
+            })
+            // break ties by signer address to keep the eviction deterministic.
+            .min_by(|(left_address, left), (right_address, right)| {
+                right
+                    .is_parked
+                    .cmp(&left.is_parked)
+                    .then_with(|| left.priority.cmp(&right.priority))
+                    .then_with(|| left_address.get().cmp(&right_address.get()))
+            })
+            .map(|(_, candidate)| candidate)
+    }
+}
+
+/// A candidate for inclusion in a block: the lowest pending nonce of a signer.
+#[derive(PartialEq, Eq)]
+struct Candidate {
+    priority: TransactionPriority,
+    signer: Address,
+    nonce: u32,
+}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // break ties by signer address to keep the selection deterministic.
+        self.priority
+            .cmp(&other.priority)
+            .then_with(|| other.signer.get().cmp(&self.signer.get()))
+    }
+}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// The app-side mempool.
+///
+/// This is cheap to clone; all clones share the same underlying transactions.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Mempool {
+    inner: Arc<RwLock<Inner>>,
+}
+
+impl Mempool {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of transactions in the mempool.
+    pub(crate) async fn len(&self) -> usize {
+        self.inner.read().await.hashes.len()
+    }
+
+    /// Inserts `tx` into the mempool.
+    ///
+    /// `tx_bytes` are the bytes the transaction was submitted as, and `current_account_nonce`
+    /// is the nonce of the signer's account in the latest committed state.
+    ///
+    /// A transaction replaces a transaction of the same signer and nonce only if it has a
+    /// strictly higher priority. Inserting a transaction that is already in the mempool is a
+    /// no-op.
+    ///
+    /// If the mempool is full, a parked transaction of another signer is evicted to make room,
+    /// or else a pending one of lower priority if `tx` is pending itself.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the nonce of `tx` was already used or is more than
+    /// [`MAX_NONCE_GAP`] ahead of the account nonce, if a transaction with the same nonce and at
+    /// least the same priority is in the mempool, if the signer has reached its maximum number of
+    /// transactions, or if the mempool is full and holds no transaction that can be evicted.
+    pub(crate) async fn insert(
+        &self,
+        tx: &SignedTransaction,
+        tx_bytes: Bytes,
+        priority: TransactionPriority,
+        current_account_nonce: u32,
+    ) -> anyhow::Result<InsertionStatus> {
+        let tx_hash: [u8; 32] = Sha256::digest(&tx_bytes).into();
+        let signer = Address::from_verification_key(tx.verification_key());
+        let nonce = tx.nonce();
+        ensure!(
+            nonce >= current_account_nonce,
+            "nonce already used by account"
+        );
+        ensure!(
+            nonce - current_account_nonce < MAX_NONCE_GAP,
+            "nonce `{nonce}` is {MAX_NONCE_GAP} or more ahead of the account nonce \
+             `{current_account_nonce}`"
+        );
+        let sequence_data_bytes = tx
+            .actions()
+            .iter()
+            .filter_map(Action::as_sequence)
+            .fold(0usize, |acc, seq| acc + seq.data.len());
+
+        let mut inner = self.inner.write().await;
+        if !inner.hashes.contains_key(&tx_hash) {
+            // the checks run before the signer's entry is created so that a rejected transaction
+            // leaves no empty entry behind.
+            let account = inner.accounts.get(&signer);
+            if let Some(existing) = account.and_then(|account| account.txs.get(&nonce)) {
+                ensure!(
+                    priority > existing.priority,
+                    "a transaction with the same nonce and at least the same priority is already \
+                     in the mempool"
+                );
+                let existing_hash = existing.tx_hash;
+                inner.hashes.remove(&existing_hash);
+            } else {
+                ensure!(
+                    account.map_or(0, |account| account.txs.len()) < MAX_TRANSACTIONS_PER_ACCOUNT,
+                    "account has reached the maximum of {MAX_TRANSACTIONS_PER_ACCOUNT} \
+                     transactions in the mempool"
+                );
+                if inner.hashes.len() >= MAX_TRANSACTIONS {
+                    let is_pending = (current_account_nonce..nonce).all(|nonce| {
+                        account.is_some_and(|account| account.txs.contains_key(&nonce))
+                    });
+                    let evicted = inner.eviction_candidate(&signer).filter(|candidate| {
+                        candidate.is_parked || (is_pending && priority > candidate.priority)
+                    });
+                    let Some(evicted) = evicted else {
+                        bail!(
+                            "the mempool has reached its maximum of {MAX_TRANSACTIONS} \
+                             transactions and holds none that can be evicted for this one"
+                        );
+                    };
+                    debug!(
+                        evicted_transaction_hash = %telemetry::display::base64(&evicted.tx_hash),
+                        "mempool is full; evicting transaction",
+                    );
+                    inner.remove(&evicted.tx_hash);
+                }
+            }
+            let inserted_at_height = inner.height;
+            let account = inner.accounts.entry(signer).or_default();
+            account.txs.insert(
+                nonce,
+                EnqueuedTransaction {
+                    tx_hash,
+                    tx_bytes,
+                    priority,
+                    sequence_data_bytes,
+                    inserted_at_height,
+                },
+            );
+            inner.hashes.insert(tx_hash, (signer, nonce));
+        }
+
+        let account = inner
+            .accounts
+            .get_mut(&signer)
+            .expect("the signer's transactions are in the mempool after inserting one of them");
+        account.nonce = current_account_nonce;
+        if account.is_parked(nonce) {
+            Ok(InsertionStatus::Parked)
+        } else {
+            Ok(InsertionStatus::Pending)
+        }
+    }
+
+    /// Removes the transaction with hash `tx_hash` from the mempool.
+    ///
+    /// Returns `true` if the transaction was in the mempool.
+    pub(crate) async fn remove(&self, tx_hash: &[u8; 32]) -> bool {
+        self.inner.write().await.remove(tx_hash)
+    }
+
+    /// Selects the transactions to be proposed in the next block, in the order they should be
+    /// executed.
+    ///
+    /// Only pending transactions are selected, and the transactions of each signer are returned
+    /// in nonce order. Among the signers, the transaction with the highest priority is selected
+    /// next. If a transaction does not fit into the block, no further transactions of its
+    /// signer are selected.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the account nonce of a signer could not be read from `state`.
+    pub(crate) async fn select_for_block<S: StateReadExt + 'static>(
+        &self,
+        state: &S,
+        max_sequence_data_bytes: usize,
+        max_tx_bytes: usize,
+    ) -> anyhow::Result<Vec<Bytes>> {
+        let inner = self.inner.read().await;
+
+        let mut candidates = BinaryHeap::with_capacity(inner.accounts.len());
+        for (signer, account) in &inner.accounts {
+            let txs = &account.txs;
+            let current_nonce = state
+                .get_account_nonce(*signer)
+                .await
+                .context("failed to get account nonce")?;
+            if let Some(tx) = txs.get(&current_nonce) {
+                candidates.push(Candidate {
+                    priority: tx.priority,
+                    signer: *signer,
+                    nonce: current_nonce,
+                });
+            }
+        }
+
+        let mut selected = Vec::new();
+        let mut sequence_data_bytes = 0usize;
+        let mut tx_bytes = 0usize;
+        while let Some(Candidate {
+            signer,
+            nonce,
+            ..
+        }) = candidates.pop()
+        {
+            let txs = &inner.accounts[&signer].txs;
+            let tx = &txs[&nonce];
+            let encoded_len = encoded_tx_len(tx.tx_bytes.len());
+            if sequence_data_bytes + tx.sequence_data_bytes > max_sequence_data_bytes
+                || tx_bytes + encoded_len > max_tx_bytes
+            {
+                debug!(
+                    transaction_hash = %telemetry::display::base64(&tx.tx_hash),
+                    "transaction does not fit into block; skipping remaining transactions of \
+                     signer",
+                );
+                continue;
+            }
+            sequence_data_bytes += tx.sequence_data_bytes;
+            tx_bytes += encoded_len;
+            selected.push(tx.tx_bytes.clone());
+
+            if let Some(next) = nonce.checked_add(1).and_then(|next| {
+                txs.get(&next).map(|tx| Candidate {
+                    priority: tx.priority,
+                    signer,
+                    nonce: next,
+                })
+            }) {
+                candidates.push(next);
+            }
+        }
+        Ok(selected)
+    }
+
+    /// Evicts all transactions whose nonces were already used according to `state`, and all
+    /// transactions that are parked since [`PARKED_TRANSACTION_TTL_BLOCKS`] blocks.
+    ///
+    /// This should be called after each commit, with `state` being the latest committed state
+    /// at `height`. Returns the number of evicted transactions.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the account nonce of a signer could not be read from `state`.
+    pub(crate) async fn run_maintenance<S: StateReadExt + 'static>(
+        &self,
+        state: &S,
+        height: u64,
+    ) -> anyhow::Result<usize> {
+        let signers: Vec<Address> = self.inner.read().await.accounts.keys().copied().collect();
+        let mut current_nonces = Vec::with_capacity(signers.len());
+        for signer in signers {
+            let nonce = state
+                .get_account_nonce(signer)
+                .await
+                .context("failed to get account nonce")?;
+            current_nonces.push((signer, nonce));
+        }
+
+        let mut inner = self.inner.write().await;
+        inner.height = height;
+        let mut stale = Vec::new();
+        for (signer, current_nonce) in current_nonces {
+            let Some(account) = inner.accounts.get_mut(&signer) else {
+                continue;
+            };
+            account.nonce = current_nonce;
+            stale.extend(account.txs.range(..current_nonce).map(|(_, tx)| tx.tx_hash));
+            stale.extend(
+                account
+                    .txs
+                    .range(current_nonce..)
+                    .filter(|(nonce, tx)| {
+                        height.saturating_sub(tx.inserted_at_height)
+                            >= PARKED_TRANSACTION_TTL_BLOCKS
+                            && account.is_parked(**nonce)
+                    })
+                    .map(|(_, tx)| tx.tx_hash),
+            );
+        }
+        for tx_hash in &stale {
+            inner.remove(tx_hash);
+        }
+        Ok(stale.len())
+    }
+}
+
+/// Returns the number of bytes a transaction of `len` bytes takes up in the block data, which is
+/// what CometBFT's `max_tx_bytes` limit is checked against.
+pub(crate) fn encoded_tx_len(len: usize) -> usize {
+    // each transaction is encoded as a length-delimited protobuf field with a 1 byte tag.
+    1 + prost::encoding::encoded_len_varint(len as u64) + len
+}
+
+#[cfg(test)]
+mod test {
+    use astria_core::sequencer::v1::{
+        asset::default_native_asset_id,
+        transaction::action::SequenceAction,
+        RollupId,
+        TransactionParams,
+        UnsignedTransaction,
+    };
+    use cnidarium::StateDelta;
+    use ed25519_consensus::SigningKey;
+    use prost::Message as _;
+
+    use super::*;
+    use crate::accounts::state_ext::StateWriteExt as _;
+
+    fn signed_tx(
+        signing_key: &SigningKey,
+        nonce: u32,
+        data_len: usize,
+    ) -> (SignedTransaction, Bytes) {
+        let tx = UnsignedTransaction {
+            actions: vec![
+                SequenceAction {
+                    rollup_id: RollupId::from_unhashed_bytes(b"testchainid"),
+                    data: vec![0; data_len],
+                    fee_asset_id: default_native_asset_id(),
+                }
+                .into(),
+            ],
+            params: TransactionParams {
+                nonce,
+                chain_id: "test".to_string(),
+            },
+        }
+        .into_signed(signing_key);
+        let bytes = tx.to_raw().encode_to_vec().into();
+        (tx, bytes)
+    }
+
+    #[test]
+    fn transaction_priority_compares_fee_per_byte() {
+        assert!(TransactionPriority::new(10, 10) > TransactionPriority::new(9, 10));
+        assert!(TransactionPriority::new(10, 10) > TransactionPriority::new(10, 11));
+        assert_eq!(
+            TransactionPriority::new(10, 10),
+            TransactionPriority::new(20, 20)
+        );
+    }
+
+    #[tokio::test]
+    async fn insert_reports_pending_and_parked() {
+        let mempool = Mempool::new();
+        let signing_key = SigningKey::from([1; 32]);
+
+        let (tx, bytes) = signed_tx(&signing_key, 1, 10);
+        let priority = TransactionPriority::new(1, bytes.len());
+        assert_eq!(
+            mempool.insert(&tx, bytes, priority, 0).await.unwrap(),
+            InsertionStatus::Parked
+        );
+
+        let (tx, bytes) = signed_tx(&signing_key, 0, 10);
+        assert_eq!(
+            mempool.insert(&tx, bytes, priority, 0).await.unwrap(),
+            InsertionStatus::Pending
+        );
+        assert_eq!(mempool.len().await, 2);
+
+        // nonces that were already used are rejected
+        assert!(
+            mempool
+                .insert(&tx, Bytes::new(), priority, 1)
+                .await
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn insert_replaces_only_with_higher_priority() {
+        let mempool = Mempool::new();
+        let signing_key = SigningKey::from([1; 32]);
+
+        let (tx, bytes) = signed_tx(&signing_key, 0, 10);
+        mempool
+            .insert(
+                &tx,
+                bytes.clone(),
+                TransactionPriority::new(2, bytes.len()),
+                0,
+            )
+            .await
+            .unwrap();
+
+        let (tx, bytes) = signed_tx(&signing_key, 0, 20);
+        assert!(
+            mempool
+                .insert(
+                    &tx,
+                    bytes.clone(),
+                    TransactionPriority::new(1, bytes.len()),
+                    0
+                )
+                .await
+                .is_err()
+        );
+        mempool
+            .insert(
+                &tx,
+                bytes.clone(),
+                TransactionPriority::new(100, bytes.len()),
+                0,
+            )
+            .await
+            .unwrap();
+        assert_eq!(mempool.len().await, 1);
+    }
+
+    #[tokio::test]
+    async fn select_for_block_orders_by_priority_and_nonce() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let state = StateDelta::new(storage.latest_snapshot());
+        let mempool = Mempool::new();
+
+        let alice = SigningKey::from([1; 32]);
+        let bob = SigningKey::from([2; 32]);
+
+        // alice's second transaction pays a high fee, but must come after her first one
+        let (alice_0, alice_0_bytes) = signed_tx(&alice, 0, 10);
+        let (alice_1, alice_1_bytes) = signed_tx(&alice, 1, 10);
+        let (bob_0, bob_0_bytes) = signed_tx(&bob, 0, 10);
+        // bob's transaction with nonce 2 is parked behind the missing nonce 1
+        let (bob_2, bob_2_bytes) = signed_tx(&bob, 2, 10);
+        for (tx, bytes, fee) in [
+            (&alice_0, &alice_0_bytes, 1),
+            (&alice_1, &alice_1_bytes, 100),
+            (&bob_0, &bob_0_bytes, 10),
+            (&bob_2, &bob_2_bytes, 1000),
+        ] {
+            mempool
+                .insert(
+                    tx,
+                    bytes.clone(),
+                    TransactionPriority::new(fee, bytes.len()),
+                    0,
+                )
+                .await
+                .unwrap();
+        }
+
+        let selected = mempool
+            .select_for_block(&state, usize::MAX, usize::MAX)
+            .await
+            .unwrap();
+        assert_eq!(selected, vec![bob_0_bytes, alice_0_bytes, alice_1_bytes]);
+    }
+
+    #[tokio::test]
+    async fn select_for_block_respects_limits() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let state = StateDelta::new(storage.latest_snapshot());
+        let mempool = Mempool::new();
+
+        let alice = SigningKey::from([1; 32]);
+        let bob = SigningKey::from([2; 32]);
+        let (alice_0, alice_0_bytes) = signed_tx(&alice, 0, 100);
+        let (alice_1, alice_1_bytes) = signed_tx(&alice, 1, 10);
+        let (bob_0, bob_0_bytes) = signed_tx(&bob, 0, 50);
+        for (tx, bytes, fee) in [
+            (&alice_0, &alice_0_bytes, 1000),
+            (&alice_1, &alice_1_bytes, 1000),
+            (&bob_0, &bob_0_bytes, 1),
+        ] {
+            mempool
+                .insert(
+                    tx,
+                    bytes.clone(),
+                    TransactionPriority::new(fee, bytes.len()),
+                    0,
+                )
+                .await
+                .unwrap();
+        }
+
+        // alice's first transaction does not fit, so her second one must not be selected either
+        let selected = mempool
+            .select_for_block(&state, 60, usize::MAX)
+            .await
+            .unwrap();
+        assert_eq!(selected, vec![bob_0_bytes]);
+    }
+
+    #[tokio::test]
+    async fn run_maintenance_evicts_used_nonces() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        let mempool = Mempool::new();
+
+        let signing_key = SigningKey::from([1; 32]);
+        for nonce in 0..3 {
+            let (tx, bytes) = signed_tx(&signing_key, nonce, 10);
+            let priority = TransactionPriority::new(1, bytes.len());
+            mempool.insert(&tx, bytes, priority, 0).await.unwrap();
+        }
+
+        state
+            .put_account_nonce(
+                Address::from_verification_key(signing_key.verification_key()),
+                2,
+            )
+            .unwrap();
+        assert_eq!(mempool.run_maintenance(&state, 1).await.unwrap(), 2);
+        assert_eq!(mempool.len().await, 1);
+    }
+
+    #[tokio::test]
+    async fn run_maintenance_expires_parked_transactions() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let state = StateDelta::new(storage.latest_snapshot());
+        let mempool = Mempool::new();
+
+        let signing_key = SigningKey::from([1; 32]);
+        for nonce in [0, 2] {
+            let (tx, bytes) = signed_tx(&signing_key, nonce, 10);
+            let priority = TransactionPriority::new(1, bytes.len());
+            mempool.insert(&tx, bytes, priority, 0).await.unwrap();
+        }
+
+        assert_eq!(
+            mempool
+                .run_maintenance(&state, PARKED_TRANSACTION_TTL_BLOCKS - 1)
+                .await
+                .unwrap(),
+            0
+        );
+        // only the transaction parked behind the missing nonce 1 expires
+        assert_eq!(
+            mempool
+                .run_maintenance(&state, PARKED_TRANSACTION_TTL_BLOCKS)
+                .await
+                .unwrap(),
+            1
+        );
+        assert_eq!(mempool.len().await, 1);
+    }
+
+    #[tokio::test]
+    async fn insert_rejects_nonce_gaps_above_max() {
+        let mempool = Mempool::new();
+        let signing_key = SigningKey::from([1; 32]);
+
+        let (tx, bytes) = signed_tx(&signing_key, MAX_NONCE_GAP, 10);
+        let priority = TransactionPriority::new(1, bytes.len());
+        assert!(
+            mempool
+                .insert(&tx, bytes.clone(), priority, 0)
+                .await
+                .is_err()
+        );
+        assert_eq!(
+            mempool.insert(&tx, bytes, priority, 1).await.unwrap(),
+            InsertionStatus::Parked
+        );
+    }
+
+    #[tokio::test]
+    async fn insert_is_rejected_once_mempool_is_full() {
+        let mempool = Mempool::new();
+        let signers_to_fill = MAX_TRANSACTIONS / MAX_TRANSACTIONS_PER_ACCOUNT;
+        for signer in 0..signers_to_fill {
+            let signing_key = SigningKey::from([u8::try_from(signer).unwrap(); 32]);
+            for nonce in 0..MAX_TRANSACTIONS_PER_ACCOUNT {
+                let (tx, bytes) = signed_tx(&signing_key, u32::try_from(nonce).unwrap(), 0);
+                let priority = TransactionPriority::new(1, bytes.len());
+                mempool.insert(&tx, bytes, priority, 0).await.unwrap();
+            }
+        }
+        assert_eq!(mempool.len().await, MAX_TRANSACTIONS);
+
+        let signing_key = SigningKey::from([u8::MAX; 32]);
+        let (tx, bytes) = signed_tx(&signing_key, 0, 0);
+        let priority = TransactionPriority::new(1, bytes.len());
+        assert!(mempool.insert(&tx, bytes, priority, 0).await.is_err());
+        // the rejected signer is not left behind with an empty entry
+        assert_eq!(mempool.inner.read().await.accounts.len(), signers_to_fill);
+    }
+
+    #[tokio::test]
+    async fn full_mempool_evicts_parked_then_lower_priority_transactions() {
+        let mempool = Mempool::new();
+        // the first signer's transactions are all parked behind its missing nonce 0.
+        let parked_key = SigningKey::from([0; 32]);
+        for nonce in 1..MAX_NONCE_GAP {
+            let (tx, bytes) = signed_tx(&parked_key, nonce, 0);
+            let priority = TransactionPriority::new(2, bytes.len());
+            mempool.insert(&tx, bytes, priority, 0).await.unwrap();
+        }
+        let mut signer = 1;
+        while mempool.len().await < MAX_TRANSACTIONS {
+            let signing_key = SigningKey::from([signer; 32]);
+            for nonce in 0..MAX_TRANSACTIONS_PER_ACCOUNT {
+                if mempool.len().await == MAX_TRANSACTIONS {
+                    break;
+                }
+                let (tx, bytes) = signed_tx(&signing_key, u32::try_from(nonce).unwrap(), 0);
+                let priority = TransactionPriority::new(2, bytes.len());
+                mempool.insert(&tx, bytes, priority, 0).await.unwrap();
+            }
+            signer += 1;
+        }
+        let parked_signer = Address::from_verification_key(parked_key.verification_key());
+        let num_parked = mempool.inner.read().await.accounts[&parked_signer]
+            .txs
+            .len();
+
+        // a parked transaction is evicted even for a transaction of lower priority.
+        let (tx, bytes) = signed_tx(&SigningKey::from([u8::MAX; 32]), 0, 0);
+        let low_priority = TransactionPriority::new(1, bytes.len());
+        mempool.insert(&tx, bytes, low_priority, 0).await.unwrap();
+        assert_eq!(
+            mempool.inner.read().await.accounts[&parked_signer]
+                .txs
+                .len(),
+            num_parked - 1
+        );
+
+        // without parked transactions, only a transaction of higher priority evicts another one.
+        mempool
+            .inner
+            .write()
+            .await
+            .accounts
+            .get_mut(&parked_signer)
+            .unwrap()
+            .nonce = 1;
+        let (tx, bytes) = signed_tx(&SigningKey::from([u8::MAX - 1; 32]), 0, 0);
+        assert!(
+            mempool
+                .insert(&tx, bytes.clone(), low_priority, 0)
+                .await
+                .is_err()
+        );
+        let higher_priority = TransactionPriority::new(2, bytes.len());
+        mempool
+            .insert(&tx, bytes, higher_priority, 0)
+            .await
+            .unwrap();
+        let low_priority_signer =
+            Address::from_verification_key(SigningKey::from([u8::MAX; 32]).verification_key());
+        assert!(
+            !mempool
+                .inner
+                .read()
+                .await
+                .accounts
+                .contains_key(&low_priority_signer)
+        );
+        assert_eq!(mempool.len().await, MAX_TRANSACTIONS);
+    }
+}
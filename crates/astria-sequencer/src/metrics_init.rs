@@ -0,0 +1,107 @@
+//! Crate-specific metrics functionality.
+//!
+//! Registers metrics & lists constants to be used as metric names throughout crate.
+
+use metrics::{
+    describe_counter,
+    describe_histogram,
+    Unit,
+};
+
+/// Registers all metrics used by this crate.
+pub fn register() {
+    describe_histogram!(
+        PREPARE_PROPOSAL_LATENCY,
+        Unit::Seconds,
+        "The time it takes to prepare a block proposal"
+    );
+
+    describe_histogram!(
+        PROCESS_PROPOSAL_LATENCY,
+        Unit::Seconds,
+        "The time it takes to process a block proposal"
+    );
+
+    describe_histogram!(
+        PROPOSAL_TRANSACTIONS,
+        Unit::Count,
+        "The number of transactions executed and included in a proposed block"
+    );
+
+    describe_counter!(
+        PROPOSAL_EXCLUDED_TRANSACTIONS_COUNT,
+        Unit::Count,
+        "The number of transactions excluded from proposed blocks, labelled by the reason for \
+         their exclusion"
+    );
+
+    describe_histogram!(
+        PROPOSAL_SEQUENCE_DATA_BYTES,
+        Unit::Bytes,
+        "The number of bytes of sequence action data included in a proposed block"
+    );
+
+    describe_counter!(
+        ACTION_EXECUTION_COUNT,
+        Unit::Count,
+        "The number of executed actions, labelled by action type"
+    );
+
+    describe_counter!(
+        ACTION_EXECUTION_FAILURE_COUNT,
+        Unit::Count,
+        "The number of actions which failed execution, labelled by action type"
+    );
+
+    describe_counter!(
+        CHECK_TX_REJECTION_COUNT,
+        Unit::Count,
+        "The number of transactions rejected during CheckTx, labelled by the returned ABCI error \
+         code"
+    );
+
+    describe_histogram!(
+        COMMIT_LATENCY,
+        Unit::Seconds,
+        "The time it takes to commit the state of a block to storage"
+    );
+}
+
+/// The label value of [`PROPOSAL_EXCLUDED_TRANSACTIONS_COUNT`] for transactions which could not
+/// be decoded.
+pub const EXCLUSION_REASON_DECODING_FAILED: &str = "decoding_failed";
+
+/// The label value of [`PROPOSAL_EXCLUDED_TRANSACTIONS_COUNT`] for transactions which would have
+/// exceeded the maximum sequence data bytes of a block.
+pub const EXCLUSION_REASON_SEQUENCE_DATA_LIMIT: &str = "sequence_data_limit";
+
+/// The label value of [`PROPOSAL_EXCLUDED_TRANSACTIONS_COUNT`] for transactions which failed
+/// execution.
+pub const EXCLUSION_REASON_EXECUTION_FAILED: &str = "execution_failed";
+
+pub const PREPARE_PROPOSAL_LATENCY: &str =
+    concat!(env!("CARGO_CRATE_NAME"), "_prepare_proposal_latency");
+
+pub const PROCESS_PROPOSAL_LATENCY: &str =
+    concat!(env!("CARGO_CRATE_NAME"), "_process_proposal_latency");
+
+pub const PROPOSAL_TRANSACTIONS: &str = concat!(env!("CARGO_CRATE_NAME"), "_proposal_transactions");
+
+pub const PROPOSAL_EXCLUDED_TRANSACTIONS_COUNT: &str = concat!(
+    env!("CARGO_CRATE_NAME"),
+    "_proposal_excluded_transactions_count"
+);
+
+pub const PROPOSAL_SEQUENCE_DATA_BYTES: &str =
+    concat!(env!("CARGO_CRATE_NAME"), "_proposal_sequence_data_bytes");
+
+pub const ACTION_EXECUTION_COUNT: &str =
+    concat!(env!("CARGO_CRATE_NAME"), "_action_execution_count");
+
+pub const ACTION_EXECUTION_FAILURE_COUNT: &str =
+    concat!(env!("CARGO_CRATE_NAME"), "_action_execution_failure_count");
+
+pub const CHECK_TX_REJECTION_COUNT: &str =
+    concat!(env!("CARGO_CRATE_NAME"), "_check_tx_rejection_count");
+
+pub const COMMIT_LATENCY: &str = concat!(env!("CARGO_CRATE_NAME"), "_commit_latency");
@@ -92,6 +92,7 @@ mod test {
             TransferAction,
         },
         Address,
+        TransactionParams,
         UnsignedTransaction,
     };
     use ed25519_consensus::SigningKey;
@@ -121,8 +122,11 @@ mod test {
 
         let signing_key = SigningKey::new(OsRng);
         let tx = UnsignedTransaction {
-            nonce: 0,
             actions: vec![sequence_action.clone().into(), transfer_action.into()],
+            params: TransactionParams {
+                nonce: 0,
+                chain_id: "test".to_string(),
+            },
         };
 
         let signed_tx = tx.into_signed(&signing_key);
@@ -134,8 +138,11 @@ mod test {
 
         let signing_key = SigningKey::new(OsRng);
         let tx = UnsignedTransaction {
-            nonce: 0,
             actions: vec![sequence_action.into()],
+            params: TransactionParams {
+                nonce: 0,
+                chain_id: "test".to_string(),
+            },
         };
 
         let signed_tx = tx.into_signed(&signing_key);
@@ -170,8 +177,11 @@ mod test {
 
         let signing_key = SigningKey::new(OsRng);
         let tx = UnsignedTransaction {
-            nonce: 0,
             actions: vec![sequence_action.into(), transfer_action.into()],
+            params: TransactionParams {
+                nonce: 0,
+                chain_id: "test".to_string(),
+            },
         };
 
         let signed_tx = tx.into_signed(&signing_key);
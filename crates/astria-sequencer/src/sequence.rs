@@ -7,6 +7,7 @@ use astria_core::sequencer::v1::{
     transaction::action::SequenceAction,
     Address,
 };
+use cnidarium::StateRead;
 use tracing::instrument;
 
 use crate::{
@@ -14,6 +15,7 @@ use crate::{
         StateReadExt,
         StateWriteExt,
     },
+    fees::state_ext::StateReadExt as _,
     state_ext::{
         StateReadExt as _,
         StateWriteExt as _,
@@ -21,9 +23,6 @@ use crate::{
     transaction::action_handler::ActionHandler,
 };
 
-/// Fee charged for a sequence `Action` per byte of `data` included.
-const SEQUENCE_ACTION_FEE_PER_BYTE: u128 = 1;
-
 #[async_trait::async_trait]
 impl ActionHandler for SequenceAction {
     async fn check_stateful<S: StateReadExt + 'static>(
@@ -40,7 +39,9 @@ impl ActionHandler for SequenceAction {
             .get_account_balance(from, self.fee_asset_id)
             .await
             .context("failed getting `from` account balance for fee payment")?;
-        let fee = calculate_fee(&self.data).context("calculated fee overflows u128")?;
+        let fee = calculate_fee_from_state(&self.data, state)
+            .await
+            .context("failed to calculate fee")?;
         ensure!(curr_balance >= fee, "insufficient funds");
         Ok(())
     }
@@ -62,7 +63,9 @@ impl ActionHandler for SequenceAction {
         )
     )]
     async fn execute<S: StateWriteExt>(&self, state: &mut S, from: Address) -> Result<()> {
-        let fee = calculate_fee(&self.data).context("failed to calculate fee")?;
+        let fee = calculate_fee_from_state(&self.data, state)
+            .await
+            .context("failed to calculate fee")?;
         state
             .get_and_increase_block_fees(self.fee_asset_id, fee)
             .await
@@ -76,10 +79,23 @@ impl ActionHandler for SequenceAction {
     }
 }
 
+/// Calculates the fee for a sequence `Action` based on the length of the `data`
+/// and the per-byte fee currently stored in `state`.
+pub(crate) async fn calculate_fee_from_state<S: StateRead + ?Sized>(
+    data: &[u8],
+    state: &S,
+) -> Result<u128> {
+    let fee_per_byte = state
+        .get_sequence_byte_fee()
+        .await
+        .context("failed to get sequence byte fee from state")?;
+    calculate_fee(data, fee_per_byte).context("calculated fee overflows u128")
+}
+
 /// Calculates the fee for a sequence `Action` based on the length of the `data`.
 /// Returns `None` if the fee overflows `u128`.
-pub(crate) fn calculate_fee(data: &[u8]) -> Option<u128> {
-    SEQUENCE_ACTION_FEE_PER_BYTE.checked_mul(
+pub(crate) fn calculate_fee(data: &[u8], fee_per_byte: u128) -> Option<u128> {
+    fee_per_byte.checked_mul(
         data.len()
             .try_into()
             .expect("a usize should always convert to a u128"),
@@ -92,8 +108,10 @@ mod test {
 
     #[test]
     fn calculate_fee_ok() {
-        assert_eq!(calculate_fee(&[]), Some(0));
-        assert_eq!(calculate_fee(&[0]), Some(1));
-        assert_eq!(calculate_fee(&[0u8; 10]), Some(10));
+        assert_eq!(calculate_fee(&[], 1), Some(0));
+        assert_eq!(calculate_fee(&[0], 1), Some(1));
+        assert_eq!(calculate_fee(&[0u8; 10], 1), Some(10));
+        assert_eq!(calculate_fee(&[0u8; 10], 3), Some(30));
+        assert_eq!(calculate_fee(&[0u8; 10], u128::MAX), None);
     }
 }
@@ -33,7 +33,9 @@ use crate::{
     config::Config,
     grpc::sequencer::SequencerServer,
     ibc::host_interface::AstriaHost,
+    mempool::Mempool,
     service,
+    snapshot,
     state_ext::StateReadExt as _,
 };
 
@@ -60,17 +62,15 @@ impl Sequencer {
 
         let mut signals = spawn_signal_handler();
 
-        let substore_prefixes = vec![penumbra_ibc::IBC_SUBSTORE_PREFIX];
-
-        let storage = cnidarium::Storage::load(
-            config.db_filepath.clone(),
-            substore_prefixes
-                .into_iter()
-                .map(std::string::ToString::to_string)
-                .collect(),
-        )
-        .await
-        .context("failed to load storage backing chain state")?;
+        let substore_prefixes: Vec<String> = vec![penumbra_ibc::IBC_SUBSTORE_PREFIX]
+            .into_iter()
+            .map(std::string::ToString::to_string)
+            .collect();
+
+        let storage =
+            cnidarium::Storage::load(config.db_filepath.clone(), substore_prefixes.clone())
+                .await
+                .context("failed to load storage backing chain state")?;
         let snapshot = storage.latest_snapshot();
 
         // the native asset should be configurable only at genesis.
@@ -86,19 +86,32 @@ impl Sequencer {
             crate::asset::initialize_native_asset(&native_asset);
         }
 
-        let app = App::new(snapshot);
+        let snapshot_manager = snapshot::Manager::new(
+            config.snapshot_dir.clone(),
+            config.snapshot_interval,
+            config.snapshot_keep_recent,
+            substore_prefixes,
+        );
+
+        let mempool = Mempool::new();
+        let app = App::new(snapshot, mempool.clone());
         let consensus_service = tower::ServiceBuilder::new()
             .layer(request_span::layer(|req: &ConsensusRequest| {
                 req.create_span()
             }))
             .service(tower_actor::Actor::new(10, |queue: _| {
                 let storage = storage.clone();
-                async move { service::Consensus::new(storage, app, queue).run().await }
+                let snapshot_manager = snapshot_manager.clone();
+                async move {
+                    service::Consensus::new(storage, app, snapshot_manager, queue)
+                        .run()
+                        .await
+                }
             }));
-        let mempool_service = service::Mempool::new(storage.clone());
+        let mempool_service = service::Mempool::new(storage.clone(), mempool);
         let info_service =
             service::Info::new(storage.clone()).context("failed initializing info service")?;
-        let snapshot_service = service::Snapshot;
+        let snapshot_service = service::Snapshot::new(snapshot_manager, storage.clone());
 
         let server = Server::builder()
             .consensus(consensus_service)
@@ -1,3 +1,5 @@
+use std::time::Instant;
+
 use anyhow::{
     bail,
     Context,
@@ -26,24 +28,29 @@ use tracing::{
 use crate::{
     app::App,
     genesis::GenesisState,
+    metrics_init,
+    snapshot,
 };
 
 pub(crate) struct Consensus {
     queue: mpsc::Receiver<Message<ConsensusRequest, ConsensusResponse, tower::BoxError>>,
     storage: Storage,
     app: App,
+    snapshot_manager: snapshot::Manager,
 }
 
 impl Consensus {
     pub(crate) fn new(
         storage: Storage,
         app: App,
+        snapshot_manager: snapshot::Manager,
         queue: mpsc::Receiver<Message<ConsensusRequest, ConsensusResponse, tower::BoxError>>,
     ) -> Self {
         Self {
             queue,
             storage,
             app,
+            snapshot_manager,
         }
     }
 
@@ -174,9 +181,13 @@ impl Consensus {
         &mut self,
         prepare_proposal: request::PrepareProposal,
     ) -> anyhow::Result<response::PrepareProposal> {
-        self.app
+        let start = Instant::now();
+        let res = self
+            .app
             .prepare_proposal(prepare_proposal, self.storage.clone())
-            .await
+            .await;
+        metrics::histogram!(metrics_init::PREPARE_PROPOSAL_LATENCY).record(start.elapsed());
+        res
     }
 
     #[instrument(skip_all, fields(
@@ -191,9 +202,13 @@ impl Consensus {
         &mut self,
         process_proposal: request::ProcessProposal,
     ) -> anyhow::Result<()> {
-        self.app
+        let start = Instant::now();
+        let res = self
+            .app
             .process_proposal(process_proposal, self.storage.clone())
-            .await
+            .await;
+        metrics::histogram!(metrics_init::PROCESS_PROPOSAL_LATENCY).record(start.elapsed());
+        res
     }
 
     #[instrument(skip_all, fields(
@@ -220,7 +235,10 @@ impl Consensus {
         tx_hash = %telemetry::display::base64(&Sha256::digest(&deliver_tx.tx))
     ))]
     async fn deliver_tx(&mut self, deliver_tx: request::DeliverTx) -> response::DeliverTx {
-        use crate::transaction::InvalidNonce;
+        use crate::transaction::{
+            InvalidChainId,
+            InvalidNonce,
+        };
 
         match self
             .app
@@ -235,6 +253,8 @@ impl Consensus {
             Err(e) => {
                 let code = if e.downcast_ref::<InvalidNonce>().is_some() {
                     AbciErrorCode::INVALID_NONCE
+                } else if e.downcast_ref::<InvalidChainId>().is_some() {
+                    AbciErrorCode::INVALID_CHAIN_ID
                 } else {
                     AbciErrorCode::INTERNAL_ERROR
                 };
@@ -262,7 +282,12 @@ impl Consensus {
 
     #[instrument(skip_all)]
     async fn commit(&mut self) -> anyhow::Result<response::Commit> {
+        let start = Instant::now();
         let app_hash = self.app.commit(self.storage.clone()).await;
+        metrics::histogram!(metrics_init::COMMIT_LATENCY).record(start.elapsed());
+        self.snapshot_manager
+            .maybe_spawn_create(&self.storage)
+            .await;
         Ok(response::Commit {
             data: app_hash.0.to_vec().into(),
             ..Default::default()
@@ -282,6 +307,7 @@ mod test {
         transaction::action::SequenceAction,
         Address,
         RollupId,
+        TransactionParams,
         UnsignedTransaction,
     };
     use bytes::Bytes;
@@ -300,12 +326,15 @@ mod test {
     use super::*;
     use crate::{
         asset::get_native_asset,
+        mempool::{
+            Mempool,
+            TransactionPriority,
+        },
         proposal::commitment::generate_rollup_datas_commitment,
     };
 
     fn make_unsigned_tx() -> UnsignedTransaction {
         UnsignedTransaction {
-            nonce: 0,
             actions: vec![
                 SequenceAction {
                     rollup_id: RollupId::from_unhashed_bytes(b"testchainid"),
@@ -314,6 +343,10 @@ mod test {
                 }
                 .into(),
             ],
+            params: TransactionParams {
+                nonce: 0,
+                chain_id: "test".to_string(),
+            },
         }
     }
 
@@ -346,12 +379,21 @@ mod test {
     #[tokio::test]
     async fn prepare_and_process_proposal() {
         let signing_key = SigningKey::new(OsRng);
-        let mut consensus_service =
+        let (mut consensus_service, mempool) =
             new_consensus_service(Some(signing_key.verification_key())).await;
         let tx = make_unsigned_tx();
         let signed_tx = tx.into_signed(&signing_key);
-        let tx_bytes = signed_tx.clone().into_raw().encode_to_vec();
-        let txs = vec![tx_bytes.into()];
+        let tx_bytes: Bytes = signed_tx.clone().into_raw().encode_to_vec().into();
+        let txs = vec![tx_bytes.clone()];
+        mempool
+            .insert(
+                &signed_tx,
+                tx_bytes.clone(),
+                TransactionPriority::new(0, tx_bytes.len()),
+                0,
+            )
+            .await
+            .unwrap();
 
         let res = generate_rollup_datas_commitment(&vec![signed_tx], HashMap::new());
 
@@ -367,7 +409,7 @@ mod test {
             }
         );
 
-        let mut consensus_service =
+        let (mut consensus_service, _) =
             new_consensus_service(Some(signing_key.verification_key())).await;
         let process_proposal = new_process_proposal_request(prepare_proposal_response.txs);
         consensus_service
@@ -376,10 +418,53 @@ mod test {
             .unwrap();
     }
 
+    #[tokio::test]
+    async fn prepare_proposal_excludes_parked_transactions() {
+        let signing_key = SigningKey::new(OsRng);
+        let (mut consensus_service, mempool) =
+            new_consensus_service(Some(signing_key.verification_key())).await;
+
+        let mut signed_txs = vec![];
+        let mut txs: Vec<Bytes> = vec![];
+        for nonce in [0, 2] {
+            let mut tx = make_unsigned_tx();
+            tx.params.nonce = nonce;
+            let signed_tx = tx.into_signed(&signing_key);
+            let tx_bytes: Bytes = signed_tx.clone().into_raw().encode_to_vec().into();
+            mempool
+                .insert(
+                    &signed_tx,
+                    tx_bytes.clone(),
+                    TransactionPriority::new(0, tx_bytes.len()),
+                    0,
+                )
+                .await
+                .unwrap();
+            signed_txs.push(signed_tx);
+            txs.push(tx_bytes);
+        }
+
+        // the transaction with nonce 2 is parked behind the missing nonce 1,
+        // so only the transaction with nonce 0 is proposed.
+        let res = generate_rollup_datas_commitment(&signed_txs[..1], HashMap::new());
+        let prepare_proposal = new_prepare_proposal_request(vec![]);
+        let prepare_proposal_response = consensus_service
+            .handle_prepare_proposal(prepare_proposal)
+            .await
+            .unwrap();
+        assert_eq!(
+            prepare_proposal_response,
+            response::PrepareProposal {
+                txs: res.into_transactions(txs[..1].to_vec())
+            }
+        );
+        assert_eq!(mempool.len().await, 2);
+    }
+
     #[tokio::test]
     async fn process_proposal_ok() {
         let signing_key = SigningKey::new(OsRng);
-        let mut consensus_service =
+        let (mut consensus_service, _) =
             new_consensus_service(Some(signing_key.verification_key())).await;
         let tx = make_unsigned_tx();
         let signed_tx = tx.into_signed(&signing_key);
@@ -395,7 +480,7 @@ mod test {
 
     #[tokio::test]
     async fn process_proposal_fail_missing_action_commitment() {
-        let mut consensus_service = new_consensus_service(None).await;
+        let (mut consensus_service, _) = new_consensus_service(None).await;
         let process_proposal = new_process_proposal_request(vec![]);
         assert!(
             consensus_service
@@ -410,7 +495,7 @@ mod test {
 
     #[tokio::test]
     async fn process_proposal_fail_wrong_commitment_length() {
-        let mut consensus_service = new_consensus_service(None).await;
+        let (mut consensus_service, _) = new_consensus_service(None).await;
         let process_proposal = new_process_proposal_request(vec![[0u8; 16].to_vec().into()]);
         assert!(
             consensus_service
@@ -425,7 +510,7 @@ mod test {
 
     #[tokio::test]
     async fn process_proposal_fail_wrong_commitment_value() {
-        let mut consensus_service = new_consensus_service(None).await;
+        let (mut consensus_service, _) = new_consensus_service(None).await;
         let process_proposal = new_process_proposal_request(vec![
             [99u8; 32].to_vec().into(),
             [99u8; 32].to_vec().into(),
@@ -443,7 +528,7 @@ mod test {
 
     #[tokio::test]
     async fn prepare_proposal_empty_block() {
-        let mut consensus_service = new_consensus_service(None).await;
+        let (mut consensus_service, _) = new_consensus_service(None).await;
         let txs = vec![];
         let res = generate_rollup_datas_commitment(&txs.clone(), HashMap::new());
         let prepare_proposal = new_prepare_proposal_request(vec![]);
@@ -462,7 +547,7 @@ mod test {
 
     #[tokio::test]
     async fn process_proposal_ok_empty_block() {
-        let mut consensus_service = new_consensus_service(None).await;
+        let (mut consensus_service, _) = new_consensus_service(None).await;
         let txs = vec![];
         let res = generate_rollup_datas_commitment(&txs, HashMap::new());
         let process_proposal = new_process_proposal_request(res.into_transactions(vec![]));
@@ -515,11 +600,12 @@ mod test {
                 native_asset_base_denomination: DEFAULT_NATIVE_ASSET_DENOM.to_string(),
                 ibc_params: penumbra_ibc::params::IBCParameters::default(),
                 allowed_fee_assets: vec![DEFAULT_NATIVE_ASSET_DENOM.to_owned().into()],
+                fees: crate::app::test_utils::default_fees(),
             }
         }
     }
 
-    async fn new_consensus_service(funded_key: Option<VerificationKey>) -> Consensus {
+    async fn new_consensus_service(funded_key: Option<VerificationKey>) -> (Consensus, Mempool) {
         let accounts = if funded_key.is_some() {
             vec![crate::genesis::Account {
                 address: Address::from_verification_key(funded_key.unwrap()),
@@ -535,20 +621,25 @@ mod test {
 
         let storage = cnidarium::TempStorage::new().await.unwrap();
         let snapshot = storage.latest_snapshot();
-        let mut app = App::new(snapshot);
+        let mempool = Mempool::new();
+        let mut app = App::new(snapshot, mempool.clone());
         app.init_chain(genesis_state, vec![], "test".to_string())
             .await
             .unwrap();
         app.commit(storage.clone()).await;
 
+        let snapshot_manager = snapshot::Manager::new(std::path::PathBuf::new(), 0, 0, vec![]);
         let (_tx, rx) = mpsc::channel(1);
-        Consensus::new(storage.clone(), app, rx)
+        (
+            Consensus::new(storage.clone(), app, snapshot_manager, rx),
+            mempool,
+        )
     }
 
     #[tokio::test]
     async fn block_lifecycle() {
         let signing_key = SigningKey::new(OsRng);
-        let mut consensus_service =
+        let (mut consensus_service, _) =
             new_consensus_service(Some(signing_key.verification_key())).await;
 
         let tx = make_unsigned_tx();
@@ -55,6 +55,15 @@ impl Info {
                 crate::accounts::query::nonce_request,
             )
             .context("invalid path: `accounts/nonce/:account`")?;
+        query_router
+            .insert("fees/schedule", crate::fees::query::fee_schedule_request)
+            .context("invalid path: `fees/schedule`")?;
+        query_router
+            .insert(
+                "asset/allowed_fee_asset_ids",
+                crate::asset::query::allowed_fee_asset_ids_request,
+            )
+            .context("invalid path: `asset/allowed_fee_asset_ids`")?;
         Ok(Self {
             storage,
             query_router,
@@ -147,12 +156,16 @@ impl Service<InfoRequest> for Info {
 mod test {
     use astria_core::sequencer::v1::{
         asset::{
+            AllowedFeeAssetIdsResponse,
             Denom,
             DEFAULT_NATIVE_ASSET_DENOM,
         },
         Address,
+        FeeSchedule,
+        FeeScheduleResponse,
     };
     use cnidarium::StateDelta;
+    use prost::Message as _;
     use tendermint::v0_37::abci::{
         request,
         InfoRequest,
@@ -166,6 +179,7 @@ mod test {
             get_native_asset,
             NATIVE_ASSET,
         },
+        fees::state_ext::StateWriteExt as _,
         state_ext::StateWriteExt as _,
     };
 
@@ -213,4 +227,109 @@ mod test {
         };
         assert!(query_response.code.is_ok());
     }
+
+    #[tokio::test]
+    async fn handle_fee_schedule_query() {
+        let storage = cnidarium::TempStorage::new()
+            .await
+            .expect("failed to create temp storage backing chain state");
+        let height = 99;
+        let version = storage.latest_version().wrapping_add(1);
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        state.put_storage_version_by_height(height, version);
+
+        let fees = FeeSchedule {
+            transfer_fee: 12,
+            sequence_byte_fee: 1,
+            init_bridge_account_fee: 48,
+            ics20_withdrawal_fee: 24,
+        };
+        state.put_fee_schedule(fees).unwrap();
+        state.put_block_height(height);
+
+        storage.commit(state).await.unwrap();
+
+        let info_request = InfoRequest::Query(request::Query {
+            path: "fees/schedule".to_string(),
+            data: vec![].into(),
+            height: u32::try_from(height).unwrap().into(),
+            prove: false,
+        });
+
+        let response = {
+            let storage = (*storage).clone();
+            let info_service = Info::new(storage).unwrap();
+            info_service
+                .handle_info_request(info_request)
+                .await
+                .unwrap()
+        };
+        let query_response = match response {
+            InfoResponse::Query(query) => query,
+            other => panic!("expected InfoResponse::Query, got {other:?}"),
+        };
+        assert!(query_response.code.is_ok());
+
+        let expected = FeeScheduleResponse {
+            height,
+            fees,
+        };
+        let actual = astria_core::generated::sequencer::v1::FeeScheduleResponse::decode(
+            query_response.value,
+        )
+        .unwrap()
+        .into_native();
+        assert_eq!(expected, actual);
+    }
+
+    #[tokio::test]
+    async fn handle_allowed_fee_asset_ids_query() {
+        let storage = cnidarium::TempStorage::new()
+            .await
+            .expect("failed to create temp storage backing chain state");
+        let height = 99;
+        let version = storage.latest_version().wrapping_add(1);
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        state.put_storage_version_by_height(height, version);
+
+        let fee_asset_id = Denom::from_base_denom(DEFAULT_NATIVE_ASSET_DENOM).id();
+        state.put_allowed_fee_asset(fee_asset_id);
+        state.put_block_height(height);
+
+        storage.commit(state).await.unwrap();
+
+        let info_request = InfoRequest::Query(request::Query {
+            path: "asset/allowed_fee_asset_ids".to_string(),
+            data: vec![].into(),
+            height: u32::try_from(height).unwrap().into(),
+            prove: false,
+        });
+
+        let response = {
+            let storage = (*storage).clone();
+            let info_service = Info::new(storage).unwrap();
+            info_service
+                .handle_info_request(info_request)
+                .await
+                .unwrap()
+        };
+        let query_response = match response {
+            InfoResponse::Query(query) => query,
+            other => panic!("expected InfoResponse::Query, got {other:?}"),
+        };
+        assert!(query_response.code.is_ok());
+
+        let expected = AllowedFeeAssetIdsResponse {
+            height,
+            fee_asset_ids: vec![fee_asset_id],
+        };
+        let actual = AllowedFeeAssetIdsResponse::try_from_raw(
+            &astria_core::generated::sequencer::v1::AllowedFeeAssetIdsResponse::decode(
+                query_response.value,
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(expected, actual);
+    }
 }
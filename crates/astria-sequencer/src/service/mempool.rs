@@ -6,19 +6,26 @@ use std::{
     },
 };
 
+use anyhow::Context as _;
 use astria_core::{
     generated::sequencer::v1 as raw,
     sequencer::v1::{
         AbciErrorCode,
+        Address,
         SignedTransaction,
     },
 };
+use bytes::Bytes;
 use cnidarium::Storage;
 use futures::{
     Future,
     FutureExt,
 };
 use prost::Message as _;
+use sha2::{
+    Digest as _,
+    Sha256,
+};
 use tendermint::v0_37::abci::{
     request,
     response,
@@ -27,10 +34,19 @@ use tendermint::v0_37::abci::{
 };
 use tower::Service;
 use tower_abci::BoxError;
-use tracing::Instrument;
+use tracing::{
+    debug,
+    Instrument,
+};
 
 use crate::{
     accounts::state_ext::StateReadExt,
+    fees::state_ext::StateReadExt as _,
+    mempool::{
+        Mempool as AppMempool,
+        TransactionPriority,
+    },
+    metrics_init,
     transaction,
 };
 
@@ -40,15 +56,20 @@ const MAX_TX_SIZE: usize = 256_000; // 256 KB
 //
 /// It performs a stateless check of the given transaction,
 /// returning a [`tendermint::v0_37::abci::response::CheckTx`].
+///
+/// Transactions passing all checks are inserted into the app-side mempool,
+/// from which block proposals are built.
 #[derive(Clone)]
 pub(crate) struct Mempool {
     storage: Storage,
+    inner: AppMempool,
 }
 
 impl Mempool {
-    pub(crate) fn new(storage: Storage) -> Self {
+    pub(crate) fn new(storage: Storage, mempool: AppMempool) -> Self {
         Self {
             storage,
+            inner: mempool,
         }
     }
 }
@@ -66,10 +87,30 @@ impl Service<MempoolRequest> for Mempool {
         use penumbra_tower_trace::v037::RequestExt as _;
         let span = req.create_span();
         let storage = self.storage.clone();
+        let mempool = self.inner.clone();
         async move {
             let rsp = match req {
                 MempoolRequest::CheckTx(req) => {
-                    MempoolResponse::CheckTx(handle_check_tx(req, storage.latest_snapshot()).await)
+                    let tx_hash: [u8; 32] = Sha256::digest(&req.tx).into();
+                    let rsp = handle_check_tx(req, storage.latest_snapshot(), &mempool).await;
+                    if rsp.code.is_err() {
+                        metrics::counter!(
+                            metrics_init::CHECK_TX_REJECTION_COUNT,
+                            "code" => rsp.code.value().to_string(),
+                        )
+                        .increment(1);
+
+                        // transactions are re-checked by CometBFT after every block; those that
+                        // are no longer valid must not be proposed from the app-side mempool
+                        // either.
+                        if mempool.remove(&tx_hash).await {
+                            debug!(
+                                transaction_hash = %telemetry::display::base64(&tx_hash),
+                                "removed transaction failing check from app-side mempool",
+                            );
+                        }
+                    }
+                    MempoolResponse::CheckTx(rsp)
                 }
             };
             Ok(rsp)
@@ -84,10 +125,11 @@ impl Service<MempoolRequest> for Mempool {
 /// Performs stateless checks (decoding and signature check),
 /// as well as stateful checks (nonce and balance checks).
 ///
-/// If the tx passes all checks, status code 0 is returned.
+/// If the tx passes all checks, it is inserted into `mempool` and status code 0 is returned.
 async fn handle_check_tx<S: StateReadExt + 'static>(
     req: request::CheckTx,
     state: S,
+    mempool: &AppMempool,
 ) -> response::CheckTx {
     let request::CheckTx {
         tx, ..
@@ -104,7 +146,7 @@ async fn handle_check_tx<S: StateReadExt + 'static>(
         };
     }
 
-    let raw_signed_tx = match raw::SignedTransaction::decode(tx) {
+    let raw_signed_tx = match raw::SignedTransaction::decode(tx.clone()) {
         Ok(tx) => tx,
         Err(e) => {
             return response::CheckTx {
@@ -138,6 +180,15 @@ async fn handle_check_tx<S: StateReadExt + 'static>(
         };
     };
 
+    if let Err(e) = transaction::check_chain_id_mempool(&signed_tx, &state).await {
+        return response::CheckTx {
+            code: AbciErrorCode::INVALID_CHAIN_ID.into(),
+            info: "failed verifying chain id".into(),
+            log: e.to_string(),
+            ..response::CheckTx::default()
+        };
+    }
+
     if let Err(e) = transaction::check_nonce_mempool(&signed_tx, &state).await {
         return response::CheckTx {
             code: AbciErrorCode::INVALID_NONCE.into(),
@@ -156,5 +207,37 @@ async fn handle_check_tx<S: StateReadExt + 'static>(
         };
     };
 
+    if let Err(e) = insert_into_mempool(&signed_tx, tx, &state, mempool).await {
+        return response::CheckTx {
+            code: AbciErrorCode::MEMPOOL_INSERTION_FAILED.into(),
+            info: "failed inserting transaction into the mempool".into(),
+            log: e.to_string(),
+            ..response::CheckTx::default()
+        };
+    }
+
     response::CheckTx::default()
 }
+
+async fn insert_into_mempool<S: StateReadExt + 'static>(
+    signed_tx: &SignedTransaction,
+    tx: Bytes,
+    state: &S,
+    mempool: &AppMempool,
+) -> anyhow::Result<()> {
+    let fees = state
+        .get_fee_schedule()
+        .await
+        .context("failed to get fee schedule from state")?;
+    let total_fee = transaction::get_total_fee(signed_tx, &fees)?;
+    let priority = TransactionPriority::new(total_fee, tx.len());
+    let current_account_nonce = state
+        .get_account_nonce(Address::from_verification_key(signed_tx.verification_key()))
+        .await
+        .context("failed to get account nonce")?;
+    let status = mempool
+        .insert(signed_tx, tx, priority, current_account_nonce)
+        .await?;
+    debug!(?status, "inserted transaction into app-side mempool");
+    Ok(())
+}
@@ -1,19 +1,23 @@
 use std::{
     pin::Pin,
+    sync::Arc,
     task::{
         Context,
         Poll,
     },
 };
 
+use cnidarium::Storage;
 use futures::{
     Future,
     FutureExt,
 };
 use penumbra_tower_trace::v037::RequestExt as _;
 use tendermint::v0_37::abci::{
+    request,
     response::{
         ApplySnapshotChunk,
+        ApplySnapshotChunkResult,
         ListSnapshots,
         LoadSnapshotChunk,
         OfferSnapshot,
@@ -21,12 +25,202 @@ use tendermint::v0_37::abci::{
     SnapshotRequest,
     SnapshotResponse,
 };
+use tokio::sync::Mutex;
 use tower::Service;
 use tower_abci::BoxError;
-use tracing::Instrument as _;
+use tracing::{
+    info,
+    instrument,
+    warn,
+    Instrument as _,
+};
+
+use crate::snapshot::{
+    Manager,
+    Restore,
+};
+
+/// Serves state-sync snapshots to peers and restores this node's state from snapshots
+/// offered by peers.
+#[derive(Clone)]
+pub(crate) struct Snapshot {
+    manager: Manager,
+    storage: Storage,
+    restore: Arc<Mutex<Option<Restore>>>,
+}
+
+impl Snapshot {
+    pub(crate) fn new(manager: Manager, storage: Storage) -> Self {
+        Self {
+            manager,
+            storage,
+            restore: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    async fn handle_request(self, req: SnapshotRequest) -> SnapshotResponse {
+        match req {
+            SnapshotRequest::ListSnapshots => {
+                SnapshotResponse::ListSnapshots(self.list_snapshots().await)
+            }
+            SnapshotRequest::OfferSnapshot(offer_snapshot) => {
+                SnapshotResponse::OfferSnapshot(self.offer_snapshot(offer_snapshot).await)
+            }
+            SnapshotRequest::LoadSnapshotChunk(load_snapshot_chunk) => {
+                SnapshotResponse::LoadSnapshotChunk(
+                    self.load_snapshot_chunk(load_snapshot_chunk).await,
+                )
+            }
+            SnapshotRequest::ApplySnapshotChunk(apply_snapshot_chunk) => {
+                SnapshotResponse::ApplySnapshotChunk(
+                    self.apply_snapshot_chunk(apply_snapshot_chunk).await,
+                )
+            }
+        }
+    }
+
+    #[instrument(skip_all)]
+    async fn list_snapshots(&self) -> ListSnapshots {
+        match self.manager.list().await {
+            Ok(snapshots) => ListSnapshots {
+                snapshots,
+            },
+            Err(e) => {
+                warn!(
+                    error = AsRef::<dyn std::error::Error>::as_ref(&e),
+                    "failed listing snapshots; returning none",
+                );
+                ListSnapshots::default()
+            }
+        }
+    }
+
+    #[instrument(skip_all, fields(
+        height = %offer_snapshot.snapshot.height,
+        format = offer_snapshot.snapshot.format,
+        chunks = offer_snapshot.snapshot.chunks,
+    ))]
+    async fn offer_snapshot(&self, offer_snapshot: request::OfferSnapshot) -> OfferSnapshot {
+        let request::OfferSnapshot {
+            snapshot,
+            app_hash,
+        } = offer_snapshot;
+        if snapshot.format != crate::snapshot::SNAPSHOT_FORMAT {
+            info!("rejecting snapshot offered in an unsupported format");
+            return OfferSnapshot::RejectFormat;
+        }
+        match Restore::offer(&self.storage, &snapshot, &app_hash) {
+            Ok(restore) => {
+                info!("accepted snapshot offer; starting restore");
+                *self.restore.lock().await = Some(restore);
+                OfferSnapshot::Accept
+            }
+            Err(e) => {
+                warn!(
+                    error = AsRef::<dyn std::error::Error>::as_ref(&e),
+                    "rejecting snapshot offer",
+                );
+                OfferSnapshot::Reject
+            }
+        }
+    }
+
+    #[instrument(skip_all, fields(
+        height = %load_snapshot_chunk.height,
+        format = load_snapshot_chunk.format,
+        chunk = load_snapshot_chunk.chunk,
+    ))]
+    async fn load_snapshot_chunk(
+        &self,
+        load_snapshot_chunk: request::LoadSnapshotChunk,
+    ) -> LoadSnapshotChunk {
+        match self
+            .manager
+            .load_chunk(
+                load_snapshot_chunk.height.value(),
+                load_snapshot_chunk.format,
+                load_snapshot_chunk.chunk,
+            )
+            .await
+        {
+            Ok(chunk) => LoadSnapshotChunk {
+                chunk,
+            },
+            Err(e) => {
+                warn!(
+                    error = AsRef::<dyn std::error::Error>::as_ref(&e),
+                    "failed loading snapshot chunk; returning empty chunk",
+                );
+                LoadSnapshotChunk::default()
+            }
+        }
+    }
+
+    #[instrument(skip_all, fields(
+        index = apply_snapshot_chunk.index,
+        sender = apply_snapshot_chunk.sender,
+    ))]
+    async fn apply_snapshot_chunk(
+        &self,
+        apply_snapshot_chunk: request::ApplySnapshotChunk,
+    ) -> ApplySnapshotChunk {
+        let request::ApplySnapshotChunk {
+            index,
+            chunk,
+            sender,
+        } = apply_snapshot_chunk;
+
+        let mut guard = self.restore.lock().await;
+        let Some(restore) = guard.as_mut() else {
+            warn!("received snapshot chunk without an accepted snapshot offer; aborting");
+            return apply_snapshot_chunk_result(ApplySnapshotChunkResult::Abort);
+        };
+
+        if let Err(e) = restore.apply_chunk(index, &chunk) {
+            warn!(
+                error = AsRef::<dyn std::error::Error>::as_ref(&e),
+                "failed applying snapshot chunk; requesting it from another peer",
+            );
+            return ApplySnapshotChunk {
+                result: ApplySnapshotChunkResult::Retry,
+                refetch_chunks: vec![index],
+                reject_senders: vec![sender],
+            };
+        }
+
+        if !restore.is_complete() {
+            return apply_snapshot_chunk_result(ApplySnapshotChunkResult::Accept);
+        }
 
-#[derive(Clone, Default)]
-pub(crate) struct Snapshot;
+        let restore = guard
+            .take()
+            .expect("the restore was checked to be present above");
+        match restore.commit(&self.storage).await {
+            Ok(native_asset) => {
+                crate::asset::initialize_native_asset(&native_asset);
+                apply_snapshot_chunk_result(ApplySnapshotChunkResult::Accept)
+            }
+            Err(e) => {
+                // the restored state was possibly written to storage already, so no other
+                // snapshot can be applied on top of it.
+                warn!(
+                    error = AsRef::<dyn std::error::Error>::as_ref(&e),
+                    "failed committing restored snapshot; the database must be wiped before state \
+                     sync can be attempted again",
+                );
+                apply_snapshot_chunk_result(ApplySnapshotChunkResult::Abort)
+            }
+        }
+    }
+}
+
+fn apply_snapshot_chunk_result(result: ApplySnapshotChunkResult) -> ApplySnapshotChunk {
+    ApplySnapshotChunk {
+        result,
+        refetch_chunks: vec![],
+        reject_senders: vec![],
+    }
+}
 
 impl Service<SnapshotRequest> for Snapshot {
     type Error = BoxError;
@@ -40,26 +234,207 @@ impl Service<SnapshotRequest> for Snapshot {
 
     fn call(&mut self, req: SnapshotRequest) -> Self::Future {
         let span = req.create_span();
-        async move {
-            Ok(match req {
-                SnapshotRequest::ListSnapshots => {
-                    SnapshotResponse::ListSnapshots(ListSnapshots::default())
-                }
-
-                SnapshotRequest::OfferSnapshot(_) => {
-                    SnapshotResponse::OfferSnapshot(OfferSnapshot::default())
-                }
-
-                SnapshotRequest::LoadSnapshotChunk(_) => {
-                    SnapshotResponse::LoadSnapshotChunk(LoadSnapshotChunk::default())
-                }
-
-                SnapshotRequest::ApplySnapshotChunk(_) => {
-                    SnapshotResponse::ApplySnapshotChunk(ApplySnapshotChunk::default())
-                }
-            })
+        let this = self.clone();
+        async move { Ok(this.handle_request(req).await) }
+            .instrument(span)
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use cnidarium::TempStorage;
+
+    use super::*;
+    use crate::{
+        accounts::state_ext::StateReadExt as _,
+        app::{
+            test_utils::*,
+            App,
+        },
+        asset::get_native_asset,
+        genesis::{
+            Account,
+            GenesisState,
+        },
+        mempool::Mempool,
+        state_ext::StateReadExt as _,
+    };
+
+    fn genesis_state() -> GenesisState {
+        GenesisState {
+            accounts: vec![
+                Account {
+                    address: address_from_hex_string(ALICE_ADDRESS),
+                    balance: 10u128.pow(19),
+                },
+                Account {
+                    address: address_from_hex_string(BOB_ADDRESS),
+                    balance: 10u128.pow(19),
+                },
+            ],
+            ..Default::default()
         }
-        .instrument(span)
-        .boxed()
+    }
+
+    #[tokio::test]
+    async fn node_is_restored_from_snapshot_of_other_app() {
+        // create the state of the node serving the snapshot
+        let serving_storage = TempStorage::new().await.unwrap();
+        let mut app = App::new(serving_storage.latest_snapshot(), Mempool::new());
+        app.init_chain(genesis_state(), vec![], "test".to_string())
+            .await
+            .unwrap();
+        let app_hash = app.commit(serving_storage.clone()).await;
+
+        let snapshot_dir = tempfile::tempdir().unwrap();
+        let serving_manager = Manager::new(snapshot_dir.path().to_path_buf(), 1, 1, vec![]);
+        serving_manager
+            .create(0, serving_storage.latest_snapshot())
+            .await
+            .unwrap();
+        let mut serving = Snapshot::new(serving_manager, serving_storage.clone());
+
+        // restore the state on an empty node
+        let restoring_storage = TempStorage::new().await.unwrap();
+        let restoring_dir = tempfile::tempdir().unwrap();
+        let mut restoring = Snapshot::new(
+            Manager::new(restoring_dir.path().to_path_buf(), 0, 1, vec![]),
+            restoring_storage.clone(),
+        );
+
+        let SnapshotResponse::ListSnapshots(ListSnapshots {
+            snapshots,
+        }) = serving.call(SnapshotRequest::ListSnapshots).await.unwrap()
+        else {
+            panic!("expected a list snapshots response");
+        };
+        assert_eq!(1, snapshots.len());
+        let snapshot = snapshots.into_iter().next().unwrap();
+
+        let offer_response = restoring
+            .call(SnapshotRequest::OfferSnapshot(request::OfferSnapshot {
+                snapshot: snapshot.clone(),
+                app_hash: app_hash.0.to_vec().try_into().unwrap(),
+            }))
+            .await
+            .unwrap();
+        assert_eq!(
+            SnapshotResponse::OfferSnapshot(OfferSnapshot::Accept),
+            offer_response
+        );
+
+        for index in 0..snapshot.chunks {
+            let SnapshotResponse::LoadSnapshotChunk(LoadSnapshotChunk {
+                chunk,
+            }) = serving
+                .call(SnapshotRequest::LoadSnapshotChunk(
+                    request::LoadSnapshotChunk {
+                        height: snapshot.height,
+                        format: snapshot.format,
+                        chunk: index,
+                    },
+                ))
+                .await
+                .unwrap()
+            else {
+                panic!("expected a load snapshot chunk response");
+            };
+            let SnapshotResponse::ApplySnapshotChunk(apply_response) = restoring
+                .call(SnapshotRequest::ApplySnapshotChunk(
+                    request::ApplySnapshotChunk {
+                        index,
+                        chunk,
+                        sender: "serving".to_string(),
+                    },
+                ))
+                .await
+                .unwrap()
+            else {
+                panic!("expected an apply snapshot chunk response");
+            };
+            assert_eq!(ApplySnapshotChunkResult::Accept, apply_response.result);
+        }
+
+        let restored = restoring_storage.latest_snapshot();
+        assert_eq!(app_hash, restored.root_hash().await.unwrap());
+        assert_eq!(0, restored.get_block_height().await.unwrap());
+        assert_eq!("test", restored.get_chain_id().await.unwrap());
+        assert_eq!(
+            10u128.pow(19),
+            restored
+                .get_account_balance(
+                    address_from_hex_string(ALICE_ADDRESS),
+                    get_native_asset().id()
+                )
+                .await
+                .unwrap(),
+        );
+        assert_eq!(0, restored.get_storage_version_by_height(0).await.unwrap());
+
+        // the restored node can continue the chain from the restored state
+        let mut restored_app = App::new(restored, Mempool::new());
+        assert_eq!(
+            app_hash,
+            restored_app.commit(restoring_storage.clone()).await
+        );
+    }
+
+    #[tokio::test]
+    async fn offer_with_mismatched_app_hash_is_rejected() {
+        let serving_storage = TempStorage::new().await.unwrap();
+        let mut app = App::new(serving_storage.latest_snapshot(), Mempool::new());
+        app.init_chain(genesis_state(), vec![], "test".to_string())
+            .await
+            .unwrap();
+        app.commit(serving_storage.clone()).await;
+
+        let snapshot_dir = tempfile::tempdir().unwrap();
+        let snapshot = Manager::new(snapshot_dir.path().to_path_buf(), 1, 1, vec![])
+            .create(0, serving_storage.latest_snapshot())
+            .await
+            .unwrap();
+
+        let restoring_storage = TempStorage::new().await.unwrap();
+        let restoring_dir = tempfile::tempdir().unwrap();
+        let mut restoring = Snapshot::new(
+            Manager::new(restoring_dir.path().to_path_buf(), 0, 1, vec![]),
+            restoring_storage.clone(),
+        );
+        let offer_response = restoring
+            .call(SnapshotRequest::OfferSnapshot(request::OfferSnapshot {
+                snapshot,
+                app_hash: [42u8; 32].to_vec().try_into().unwrap(),
+            }))
+            .await
+            .unwrap();
+        assert_eq!(
+            SnapshotResponse::OfferSnapshot(OfferSnapshot::Reject),
+            offer_response
+        );
+    }
+
+    #[tokio::test]
+    async fn chunk_without_offer_aborts() {
+        let storage = TempStorage::new().await.unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let mut service = Snapshot::new(
+            Manager::new(dir.path().to_path_buf(), 0, 1, vec![]),
+            storage.clone(),
+        );
+        let SnapshotResponse::ApplySnapshotChunk(apply_response) = service
+            .call(SnapshotRequest::ApplySnapshotChunk(
+                request::ApplySnapshotChunk {
+                    index: 0,
+                    chunk: vec![].into(),
+                    sender: "peer".to_string(),
+                },
+            ))
+            .await
+            .unwrap()
+        else {
+            panic!("expected an apply snapshot chunk response");
+        };
+        assert_eq!(ApplySnapshotChunkResult::Abort, apply_response.result);
     }
 }
@@ -0,0 +1,619 @@
+//! State-sync snapshots of the sequencer's chain state.
+//!
+//! A snapshot is a copy of all key-value pairs in the verifiable and nonverifiable
+//! stores of a [`cnidarium::Snapshot`] taken at a given block height. The entries are
+//! split into borsh-encoded chunks which are written to disk, and served to peers
+//! through the ABCI snapshot connection. Peers restoring from a snapshot use the
+//! [`Restore`] type to verify and apply the chunks to their empty storage.
+//!
+//! On disk, every snapshot lives in a directory named after its height:
+//!
+//! ```text
+//! <snapshot_dir>/<height>/metadata
+//! <snapshot_dir>/<height>/chunk-0
+//! <snapshot_dir>/<height>/chunk-1
+//! ...
+//! ```
+
+use std::{
+    path::{
+        Path,
+        PathBuf,
+    },
+    sync::Arc,
+};
+
+use anyhow::{
+    ensure,
+    Context as _,
+};
+use borsh::{
+    BorshDeserialize,
+    BorshSerialize,
+};
+use bytes::Bytes;
+use cnidarium::{
+    StateRead as _,
+    Storage,
+};
+use futures::{
+    Stream,
+    StreamExt as _,
+};
+use sha2::{
+    Digest as _,
+    Sha256,
+};
+use tendermint::abci::types::Snapshot as AbciSnapshot;
+use tracing::{
+    debug,
+    info,
+    instrument,
+    warn,
+};
+
+use crate::state_ext::{
+    StateReadExt as _,
+    STORAGE_VERSION_BY_HEIGHT_PREFIX,
+};
+
+mod restore;
+
+pub(crate) use restore::Restore;
+
+/// The version of the snapshot format produced and accepted by this sequencer.
+///
+/// Must be incremented whenever the encoding of [`Entry`] or [`Metadata`] changes.
+pub(crate) const SNAPSHOT_FORMAT: u32 = 1;
+
+/// The number of bytes of state entries after which a chunk is closed and a new one is begun.
+///
+/// This is a soft limit: a chunk is only closed after the entry that pushed it over the
+/// limit was added.
+const CHUNK_SIZE_BYTES: usize = 8 * 1024 * 1024;
+
+const METADATA_FILE_NAME: &str = "metadata";
+const CHUNK_FILE_PREFIX: &str = "chunk-";
+const IN_PROGRESS_SUFFIX: &str = ".in-progress";
+
+/// A single key-value pair read from storage.
+#[derive(Debug, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+enum Entry {
+    Verifiable { key: String, value: Vec<u8> },
+    Nonverifiable { key: Vec<u8>, value: Vec<u8> },
+}
+
+impl Entry {
+    fn encoded_len(&self) -> usize {
+        match self {
+            Self::Verifiable {
+                key,
+                value,
+            } => key.len() + value.len(),
+            Self::Nonverifiable {
+                key,
+                value,
+            } => key.len() + value.len(),
+        }
+    }
+}
+
+/// The metadata of a snapshot, sent to peers alongside its height, format, and hash.
+///
+/// The snapshot hash is the sha256 hash of the borsh-encoded metadata.
+#[derive(Debug, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub(crate) struct Metadata {
+    /// The app hash of the state contained in the snapshot.
+    app_hash: [u8; 32],
+    /// The sha256 hashes of the chunks, in order.
+    chunk_hashes: Vec<[u8; 32]>,
+}
+
+impl Metadata {
+    fn to_bytes(&self) -> Vec<u8> {
+        borsh::to_vec(self).expect("borsh-encoding snapshot metadata to a vec must not fail")
+    }
+
+    fn hash(&self) -> [u8; 32] {
+        Sha256::digest(self.to_bytes()).into()
+    }
+}
+
+/// Creates, lists, loads, and prunes the snapshots stored on disk.
+#[derive(Clone, Debug)]
+pub(crate) struct Manager {
+    inner: Arc<Inner>,
+}
+
+#[derive(Debug)]
+struct Inner {
+    dir: PathBuf,
+    interval: u64,
+    keep_recent: usize,
+    substore_prefixes: Vec<String>,
+}
+
+impl Manager {
+    /// Creates a new snapshot manager.
+    ///
+    /// Snapshots are written to `dir` every `interval` blocks, keeping the
+    /// `keep_recent` most recent ones. Setting `interval` to 0 disables the
+    /// creation of snapshots. `substore_prefixes` must match the prefixes
+    /// that the storage was loaded with.
+    pub(crate) fn new(
+        dir: PathBuf,
+        interval: u64,
+        keep_recent: usize,
+        substore_prefixes: Vec<String>,
+    ) -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                dir,
+                interval,
+                keep_recent,
+                substore_prefixes,
+            }),
+        }
+    }
+
+    fn is_snapshot_height(&self, height: u64) -> bool {
+        self.inner.interval > 0 && height > 0 && height % self.inner.interval == 0
+    }
+
+    /// Spawns a task creating a snapshot of the latest state in `storage` if its
+    /// block height is a multiple of the configured snapshot interval.
+    ///
+    /// Creating the snapshot happens in the background, so that block production is not
+    /// held up. Errors are logged and not returned.
+    pub(crate) async fn maybe_spawn_create(&self, storage: &Storage) {
+        let snapshot = storage.latest_snapshot();
+        let height = match snapshot.get_block_height().await {
+            Ok(height) => height,
+            Err(e) => {
+                warn!(
+                    error = AsRef::<dyn std::error::Error>::as_ref(&e),
+                    "failed reading block height from latest snapshot; not creating a state-sync \
+                     snapshot",
+                );
+                return;
+            }
+        };
+        if !self.is_snapshot_height(height) {
+            return;
+        }
+        let manager = self.clone();
+        tokio::spawn(async move {
+            match manager.create(height, snapshot).await {
+                Ok(created) => info!(
+                    height,
+                    chunks = created.chunks,
+                    hash = %telemetry::display::base64(&created.hash),
+                    "created state-sync snapshot",
+                ),
+                Err(e) => warn!(
+                    height,
+                    error = AsRef::<dyn std::error::Error>::as_ref(&e),
+                    "failed creating state-sync snapshot",
+                ),
+            }
+            if let Err(e) = manager.prune().await {
+                warn!(
+                    error = AsRef::<dyn std::error::Error>::as_ref(&e),
+                    "failed pruning old state-sync snapshots",
+                );
+            }
+        });
+    }
+
+    /// Writes all entries of `snapshot` to disk as a new state-sync snapshot at `height`.
+    ///
+    /// The snapshot is first written to a temporary directory which is moved into place once
+    /// all chunks and the metadata were written, so that a crash never leaves a partially
+    /// written snapshot behind that could be served to peers.
+    #[instrument(skip_all, fields(height))]
+    pub(crate) async fn create(
+        &self,
+        height: u64,
+        snapshot: cnidarium::Snapshot,
+    ) -> anyhow::Result<AbciSnapshot> {
+        let final_dir = self.snapshot_dir(height);
+        ensure!(
+            !tokio::fs::try_exists(&final_dir)
+                .await
+                .context("failed checking if snapshot directory exists")?,
+            "a snapshot at height `{height}` already exists",
+        );
+        let tmp_dir = self.inner.dir.join(format!("{height}{IN_PROGRESS_SUFFIX}"));
+        if tokio::fs::try_exists(&tmp_dir)
+            .await
+            .context("failed checking if temporary snapshot directory exists")?
+        {
+            tokio::fs::remove_dir_all(&tmp_dir)
+                .await
+                .context("failed removing stale temporary snapshot directory")?;
+        }
+        tokio::fs::create_dir_all(&tmp_dir)
+            .await
+            .context("failed creating temporary snapshot directory")?;
+
+        let app_hash = snapshot
+            .root_hash()
+            .await
+            .context("failed reading app hash of snapshot")?;
+
+        let mut writer = ChunkWriter::new(&tmp_dir);
+        self.write_verifiable_entries(&snapshot, &mut writer)
+            .await
+            .context("failed writing verifiable entries to snapshot")?;
+        self.write_nonverifiable_entries(&snapshot, &mut writer)
+            .await
+            .context("failed writing nonverifiable entries to snapshot")?;
+        let chunk_hashes = writer.finish().await?;
+
+        let metadata = Metadata {
+            app_hash: app_hash.0,
+            chunk_hashes,
+        };
+        tokio::fs::write(tmp_dir.join(METADATA_FILE_NAME), metadata.to_bytes())
+            .await
+            .context("failed writing snapshot metadata to disk")?;
+        tokio::fs::rename(&tmp_dir, &final_dir)
+            .await
+            .context("failed moving snapshot directory into place")?;
+
+        to_abci_snapshot(height, &metadata)
+    }
+
+    async fn write_verifiable_entries(
+        &self,
+        snapshot: &cnidarium::Snapshot,
+        writer: &mut ChunkWriter,
+    ) -> anyhow::Result<()> {
+        // The main store contains the root hashes of all substores under their prefixes.
+        // These are written by cnidarium on commit and must not be restored explicitly.
+        let substore_prefixes = &self.inner.substore_prefixes;
+        write_all(
+            snapshot.prefix_raw(""),
+            writer,
+            |key: &String| !substore_prefixes.contains(key),
+            |(key, value)| Entry::Verifiable {
+                key,
+                value,
+            },
+        )
+        .await
+        .context("failed reading entries from main store")?;
+        for prefix in substore_prefixes {
+            write_all(
+                snapshot.prefix_raw(&format!("{prefix}/")),
+                writer,
+                |_: &String| true,
+                |(key, value)| Entry::Verifiable {
+                    key,
+                    value,
+                },
+            )
+            .await
+            .with_context(|| format!("failed reading entries from substore `{prefix}`"))?;
+        }
+        Ok(())
+    }
+
+    async fn write_nonverifiable_entries(
+        &self,
+        snapshot: &cnidarium::Snapshot,
+        writer: &mut ChunkWriter,
+    ) -> anyhow::Result<()> {
+        let is_portable =
+            |key: &Vec<u8>| !key.starts_with(STORAGE_VERSION_BY_HEIGHT_PREFIX.as_bytes());
+        write_all(
+            snapshot.nonverifiable_prefix_raw(&[]),
+            writer,
+            is_portable,
+            |(key, value)| Entry::Nonverifiable {
+                key,
+                value,
+            },
+        )
+        .await
+        .context("failed reading nonverifiable entries from main store")?;
+        for prefix in &self.inner.substore_prefixes {
+            write_all(
+                snapshot.nonverifiable_prefix_raw(format!("{prefix}/").as_bytes()),
+                writer,
+                is_portable,
+                |(key, value)| Entry::Nonverifiable {
+                    key,
+                    value,
+                },
+            )
+            .await
+            .with_context(|| {
+                format!("failed reading nonverifiable entries from substore `{prefix}`")
+            })?;
+        }
+        Ok(())
+    }
+
+    /// Returns all complete snapshots on disk, ordered by ascending height.
+    pub(crate) async fn list(&self) -> anyhow::Result<Vec<AbciSnapshot>> {
+        let mut snapshots = Vec::new();
+        for height in self.list_heights().await? {
+            let metadata = self
+                .read_metadata(height)
+                .await
+                .with_context(|| format!("failed reading metadata of snapshot at `{height}`"))?;
+            snapshots.push(to_abci_snapshot(height, &metadata)?);
+        }
+        Ok(snapshots)
+    }
+
+    /// Reads the chunk at `index` of the snapshot at `height`.
+    pub(crate) async fn load_chunk(
+        &self,
+        height: u64,
+        format: u32,
+        index: u32,
+    ) -> anyhow::Result<Bytes> {
+        ensure!(
+            format == SNAPSHOT_FORMAT,
+            "unsupported snapshot format `{format}`; only format `{SNAPSHOT_FORMAT}` is supported",
+        );
+        let path = self
+            .snapshot_dir(height)
+            .join(format!("{CHUNK_FILE_PREFIX}{index}"));
+        let chunk = tokio::fs::read(&path)
+            .await
+            .with_context(|| format!("failed reading chunk from `{}`", path.display()))?;
+        Ok(chunk.into())
+    }
+
+    /// Deletes all but the `keep_recent` most recent snapshots.
+    async fn prune(&self) -> anyhow::Result<()> {
+        let heights = self.list_heights().await?;
+        let excess = heights.len().saturating_sub(self.inner.keep_recent);
+        for height in heights.into_iter().take(excess) {
+            tokio::fs::remove_dir_all(self.snapshot_dir(height))
+                .await
+                .with_context(|| format!("failed deleting snapshot at height `{height}`"))?;
+            debug!(height, "deleted state-sync snapshot");
+        }
+        Ok(())
+    }
+
+    /// Returns the heights of all complete snapshots on disk in ascending order.
+    async fn list_heights(&self) -> anyhow::Result<Vec<u64>> {
+        if !tokio::fs::try_exists(&self.inner.dir)
+            .await
+            .context("failed checking if snapshot directory exists")?
+        {
+            return Ok(vec![]);
+        }
+        let mut heights = Vec::new();
+        let mut entries = tokio::fs::read_dir(&self.inner.dir)
+            .await
+            .context("failed reading snapshot directory")?;
+        while let Some(entry) = entries
+            .next_entry()
+            .await
+            .context("failed reading entry of snapshot directory")?
+        {
+            // directories of snapshots that are still in progress carry a suffix and
+            // are skipped here.
+            if let Some(height) = entry
+                .file_name()
+                .to_str()
+                .and_then(|name| name.parse::<u64>().ok())
+            {
+                heights.push(height);
+            }
+        }
+        heights.sort_unstable();
+        Ok(heights)
+    }
+
+    async fn read_metadata(&self, height: u64) -> anyhow::Result<Metadata> {
+        let bytes = tokio::fs::read(self.snapshot_dir(height).join(METADATA_FILE_NAME))
+            .await
+            .context("failed reading metadata file")?;
+        Metadata::try_from_slice(&bytes).context("failed decoding metadata")
+    }
+
+    fn snapshot_dir(&self, height: u64) -> PathBuf {
+        self.inner.dir.join(height.to_string())
+    }
+}
+
+fn to_abci_snapshot(height: u64, metadata: &Metadata) -> anyhow::Result<AbciSnapshot> {
+    Ok(AbciSnapshot {
+        height: height
+            .try_into()
+            .context("snapshot height does not fit into a tendermint block height")?,
+        format: SNAPSHOT_FORMAT,
+        chunks: metadata
+            .chunk_hashes
+            .len()
+            .try_into()
+            .context("number of chunks in snapshot does not fit into u32")?,
+        hash: metadata.hash().to_vec().into(),
+        metadata: metadata.to_bytes().into(),
+    })
+}
+
+/// Reads all key-value pairs from `stream`, writing those for which `filter`
+/// returns true to `writer`.
+async fn write_all<K, S, F, E>(
+    stream: S,
+    writer: &mut ChunkWriter,
+    filter: F,
+    to_entry: E,
+) -> anyhow::Result<()>
+where
+    S: Stream<Item = anyhow::Result<(K, Vec<u8>)>>,
+    F: Fn(&K) -> bool,
+    E: Fn((K, Vec<u8>)) -> Entry,
+{
+    let mut stream = std::pin::pin!(stream);
+    while let Some(item) = stream.next().await {
+        let (key, value) = item.context("failed reading entry from storage")?;
+        if filter(&key) {
+            writer.push(to_entry((key, value))).await?;
+        }
+    }
+    Ok(())
+}
+
+/// Collects entries into chunks, writing each chunk to disk once it exceeds
+/// [`CHUNK_SIZE_BYTES`].
+struct ChunkWriter<'a> {
+    dir: &'a Path,
+    current: Vec<Entry>,
+    current_size: usize,
+    chunk_hashes: Vec<[u8; 32]>,
+}
+
+impl<'a> ChunkWriter<'a> {
+    fn new(dir: &'a Path) -> Self {
+        Self {
+            dir,
+            current: Vec::new(),
+            current_size: 0,
+            chunk_hashes: Vec::new(),
+        }
+    }
+
+    async fn push(&mut self, entry: Entry) -> anyhow::Result<()> {
+        self.current_size += entry.encoded_len();
+        self.current.push(entry);
+        if self.current_size >= CHUNK_SIZE_BYTES {
+            self.flush().await?;
+        }
+        Ok(())
+    }
+
+    async fn flush(&mut self) -> anyhow::Result<()> {
+        let entries = std::mem::take(&mut self.current);
+        self.current_size = 0;
+        let bytes = borsh::to_vec(&entries).context("failed encoding chunk")?;
+        let path = self
+            .dir
+            .join(format!("{CHUNK_FILE_PREFIX}{}", self.chunk_hashes.len()));
+        tokio::fs::write(&path, &bytes)
+            .await
+            .with_context(|| format!("failed writing chunk to `{}`", path.display()))?;
+        self.chunk_hashes.push(Sha256::digest(&bytes).into());
+        Ok(())
+    }
+
+    /// Flushes the last chunk and returns the hashes of all written chunks.
+    ///
+    /// A snapshot always contains at least one chunk, even if it is empty.
+    async fn finish(mut self) -> anyhow::Result<Vec<[u8; 32]>> {
+        if !self.current.is_empty() || self.chunk_hashes.is_empty() {
+            self.flush().await?;
+        }
+        Ok(self.chunk_hashes)
+    }
+}
+
+fn decode_chunk(bytes: &[u8]) -> anyhow::Result<Vec<Entry>> {
+    Vec::<Entry>::try_from_slice(bytes).context("failed decoding chunk")
+}
+
+#[cfg(test)]
+mod test {
+    use cnidarium::{
+        StateDelta,
+        StateWrite as _,
+        TempStorage,
+    };
+
+    use super::*;
+    use crate::state_ext::StateWriteExt as _;
+
+    async fn storage_at_height(height: u64) -> TempStorage {
+        let storage = TempStorage::new().await.unwrap();
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        state.put_block_height(height);
+        state.put_storage_version_by_height(height, 0);
+        state.put_raw("some/key".to_string(), b"value".to_vec());
+        state.nonverifiable_put_raw(b"some/nonverifiable/key".to_vec(), b"value".to_vec());
+        storage.commit(state).await.unwrap();
+        storage
+    }
+
+    #[test]
+    fn snapshot_heights_follow_interval() {
+        let manager = Manager::new(PathBuf::new(), 10, 2, vec![]);
+        assert!(!manager.is_snapshot_height(0));
+        assert!(!manager.is_snapshot_height(5));
+        assert!(manager.is_snapshot_height(10));
+        assert!(manager.is_snapshot_height(20));
+
+        let disabled = Manager::new(PathBuf::new(), 0, 2, vec![]);
+        assert!(!disabled.is_snapshot_height(10));
+    }
+
+    #[tokio::test]
+    async fn created_snapshot_is_listed_and_loadable() {
+        let dir = tempfile::tempdir().unwrap();
+        let storage = storage_at_height(10).await;
+        let manager = Manager::new(dir.path().to_path_buf(), 10, 2, vec![]);
+
+        let created = manager.create(10, storage.latest_snapshot()).await.unwrap();
+        assert_eq!(created.chunks, 1);
+        assert_eq!(vec![created.clone()], manager.list().await.unwrap());
+
+        let chunk = manager.load_chunk(10, SNAPSHOT_FORMAT, 0).await.unwrap();
+        let entries = decode_chunk(&chunk).unwrap();
+        assert!(entries.contains(&Entry::Verifiable {
+            key: "some/key".to_string(),
+            value: b"value".to_vec(),
+        }));
+        assert!(entries.contains(&Entry::Nonverifiable {
+            key: b"some/nonverifiable/key".to_vec(),
+            value: b"value".to_vec(),
+        }));
+        assert!(
+            !entries.iter().any(|entry| matches!(
+                entry,
+                Entry::Nonverifiable { key, .. }
+                    if key.starts_with(STORAGE_VERSION_BY_HEIGHT_PREFIX.as_bytes())
+            )),
+            "storage versions are local to a node and must not be part of a snapshot",
+        );
+
+        manager
+            .load_chunk(10, SNAPSHOT_FORMAT + 1, 0)
+            .await
+            .unwrap_err();
+        manager
+            .load_chunk(10, SNAPSHOT_FORMAT, 1)
+            .await
+            .unwrap_err();
+    }
+
+    #[tokio::test]
+    async fn prune_keeps_most_recent_snapshots() {
+        let dir = tempfile::tempdir().unwrap();
+        let storage = storage_at_height(10).await;
+        let manager = Manager::new(dir.path().to_path_buf(), 10, 2, vec![]);
+
+        for height in [10, 20, 30] {
+            manager
+                .create(height, storage.latest_snapshot())
+                .await
+                .unwrap();
+        }
+        manager.prune().await.unwrap();
+
+        let heights: Vec<_> = manager
+            .list()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|snapshot| snapshot.height.value())
+            .collect();
+        assert_eq!(vec![20, 30], heights);
+    }
+}
@@ -0,0 +1,187 @@
+use anyhow::{
+    ensure,
+    Context as _,
+};
+use borsh::BorshDeserialize as _;
+use cnidarium::{
+    StateDelta,
+    StateWrite as _,
+    Storage,
+};
+use sha2::{
+    Digest as _,
+    Sha256,
+};
+use tendermint::{
+    abci::types::Snapshot as AbciSnapshot,
+    AppHash,
+};
+use tracing::{
+    debug,
+    info,
+    instrument,
+};
+
+use super::{
+    decode_chunk,
+    Entry,
+    Metadata,
+    SNAPSHOT_FORMAT,
+};
+use crate::state_ext::{
+    StateReadExt as _,
+    StateWriteExt as _,
+};
+
+/// An in-progress restore of a snapshot that was offered by a peer.
+///
+/// Chunks are verified against the hashes contained in the snapshot's metadata and written
+/// into a single [`StateDelta`]. Once the last chunk was applied, the delta is committed to
+/// storage and the resulting app hash is checked against the trusted app hash provided by
+/// CometBFT.
+pub(crate) struct Restore {
+    height: u64,
+    app_hash: [u8; 32],
+    chunk_hashes: Vec<[u8; 32]>,
+    applied: Vec<bool>,
+    state: StateDelta<cnidarium::Snapshot>,
+}
+
+impl Restore {
+    /// Starts restoring `snapshot` into `storage`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the snapshot was produced in an unsupported format, if its
+    /// metadata does not match its hash, or if the app hash it commits to is not the trusted
+    /// `app_hash` passed in by CometBFT.
+    #[instrument(skip_all, fields(height = %snapshot.height))]
+    pub(crate) fn offer(
+        storage: &Storage,
+        snapshot: &AbciSnapshot,
+        app_hash: &AppHash,
+    ) -> anyhow::Result<Self> {
+        ensure!(
+            snapshot.format == SNAPSHOT_FORMAT,
+            "unsupported snapshot format `{}`; only format `{SNAPSHOT_FORMAT}` is supported",
+            snapshot.format,
+        );
+        ensure!(
+            storage.latest_version() == u64::MAX,
+            "storage already contains state; refusing to restore a snapshot into it",
+        );
+        let metadata = Metadata::try_from_slice(&snapshot.metadata)
+            .context("failed decoding snapshot metadata")?;
+        ensure!(
+            metadata.hash().as_slice() == snapshot.hash.as_ref(),
+            "snapshot hash does not match the hash of its metadata",
+        );
+        ensure!(
+            metadata.app_hash.as_slice() == app_hash.as_bytes(),
+            "app hash committed to by the snapshot does not match the trusted app hash",
+        );
+        ensure!(
+            metadata.chunk_hashes.len() == snapshot.chunks as usize,
+            "number of chunk hashes in metadata does not match number of chunks in snapshot",
+        );
+
+        Ok(Self {
+            height: snapshot.height.value(),
+            app_hash: metadata.app_hash,
+            applied: vec![false; metadata.chunk_hashes.len()],
+            chunk_hashes: metadata.chunk_hashes,
+            state: StateDelta::new(storage.latest_snapshot()),
+        })
+    }
+
+    /// Returns if all chunks of the snapshot were applied.
+    pub(crate) fn is_complete(&self) -> bool {
+        self.applied.iter().all(|applied| *applied)
+    }
+
+    /// Verifies the chunk at `index` and writes its entries into the pending state.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the chunk is not part of the snapshot, does not match the hash
+    /// recorded in the snapshot metadata, or cannot be decoded. The chunk should then be
+    /// fetched again, possibly from another peer.
+    #[instrument(skip_all, fields(height = self.height, index))]
+    pub(crate) fn apply_chunk(&mut self, index: u32, chunk: &[u8]) -> anyhow::Result<()> {
+        let expected_hash = self
+            .chunk_hashes
+            .get(index as usize)
+            .context("chunk index is out of range")?;
+        ensure!(
+            Sha256::digest(chunk).as_slice() == expected_hash.as_slice(),
+            "chunk hash does not match the hash recorded in the snapshot metadata",
+        );
+        let entries = decode_chunk(chunk)?;
+
+        let entry_count = entries.len();
+        for entry in entries {
+            match entry {
+                Entry::Verifiable {
+                    key,
+                    value,
+                } => self.state.put_raw(key, value),
+                Entry::Nonverifiable {
+                    key,
+                    value,
+                } => self.state.nonverifiable_put_raw(key, value),
+            }
+        }
+        self.applied[index as usize] = true;
+        debug!(entry_count, "applied snapshot chunk");
+        Ok(())
+    }
+
+    /// Commits the restored state to `storage`.
+    ///
+    /// Returns the name of the native asset read from the restored state.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if not all chunks were applied, if committing failed, or if the app
+    /// hash of the committed state does not match the trusted app hash of the snapshot.
+    #[instrument(skip_all, fields(height = self.height))]
+    pub(crate) async fn commit(self, storage: &Storage) -> anyhow::Result<String> {
+        ensure!(
+            self.is_complete(),
+            "not all chunks of the snapshot were applied"
+        );
+        let Self {
+            height,
+            app_hash,
+            mut state,
+            ..
+        } = self;
+
+        // the restored storage starts at version 0, so the mapping of heights to
+        // storage versions has to start from there as well.
+        let version = storage.latest_version().wrapping_add(1);
+        state.put_storage_version_by_height(height, version);
+
+        let native_asset = state
+            .get_native_asset_denom()
+            .await
+            .context("failed reading native asset from restored state")?;
+
+        let restored_app_hash = storage
+            .commit(state)
+            .await
+            .context("failed committing restored state to storage")?;
+        ensure!(
+            restored_app_hash.0 == app_hash,
+            "app hash of restored state `{}` does not match the snapshot's app hash `{}`",
+            telemetry::display::base64(&restored_app_hash.0),
+            telemetry::display::base64(&app_hash),
+        );
+        info!(
+            version,
+            app_hash = %telemetry::display::base64(&app_hash),
+            "restored state from snapshot",
+        );
+        Ok(native_asset)
+    }
+}
@@ -16,9 +16,14 @@ use tracing::instrument;
 const NATIVE_ASSET_KEY: &[u8] = b"nativeasset";
 const BLOCK_FEES_PREFIX: &str = "block_fees/";
 const FEE_ASSET_PREFIX: &str = "fee_asset/";
+/// The prefix of the nonverifiable keys mapping block heights to storage versions.
+///
+/// These mappings are specific to a node's local storage and must not be carried over
+/// to other nodes (for example as part of a state-sync snapshot).
+pub(crate) const STORAGE_VERSION_BY_HEIGHT_PREFIX: &str = "storage_version/";
 
 fn storage_version_by_height_key(height: u64) -> Vec<u8> {
-    format!("storage_version/{height}").into()
+    format!("{STORAGE_VERSION_BY_HEIGHT_PREFIX}{height}").into()
 }
 
 fn block_fees_key(asset: asset::Id) -> Vec<u8> {
@@ -4,7 +4,10 @@ use std::fmt;
 
 pub(crate) use action_handler::ActionHandler;
 #[cfg(not(feature = "mint"))]
-use anyhow::bail;
+use anyhow::{
+    anyhow,
+    bail,
+};
 use anyhow::{
     ensure,
     Context as _,
@@ -12,25 +15,24 @@ use anyhow::{
 use astria_core::sequencer::v1::{
     transaction::action::Action,
     Address,
+    FeeSchedule,
     SignedTransaction,
     UnsignedTransaction,
 };
 use tracing::instrument;
 
 use crate::{
-    accounts::{
-        action::TRANSFER_FEE,
-        state_ext::{
-            StateReadExt,
-            StateWriteExt,
-        },
+    accounts::state_ext::{
+        StateReadExt,
+        StateWriteExt,
     },
-    bridge::init_bridge_account_action::INIT_BRIDGE_ACCOUNT_FEE,
+    fees::state_ext::StateReadExt as _,
     ibc::{
         host_interface::AstriaHost,
-        ics20_withdrawal::ICS20_WITHDRAWAL_FEE,
         state_ext::StateReadExt as _,
     },
+    metrics_init,
+    state_ext::StateReadExt as _,
 };
 
 pub(crate) async fn check_nonce_mempool<S: StateReadExt + 'static>(
@@ -42,9 +44,21 @@ pub(crate) async fn check_nonce_mempool<S: StateReadExt + 'static>(
         .get_account_nonce(signer_address)
         .await
         .context("failed to get account nonce")?;
+    ensure!(tx.nonce() >= curr_nonce, "nonce already used by account");
+    Ok(())
+}
+
+pub(crate) async fn check_chain_id_mempool<S: StateReadExt + 'static>(
+    tx: &SignedTransaction,
+    state: &S,
+) -> anyhow::Result<()> {
+    let chain_id = state
+        .get_chain_id()
+        .await
+        .context("failed to get chain id")?;
     ensure!(
-        tx.unsigned_transaction().nonce >= curr_nonce,
-        "nonce already used by account"
+        tx.chain_id() == chain_id,
+        InvalidChainId(tx.chain_id().to_string())
     );
     Ok(())
 }
@@ -56,6 +70,10 @@ pub(crate) async fn check_balance_mempool<S: StateReadExt + 'static>(
     use std::collections::HashMap;
 
     let signer_address = Address::from_verification_key(tx.verification_key());
+    let fees = state
+        .get_fee_schedule()
+        .await
+        .context("failed to get fee schedule from state")?;
     let mut fees_by_asset = HashMap::new();
     for action in tx.actions() {
         match action {
@@ -66,11 +84,11 @@ pub(crate) async fn check_balance_mempool<S: StateReadExt + 'static>(
                     .or_insert(act.amount);
                 fees_by_asset
                     .entry(act.fee_asset_id)
-                    .and_modify(|amt| *amt += TRANSFER_FEE)
-                    .or_insert(TRANSFER_FEE);
+                    .and_modify(|amt| *amt += fees.transfer_fee)
+                    .or_insert(fees.transfer_fee);
             }
             Action::Sequence(act) => {
-                let fee = crate::sequence::calculate_fee(&act.data)
+                let fee = crate::sequence::calculate_fee(&act.data, fees.sequence_byte_fee)
                     .context("fee for sequence action overflowed; data too large")?;
                 fees_by_asset
                     .entry(act.fee_asset_id)
@@ -84,14 +102,14 @@ pub(crate) async fn check_balance_mempool<S: StateReadExt + 'static>(
                     .or_insert(act.amount());
                 fees_by_asset
                     .entry(*act.fee_asset_id())
-                    .and_modify(|amt| *amt += ICS20_WITHDRAWAL_FEE)
-                    .or_insert(ICS20_WITHDRAWAL_FEE);
+                    .and_modify(|amt| *amt += fees.ics20_withdrawal_fee)
+                    .or_insert(fees.ics20_withdrawal_fee);
             }
             Action::InitBridgeAccount(act) => {
                 fees_by_asset
                     .entry(act.fee_asset_id)
-                    .and_modify(|amt| *amt += INIT_BRIDGE_ACCOUNT_FEE)
-                    .or_insert(INIT_BRIDGE_ACCOUNT_FEE);
+                    .and_modify(|amt| *amt += fees.init_bridge_account_fee)
+                    .or_insert(fees.init_bridge_account_fee);
             }
             Action::BridgeLock(act) => {
                 fees_by_asset
@@ -100,14 +118,28 @@ pub(crate) async fn check_balance_mempool<S: StateReadExt + 'static>(
                     .or_insert(act.amount);
                 fees_by_asset
                     .entry(act.fee_asset_id)
-                    .and_modify(|amt| *amt += TRANSFER_FEE)
-                    .or_insert(TRANSFER_FEE);
+                    .and_modify(|amt| *amt += fees.transfer_fee)
+                    .or_insert(fees.transfer_fee);
+            }
+            // the unlocked amount and the fee are paid by the bridge account,
+            // which is only the signer if it is its own withdrawer.
+            Action::BridgeUnlock(act) if act.bridge_address == signer_address => {
+                fees_by_asset
+                    .entry(act.asset_id)
+                    .and_modify(|amt| *amt += act.amount)
+                    .or_insert(act.amount);
+                fees_by_asset
+                    .entry(act.fee_asset_id)
+                    .and_modify(|amt| *amt += fees.transfer_fee)
+                    .or_insert(fees.transfer_fee);
             }
             Action::ValidatorUpdate(_)
+            | Action::BridgeUnlock(_)
             | Action::SudoAddressChange(_)
             | Action::Ibc(_)
             | Action::IbcRelayerChange(_)
             | Action::FeeAssetChange(_)
+            | Action::FeeChange(_)
             | Action::Mint(_) => {
                 continue;
             }
@@ -128,6 +160,57 @@ pub(crate) async fn check_balance_mempool<S: StateReadExt + 'static>(
     Ok(())
 }
 
+/// Returns the sum of the fees paid by all actions of `tx`, irrespective of the assets they are
+/// paid in.
+///
+/// This is used to prioritize transactions in the mempool.
+pub(crate) fn get_total_fee(tx: &SignedTransaction, fees: &FeeSchedule) -> anyhow::Result<u128> {
+    let mut total_fee: u128 = 0;
+    for action in tx.actions() {
+        let fee = match action {
+            Action::Transfer(_) | Action::BridgeLock(_) | Action::BridgeUnlock(_) => {
+                fees.transfer_fee
+            }
+            Action::Sequence(act) => {
+                crate::sequence::calculate_fee(&act.data, fees.sequence_byte_fee)
+                    .context("fee for sequence action overflowed; data too large")?
+            }
+            Action::Ics20Withdrawal(_) => fees.ics20_withdrawal_fee,
+            Action::InitBridgeAccount(_) => fees.init_bridge_account_fee,
+            Action::ValidatorUpdate(_)
+            | Action::SudoAddressChange(_)
+            | Action::Ibc(_)
+            | Action::IbcRelayerChange(_)
+            | Action::FeeAssetChange(_)
+            | Action::FeeChange(_)
+            | Action::Mint(_) => 0,
+        };
+        total_fee = total_fee
+            .checked_add(fee)
+            .context("total fee of transaction overflowed")?;
+    }
+    Ok(total_fee)
+}
+
+/// Returns the value of the `action` label used for per-action metrics.
+fn action_metric_label(action: &Action) -> &'static str {
+    match action {
+        Action::Transfer(_) => "transfer",
+        Action::Sequence(_) => "sequence",
+        Action::ValidatorUpdate(_) => "validator_update",
+        Action::SudoAddressChange(_) => "sudo_address_change",
+        Action::Ibc(_) => "ibc",
+        Action::Ics20Withdrawal(_) => "ics20_withdrawal",
+        Action::IbcRelayerChange(_) => "ibc_relayer_change",
+        Action::FeeAssetChange(_) => "fee_asset_change",
+        Action::InitBridgeAccount(_) => "init_bridge_account",
+        Action::BridgeLock(_) => "bridge_lock",
+        Action::BridgeUnlock(_) => "bridge_unlock",
+        Action::FeeChange(_) => "fee_change",
+        Action::Mint(_) => "mint",
+    }
+}
+
 pub(crate) async fn check_stateless(tx: &SignedTransaction) -> anyhow::Result<()> {
     tx.unsigned_transaction()
         .check_stateless()
@@ -170,6 +253,21 @@ impl fmt::Display for InvalidNonce {
 
 impl std::error::Error for InvalidNonce {}
 
+#[derive(Debug)]
+pub(crate) struct InvalidChainId(pub(crate) String);
+
+impl fmt::Display for InvalidChainId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "provided chain id {} does not match expected chain id",
+            self.0,
+        )
+    }
+}
+
+impl std::error::Error for InvalidChainId {}
+
 #[async_trait::async_trait]
 impl ActionHandler for UnsignedTransaction {
     async fn check_stateless(&self) -> anyhow::Result<()> {
@@ -222,6 +320,14 @@ impl ActionHandler for UnsignedTransaction {
                     .check_stateless()
                     .await
                     .context("stateless check failed for BridgeLockAction")?,
+                Action::BridgeUnlock(act) => act
+                    .check_stateless()
+                    .await
+                    .context("stateless check failed for BridgeUnlockAction")?,
+                Action::FeeChange(act) => act
+                    .check_stateless()
+                    .await
+                    .context("stateless check failed for FeeChangeAction")?,
                 #[cfg(feature = "mint")]
                 Action::Mint(act) => act
                     .check_stateless()
@@ -239,10 +345,23 @@ impl ActionHandler for UnsignedTransaction {
         state: &S,
         from: Address,
     ) -> anyhow::Result<()> {
+        // Transactions must be bound to this chain to prevent replays across networks.
+        let chain_id = state
+            .get_chain_id()
+            .await
+            .context("failed to get chain id")?;
+        ensure!(
+            self.params.chain_id == chain_id,
+            InvalidChainId(self.params.chain_id.clone())
+        );
+
         // Nonce should be equal to the number of executed transactions before this tx.
         // First tx has nonce 0.
         let curr_nonce = state.get_account_nonce(from).await?;
-        ensure!(curr_nonce == self.nonce, InvalidNonce(self.nonce));
+        ensure!(
+            curr_nonce == self.params.nonce,
+            InvalidNonce(self.params.nonce)
+        );
 
         for action in &self.actions {
             match action {
@@ -291,6 +410,14 @@ impl ActionHandler for UnsignedTransaction {
                     .check_stateful(state, from)
                     .await
                     .context("stateful check failed for BridgeLockAction")?,
+                Action::BridgeUnlock(act) => act
+                    .check_stateful(state, from)
+                    .await
+                    .context("stateful check failed for BridgeUnlockAction")?,
+                Action::FeeChange(act) => act
+                    .check_stateful(state, from)
+                    .await
+                    .context("stateful check failed for FeeChangeAction")?,
                 #[cfg(feature = "mint")]
                 Action::Mint(act) => act
                     .check_stateful(state, from)
@@ -307,7 +434,7 @@ impl ActionHandler for UnsignedTransaction {
     #[instrument(
         skip_all,
         fields(
-            nonce = self.nonce,
+            nonce = self.params.nonce,
             from = from.to_string(),
         )
     )]
@@ -324,27 +451,23 @@ impl ActionHandler for UnsignedTransaction {
             .context("failed updating `from` nonce")?;
 
         for action in &self.actions {
-            match action {
-                Action::Transfer(act) => {
-                    act.execute(state, from)
-                        .await
-                        .context("execution failed for TransferAction")?;
-                }
-                Action::Sequence(act) => {
-                    act.execute(state, from)
-                        .await
-                        .context("execution failed for SequenceAction")?;
-                }
-                Action::ValidatorUpdate(act) => {
-                    act.execute(state, from)
-                        .await
-                        .context("execution failed for ValidatorUpdateAction")?;
-                }
-                Action::SudoAddressChange(act) => {
-                    act.execute(state, from)
-                        .await
-                        .context("execution failed for SudoAddressChangeAction")?;
-                }
+            let result = match action {
+                Action::Transfer(act) => act
+                    .execute(state, from)
+                    .await
+                    .context("execution failed for TransferAction"),
+                Action::Sequence(act) => act
+                    .execute(state, from)
+                    .await
+                    .context("execution failed for SequenceAction"),
+                Action::ValidatorUpdate(act) => act
+                    .execute(state, from)
+                    .await
+                    .context("execution failed for ValidatorUpdateAction"),
+                Action::SudoAddressChange(act) => act
+                    .execute(state, from)
+                    .await
+                    .context("execution failed for SudoAddressChangeAction"),
                 Action::Ibc(act) => {
                     let action = act
                         .clone()
@@ -352,42 +475,58 @@ impl ActionHandler for UnsignedTransaction {
                     action
                         .execute(&mut *state)
                         .await
-                        .context("execution failed for IbcAction")?;
-                }
-                Action::Ics20Withdrawal(act) => {
-                    act.execute(state, from)
-                        .await
-                        .context("execution failed for Ics20WithdrawalAction")?;
-                }
-                Action::IbcRelayerChange(act) => {
-                    act.execute(state, from)
-                        .await
-                        .context("execution failed for IbcRelayerChangeAction")?;
-                }
-                Action::FeeAssetChange(act) => {
-                    act.execute(state, from)
-                        .await
-                        .context("execution failed for FeeAssetChangeAction")?;
-                }
-                Action::InitBridgeAccount(act) => {
-                    act.execute(state, from)
-                        .await
-                        .context("execution failed for InitBridgeAccountAction")?;
-                }
-                Action::BridgeLock(act) => {
-                    act.execute(state, from)
-                        .await
-                        .context("execution failed for BridgeLockAction")?;
+                        .context("execution failed for IbcAction")
                 }
+                Action::Ics20Withdrawal(act) => act
+                    .execute(state, from)
+                    .await
+                    .context("execution failed for Ics20WithdrawalAction"),
+                Action::IbcRelayerChange(act) => act
+                    .execute(state, from)
+                    .await
+                    .context("execution failed for IbcRelayerChangeAction"),
+                Action::FeeAssetChange(act) => act
+                    .execute(state, from)
+                    .await
+                    .context("execution failed for FeeAssetChangeAction"),
+                Action::InitBridgeAccount(act) => act
+                    .execute(state, from)
+                    .await
+                    .context("execution failed for InitBridgeAccountAction"),
+                Action::BridgeLock(act) => act
+                    .execute(state, from)
+                    .await
+                    .context("execution failed for BridgeLockAction"),
+                Action::BridgeUnlock(act) => act
+                    .execute(state, from)
+                    .await
+                    .context("execution failed for BridgeUnlockAction"),
+                Action::FeeChange(act) => act
+                    .execute(state, from)
+                    .await
+                    .context("execution failed for FeeChangeAction"),
                 #[cfg(feature = "mint")]
-                Action::Mint(act) => {
-                    act.execute(state, from)
-                        .await
-                        .context("execution failed for MintAction")?;
-                }
+                Action::Mint(act) => act
+                    .execute(state, from)
+                    .await
+                    .context("execution failed for MintAction"),
                 #[cfg(not(feature = "mint"))]
-                _ => bail!("unsupported action type: {:?}", action),
+                _ => Err(anyhow!("unsupported action type: {:?}", action)),
+            };
+
+            metrics::counter!(
+                metrics_init::ACTION_EXECUTION_COUNT,
+                "action" => action_metric_label(action),
+            )
+            .increment(1);
+            if result.is_err() {
+                metrics::counter!(
+                    metrics_init::ACTION_EXECUTION_FAILURE_COUNT,
+                    "action" => action_metric_label(action),
+                )
+                .increment(1);
             }
+            result?;
         }
 
         Ok(())
@@ -406,12 +545,16 @@ mod test {
             TransferAction,
         },
         RollupId,
+        TransactionParams,
         ADDRESS_LEN,
     };
     use cnidarium::StateDelta;
 
     use super::*;
-    use crate::app::test_utils::*;
+    use crate::{
+        app::test_utils::*,
+        fees::state_ext::StateWriteExt as _,
+    };
 
     #[tokio::test]
     async fn check_balance_mempool_ok() {
@@ -423,6 +566,9 @@ mod test {
         let native_asset = crate::asset::get_native_asset().id();
         let other_asset = Denom::from_base_denom("other").id();
 
+        let fees = default_fees();
+        state_tx.put_fee_schedule(fees).unwrap();
+
         let (alice_signing_key, alice_address) = get_alice_signing_key_and_address();
         let amount = 100;
         let data = [0; 32].to_vec();
@@ -430,7 +576,8 @@ mod test {
             .increase_balance(
                 alice_address,
                 native_asset,
-                TRANSFER_FEE + crate::sequence::calculate_fee(&data).unwrap(),
+                fees.transfer_fee
+                    + crate::sequence::calculate_fee(&data, fees.sequence_byte_fee).unwrap(),
             )
             .await
             .unwrap();
@@ -454,8 +601,11 @@ mod test {
         ];
 
         let tx = UnsignedTransaction {
-            nonce: 0,
             actions,
+            params: TransactionParams {
+                nonce: 0,
+                chain_id: "test".to_string(),
+            },
         };
 
         let signed_tx = tx.into_signed(&alice_signing_key);
@@ -464,6 +614,74 @@ mod test {
             .expect("sufficient balance for all actions");
     }
 
+    #[test]
+    fn get_total_fee_sums_fees_of_all_actions() {
+        let (alice_signing_key, _) = get_alice_signing_key_and_address();
+        let asset = Denom::from_base_denom(DEFAULT_NATIVE_ASSET_DENOM).id();
+        let data = vec![0; 32];
+        let tx = UnsignedTransaction {
+            actions: vec![
+                Action::Transfer(TransferAction {
+                    asset_id: asset,
+                    amount: 100,
+                    fee_asset_id: asset,
+                    to: [0; ADDRESS_LEN].into(),
+                }),
+                Action::Sequence(SequenceAction {
+                    rollup_id: RollupId::from_unhashed_bytes([0; 32]),
+                    data: data.clone(),
+                    fee_asset_id: asset,
+                }),
+            ],
+            params: TransactionParams {
+                nonce: 0,
+                chain_id: "test".to_string(),
+            },
+        }
+        .into_signed(&alice_signing_key);
+
+        let fees = default_fees();
+        assert_eq!(
+            get_total_fee(&tx, &fees).unwrap(),
+            fees.transfer_fee
+                + crate::sequence::calculate_fee(&data, fees.sequence_byte_fee).unwrap(),
+        );
+    }
+
+    #[tokio::test]
+    async fn check_chain_id_mempool_mismatch() {
+        use crate::state_ext::StateWriteExt as _;
+
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state_tx = StateDelta::new(snapshot);
+        state_tx.put_chain_id("test".to_string());
+
+        let (alice_signing_key, _) = get_alice_signing_key_and_address();
+        let tx = |chain_id: &str| {
+            UnsignedTransaction {
+                actions: vec![Action::Sequence(SequenceAction {
+                    rollup_id: RollupId::from_unhashed_bytes([0; 32]),
+                    data: vec![0; 32],
+                    fee_asset_id: Denom::from_base_denom(DEFAULT_NATIVE_ASSET_DENOM).id(),
+                })],
+                params: TransactionParams {
+                    nonce: 0,
+                    chain_id: chain_id.to_string(),
+                },
+            }
+            .into_signed(&alice_signing_key)
+        };
+
+        check_chain_id_mempool(&tx("test"), &state_tx)
+            .await
+            .expect("chain id matches");
+        let err = check_chain_id_mempool(&tx("other"), &state_tx)
+            .await
+            .expect_err("chain id does not match");
+        assert!(err.downcast_ref::<InvalidChainId>().is_some());
+    }
+
     #[tokio::test]
     async fn check_balance_mempool_insufficient_other_asset_balance() {
         let storage = cnidarium::TempStorage::new().await.unwrap();
@@ -474,6 +692,9 @@ mod test {
         let native_asset = crate::asset::get_native_asset().id();
         let other_asset = Denom::from_base_denom("other").id();
 
+        let fees = default_fees();
+        state_tx.put_fee_schedule(fees).unwrap();
+
         let (alice_signing_key, alice_address) = get_alice_signing_key_and_address();
         let amount = 100;
         let data = [0; 32].to_vec();
@@ -481,7 +702,8 @@ mod test {
             .increase_balance(
                 alice_address,
                 native_asset,
-                TRANSFER_FEE + crate::sequence::calculate_fee(&data).unwrap(),
+                fees.transfer_fee
+                    + crate::sequence::calculate_fee(&data, fees.sequence_byte_fee).unwrap(),
             )
             .await
             .unwrap();
@@ -501,8 +723,11 @@ mod test {
         ];
 
         let tx = UnsignedTransaction {
-            nonce: 0,
             actions,
+            params: TransactionParams {
+                nonce: 0,
+                chain_id: "test".to_string(),
+            },
         };
 
         let signed_tx = tx.into_signed(&alice_signing_key);
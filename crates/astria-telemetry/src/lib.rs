@@ -14,12 +14,14 @@ use std::{
         AddrParseError,
         SocketAddr,
     },
+    time::Duration,
 };
 
 use metrics_exporter_prometheus::{
     BuildError,
     PrometheusBuilder,
 };
+use metrics_util::MetricKindMask;
 use opentelemetry::{
     global,
     trace::TracerProvider as _,
@@ -136,6 +138,7 @@ pub struct Config {
     metrics_addr: Option<String>,
     service_name: String,
     metric_buckets: Option<Vec<f64>>,
+    gauge_idle_timeout: Option<Duration>,
 }
 
 impl Config {
@@ -150,6 +153,7 @@ impl Config {
             metrics_addr: None,
             service_name: String::new(),
             metric_buckets: None,
+            gauge_idle_timeout: None,
         }
     }
 }
@@ -237,6 +241,15 @@ impl Config {
         }
     }
 
+    /// Stops exporting gauges that were not updated for `gauge_idle_timeout`.
+    #[must_use = "telemetry must be initialized to be useful"]
+    pub fn gauge_idle_timeout(self, gauge_idle_timeout: Duration) -> Self {
+        Self {
+            gauge_idle_timeout: Some(gauge_idle_timeout),
+            ..self
+        }
+    }
+
     /// Initialize telemetry, consuming the config.
     ///
     /// # Errors
@@ -252,6 +265,7 @@ impl Config {
             metrics_addr,
             service_name,
             metric_buckets,
+            gauge_idle_timeout,
         } = self;
 
         let env_filter = {
@@ -322,6 +336,11 @@ impl Config {
                     .map_err(Error::bucket_error)?;
             }
 
+            if let Some(gauge_idle_timeout) = gauge_idle_timeout {
+                metrics_builder =
+                    metrics_builder.idle_timeout(MetricKindMask::GAUGE, Some(gauge_idle_timeout));
+            }
+
             metrics_builder.install().map_err(Error::exporter_install)?;
         }
 
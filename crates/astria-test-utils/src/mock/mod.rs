@@ -2,3 +2,5 @@
 pub mod geth;
 #[cfg(feature = "geth")]
 pub use geth::Geth;
+#[cfg(feature = "sequencer")]
+pub mod sequencer;
@@ -0,0 +1,71 @@
+//! Mocks of the sequencer's cometbft JSONRPC endpoints for testing against a [`MockServer`].
+
+use serde_json::json;
+use tendermint_rpc::{
+    response,
+    Id,
+};
+use wiremock::{
+    matchers::body_partial_json,
+    Mock,
+    MockServer,
+    ResponseTemplate,
+};
+
+/// Mount a mock for the `status` endpoint, reporting `chain_id` as the network of the node.
+///
+/// # Panics
+///
+/// Panics if the mocked response cannot be deserialized, which is a bug in this function.
+pub async fn mount_status_mock(server: &MockServer, chain_id: &str) {
+    let response: tendermint_rpc::endpoint::status::Response = serde_json::from_value(json!({
+        "node_info": {
+            "protocol_version": {
+                "p2p": "8",
+                "block": "11",
+                "app": "0"
+            },
+            "id": "a1d3bbddb7800c6da2e64169fec281494e963ba3",
+            "listen_addr": "tcp://0.0.0.0:26656",
+            "network": chain_id,
+            "version": "0.37.4",
+            "channels": "40202122233038606100",
+            "moniker": "test",
+            "other": {
+                "tx_index": "on",
+                "rpc_address": "tcp://0.0.0.0:26657"
+            }
+        },
+        "sync_info": {
+            "latest_block_hash": "0000000000000000000000000000000000000000000000000000000000000000",
+            "latest_app_hash": "0000000000000000000000000000000000000000000000000000000000000000",
+            "latest_block_height": "1",
+            "latest_block_time": "1970-01-01T00:00:00Z",
+            "earliest_block_hash": "0000000000000000000000000000000000000000000000000000000000000000",
+            "earliest_app_hash": "0000000000000000000000000000000000000000000000000000000000000000",
+            "earliest_block_height": "1",
+            "earliest_block_time": "1970-01-01T00:00:00Z",
+            "catching_up": false
+        },
+        "validator_info": {
+            "address": "F1B2AE8E2A1D1F2D26F2B0B7F4F6A4F8C4E2D6A0",
+            "pub_key": {
+                "type": "tendermint/PubKeyEd25519",
+                "value": "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA="
+            },
+            "voting_power": "0"
+        }
+    }))
+    .unwrap();
+    let wrapper = response::Wrapper::new_with_id(Id::Num(1), Some(response), None);
+    Mock::given(body_partial_json(json!({
+        "method": "status"
+    })))
+    .respond_with(
+        ResponseTemplate::new(200)
+            .set_body_json(&wrapper)
+            .append_header("Content-Type", "application/json"),
+    )
+    .mount(server)
+    .await;
+}